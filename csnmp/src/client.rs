@@ -286,6 +286,10 @@ pub enum SnmpClientError {
 
     /// The operation took longer than allowed by the timeout value.
     TimedOut,
+
+    /// The agent refused the request with a non-zero error status; `error_index` is the 1-based
+    /// position of the offending variable binding, or 0 when the error concerns no binding.
+    ErrorResponse { error_status: ErrorStatus, error_index: u32 },
 }
 impl fmt::Display for SnmpClientError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -320,6 +324,8 @@ impl fmt::Display for SnmpClientError {
                 => write!(f, "failed binding encountered: {:?}", binding),
             Self::TimedOut
                 => write!(f, "operation timed out"),
+            Self::ErrorResponse { error_status, error_index }
+                => write!(f, "agent responded with {} at binding {}", error_status, error_index),
         }
     }
 }
@@ -341,6 +347,7 @@ impl std::error::Error for SnmpClientError {
             SnmpClientError::DuplicateValue { .. } => None,
             SnmpClientError::FailedBinding { .. } => None,
             SnmpClientError::TimedOut => None,
+            SnmpClientError::ErrorResponse { .. } => None,
         }
     }
 }
@@ -684,6 +691,10 @@ impl LowLevelSnmp2cClient {
             options.receive_timeout,
         ).await?;
 
+        if pdu.error_status != ErrorStatus::NoError {
+            return Err(SnmpClientError::ErrorResponse { error_status: pdu.error_status, error_index: pdu.error_index });
+        }
+
         if pdu.variable_bindings.len() != 1 {
             return Err(SnmpClientError::BindingCount { expected: 1, obtained: pdu.variable_bindings });
         }
@@ -735,6 +746,10 @@ impl LowLevelSnmp2cClient {
             options.receive_timeout,
         ).await?;
 
+        if pdu.error_status != ErrorStatus::NoError {
+            return Err(SnmpClientError::ErrorResponse { error_status: pdu.error_status, error_index: pdu.error_index });
+        }
+
         if pdu.variable_bindings.len() != binding_count {
             return Err(SnmpClientError::BindingCount { expected: binding_count, obtained: pdu.variable_bindings });
         }
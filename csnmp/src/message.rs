@@ -566,6 +566,34 @@ pub enum ErrorStatus {
     InconsistentName = 18,
 }
 
+impl fmt::Display for ErrorStatus {
+    /// The name of the status as written in RFC 3416, such as `notWritable`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::NoError => "noError",
+            Self::TooBig => "tooBig",
+            Self::NoSuchName => "noSuchName",
+            Self::BadValue => "badValue",
+            Self::ReadOnly => "readOnly",
+            Self::GenErr => "genErr",
+            Self::NoAccess => "noAccess",
+            Self::WrongType => "wrongType",
+            Self::WrongLength => "wrongLength",
+            Self::WrongEncoding => "wrongEncoding",
+            Self::WrongValue => "wrongValue",
+            Self::NoCreation => "noCreation",
+            Self::InconsistentValue => "inconsistentValue",
+            Self::ResourceUnavailable => "resourceUnavailable",
+            Self::CommitFailed => "commitFailed",
+            Self::UndoFailed => "undoFailed",
+            Self::AuthorizationError => "authorizationError",
+            Self::NotWritable => "notWritable",
+            Self::InconsistentName => "inconsistentName",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct InnerPdu {
     pub request_id: i32,
@@ -0,0 +1,587 @@
+pub mod AlertModule {
+    use serde::{Deserialize, Serialize};
+    use std::cmp::Ordering;
+    use std::collections::{BTreeMap, HashSet, VecDeque};
+    use std::fs::{self, OpenOptions};
+    use std::io::{self, Write};
+    use std::path::Path;
+
+    use crate::config::ConfigModule::{AlertConfig, AlertRule, Severity};
+    use crate::enums::EnumModule;
+    use crate::mibobject::MibModule::MibObject;
+
+    /// how many events are kept in memory for the GUI
+    const RECENT_EVENTS: usize = 200;
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Subject {
+        Value(String),
+        Rate(String),
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum Op {
+        Eq,
+        Ne,
+        Gt,
+        Ge,
+        Lt,
+        Le,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Operand {
+        /// a number, already scaled to per second when written as a rate like `10/s` or `600/m`
+        Number(f64),
+        /// a bare word, either an enum label like `up` or a string to compare against
+        Word(String),
+        /// a quoted string
+        Text(String),
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Check {
+        Compare(Op, Operand),
+        Decreased,
+        Changed,
+    }
+
+    /// a parsed rule expression such as `rate(ifInErrors) > 10/s for 5m`
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Condition {
+        pub subject: Subject,
+        pub check: Check,
+        pub for_secs: i64,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum AlertStatus {
+        Pending,
+        Firing,
+        Resolved,
+    }
+
+    /// an alert that is currently pending or firing for one instance of a rule
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct Alert {
+        pub target: String,
+        pub rule: String,
+        pub instance: String,
+        pub severity: Severity,
+        pub status: AlertStatus,
+        pub since: i64,
+        pub breached_since: i64,
+        pub cleared_since: Option<i64>,
+        pub value: String,
+    }
+
+    /// one state transition, written to the event log
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct AlertEvent {
+        pub timestamp: i64,
+        pub target: String,
+        pub rule: String,
+        pub instance: String,
+        pub severity: Severity,
+        pub status: AlertStatus,
+        pub value: String,
+        pub message: String,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    struct Previous {
+        timestamp: i64,
+        text: String,
+        number: Option<i64>,
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct EngineState {
+        alerts: BTreeMap<String, Alert>,
+        previous: BTreeMap<String, Previous>,
+    }
+
+    /// one value instance of a polled object
+    struct Instance {
+        base_name: String,
+        base_oid: String,
+        name: String,
+        key: String,
+        text: String,
+        number: Option<i64>,
+    }
+
+    impl Condition {
+        pub fn parse(expr: &str) -> Result<Self, String> {
+            let expr = expr.trim();
+            // a " for " inside a quoted string is part of the string, not the start of a duration
+            let (expr, for_secs) = match expr.rsplit_once(" for ").filter(|(rest, _)| rest.matches('"').count() % 2 == 0) {
+                Some((rest, duration)) => (rest.trim(), parse_duration(duration.trim())?),
+                None => (expr, 0),
+            };
+            if let Some(subject) = expr.strip_suffix(" decreased") {
+                return Ok(Condition { subject: parse_subject(subject)?, check: Check::Decreased, for_secs });
+            }
+            if let Some(subject) = expr.strip_suffix(" changed") {
+                return Ok(Condition { subject: parse_subject(subject)?, check: Check::Changed, for_secs });
+            }
+            let ops = [("!=", Op::Ne), (">=", Op::Ge), ("<=", Op::Le), ("==", Op::Eq), (">", Op::Gt), ("<", Op::Lt), ("=", Op::Eq)];
+            let (position, token, op) = ops
+                .iter()
+                .filter_map(|(token, op)| expr.find(token).map(|position| (position, *token, *op)))
+                .min_by_key(|a| (a.0, usize::MAX - a.1.len()))
+                .ok_or(format!("no comparison in '{}'", expr))?;
+            let subject = parse_subject(&expr[..position])?;
+            let operand = parse_operand(expr[position + token.len()..].trim())?;
+            Ok(Condition { subject, check: Check::Compare(op, operand), for_secs })
+        }
+
+        fn needs_previous(&self) -> bool {
+            matches!(self.subject, Subject::Rate(_)) || matches!(self.check, Check::Decreased | Check::Changed)
+        }
+
+        /// the numeric value the rule compares, `None` for a rate without a usable previous sample
+        fn measure(&self, instance: &Instance, previous: Option<&Previous>, now: i64) -> Option<f64> {
+            match &self.subject {
+                Subject::Value(_) => instance.number.map(|a| a as f64),
+                Subject::Rate(_) => {
+                    let previous = previous?;
+                    let (value, last) = (instance.number?, previous.number?);
+                    // a counter that went backwards wrapped or was reset, skip the sample
+                    match now > previous.timestamp && value >= last {
+                        true => Some((value - last) as f64 / (now - previous.timestamp) as f64),
+                        false => None,
+                    }
+                }
+            }
+        }
+
+        fn breached(&self, instance: &Instance, measured: Option<f64>, previous: Option<&Previous>) -> bool {
+            match &self.check {
+                Check::Decreased => match (instance.number, previous.and_then(|a| a.number)) {
+                    (Some(value), Some(last)) => value < last,
+                    _ => false,
+                },
+                Check::Changed => previous.map(|a| a.text != instance.text).unwrap_or(false),
+                Check::Compare(op, Operand::Number(threshold)) => match measured {
+                    Some(value) => compare(*op, value.partial_cmp(threshold)),
+                    None => false,
+                },
+                Check::Compare(op, Operand::Word(word)) => match (enum_value(&instance.base_name, word), measured) {
+                    (Some(label), Some(value)) => compare(*op, value.partial_cmp(&(label as f64))),
+                    _ => compare(*op, Some(instance.text.as_str().cmp(word))),
+                },
+                Check::Compare(op, Operand::Text(text)) => compare(*op, Some(instance.text.as_str().cmp(text))),
+            }
+        }
+
+        /// whether a firing alert may resolve, honouring the rule's `clear` threshold
+        fn cleared(&self, rule: &AlertRule, measured: Option<f64>) -> bool {
+            match (rule.clear, &self.check, measured) {
+                (Some(clear), Check::Compare(Op::Gt | Op::Ge, _), Some(value)) => value < clear,
+                (Some(clear), Check::Compare(Op::Lt | Op::Le, _), Some(value)) => value > clear,
+                _ => true,
+            }
+        }
+    }
+
+    impl Subject {
+        fn matches(&self, instance: &Instance) -> bool {
+            let name = match self {
+                Subject::Value(name) => name,
+                Subject::Rate(name) => name,
+            };
+            [&instance.base_name, &instance.name, &instance.base_oid, &instance.key].contains(&name)
+        }
+    }
+
+    fn compare(op: Op, ordering: Option<Ordering>) -> bool {
+        match ordering {
+            Some(ordering) => match op {
+                Op::Eq => ordering == Ordering::Equal,
+                Op::Ne => ordering != Ordering::Equal,
+                Op::Gt => ordering == Ordering::Greater,
+                Op::Ge => ordering != Ordering::Less,
+                Op::Lt => ordering == Ordering::Less,
+                Op::Le => ordering != Ordering::Greater,
+            },
+            None => false,
+        }
+    }
+
+    fn parse_subject(subject: &str) -> Result<Subject, String> {
+        let subject = subject.trim();
+        let subject = match subject.strip_prefix("rate(").and_then(|a| a.strip_suffix(')')) {
+            Some(name) => Subject::Rate(name.trim().to_owned()),
+            None => Subject::Value(subject.to_owned()),
+        };
+        match &subject {
+            Subject::Value(name) | Subject::Rate(name) if name.is_empty() || name.contains(char::is_whitespace) => {
+                Err(format!("invalid subject '{}'", name))
+            }
+            _ => Ok(subject),
+        }
+    }
+
+    fn parse_operand(operand: &str) -> Result<Operand, String> {
+        if operand.is_empty() {
+            return Err("missing value to compare against".to_owned());
+        }
+        if let Some(text) = operand.strip_prefix('"').and_then(|a| a.strip_suffix('"')) {
+            return Ok(Operand::Text(text.to_owned()));
+        }
+        let (number, per) = match operand.split_once('/') {
+            Some((number, unit)) => (number, Some(parse_duration(&["1", unit].concat())?)),
+            None => (operand, None),
+        };
+        match (number.parse::<f64>(), per) {
+            (Ok(number), Some(per)) => Ok(Operand::Number(number / per as f64)),
+            (Ok(number), None) => Ok(Operand::Number(number)),
+            (Err(_), None) => Ok(Operand::Word(operand.to_owned())),
+            (Err(_), Some(_)) => Err(format!("invalid rate '{}'", operand)),
+        }
+    }
+
+    /// parses durations like `30s`, `5m` or `1h` into seconds
+    fn parse_duration(duration: &str) -> Result<i64, String> {
+        let unit = match duration.chars().last() {
+            Some('s') => 1,
+            Some('m') => 60,
+            Some('h') => 3600,
+            Some('d') => 86400,
+            _ => return Err(format!("invalid duration '{}'", duration)),
+        };
+        match duration[..duration.len() - 1].parse::<i64>() {
+            Ok(count) => Ok(count * unit),
+            Err(_) => Err(format!("invalid duration '{}'", duration)),
+        }
+    }
+
+    /// the numeric value of an enum label of the named object, e.g. `up` for `ifOperStatus`;
+    /// objects without known labels are compared as TruthValues
+    fn enum_value(name: &str, word: &str) -> Option<i64> {
+        EnumModule::value(name, word).or_else(|| ["true", "false"].iter().position(|a| *a == word).map(|a| a as i64 + 1))
+    }
+
+    fn instances(object: &MibObject) -> Vec<Instance> {
+        object
+            .values()
+            .into_iter()
+            .flat_map(|value| {
+                let base_name = value.get_name();
+                let base_oid = value.get_oid().iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".");
+                let numbers = value.val_as_mvinti64().unwrap_or_default();
+                value
+                    .display_values()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(position, text)| {
+                        let suffix = value.instance_index(position).iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".");
+                        Instance {
+                            name: [base_name.clone(), suffix.clone()].join("."),
+                            key: [base_oid.clone(), suffix].join("."),
+                            base_name: base_name.clone(),
+                            base_oid: base_oid.clone(),
+                            text,
+                            number: numbers.get(position).copied(),
+                        }
+                    })
+                    .collect::<Vec<Instance>>()
+            })
+            .collect()
+    }
+
+    /// evaluates the configured rules against every polled object and tracks alert state
+    pub struct AlertEngine {
+        config: AlertConfig,
+        rules: Vec<(AlertRule, Condition)>,
+        state: EngineState,
+        recent: VecDeque<AlertEvent>,
+    }
+
+    impl AlertEngine {
+        /// parses the rules, skipping invalid ones, and restores the persisted alert state
+        pub fn new(config: AlertConfig) -> Self {
+            let rules = config
+                .rules
+                .iter()
+                .filter_map(|rule| match Condition::parse(&rule.expr) {
+                    Ok(condition) => Some((rule.clone(), condition)),
+                    Err(err) => {
+                        println!("skipping alert rule '{}': {}", rule.name, err);
+                        None
+                    }
+                })
+                .collect();
+            let state = match fs::read_to_string(&config.state_file) {
+                Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|err| {
+                    println!("could not parse {}, starting without alert state: {}", config.state_file, err);
+                    EngineState::default()
+                }),
+                Err(_) => EngineState::default(),
+            };
+            AlertEngine { config, rules, state, recent: VecDeque::new() }
+        }
+
+        /// runs every rule against `object` and returns the resulting state transitions
+        pub fn evaluate(&mut self, object: &MibObject) -> Vec<AlertEvent> {
+            let now = object.timestamp;
+            let target = object.target.clone();
+            let instances = instances(object);
+            let mut events = vec![];
+            let mut seen = HashSet::new();
+            let mut tracked = HashSet::new();
+
+            for (rule, condition) in &self.rules {
+                if !rule.targets.is_empty() && !rule.targets.contains(&target) {
+                    continue;
+                }
+                if !rule.profile.is_empty() && rule.profile != object.vendor.profile {
+                    continue;
+                }
+                for instance in instances.iter().filter(|a| condition.subject.matches(a)) {
+                    let previous_key = format!("{}|{}", target, instance.key);
+                    if condition.needs_previous() {
+                        tracked.insert(previous_key.clone());
+                    }
+                    let previous = self.state.previous.get(&previous_key);
+                    let measured = condition.measure(instance, previous, now);
+                    let id = format!("{}|{}|{}", target, rule.name, instance.name);
+                    seen.insert(id.clone());
+                    if matches!(condition.subject, Subject::Rate(_)) && measured.is_none() {
+                        continue;
+                    }
+                    let value = measured.map(|a| a.to_string()).unwrap_or(instance.text.clone());
+
+                    if condition.breached(instance, measured, previous) {
+                        let alert = self.state.alerts.entry(id).or_insert_with(|| Alert {
+                            target: target.clone(),
+                            rule: rule.name.clone(),
+                            instance: instance.name.clone(),
+                            severity: rule.severity,
+                            status: AlertStatus::Pending,
+                            since: now,
+                            breached_since: now,
+                            cleared_since: None,
+                            value: value.clone(),
+                        });
+                        alert.value = value;
+                        alert.cleared_since = None;
+                        if alert.status == AlertStatus::Pending && alert.since == now && condition.for_secs > 0 {
+                            events.push(event(alert, now));
+                        }
+                        if alert.status == AlertStatus::Pending && now - alert.breached_since >= condition.for_secs {
+                            alert.status = AlertStatus::Firing;
+                            alert.since = now;
+                            events.push(event(alert, now));
+                        }
+                    } else if let Some(alert) = self.state.alerts.get_mut(&id) {
+                        alert.value = value;
+                        match alert.status {
+                            AlertStatus::Firing if condition.cleared(rule, measured) => {
+                                let cleared_since = *alert.cleared_since.get_or_insert(now);
+                                if now - cleared_since >= rule.clear_for_secs {
+                                    alert.status = AlertStatus::Resolved;
+                                    events.push(event(alert, now));
+                                    self.state.alerts.remove(&id);
+                                }
+                            }
+                            AlertStatus::Firing => alert.cleared_since = None,
+                            _ => {
+                                self.state.alerts.remove(&id);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // instances that disappeared from the target, or rules that were removed, resolve
+            let gone = self
+                .state
+                .alerts
+                .iter()
+                .filter(|a| a.1.target == target && !seen.contains(a.0))
+                .map(|a| a.0.clone())
+                .collect::<Vec<String>>();
+            for id in gone {
+                let mut alert = self.state.alerts.remove(&id).unwrap();
+                if alert.status == AlertStatus::Firing {
+                    alert.status = AlertStatus::Resolved;
+                    events.push(event(&alert, now));
+                }
+            }
+
+            for instance in &instances {
+                let previous_key = format!("{}|{}", target, instance.key);
+                if tracked.contains(&previous_key) {
+                    self.state.previous.insert(
+                        previous_key,
+                        Previous { timestamp: now, text: instance.text.clone(), number: instance.number },
+                    );
+                }
+            }
+
+            if let Err(err) = self.persist(&events) {
+                println!("could not persist alert state: {}", err);
+            }
+            events.iter().for_each(|event| {
+                println!("{}", event.message);
+                self.recent.push_front(event.clone());
+            });
+            self.recent.truncate(RECENT_EVENTS);
+            events
+        }
+
+        fn persist(&self, events: &[AlertEvent]) -> io::Result<()> {
+            for path in [&self.config.state_file, &self.config.event_log] {
+                if let Some(parent) = Path::new(path).parent() {
+                    fs::create_dir_all(parent)?;
+                }
+            }
+            let tmp = [self.config.state_file.as_str(), ".tmp"].concat();
+            fs::write(&tmp, serde_json::to_vec(&self.state)?)?;
+            fs::rename(&tmp, &self.config.state_file)?;
+
+            if !events.is_empty() {
+                let mut log = OpenOptions::new().append(true).create(true).open(&self.config.event_log)?;
+                for event in events {
+                    log.write_all([serde_json::to_string(event)?, "\n".to_owned()].concat().as_bytes())?;
+                }
+            }
+            Ok(())
+        }
+
+        /// pending and firing alerts, most severe first
+        pub fn active(&self) -> Vec<Alert> {
+            let mut alerts = self.state.alerts.values().cloned().collect::<Vec<Alert>>();
+            alerts.sort_by(|a, b| b.severity.cmp(&a.severity).then(a.since.cmp(&b.since)));
+            alerts
+        }
+
+        /// the latest state transitions, newest first
+        pub fn recent(&self) -> Vec<AlertEvent> {
+            self.recent.iter().cloned().collect()
+        }
+    }
+
+    fn event(alert: &Alert, now: i64) -> AlertEvent {
+        AlertEvent {
+            timestamp: now,
+            target: alert.target.clone(),
+            rule: alert.rule.clone(),
+            instance: alert.instance.clone(),
+            severity: alert.severity,
+            status: alert.status,
+            value: alert.value.clone(),
+            message: format!(
+                "[{:?}] {} {:?} on {}: {} = {}",
+                alert.severity, alert.rule, alert.status, alert.target, alert.instance, alert.value
+            ),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::{mvinti32, mvintu32, MibValue};
+
+        fn test_config(name: &str, rules: Vec<AlertRule>) -> AlertConfig {
+            let dir = std::env::temp_dir().join(format!("snmp-monitor-alert-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            AlertConfig {
+                rules,
+                state_file: dir.join("alerts.json").to_string_lossy().into_owned(),
+                event_log: dir.join("alerts.log").to_string_lossy().into_owned(),
+            }
+        }
+
+        fn rule(name: &str, expr: &str) -> AlertRule {
+            AlertRule { name: name.to_owned(), expr: expr.to_owned(), ..AlertRule::default() }
+        }
+
+        fn object(timestamp: i64, oper_status: Vec<i32>, in_errors: u32) -> MibObject {
+            let mut object = MibObject::new();
+            object.target = "192.0.2.1".to_owned();
+            object.timestamp = timestamp;
+            if let MibValue::inti32(mvinti32 { value, .. }) = &mut object.interfaces.ifTable.ifOperStatus {
+                *value = oper_status;
+            }
+            if let MibValue::intu32(mvintu32 { value, .. }) = &mut object.interfaces.ifTable.ifInErrors {
+                *value = vec![in_errors];
+            }
+            object
+        }
+
+        #[test]
+        fn test_parse() {
+            assert_eq!(Condition::parse("ifOperStatus != up").unwrap(), Condition {
+                subject: Subject::Value("ifOperStatus".to_owned()),
+                check: Check::Compare(Op::Ne, Operand::Word("up".to_owned())),
+                for_secs: 0,
+            });
+            assert_eq!(Condition::parse("rate(ifInErrors) > 600/m for 5m").unwrap(), Condition {
+                subject: Subject::Rate("ifInErrors".to_owned()),
+                check: Check::Compare(Op::Gt, Operand::Number(10.0)),
+                for_secs: 300,
+            });
+            assert_eq!(Condition::parse("sysUpTime decreased").unwrap().check, Check::Decreased);
+            assert_eq!(Condition::parse("1.3.6.1.2.1.1.5.0 == \"core-1\"").unwrap().check, Check::Compare(Op::Eq, Operand::Text("core-1".to_owned())));
+            assert!(Condition::parse("ifOperStatus").is_err());
+            assert!(Condition::parse("ifInErrors > 1 for ever").is_err());
+            assert_eq!(Condition::parse("sysDescr == \"switch for lab\"").unwrap(), Condition {
+                subject: Subject::Value("sysDescr".to_owned()),
+                check: Check::Compare(Op::Eq, Operand::Text("switch for lab".to_owned())),
+                for_secs: 0,
+            });
+            assert_eq!(Condition::parse("sysDescr != \"switch for lab\" for 10m").unwrap().for_secs, 600);
+        }
+
+        #[test]
+        fn test_pending_firing_resolved() {
+            let mut errors = rule("errors", "rate(ifInErrors) > 10/s for 60s");
+            errors.clear = Some(5.0);
+            let config = test_config("lifecycle", vec![rule("link", "ifOperStatus != up"), errors]);
+            let mut engine = AlertEngine::new(config.clone());
+
+            assert!(engine.evaluate(&object(0, vec![1, 1], 0)).is_empty());
+            let events = engine.evaluate(&object(30, vec![1, 2], 600));
+            let statuses = events.iter().map(|a| (a.rule.as_str(), a.status)).collect::<Vec<_>>();
+            assert_eq!(statuses, vec![("link", AlertStatus::Firing), ("errors", AlertStatus::Pending)]);
+            assert_eq!(events[0].instance, "ifOperStatus.2");
+
+            let events = engine.evaluate(&object(90, vec![1, 2], 1500));
+            assert_eq!(events.iter().map(|a| a.status).collect::<Vec<_>>(), vec![AlertStatus::Firing]);
+
+            // 7/s is below the threshold but above the clear level, so the alert keeps firing
+            let events = engine.evaluate(&object(120, vec![1, 2], 1710));
+            assert!(events.is_empty());
+            assert_eq!(engine.active().len(), 2);
+
+            // state survives a restart
+            let mut engine = AlertEngine::new(config.clone());
+            let events = engine.evaluate(&object(150, vec![1, 1], 1740));
+            assert_eq!(events.iter().map(|a| (a.rule.as_str(), a.status)).collect::<Vec<_>>(), vec![
+                ("link", AlertStatus::Resolved),
+                ("errors", AlertStatus::Resolved),
+            ]);
+            assert!(engine.active().is_empty());
+            let logged = fs::read_to_string(&config.event_log).unwrap().lines().count();
+            assert_eq!(logged, 5);
+            let _ = fs::remove_dir_all(Path::new(&config.state_file).parent().unwrap());
+        }
+
+        #[test]
+        fn test_profile_rules() {
+            let link = AlertRule { profile: "cisco".to_owned(), ..rule("link", "ifOperStatus != up") };
+            let config = test_config("profile", vec![link]);
+            let mut engine = AlertEngine::new(config.clone());
+            let mut down = object(0, vec![2], 0);
+            down.vendor.profile = "juniper".to_owned();
+            assert!(engine.evaluate(&down).is_empty());
+            down.vendor.profile = "cisco".to_owned();
+            assert_eq!(engine.evaluate(&down).iter().map(|a| a.status).collect::<Vec<_>>(), vec![AlertStatus::Firing]);
+            let _ = fs::remove_dir_all(Path::new(&config.state_file).parent().unwrap());
+        }
+    }
+}
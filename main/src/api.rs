@@ -0,0 +1,291 @@
+pub mod ApiModule {
+    use serde_json::{json, Value};
+    use std::collections::BTreeSet;
+    use std::sync::{Arc, Mutex};
+
+    use crate::http::HttpModule::{Latest, Request, Response};
+    use crate::metrics::MetricsModule::oid_string;
+    use crate::mibobject::MibModule::{table_rows, MibObject};
+    use crate::tsdb::TsdbModule::{Resolution, Tsdb};
+
+    /// page size when the request does not ask for one
+    const DEFAULT_LIMIT: usize = 100;
+    const MAX_LIMIT: usize = 10000;
+
+    fn error(status: u16, message: &str) -> Response {
+        Response::json(status, &json!({ "error": message }))
+    }
+
+    /// one page of `items` as picked by the `offset` and `limit` query parameters
+    fn page(request: &Request, items: Vec<Value>) -> Response {
+        let offset = request.param("offset").and_then(|a| a.parse::<usize>().ok()).unwrap_or(0);
+        let limit = request.param("limit").and_then(|a| a.parse::<usize>().ok()).unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        let total = items.len();
+        let items = items.into_iter().skip(offset).take(limit).collect::<Vec<Value>>();
+        Response::json(200, &json!({ "total": total, "offset": offset, "limit": limit, "items": items }))
+    }
+
+    /// answers every `GET /api/...` request except the poll stream
+    pub fn route(latest: &Latest, tsdb: &Arc<Mutex<Tsdb>>, request: &Request) -> Response {
+        if request.method != "GET" {
+            return error(405, "method not allowed");
+        }
+        let parts = request.path.trim_matches('/').split('/').collect::<Vec<&str>>();
+        match parts[1..] {
+            ["targets"] => targets(latest, tsdb, request),
+            ["targets", target] => match latest.lock().unwrap().get(target) {
+                Some(object) => Response::json(200, &serde_json::to_value(object).unwrap_or_default()),
+                None => error(404, "target has not been polled"),
+            },
+            ["targets", target, "values"] => with_object(latest, target, |object| values(object, request, None)),
+            ["targets", target, "values", value] => with_object(latest, target, |object| values(object, request, Some(value))),
+            ["targets", target, "tables"] => with_object(latest, target, |object| {
+                Response::json(200, &json!(object.tables().iter().map(|a| a.0).chain(object.vendor.tables.iter().map(|a| a.name.as_str())).collect::<Vec<&str>>()))
+            }),
+            ["targets", target, "tables", table] => with_object(latest, target, |object| rows(object, request, table)),
+            ["targets", target, "series"] => series(tsdb, request, target),
+            ["targets", target, "series", key] => points(tsdb, request, target, key),
+            _ => error(404, "not found"),
+        }
+    }
+
+    fn with_object(latest: &Latest, target: &str, answer: impl FnOnce(&MibObject) -> Response) -> Response {
+        match latest.lock().unwrap().get(target) {
+            Some(object) => answer(object),
+            None => error(404, "target has not been polled"),
+        }
+    }
+
+    /// every target that has been polled since the start or has stored history
+    fn targets(latest: &Latest, tsdb: &Arc<Mutex<Tsdb>>, request: &Request) -> Response {
+        let latest = latest.lock().unwrap();
+        let mut targets = latest.keys().cloned().collect::<BTreeSet<String>>();
+        targets.extend(tsdb.lock().unwrap().targets());
+        let items = targets
+            .into_iter()
+            .map(|target| match latest.get(&target) {
+                Some(object) => json!({
+                    "target": target,
+                    "sysName": object.system.sysName.display_values().first(),
+                    "last_poll": object.timestamp,
+                }),
+                None => json!({ "target": target, "sysName": null, "last_poll": null }),
+            })
+            .collect();
+        page(request, items)
+    }
+
+    /// every instance of every value, narrowed to the value named by the path or by `name` (an
+    /// object name, its OID or the OID of one instance), to OIDs under `oid` and to the instance
+    /// `instance`
+    fn values(object: &MibObject, request: &Request, value: Option<&str>) -> Response {
+        let name = value.map(|a| a.to_owned()).or(request.param("name"));
+        let oid = request.param("oid");
+        let instance = request.param("instance");
+        let mut items = vec![];
+        for mib_value in object.values() {
+            let value_oid = oid_string(&mib_value.get_oid());
+            let value_name = mib_value.get_name();
+            if oid.as_ref().is_some_and(|oid| value_oid != *oid && !value_oid.starts_with(&[oid.as_str(), "."].concat())) {
+                continue;
+            }
+            let numbers = mib_value.val_as_mvinti64().unwrap_or_default();
+            for (position, text) in mib_value.display_values().into_iter().enumerate() {
+                let index = mib_value.instance_index(position).iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".");
+                let instance_oid = [value_oid.as_str(), index.as_str()].join(".");
+                if name.as_ref().is_some_and(|name| ![&value_name, &value_oid, &instance_oid].contains(&name)) {
+                    continue;
+                }
+                if instance.as_ref().is_some_and(|instance| *instance != index) {
+                    continue;
+                }
+                items.push(json!({
+                    "name": value_name,
+                    "oid": instance_oid,
+                    "instance": index,
+                    "value": text,
+                    "number": numbers.get(position),
+                }));
+            }
+        }
+        if value.is_some() && items.is_empty() {
+            return error(404, "no such value");
+        }
+        page(request, items)
+    }
+
+    /// the rows of `table`, keeping only rows whose columns equal every query parameter named
+    /// after a column
+    fn rows(object: &MibObject, request: &Request, table: &str) -> Response {
+        let columns = match object.table(table) {
+            Some(columns) => columns,
+            None => return error(404, "no such table"),
+        };
+        let names = columns.iter().map(|a| a.get_name()).collect::<Vec<String>>();
+        let filters = request
+            .params()
+            .into_iter()
+            .filter_map(|(key, value)| names.iter().position(|a| *a == key).map(|column| (column, value)))
+            .collect::<Vec<(usize, String)>>();
+        let items = table_rows(&columns)
+            .into_iter()
+            .filter(|(_, cells)| filters.iter().all(|(column, value)| cells[*column] == *value))
+            .map(|(index, cells)| {
+                let cells = names.iter().cloned().zip(cells.into_iter().map(Value::from)).collect::<serde_json::Map<String, Value>>();
+                json!({ "index": index, "values": cells })
+            })
+            .collect();
+        page(request, items)
+    }
+
+    /// the stored series of `target`, narrowed to those called `name`
+    fn series(tsdb: &Arc<Mutex<Tsdb>>, request: &Request, target: &str) -> Response {
+        let known = match tsdb.lock().unwrap().series(target) {
+            Ok(Some(known)) => known,
+            Ok(None) => return error(404, "nothing is stored for that target"),
+            Err(err) => return error(500, &err.to_string()),
+        };
+        let name = request.param("name");
+        let items = known
+            .into_iter()
+            .filter(|(_, series_name)| name.as_ref().is_none_or(|name| name == series_name))
+            .map(|(key, name)| json!({ "key": key, "name": name }))
+            .collect();
+        page(request, items)
+    }
+
+    /// the samples of `key` between `from` and `to`, raw or rolled up at `resolution`
+    fn points(tsdb: &Arc<Mutex<Tsdb>>, request: &Request, target: &str, key: &str) -> Response {
+        let time = |name: &str, default: i64| request.param(name).map(|a| a.parse::<i64>()).unwrap_or(Ok(default));
+        let (from, to) = match (time("from", 0), time("to", i64::MAX)) {
+            (Ok(from), Ok(to)) => (from, to),
+            _ => return error(400, "from and to take a unix time"),
+        };
+        let resolution = match Resolution::parse(request.param("resolution").as_deref().unwrap_or("raw")) {
+            Some(resolution) => resolution,
+            None => return error(400, "resolution is one of raw, 1m or 1h"),
+        };
+        let mut tsdb = tsdb.lock().unwrap();
+        match tsdb.series(target) {
+            Ok(Some(known)) if known.iter().any(|a| a.0 == key) => (),
+            Ok(Some(_)) => return error(404, "no such series"),
+            Ok(None) => return error(404, "nothing is stored for that target"),
+            Err(err) => return error(500, &err.to_string()),
+        }
+        let items = match resolution {
+            Resolution::Raw => tsdb
+                .query(target, key, from, to)
+                .map(|points| points.into_iter().map(|(timestamp, value)| json!({ "timestamp": timestamp, "value": value })).collect()),
+            _ => tsdb.query_rollup(target, key, resolution, from, to).map(|aggregates| {
+                aggregates
+                    .into_iter()
+                    .map(|a| json!({ "timestamp": a.timestamp, "min": a.min, "max": a.max, "mean": a.mean(), "count": a.count }))
+                    .collect()
+            }),
+        };
+        match items {
+            Ok(items) => page(request, items),
+            Err(err) => error(500, &err.to_string()),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::config::ConfigModule::StorageConfig;
+        use crate::mibobject::MibModule::{mvinti32, mvstring, MibValue};
+        use std::collections::BTreeMap;
+
+        fn request(method: &str, target: &str) -> Request {
+            let (path, query) = target.split_once('?').unwrap_or((target, ""));
+            Request { method: method.to_owned(), path: path.to_owned(), query: query.to_owned(), headers: vec![], body: vec![] }
+        }
+
+        fn setup(name: &str) -> (std::path::PathBuf, Latest, Arc<Mutex<Tsdb>>) {
+            let mut object = MibObject::new();
+            object.target = "192.0.2.1".to_owned();
+            let table = &mut object.interfaces.ifTable;
+            if let MibValue::inti32(mvinti32 { value, index, .. }) = &mut table.ifIndex {
+                *value = vec![1, 2, 3];
+                *index = vec![vec![1], vec![2], vec![3]];
+            }
+            if let MibValue::string(mvstring { value, index, .. }) = &mut table.ifDescr {
+                *value = vec!["lo".to_owned(), "eth0".to_owned(), "eth0".to_owned()];
+                *index = vec![vec![1], vec![2], vec![3]];
+            }
+            if let MibValue::inti32(mvinti32 { value, index, .. }) = &mut table.ifType {
+                *value = vec![24, 6, 117];
+                *index = vec![vec![1], vec![2], vec![3]];
+            }
+            let dir = std::env::temp_dir().join(format!("snmp-monitor-api-{}-{}", name, std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            let config = StorageConfig { data_dir: dir.to_string_lossy().into_owned(), ..StorageConfig::default() };
+            let latest: Latest = Arc::new(Mutex::new(BTreeMap::new()));
+            latest.lock().unwrap().insert(object.target.clone(), object);
+            (dir, latest, Arc::new(Mutex::new(Tsdb::open(config).unwrap())))
+        }
+
+        fn get(latest: &Latest, tsdb: &Arc<Mutex<Tsdb>>, target: &str) -> (u16, Value) {
+            let response = route(latest, tsdb, &request("GET", target));
+            (response.status, serde_json::from_slice(&response.body).unwrap())
+        }
+
+        fn indexes(body: &Value) -> Vec<&str> {
+            body["items"].as_array().unwrap().iter().map(|a| a["index"].as_str().unwrap()).collect()
+        }
+
+        #[test]
+        fn test_rows_paging_and_filters() {
+            let (dir, latest, tsdb) = setup("rows");
+            let (status, body) = get(&latest, &tsdb, "/api/targets/192.0.2.1/tables/ifTable?limit=2");
+            assert_eq!(status, 200);
+            assert_eq!((body["total"].clone(), body["offset"].clone(), body["limit"].clone()), (3.into(), 0.into(), 2.into()));
+            assert_eq!(indexes(&body), vec!["1", "2"]);
+            let (_, body) = get(&latest, &tsdb, "/api/targets/192.0.2.1/tables/ifTable?offset=2&limit=2");
+            assert_eq!(indexes(&body), vec!["3"]);
+            let (_, body) = get(&latest, &tsdb, "/api/targets/192.0.2.1/tables/ifTable?offset=5");
+            assert_eq!((body["total"].clone(), indexes(&body).len()), (3.into(), 0));
+            let (_, body) = get(&latest, &tsdb, &format!("/api/targets/192.0.2.1/tables/ifTable?limit={}", MAX_LIMIT + 1));
+            assert_eq!(body["limit"], MAX_LIMIT);
+
+            // every column filter has to match, other parameters are not filters
+            let (_, body) = get(&latest, &tsdb, "/api/targets/192.0.2.1/tables/ifTable?ifDescr=eth0");
+            assert_eq!(indexes(&body), vec!["2", "3"]);
+            let (_, body) = get(&latest, &tsdb, "/api/targets/192.0.2.1/tables/ifTable?ifDescr=eth0&ifType=6&limit=5");
+            assert_eq!(indexes(&body), vec!["2"]);
+            assert_eq!(body["items"][0]["values"]["ifType"], "6");
+            let (_, body) = get(&latest, &tsdb, "/api/targets/192.0.2.1/tables/ifTable?ifDescr=eth9");
+            assert_eq!(body["total"], 0);
+            let _ = std::fs::remove_dir_all(dir);
+        }
+
+        #[test]
+        fn test_not_found() {
+            let (dir, latest, tsdb) = setup("missing");
+            for path in [
+                "/api/targets/192.0.2.9",
+                "/api/targets/192.0.2.9/values",
+                "/api/targets/192.0.2.9/tables/ifTable",
+                "/api/targets/192.0.2.1/tables/nope",
+                "/api/targets/192.0.2.1/values/nope",
+                "/api/targets/192.0.2.1/series/1.3.6.1.2.1.1.3.0",
+                "/api/targets/made-up/series",
+                "/api/targets/made-up/series/1.3.6.1.2.1.1.3.0",
+                "/api/nope",
+            ] {
+                let (status, body) = get(&latest, &tsdb, path);
+                assert_eq!(status, 404, "{}", path);
+                assert!(body["error"].is_string());
+            }
+            // asking about a target leaves nothing behind for it
+            assert!(!dir.join("made-up").exists());
+            let (_, body) = get(&latest, &tsdb, "/api/targets");
+            assert_eq!(body["total"], 1);
+            assert_eq!(route(&latest, &tsdb, &request("POST", "/api/targets")).status, 405);
+            let (status, body) = get(&latest, &tsdb, "/api/targets/192.0.2.1/tables");
+            assert_eq!(status, 200);
+            assert!(body.as_array().unwrap().contains(&Value::from("ifTable")));
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+pub mod BridgeModule {
+    use std::collections::HashMap;
+
+    use crate::mibobject::MibModule::{table_maps, MibObject};
+
+    /// dot1dTpFdbStatus and dot1qTpFdbStatus by their value
+    const FDB_STATUSES: &[&str] = &["other", "invalid", "learned", "self", "mgmt"];
+
+    /// one MAC address in the forwarding database of a switch
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct FdbEntry {
+        /// the address as `00:1a:2b:3c:4d:5e`
+        pub mac: String,
+        /// the dot1qFdbId the address was learned in, which switches number after the VLAN;
+        /// `None` for the addresses only dot1dTpFdbTable lists
+        pub vlan: Option<u32>,
+        pub bridge_port: String,
+        /// the ifIndex of the bridge port, when dot1dBasePortTable has it
+        pub if_index: Option<String>,
+        /// ifDescr of the port, its bridge port number when it has no interface
+        pub port: String,
+        pub status: String,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Vlan {
+        pub id: u32,
+        pub name: String,
+        /// the ports the VLAN is sent out of, tagged or not
+        pub ports: Vec<String>,
+        pub untagged: Vec<String>,
+    }
+
+    /// where a MAC address was found on a monitored switch
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct MacLocation {
+        pub target: String,
+        /// the switch's sysName, its address when it has none
+        pub device: String,
+        pub entry: FdbEntry,
+        pub vlan_name: String,
+        /// how many addresses the switch learned on the same port; the port with the fewest
+        /// is the one the address is most likely plugged into, the others being uplinks
+        pub port_macs: usize,
+    }
+
+    /// the sub-identifiers of a row index
+    fn arcs(index: &str) -> Vec<u32> {
+        index.split('.').filter_map(|a| a.parse::<u32>().ok()).collect()
+    }
+
+    /// the MAC address an index ends in
+    fn mac(arcs: &[u32]) -> Option<String> {
+        let octets = arcs.get(arcs.len().checked_sub(6)?..)?;
+        octets.iter().map(|a| u8::try_from(*a).ok().map(|a| format!("{:02x}", a))).collect::<Option<Vec<String>>>().map(|a| a.join(":"))
+    }
+
+    /// the bytes of an OCTET STRING as it is shown, text or hex octets
+    fn octets(text: &str) -> Vec<u8> {
+        let hex = text.split(':').map(|a| Some(a).filter(|a| a.len() == 2).and_then(|a| u8::from_str_radix(a, 16).ok())).collect::<Option<Vec<u8>>>();
+        hex.unwrap_or_else(|| text.bytes().collect())
+    }
+
+    /// the ports set in a PortList, the first octet's most significant bit being port 1
+    pub fn port_list(list: &[u8]) -> Vec<u32> {
+        list.iter()
+            .enumerate()
+            .flat_map(|(octet, bits)| (0..8).filter(move |bit| bits & (0x80 >> bit) != 0).map(move |bit| octet as u32 * 8 + bit + 1))
+            .collect()
+    }
+
+    /// the name of every bridge port: ifDescr of its interface, or the port number
+    fn port_names(object: &MibObject) -> HashMap<String, (Option<String>, String)> {
+        let descrs = table_maps(&object.interfaces.ifTable.values())
+            .into_iter()
+            .filter_map(|(_, row)| Some((row.get("ifIndex")?.clone(), row.get("ifDescr").cloned().unwrap_or_default())))
+            .collect::<HashMap<String, String>>();
+        table_maps(&object.bridge.dot1dBasePortTable.values())
+            .into_iter()
+            .filter_map(|(index, row)| {
+                let port = arcs(&index).first()?.to_string();
+                let if_index = row.get("dot1dBasePortIfIndex").filter(|a| !a.is_empty() && *a != "0").cloned();
+                let name = if_index.as_ref().and_then(|a| descrs.get(a)).filter(|a| !a.is_empty()).cloned().unwrap_or(format!("bridge port {}", port));
+                Some((port, (if_index, name)))
+            })
+            .collect()
+    }
+
+    /// every address in the forwarding database of `object`, from dot1qTpFdbTable and, for the
+    /// addresses it does not list, dot1dTpFdbTable
+    pub fn fdb(object: &MibObject) -> Vec<FdbEntry> {
+        let ports = port_names(object);
+        let entry = |mac: String, vlan: Option<u32>, port: Option<&String>, status: Option<&String>| {
+            let bridge_port = port.cloned().unwrap_or_default();
+            let (if_index, name) = ports.get(&bridge_port).cloned().unwrap_or((None, format!("bridge port {}", bridge_port)));
+            let status = status.cloned().unwrap_or_default();
+            FdbEntry {
+                mac,
+                vlan,
+                bridge_port,
+                if_index,
+                port: name,
+                status: status.parse::<usize>().ok().and_then(|a| FDB_STATUSES.get(a.wrapping_sub(1))).map(|a| a.to_string()).unwrap_or(status),
+            }
+        };
+        let mut entries = table_maps(&object.bridge.dot1qTpFdbTable.values())
+            .into_iter()
+            .map(|(index, row)| (arcs(&index), row))
+            .filter_map(|(index, row)| match index.len() {
+                7 => Some(entry(mac(&index)?, Some(index[0]), row.get("dot1qTpFdbPort"), row.get("dot1qTpFdbStatus"))),
+                _ => None,
+            })
+            .collect::<Vec<FdbEntry>>();
+        for (index, row) in table_maps(&object.bridge.dot1dTpFdbTable.values()) {
+            let index = arcs(&index);
+            match mac(&index) {
+                Some(mac) if index.len() == 6 && !entries.iter().any(|a| a.mac == mac) => {
+                    entries.push(entry(mac, None, row.get("dot1dTpFdbPort"), row.get("dot1dTpFdbStatus")))
+                }
+                _ => {}
+            }
+        }
+        entries
+    }
+
+    /// every statically configured VLAN of `object` with its member ports named
+    pub fn vlans(object: &MibObject) -> Vec<Vlan> {
+        let ports = port_names(object);
+        let names = |list: Option<&String>| {
+            port_list(&octets(list.map(|a| a.as_str()).unwrap_or_default()))
+                .into_iter()
+                .map(|port| ports.get(&port.to_string()).map(|a| a.1.clone()).unwrap_or(format!("bridge port {}", port)))
+                .collect::<Vec<String>>()
+        };
+        table_maps(&object.bridge.dot1qVlanStaticTable.values())
+            .into_iter()
+            .filter_map(|(index, row)| {
+                Some(Vlan {
+                    id: *arcs(&index).first()?,
+                    name: row.get("dot1qVlanStaticName").cloned().unwrap_or_default(),
+                    ports: names(row.get("dot1qVlanStaticEgressPorts")),
+                    untagged: names(row.get("dot1qVlanStaticUntaggedPorts")),
+                })
+            })
+            .collect()
+    }
+
+    /// the hex digits of a whole or partial MAC address written with `:`, `-`, `.` or no
+    /// separators, `None` when `query` is not one
+    pub fn mac_pattern(query: &str) -> Option<String> {
+        let digits = query.trim().chars().filter(|a| !":-. ".contains(*a)).collect::<String>().to_lowercase();
+        (!digits.is_empty() && digits.len() <= 12 && digits.chars().all(|a| a.is_ascii_hexdigit())).then_some(digits)
+    }
+
+    /// every switch port among `objects` that learned an address starting with `query`, most
+    /// likely access port first; `None` when `query` is not a MAC address
+    pub fn find_mac(objects: &[&MibObject], query: &str) -> Option<Vec<MacLocation>> {
+        let pattern = mac_pattern(query)?;
+        let mut found = vec![];
+        for object in objects {
+            let entries = fdb(object);
+            let mut port_macs: HashMap<&str, usize> = HashMap::new();
+            for entry in &entries {
+                *port_macs.entry(&entry.bridge_port).or_default() += 1;
+            }
+            let vlan_names = vlans(object).into_iter().map(|a| (a.id, a.name)).collect::<HashMap<u32, String>>();
+            let sys_name = object.system.sysName.display_values().first().cloned().unwrap_or_default();
+            for entry in entries.iter().filter(|a| a.mac.replace(':', "").starts_with(&pattern)) {
+                found.push(MacLocation {
+                    target: object.target.clone(),
+                    device: if sys_name.is_empty() { object.target.clone() } else { sys_name.clone() },
+                    vlan_name: entry.vlan.and_then(|a| vlan_names.get(&a)).cloned().unwrap_or_default(),
+                    port_macs: port_macs.get(entry.bridge_port.as_str()).copied().unwrap_or_default(),
+                    entry: entry.clone(),
+                });
+            }
+        }
+        found.sort_by(|a, b| (&a.entry.mac, a.port_macs, &a.device).cmp(&(&b.entry.mac, b.port_macs, &b.device)));
+        Some(found)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::testing::{set_int, set_string};
+
+        /// a switch with an access port 1 and an uplink 2, `learned` listing the VLAN, bridge
+        /// port and last octet of every address in its forwarding database
+        fn switch(target: &str, name: &str, learned: &[(u32, i32, u32)]) -> MibObject {
+            let mut object = MibObject::new();
+            object.target = target.to_owned();
+            set_string(&mut object.system.sysName, vec![name], vec![vec![0]]);
+            let interfaces = &mut object.interfaces.ifTable;
+            set_int(&mut interfaces.ifIndex, vec![10101, 10102], vec![vec![10101], vec![10102]]);
+            set_string(&mut interfaces.ifDescr, vec!["Gi1/0/1", "Gi1/0/2"], vec![vec![10101], vec![10102]]);
+            let bridge = &mut object.bridge;
+            set_int(&mut bridge.dot1dBasePortTable.dot1dBasePortIfIndex, vec![10101, 10102], vec![vec![1], vec![2]]);
+            let index = learned.iter().map(|a| vec![a.0, 0, 0x1a, 0x2b, 0x3c, 0x4d, a.2]).collect::<Vec<Vec<u32>>>();
+            set_int(&mut bridge.dot1qTpFdbTable.dot1qTpFdbPort, learned.iter().map(|a| a.1).collect(), index.clone());
+            set_int(&mut bridge.dot1qTpFdbTable.dot1qTpFdbStatus, vec![3; learned.len()], index);
+            let vlans = &mut bridge.dot1qVlanStaticTable;
+            // port 1 untagged in VLAN 10, port 2 carries both VLANs tagged
+            set_string(&mut vlans.dot1qVlanStaticName, vec!["users", "voice"], vec![vec![10], vec![20]]);
+            set_string(&mut vlans.dot1qVlanStaticEgressPorts, vec!["c0", "@"], vec![vec![10], vec![20]]);
+            set_string(&mut vlans.dot1qVlanStaticUntaggedPorts, vec!["80", "00"], vec![vec![10], vec![20]]);
+            object
+        }
+
+        #[test]
+        fn test_fdb() {
+            let object = switch("192.0.2.2", "access-1", &[(10, 1, 0x5e), (20, 2, 0x01)]);
+            let entries = fdb(&object);
+            assert_eq!(entries.len(), 2);
+            assert_eq!(
+                entries[0],
+                FdbEntry {
+                    mac: "00:1a:2b:3c:4d:5e".to_owned(),
+                    vlan: Some(10),
+                    bridge_port: "1".to_owned(),
+                    if_index: Some("10101".to_owned()),
+                    port: "Gi1/0/1".to_owned(),
+                    status: "learned".to_owned(),
+                }
+            );
+            let vlans = vlans(&object);
+            assert_eq!((vlans[0].ports.clone(), vlans[0].untagged.clone()), (vec!["Gi1/0/1".to_owned(), "Gi1/0/2".to_owned()], vec!["Gi1/0/1".to_owned()]));
+            assert_eq!(vlans[1].ports, vec!["Gi1/0/2".to_owned()]);
+            assert_eq!(port_list(&[0x80, 0x01]), vec![1, 16]);
+        }
+
+        #[test]
+        fn test_find_mac() {
+            let access = switch("192.0.2.2", "access-1", &[(10, 1, 0x5e), (10, 2, 0x01)]);
+            let core = switch("192.0.2.1", "core-1", &[(10, 2, 0x5e), (10, 2, 0x02), (10, 2, 0x03)]);
+            let found = find_mac(&[&core, &access], "001A.2B3C.4D5E").unwrap();
+            assert_eq!(found.iter().map(|a| (a.device.as_str(), a.entry.port.as_str(), a.port_macs)).collect::<Vec<_>>(), vec![("access-1", "Gi1/0/1", 1), ("core-1", "Gi1/0/2", 3)]);
+            assert_eq!((found[0].entry.vlan, found[0].vlan_name.as_str()), (Some(10), "users"));
+            assert_eq!(find_mac(&[&core, &access], "00-1a-2b").unwrap().len(), 5);
+            assert_eq!(find_mac(&[&core], "not a mac"), None);
+        }
+    }
+}
@@ -0,0 +1,530 @@
+pub mod BrowserModule {
+    use csnmp::{ObjectIdentifier, ObjectValue, Snmp2cClient};
+    use std::collections::{BTreeMap, HashMap};
+    use std::fs;
+    use std::str::FromStr;
+
+    use crate::mibobject::MibModule::{mvinti32, mvintu32, mvintu64, octet_string, opaque_float, MibObject, MibValue};
+    use crate::vendor::VendorModule::VendorProfile;
+
+    const MAX_REPETITIONS: u32 = 25;
+
+    /// the top of the registration tree and the MIB-II groups, named even without MIB files
+    const ROOTS: &[(&str, &[u32])] = &[
+        ("ccitt", &[0]),
+        ("iso", &[1]),
+        ("joint-iso-ccitt", &[2]),
+        ("org", &[1, 3]),
+        ("dod", &[1, 3, 6]),
+        ("internet", &[1, 3, 6, 1]),
+        ("directory", &[1, 3, 6, 1, 1]),
+        ("mgmt", &[1, 3, 6, 1, 2]),
+        ("mib-2", &[1, 3, 6, 1, 2, 1]),
+        ("system", &[1, 3, 6, 1, 2, 1, 1]),
+        ("interfaces", &[1, 3, 6, 1, 2, 1, 2]),
+        ("at", &[1, 3, 6, 1, 2, 1, 3]),
+        ("ip", &[1, 3, 6, 1, 2, 1, 4]),
+        ("icmp", &[1, 3, 6, 1, 2, 1, 5]),
+        ("tcp", &[1, 3, 6, 1, 2, 1, 6]),
+        ("udp", &[1, 3, 6, 1, 2, 1, 7]),
+        ("egp", &[1, 3, 6, 1, 2, 1, 8]),
+        ("transmission", &[1, 3, 6, 1, 2, 1, 10]),
+        ("snmp", &[1, 3, 6, 1, 2, 1, 11]),
+        ("host", &[1, 3, 6, 1, 2, 1, 25]),
+        ("ifMIB", &[1, 3, 6, 1, 2, 1, 31]),
+        ("entityMIB", &[1, 3, 6, 1, 2, 1, 47]),
+        ("experimental", &[1, 3, 6, 1, 3]),
+        ("private", &[1, 3, 6, 1, 4]),
+        ("enterprises", &[1, 3, 6, 1, 4, 1]),
+        ("security", &[1, 3, 6, 1, 5]),
+        ("snmpV2", &[1, 3, 6, 1, 6]),
+        ("snmpModules", &[1, 3, 6, 1, 6, 3]),
+    ];
+
+    /// macros whose value is an OBJECT IDENTIFIER, defining the descriptor written before them
+    const MACROS: &[&str] = &[
+        "OBJECT-TYPE",
+        "OBJECT-IDENTITY",
+        "MODULE-IDENTITY",
+        "NOTIFICATION-TYPE",
+        "OBJECT-GROUP",
+        "NOTIFICATION-GROUP",
+        "MODULE-COMPLIANCE",
+        "AGENT-CAPABILITIES",
+    ];
+
+    pub fn dotted(oid: &[u32]) -> String {
+        oid.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".")
+    }
+
+    /// the names of OIDs: the roots, every object polled by `MibObject` or a vendor profile and
+    /// every definition of the MIB files loaded
+    #[derive(Clone, Debug, Default)]
+    pub struct MibTree {
+        names: BTreeMap<Vec<u32>, String>,
+        oids: HashMap<String, Vec<u32>>,
+    }
+
+    impl MibTree {
+        pub fn new(profiles: &[VendorProfile], dir: &str) -> Self {
+            let mut tree = MibTree::default();
+            ROOTS.iter().for_each(|(name, oid)| tree.insert(name, oid.to_vec()));
+            let object = MibObject::new();
+            for (name, columns) in object.tables() {
+                // the columns of a table sit under its entry, which sits under the table
+                if let Some(column) = columns.first().map(|a| a.get_oid()).filter(|a| a.len() > 2) {
+                    tree.insert(name, column[..column.len() - 2].iter().map(|a| *a as u32).collect());
+                }
+            }
+            let vendors = profiles.iter().map(|a| a.vendor()).collect::<Vec<_>>();
+            for value in object.values().into_iter().chain(vendors.iter().flat_map(|a| a.values())) {
+                tree.insert(&value.get_name(), value.get_oid().iter().map(|a| *a as u32).collect());
+            }
+            let mut paths = match fs::read_dir(dir) {
+                Ok(entries) => entries.filter_map(|a| a.ok()).map(|a| a.path()).filter(|a| a.is_file()).collect::<Vec<_>>(),
+                Err(_) => vec![],
+            };
+            paths.sort();
+            let assignments = paths
+                .iter()
+                .filter_map(|path| fs::read(path).ok())
+                .flat_map(|contents| assignments(&String::from_utf8_lossy(&contents)))
+                .collect::<Vec<_>>();
+            tree.resolve_all(assignments);
+            tree
+        }
+
+        fn insert(&mut self, name: &str, oid: Vec<u32>) {
+            self.oids.entry(name.to_owned()).or_insert(oid.clone());
+            self.names.entry(oid).or_insert(name.to_owned());
+        }
+
+        /// places every assignment whose parent is known, over and over, since modules refer to
+        /// parents defined further down or in modules read later
+        fn resolve_all(&mut self, mut pending: Vec<(String, String, Vec<u32>)>) {
+            loop {
+                let before = pending.len();
+                pending.retain(|(name, parent, arcs)| match self.oids.get(parent) {
+                    Some(oid) => {
+                        let oid = oid.iter().chain(arcs.iter()).copied().collect();
+                        self.insert(name, oid);
+                        false
+                    }
+                    None => true,
+                });
+                if pending.len() == before {
+                    break;
+                }
+            }
+        }
+
+        /// the OID of dotted numbers, a name or a name followed by an instance, like `ifDescr.3`,
+        /// `enterprises.9` or `IF-MIB::ifDescr.3`
+        pub fn resolve(&self, text: &str) -> Option<Vec<u32>> {
+            let text = text.trim().trim_start_matches('.');
+            let text = text.rsplit_once("::").map(|a| a.1).unwrap_or(text);
+            let (head, rest) = text.split_once('.').unwrap_or((text, ""));
+            let mut oid = match head.parse::<u32>() {
+                Ok(arc) => vec![arc],
+                Err(_) => self.oids.get(head)?.clone(),
+            };
+            for arc in rest.split('.').filter(|a| !a.is_empty()) {
+                oid.push(arc.parse::<u32>().ok()?);
+            }
+            (!oid.is_empty()).then_some(oid)
+        }
+
+        /// the name of the longest named prefix of `oid` followed by the rest of its arcs
+        pub fn name(&self, oid: &[u32]) -> String {
+            match (1..=oid.len()).rev().find_map(|len| Some((self.names.get(&oid[..len])?, len))) {
+                Some((name, len)) if len == oid.len() => name.clone(),
+                Some((name, len)) => [name.clone(), dotted(&oid[len..])].join("."),
+                None => dotted(oid),
+            }
+        }
+
+        /// the arcs directly under `oid` that lead to a named OID, with their names
+        pub fn children(&self, oid: &[u32]) -> Vec<(u32, String)> {
+            let mut children: Vec<(u32, String)> = vec![];
+            for key in self.names.range(oid.to_vec()..).map(|a| a.0).take_while(|a| a.starts_with(oid)) {
+                match key.get(oid.len()) {
+                    Some(arc) if children.last().map(|a| a.0) != Some(*arc) => {
+                        let child = [oid, &[*arc]].concat();
+                        children.push((*arc, self.names.get(&child).cloned().unwrap_or(arc.to_string())));
+                    }
+                    _ => {}
+                }
+            }
+            children
+        }
+    }
+
+    /// the OBJECT IDENTIFIER values assigned in the text of an SMI module, as (name, parent,
+    /// arcs under the parent); `name(n)` elements of a value are assignments of their own
+    pub fn assignments(text: &str) -> Vec<(String, String, Vec<u32>)> {
+        let text = text.lines().map(|line| line.split("--").next().unwrap_or_default()).collect::<Vec<&str>>().join("\n");
+        let text = text.replace('{', " { ").replace('}', " } ").replace('(', " ( ").replace(')', " ) ").replace(',', " ");
+        let tokens = text.split_whitespace().collect::<Vec<&str>>();
+        let descriptor = |a: &str| a.starts_with(|b: char| b.is_ascii_lowercase());
+        let mut assignments = vec![];
+        let mut name: Option<&str> = None;
+        let mut i = 0;
+        while i < tokens.len() {
+            let token = tokens[i];
+            if MACROS.contains(&token) && i > 0 && descriptor(tokens[i - 1]) {
+                name = Some(tokens[i - 1]);
+            } else if token == "OBJECT" && tokens.get(i + 1) == Some(&"IDENTIFIER") && tokens.get(i + 2) == Some(&"::=") && i > 0 && descriptor(tokens[i - 1]) {
+                name = Some(tokens[i - 1]);
+            } else if token == "::=" && tokens.get(i + 1) == Some(&"{") {
+                let end = match tokens[i..].iter().position(|a| *a == "}") {
+                    Some(end) => i + end,
+                    None => break,
+                };
+                if let (Some(name), Some((parent, elements))) = (name.take(), tokens[i + 2..end].split_first()) {
+                    let mut arcs = vec![];
+                    let mut j = 0;
+                    while j < elements.len() {
+                        match (elements[j].parse::<u32>(), elements.get(j + 1), elements.get(j + 2).and_then(|a| a.parse::<u32>().ok())) {
+                            (Ok(arc), _, _) => arcs.push(arc),
+                            (Err(_), Some(&"("), Some(arc)) => {
+                                arcs.push(arc);
+                                assignments.push((elements[j].to_owned(), parent.to_string(), arcs.clone()));
+                                j += 3;
+                            }
+                            _ => {}
+                        }
+                        j += 1;
+                    }
+                    if !arcs.is_empty() {
+                        assignments.push((name.to_owned(), parent.to_string(), arcs));
+                    }
+                }
+                i = end;
+            }
+            i += 1;
+        }
+        assignments
+    }
+
+    /// the request types the browser sends
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum Operation {
+        #[default]
+        Get,
+        GetNext,
+        Walk,
+        BulkWalk,
+    }
+
+    impl Operation {
+        pub const ALL: [Operation; 4] = [Operation::Get, Operation::GetNext, Operation::Walk, Operation::BulkWalk];
+
+        pub fn label(&self) -> &'static str {
+            match self {
+                Operation::Get => "get",
+                Operation::GetNext => "getnext",
+                Operation::Walk => "walk",
+                Operation::BulkWalk => "bulkwalk",
+            }
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct BrowseRequest {
+        pub target: String,
+        pub operation: Operation,
+        pub oid: ObjectIdentifier,
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct BrowseResult {
+        pub request: BrowseRequest,
+        pub result: Result<Vec<(ObjectIdentifier, ObjectValue)>, String>,
+    }
+
+    pub async fn run(client: &Snmp2cClient, request: BrowseRequest) -> BrowseResult {
+        let oid = request.oid;
+        let result = match request.operation {
+            Operation::Get => client.get(oid).await.map(|value| vec![(oid, value)]),
+            Operation::GetNext => client.get_next(oid).await.map(|binding| vec![binding]),
+            Operation::Walk => client.walk(oid).await.map(|bindings| bindings.into_iter().collect()),
+            Operation::BulkWalk => client.walk_bulk(oid, MAX_REPETITIONS).await.map(|bindings| bindings.into_iter().collect()),
+        };
+        BrowseResult { request, result: result.map_err(|err| err.to_string()) }
+    }
+
+    /// the content octets of the BER encoding of `value`
+    pub fn raw(value: &ObjectValue) -> Vec<u8> {
+        // the shortest big-endian form that keeps the sign, as BER writes integers
+        let minimal = |bytes: &[u8]| {
+            let mut start = 0;
+            while start + 1 < bytes.len()
+                && ((bytes[start] == 0 && bytes[start + 1] & 0x80 == 0) || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0))
+            {
+                start += 1;
+            }
+            bytes[start..].to_vec()
+        };
+        match value {
+            ObjectValue::Integer(a) => minimal(&a.to_be_bytes()),
+            ObjectValue::Counter32(a) | ObjectValue::Unsigned32(a) | ObjectValue::TimeTicks(a) => minimal(&(*a as i64).to_be_bytes()),
+            ObjectValue::Counter64(a) => minimal(&(*a as i128).to_be_bytes()),
+            ObjectValue::String(a) | ObjectValue::Opaque(a) => a.clone(),
+            ObjectValue::IpAddress(a) => a.octets().to_vec(),
+            ObjectValue::ObjectId(a) => {
+                let arcs = a.as_slice();
+                let first = arcs.first().copied().unwrap_or_default() * 40 + arcs.get(1).copied().unwrap_or_default();
+                std::iter::once(first)
+                    .chain(arcs.iter().skip(2).copied())
+                    .flat_map(|arc| {
+                        let mut bytes = vec![(arc & 0x7f) as u8];
+                        let mut rest = arc >> 7;
+                        while rest > 0 {
+                            bytes.insert(0, (rest & 0x7f) as u8 | 0x80);
+                            rest >>= 7;
+                        }
+                        bytes
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// `value` as text, with OIDs named after `tree` and TimeTicks also as a duration
+    pub fn decode(value: &ObjectValue, tree: &MibTree) -> String {
+        match value {
+            ObjectValue::Integer(a) => a.to_string(),
+            ObjectValue::Counter32(a) | ObjectValue::Unsigned32(a) => a.to_string(),
+            ObjectValue::Counter64(a) => a.to_string(),
+            ObjectValue::TimeTicks(a) => {
+                let seconds = a / 100;
+                format!("{} ({}d {:02}:{:02}:{:02}.{:02})", a, seconds / 86400, seconds / 3600 % 24, seconds / 60 % 60, seconds % 60, a % 100)
+            }
+            ObjectValue::String(a) => octet_string(a),
+            ObjectValue::Opaque(a) => opaque_float(a).unwrap_or_else(|| octet_string(a)),
+            ObjectValue::IpAddress(a) => a.to_string(),
+            ObjectValue::ObjectId(a) => match tree.name(a.as_slice()) {
+                name if name == a.to_string() => name,
+                name => format!("{} ({})", name, a),
+            },
+        }
+    }
+
+    /// one variable binding of a browse result
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Varbind {
+        pub oid: Vec<u32>,
+        pub name: String,
+        pub kind: &'static str,
+        pub raw: String,
+        pub value: String,
+        /// the value of Integer, Counter and Gauge bindings, which can be plotted
+        pub number: Option<i64>,
+    }
+
+    impl Varbind {
+        pub fn new(oid: &ObjectIdentifier, value: &ObjectValue, tree: &MibTree) -> Self {
+            Varbind {
+                oid: oid.as_slice().to_vec(),
+                name: tree.name(oid.as_slice()),
+                kind: value.as_type_str(),
+                raw: raw(value).iter().map(|a| format!("{:02x}", a)).collect::<Vec<String>>().join(" "),
+                value: decode(value, tree),
+                number: match value {
+                    ObjectValue::Integer(a) => Some(*a as i64),
+                    ObjectValue::Counter32(a) | ObjectValue::Unsigned32(a) | ObjectValue::TimeTicks(a) => Some(*a as i64),
+                    // counters past i64::MAX cannot be plotted rather than turning negative
+                    ObjectValue::Counter64(a) => i64::try_from(*a).ok(),
+                    _ => None,
+                },
+            }
+        }
+
+        /// a value polled from the parent of the leaf, so it has the leaf as one of its
+        /// instances; `None` for non-numeric leaves and arcs too large for `MibValue`
+        pub fn pinned(&self, tree: &MibTree) -> Option<MibValue> {
+            self.number?;
+            let parent = self.oid.get(..self.oid.len().checked_sub(1)?)?;
+            let oid = parent.iter().map(|a| u16::try_from(*a).ok()).collect::<Option<Vec<u16>>>()?;
+            let name = tree.name(parent);
+            Some(match self.kind {
+                "Integer" => MibValue::inti32(mvinti32 { name, oid, mutable: false, value: vec![], index: vec![] }),
+                "Counter64" => MibValue::intu64(mvintu64 { name, oid, mutable: false, value: vec![], index: vec![] }),
+                _ => MibValue::intu32(mvintu32 { name, oid, mutable: false, value: vec![], index: vec![] }),
+            })
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum SortBy {
+        #[default]
+        Oid,
+        Name,
+        Type,
+        Value,
+    }
+
+    /// orders by `by`, numbers by value when both bindings have one
+    pub fn sort(rows: &mut [Varbind], by: SortBy, ascending: bool) {
+        rows.sort_by(|a, b| {
+            let ordering = match by {
+                SortBy::Oid => a.oid.cmp(&b.oid),
+                SortBy::Name => a.name.cmp(&b.name),
+                SortBy::Type => a.kind.cmp(b.kind),
+                SortBy::Value => match (a.number, b.number) {
+                    (Some(x), Some(y)) => x.cmp(&y),
+                    _ => a.value.cmp(&b.value),
+                },
+            };
+            match ascending {
+                true => ordering.then(a.oid.cmp(&b.oid)),
+                false => ordering.then(a.oid.cmp(&b.oid)).reverse(),
+            }
+        });
+    }
+
+    /// what the browser tab shows
+    #[derive(Clone, Debug, Default)]
+    pub struct Browser {
+        pub input: String,
+        pub operation: Operation,
+        pub rows: Vec<Varbind>,
+        pub sort_by: SortBy,
+        pub ascending: bool,
+        pub status: String,
+    }
+
+    impl Browser {
+        pub fn request(&self, tree: &MibTree, target: &str) -> Result<BrowseRequest, String> {
+            let oid = tree.resolve(&self.input).ok_or(format!("'{}' is neither an OID nor a known name", self.input.trim()))?;
+            let oid = ObjectIdentifier::from_str(&dotted(&oid)).map_err(|err| err.to_string())?;
+            Ok(BrowseRequest { target: target.to_owned(), operation: self.operation, oid })
+        }
+
+        pub fn receive(&mut self, tree: &MibTree, result: BrowseResult) {
+            let BrowseResult { request, result } = result;
+            match result {
+                Ok(bindings) => {
+                    self.rows = bindings.iter().map(|(oid, value)| Varbind::new(oid, value, tree)).collect();
+                    sort(&mut self.rows, self.sort_by, self.ascending);
+                    self.status = format!("{} {} on {}: {} bindings", request.operation.label(), tree.name(request.oid.as_slice()), request.target, self.rows.len());
+                }
+                Err(err) => self.status = format!("{} {} on {} failed: {}", request.operation.label(), tree.name(request.oid.as_slice()), request.target, err),
+            }
+        }
+
+        /// sorts by `by`, or flips the order when already sorted by it
+        pub fn sort(&mut self, by: SortBy) {
+            self.ascending = match self.sort_by == by {
+                true => !self.ascending,
+                false => true,
+            };
+            self.sort_by = by;
+            sort(&mut self.rows, self.sort_by, self.ascending);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::net::Ipv4Addr;
+
+        const MIB: &str = r#"
+            EXAMPLE-MIB DEFINITIONS ::= BEGIN
+            IMPORTS
+                MODULE-IDENTITY, OBJECT-TYPE, Integer32, enterprises FROM SNMPv2-SMI;
+
+            example MODULE-IDENTITY
+                LAST-UPDATED "202401010000Z"
+                DESCRIPTION "an example"
+                ::= { enterprises 99999 }
+
+            exampleObjects OBJECT IDENTIFIER ::= { example 1 }
+
+            exampleTemperature OBJECT-TYPE
+                SYNTAX      Integer32
+                MAX-ACCESS  read-only
+                STATUS      current
+                ::= { exampleObjects 1 }
+
+            exampleTable OBJECT-TYPE
+                SYNTAX      SEQUENCE OF ExampleEntry
+                ::= { exampleObjects 2 }
+
+            ExampleEntry ::= SEQUENCE { exampleIndex Integer32, exampleName OCTET STRING }
+
+            exampleName OBJECT-TYPE
+                SYNTAX      OBJECT IDENTIFIER -- a column of exampleEntry
+                ::= { exampleEntry 2 }
+
+            exampleEntry OBJECT-TYPE
+                SYNTAX      ExampleEntry
+                INDEX       { exampleIndex }
+                ::= { exampleTable 1 }
+
+            exampleLab OBJECT IDENTIFIER ::= { exampleObjects lab(9) 3 }
+            END
+        "#;
+
+        #[test]
+        fn test_tree() {
+            let mut tree = MibTree::new(&[], "");
+            tree.resolve_all(assignments(MIB));
+            assert_eq!(tree.resolve("exampleTemperature.0"), Some(vec![1, 3, 6, 1, 4, 1, 99999, 1, 1, 0]));
+            // defined before its parent exampleEntry
+            assert_eq!(tree.resolve("EXAMPLE-MIB::exampleName"), Some(vec![1, 3, 6, 1, 4, 1, 99999, 1, 2, 1, 2]));
+            assert_eq!(tree.resolve("lab"), Some(vec![1, 3, 6, 1, 4, 1, 99999, 1, 9]));
+            assert_eq!(tree.resolve("exampleLab"), Some(vec![1, 3, 6, 1, 4, 1, 99999, 1, 9, 3]));
+            assert_eq!(tree.resolve(".1.3.6.1.2.1.1.5.0"), Some(vec![1, 3, 6, 1, 2, 1, 1, 5, 0]));
+            assert_eq!(tree.resolve("nosuchObject.1"), None);
+
+            assert_eq!(tree.name(&[1, 3, 6, 1, 2, 1, 2, 2, 1, 2, 3]), "ifDescr.3");
+            assert_eq!(tree.name(&[1, 3, 6, 1, 4, 1, 9, 1, 1208]), "enterprises.9.1.1208");
+            assert_eq!(tree.name(&[1, 3, 6, 1, 2, 1, 1]), "system");
+            assert!(tree.children(&[1, 3, 6, 1]).contains(&(2, "mgmt".to_owned())));
+            assert_eq!(tree.children(&[1, 3, 6, 1, 4, 1, 99999]), vec![(1, "exampleObjects".to_owned())]);
+        }
+
+        #[test]
+        fn test_varbind() {
+            let tree = MibTree::new(&[], "");
+            let oid = ObjectIdentifier::from_str("1.3.6.1.2.1.1.3.0").unwrap();
+            let uptime = Varbind::new(&oid, &ObjectValue::TimeTicks(9_000_012), &tree);
+            assert_eq!((uptime.name.as_str(), uptime.kind, uptime.raw.as_str()), ("sysUpTime.0", "TimeTicks", "00 89 54 4c"));
+            assert_eq!(uptime.value, "9000012 (1d 01:00:00.12)");
+            let pinned = uptime.pinned(&tree).unwrap();
+            assert_eq!((pinned.get_name(), pinned.get_oid()), ("sysUpTime".to_owned(), vec![1, 3, 6, 1, 2, 1, 1, 3]));
+
+            assert_eq!(raw(&ObjectValue::Integer(-129)), vec![0xff, 0x7f]);
+            assert_eq!(raw(&ObjectValue::Integer(127)), vec![0x7f]);
+            assert_eq!(raw(&ObjectValue::ObjectId(ObjectIdentifier::from_str("1.3.6.1.4.1.311").unwrap())), vec![0x2b, 6, 1, 4, 1, 0x82, 0x37]);
+            let id = ObjectValue::ObjectId(ObjectIdentifier::from_str("1.3.6.1.4.1.9.1.1208").unwrap());
+            assert_eq!(decode(&id, &tree), "enterprises.9.1.1208 (1.3.6.1.4.1.9.1.1208)");
+            assert_eq!(decode(&ObjectValue::String(vec![0, 0x1a, 0x2b]), &tree), "00:1a:2b");
+
+            let address = Varbind::new(&oid, &ObjectValue::IpAddress(Ipv4Addr::new(10, 0, 0, 1)), &tree);
+            assert!(address.pinned(&tree).is_none());
+            assert_eq!(Varbind::new(&oid, &ObjectValue::Counter64(u64::MAX), &tree).number, None);
+        }
+
+        #[test]
+        fn test_sort() {
+            let tree = MibTree::new(&[], "");
+            let binding = |oid: &str, value: ObjectValue| Varbind::new(&ObjectIdentifier::from_str(oid).unwrap(), &value, &tree);
+            let mut browser = Browser {
+                rows: vec![
+                    binding("1.3.6.1.2.1.2.2.1.10.10", ObjectValue::Counter32(5)),
+                    binding("1.3.6.1.2.1.2.2.1.10.2", ObjectValue::Counter32(40)),
+                    binding("1.3.6.1.2.1.2.2.1.10.3", ObjectValue::Counter32(300)),
+                ],
+                ..Browser::default()
+            };
+            browser.sort(SortBy::Oid);
+            assert_eq!(browser.rows.iter().map(|a| a.name.as_str()).collect::<Vec<&str>>(), vec!["ifInOctets.2", "ifInOctets.3", "ifInOctets.10"]);
+            browser.sort(SortBy::Value);
+            browser.sort(SortBy::Value);
+            // numbers compare as numbers, not as text
+            assert_eq!(browser.rows.iter().map(|a| a.number.unwrap()).collect::<Vec<i64>>(), vec![300, 40, 5]);
+        }
+    }
+}
@@ -0,0 +1,500 @@
+pub mod ConfigModule {
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::net::{IpAddr, SocketAddr};
+
+    pub const CONFIG_PATH: &str = "monitor.json";
+
+    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct Config {
+        pub storage: StorageConfig,
+        pub log: LogConfig,
+        pub alerts: AlertConfig,
+        pub notify: NotifyConfig,
+        pub http: HttpConfig,
+        pub metrics: MetricsConfig,
+        pub exports: Vec<ExportConfig>,
+        pub csv: CsvConfig,
+        /// devices polled from the start, discovered devices are added to `discovery.targets_file`
+        pub targets: Vec<TargetConfig>,
+        pub profiles: Vec<PollProfile>,
+        pub discovery: DiscoveryConfig,
+        pub inventory: InventoryConfig,
+        pub vendors: VendorConfig,
+        pub audit: AuditConfig,
+        pub mibs: MibsConfig,
+        pub dashboards: DashboardConfig,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct StorageConfig {
+        pub data_dir: String,
+        pub block_samples: usize,
+        pub raw_retention_days: i64,
+        pub minute_retention_days: i64,
+        pub hour_retention_days: i64,
+    }
+
+    impl Default for StorageConfig {
+        fn default() -> Self {
+            StorageConfig {
+                data_dir: "data".to_owned(),
+                block_samples: 120,
+                raw_retention_days: 7,
+                minute_retention_days: 30,
+                hour_retention_days: 365,
+            }
+        }
+    }
+
+    /// when the snapshot log is flushed to disk with fsync
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum FsyncPolicy {
+        Always,
+        Interval,
+        Never,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct LogConfig {
+        pub dir: String,
+        pub max_bytes: u64,
+        pub max_age_hours: i64,
+        pub gzip: bool,
+        pub retention_files: usize,
+        pub retention_days: i64,
+        pub fsync: FsyncPolicy,
+        pub fsync_interval_secs: i64,
+    }
+
+    impl Default for LogConfig {
+        fn default() -> Self {
+            LogConfig {
+                dir: "logs".to_owned(),
+                max_bytes: 64 * 1024 * 1024,
+                max_age_hours: 24,
+                gzip: true,
+                retention_files: 30,
+                retention_days: 30,
+                fsync: FsyncPolicy::Interval,
+                fsync_interval_secs: 300,
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Severity {
+        Info,
+        #[default]
+        Warning,
+        Critical,
+    }
+
+    /// one alert rule, `expr` is parsed by `AlertModule::Condition::parse`
+    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct AlertRule {
+        pub name: String,
+        pub expr: String,
+        pub severity: Severity,
+        /// threshold the value has to cross back over before a firing alert resolves
+        pub clear: Option<f64>,
+        /// how long the condition has to stay cleared before a firing alert resolves
+        pub clear_for_secs: i64,
+        /// targets the rule applies to, every target when empty
+        pub targets: Vec<String>,
+        /// the vendor profile that brought the rule, which then only applies to the targets the
+        /// profile was applied to
+        #[serde(skip)]
+        pub profile: String,
+        /// sinks notified when the rule fires or resolves, `NotifyConfig::default_sinks` when empty
+        pub notify: Vec<String>,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct AlertConfig {
+        pub rules: Vec<AlertRule>,
+        pub state_file: String,
+        pub event_log: String,
+    }
+
+    impl Default for AlertConfig {
+        fn default() -> Self {
+            AlertConfig {
+                rules: vec![],
+                state_file: "data/alerts.json".to_owned(),
+                event_log: "logs/alerts.log".to_owned(),
+            }
+        }
+    }
+
+    /// where the hardware inventory of every target is kept between polls and restarts
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct InventoryConfig {
+        pub state_file: String,
+        pub event_log: String,
+    }
+
+    impl Default for InventoryConfig {
+        fn default() -> Self {
+            InventoryConfig {
+                state_file: "data/inventory.json".to_owned(),
+                event_log: "logs/inventory.log".to_owned(),
+            }
+        }
+    }
+
+    /// where the vendor profiles, one `VendorModule::VendorProfile` per `.json` file, are read from
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct VendorConfig {
+        pub dir: String,
+    }
+
+    impl Default for VendorConfig {
+        fn default() -> Self {
+            VendorConfig { dir: "vendors".to_owned() }
+        }
+    }
+
+    /// where every SET sent from the GUI is recorded, one JSON line per request
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct AuditConfig {
+        pub log: String,
+    }
+
+    impl Default for AuditConfig {
+        fn default() -> Self {
+            AuditConfig { log: "logs/audit.log".to_owned() }
+        }
+    }
+
+    /// where the MIB files that name OIDs in the MIB browser are read from
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct MibsConfig {
+        pub dir: String,
+    }
+
+    impl Default for MibsConfig {
+        fn default() -> Self {
+            MibsConfig { dir: "mibs".to_owned() }
+        }
+    }
+
+    /// where named dashboards are kept, and where single ones are exported to for sharing
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct DashboardConfig {
+        pub file: String,
+        pub export_dir: String,
+    }
+
+    impl Default for DashboardConfig {
+        fn default() -> Self {
+            DashboardConfig { file: "data/dashboards.json".to_owned(), export_dir: "dashboards".to_owned() }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum SyslogProtocol {
+        #[default]
+        Udp,
+        Tcp,
+    }
+
+    /// where a sink delivers its notifications
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(tag = "type", rename_all = "lowercase")]
+    pub enum SinkKind {
+        /// POSTs the event as JSON to a plain `http://` url
+        Webhook {
+            url: String,
+            #[serde(default)]
+            headers: BTreeMap<String, String>,
+        },
+        Smtp {
+            /// `host:port` of the mail relay
+            server: String,
+            from: String,
+            to: Vec<String>,
+            #[serde(default)]
+            username: Option<String>,
+            #[serde(default)]
+            password: Option<String>,
+        },
+        Syslog {
+            /// `host:port` of the syslog collector
+            server: String,
+            #[serde(default)]
+            protocol: SyslogProtocol,
+            #[serde(default = "default_facility")]
+            facility: u8,
+        },
+        /// runs `program` with the alert in `SNMP_ALERT_*` environment variables
+        Command {
+            program: String,
+            #[serde(default)]
+            args: Vec<String>,
+        },
+    }
+
+    fn default_facility() -> u8 {
+        1
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct SinkConfig {
+        pub name: String,
+        #[serde(flatten)]
+        pub kind: SinkKind,
+        /// message body, `{{field}}` is replaced with the field of the alert event
+        #[serde(default = "default_template")]
+        pub template: String,
+        /// email subject, same placeholders as `template`
+        #[serde(default = "default_subject")]
+        pub subject: String,
+        /// events below this severity are not sent to the sink
+        #[serde(default = "default_min_severity")]
+        pub min_severity: Severity,
+        /// at most `rate_limit` notifications per `rate_window_secs`, unlimited when 0
+        #[serde(default = "default_rate_limit")]
+        pub rate_limit: usize,
+        #[serde(default = "default_rate_window")]
+        pub rate_window_secs: i64,
+    }
+
+    fn default_template() -> String {
+        "{{severity}} alert {{rule}} is {{status}} on {{target}}: {{instance}} = {{value}} at {{time}}".to_owned()
+    }
+
+    fn default_subject() -> String {
+        "[{{severity}}] {{rule}} {{status}} on {{target}}".to_owned()
+    }
+
+    fn default_min_severity() -> Severity {
+        Severity::Info
+    }
+
+    fn default_rate_limit() -> usize {
+        10
+    }
+
+    fn default_rate_window() -> i64 {
+        60
+    }
+
+    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct NotifyConfig {
+        pub sinks: Vec<SinkConfig>,
+        /// sinks used by rules that do not list their own
+        pub default_sinks: Vec<String>,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct HttpConfig {
+        /// address of the embedded HTTP server, disabled when empty
+        pub listen: String,
+    }
+
+    impl Default for HttpConfig {
+        fn default() -> Self {
+            HttpConfig { listen: "127.0.0.1:9117".to_owned() }
+        }
+    }
+
+    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct MetricsConfig {
+        /// Pushgateway url such as `http://127.0.0.1:9091/metrics/job/snmp_monitor` that every poll is pushed to
+        pub push_url: Option<String>,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum ExportFormat {
+        Influx,
+        Graphite,
+        Statsd,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum ExportTransport {
+        Udp,
+        Tcp,
+        File,
+    }
+
+    /// groups the objects matching `object` (a name or an OID prefix) into `measurement`
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct ExportMapping {
+        pub object: String,
+        pub measurement: String,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct ExportConfig {
+        pub format: ExportFormat,
+        pub transport: ExportTransport,
+        /// `host:port` for udp and tcp, a file path for file
+        pub address: String,
+        /// first path component of graphite and statsd metric names
+        #[serde(default = "default_prefix")]
+        pub prefix: String,
+        /// lines sent per write, and per datagram over udp
+        #[serde(default = "default_batch_lines")]
+        pub batch_lines: usize,
+        /// lines kept while the destination is unreachable, the oldest are dropped beyond this
+        #[serde(default = "default_buffer_lines")]
+        pub buffer_lines: usize,
+        #[serde(default)]
+        pub mappings: Vec<ExportMapping>,
+    }
+
+    fn default_prefix() -> String {
+        "snmp".to_owned()
+    }
+
+    fn default_batch_lines() -> usize {
+        20
+    }
+
+    fn default_buffer_lines() -> usize {
+        100_000
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct CsvConfig {
+        /// directory the GUI writes CSV exports to
+        pub dir: String,
+    }
+
+    impl Default for CsvConfig {
+        fn default() -> Self {
+            CsvConfig { dir: "exports".to_owned() }
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct TargetConfig {
+        /// an IP address, with `:port` when the agent does not listen on 161
+        pub address: String,
+        #[serde(default = "default_community")]
+        pub community: String,
+        #[serde(default = "default_profile")]
+        pub profile: String,
+        #[serde(default)]
+        pub vendor: String,
+        #[serde(default)]
+        pub sys_name: String,
+        #[serde(default)]
+        pub sys_object_id: String,
+    }
+
+    fn default_community() -> String {
+        "public".to_owned()
+    }
+
+    fn default_profile() -> String {
+        "default".to_owned()
+    }
+
+    impl TargetConfig {
+        pub fn new(address: &str, community: &str) -> Self {
+            TargetConfig {
+                address: address.to_owned(),
+                community: community.to_owned(),
+                profile: default_profile(),
+                vendor: String::new(),
+                sys_name: String::new(),
+                sys_object_id: String::new(),
+            }
+        }
+
+        pub fn socket_addr(&self) -> Option<SocketAddr> {
+            self.address
+                .parse::<SocketAddr>()
+                .ok()
+                .or_else(|| self.address.parse::<IpAddr>().ok().map(|ip| SocketAddr::new(ip, 161)))
+        }
+    }
+
+    /// how a group of targets is polled
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct PollProfile {
+        pub name: String,
+        pub interval_secs: u64,
+    }
+
+    /// gives targets whose sysObjectID starts with `sys_object_id` the profile `profile`
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct ProfileRule {
+        pub sys_object_id: String,
+        pub profile: String,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct DiscoveryConfig {
+        /// CIDR blocks (`10.0.0.0/24`), ranges (`10.0.0.1-10.0.0.20`) or single hosts
+        pub ranges: Vec<String>,
+        /// tried in order until one answers
+        pub communities: Vec<String>,
+        pub port: u16,
+        /// probes in flight at once
+        pub concurrency: usize,
+        /// probes started per second, 0 for no limit
+        pub rate_per_sec: u32,
+        pub timeout_ms: u64,
+        /// seconds between sweeps, 0 to sweep once at start and then only on demand
+        pub interval_secs: u64,
+        pub rules: Vec<ProfileRule>,
+        pub targets_file: String,
+    }
+
+    impl Default for DiscoveryConfig {
+        fn default() -> Self {
+            DiscoveryConfig {
+                ranges: vec![],
+                communities: vec![default_community()],
+                port: 161,
+                concurrency: 32,
+                rate_per_sec: 50,
+                timeout_ms: 1000,
+                interval_secs: 0,
+                rules: vec![],
+                targets_file: "data/targets.json".to_owned(),
+            }
+        }
+    }
+
+    impl Config {
+        /// reads the config file at `path`, falling back to the defaults when it is missing or invalid
+        pub fn load(path: &str) -> Self {
+            match fs::read_to_string(path) {
+                Ok(contents) => match serde_json::from_str::<Config>(&contents) {
+                    Ok(config) => config,
+                    Err(err) => {
+                        println!("could not parse {}, using defaults: {}", path, err);
+                        Config::default()
+                    }
+                },
+                Err(_) => Config::default(),
+            }
+        }
+    }
+}
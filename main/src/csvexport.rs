@@ -0,0 +1,251 @@
+pub mod CsvExportModule {
+    use chrono::{Local, TimeZone};
+    use std::collections::BTreeMap;
+    use std::fs::{self, File};
+    use std::io::{self, Write};
+    use std::path::{Path, PathBuf};
+
+    use crate::config::ConfigModule::Config;
+    use crate::miblog::MibLogModule;
+    use crate::mibobject::MibModule::{table_rows, MibObject, MibValue};
+    use crate::tsdb::TsdbModule::Tsdb;
+
+    /// makes Excel open the file as UTF-8 instead of the local code page
+    const BOM: &[u8] = b"\xEF\xBB\xBF";
+
+    const USAGE: &str = "usage:
+    snmp_monitor export table <table> --target <ip> [--at <unix time>] [--out <file>] [--excel]
+    snmp_monitor export series <name or key>... --target <ip> [--from <unix time>] [--to <unix time>] [--out <file>] [--excel]";
+
+    /// the unit of the MIB object `name`, if it has one worth noting
+    pub fn unit(name: &str) -> Option<&'static str> {
+        match name {
+            "sysUpTime" | "ifLastChange" | "ifCounterDiscontinuityTime" | "hrSystemUptime" | "hrSWRunPerfCPU" => Some("1/100 s"),
+            "hrMemorySize" | "hrSWRunPerfMem" => Some("KiB"),
+            "hrProcessorLoad" | "dskPercent" | "dskPercentNode" => Some("%"),
+            "laLoadInt" => Some("1/100"),
+            name if name.starts_with("mem") || name.starts_with("dskTotal") || name.starts_with("dskAvail") || name.starts_with("dskUsed") => Some("KiB"),
+            name if name.starts_with("ssCpuRaw") => Some("ticks"),
+            "ifSpeed" => Some("bit/s"),
+            "ifHighSpeed" => Some("Mbit/s"),
+            "ifMtu" => Some("octets"),
+            "ipRouteAge" | "ipReasmTimeout" => Some("s"),
+            "tcpRtoMin" | "tcpRtoMax" => Some("ms"),
+            name if name.ends_with("Octets") => Some("octets"),
+            name if name.ends_with("Segs") => Some("segments"),
+            name if name.ends_with("Msgs") => Some("messages"),
+            name if name.ends_with("Datagrams") || name.ends_with("Receives") || name.ends_with("Requests") => Some("datagrams"),
+            name if name.contains("Pkts") || name.ends_with("Discards") || name.ends_with("Errors") || name.ends_with("QLen") => Some("packets"),
+            _ => None,
+        }
+    }
+
+    /// `name [unit]`, or just `name` when it has no unit, the unit looked up by the first word
+    fn header(name: &str) -> String {
+        match unit(name.split(' ').next().unwrap_or_default()) {
+            Some(unit) => format!("{} [{}]", name, unit),
+            None => name.to_owned(),
+        }
+    }
+
+    fn local_time(timestamp: i64) -> String {
+        match Local.timestamp_opt(timestamp, 0).single() {
+            Some(time) => time.format("%Y-%m-%d %H:%M:%S").to_string(),
+            None => String::new(),
+        }
+    }
+
+    fn writer<W: Write>(mut writer: W, excel: bool) -> io::Result<csv::Writer<W>> {
+        if excel {
+            writer.write_all(BOM)?;
+        }
+        Ok(csv::Writer::from_writer(writer))
+    }
+
+    /// writes one row per table instance, the columns of `columns` lined up by their index
+    pub fn write_table<W: Write>(output: W, object: &MibObject, columns: &[&MibValue], excel: bool) -> io::Result<()> {
+        let rows = table_rows(columns);
+
+        let mut writer = writer(output, excel)?;
+        let mut headers = vec!["time".to_owned(), "timestamp".to_owned(), "target".to_owned(), "index".to_owned()];
+        headers.extend(columns.iter().map(|value| header(&value.get_name())));
+        writer.write_record(&headers)?;
+        for (index, cells) in rows {
+            let mut record = vec![local_time(object.timestamp), object.timestamp.to_string(), object.target.clone(), index];
+            record.extend(cells);
+            writer.write_record(&record)?;
+        }
+        writer.flush()
+    }
+
+    /// writes one row per timestamp and one column per `(name, points)` series, leaving cells
+    /// empty where a series has no sample
+    pub fn write_series<W: Write>(output: W, target: &str, series: &[(String, Vec<(i64, i64)>)], excel: bool) -> io::Result<()> {
+        let mut rows: BTreeMap<i64, Vec<String>> = BTreeMap::new();
+        for (column, (_, points)) in series.iter().enumerate() {
+            for (timestamp, value) in points {
+                rows.entry(*timestamp).or_insert_with(|| vec![String::new(); series.len()])[column] = value.to_string();
+            }
+        }
+
+        let mut writer = writer(output, excel)?;
+        let mut headers = vec!["time".to_owned(), "timestamp".to_owned(), "target".to_owned()];
+        headers.extend(series.iter().map(|(name, _)| header(name)));
+        writer.write_record(&headers)?;
+        for (timestamp, cells) in rows {
+            let mut record = vec![local_time(timestamp), timestamp.to_string(), target.to_owned()];
+            record.extend(cells);
+            writer.write_record(&record)?;
+        }
+        writer.flush()
+    }
+
+    /// `<dir>/<name>-<target>-<local time>.csv`, creating `dir` when needed
+    pub fn export_path(dir: &str, name: &str, target: &str, timestamp: i64) -> io::Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+        let time = match Local.timestamp_opt(timestamp, 0).single() {
+            Some(time) => time.format("%Y%m%d-%H%M%S").to_string(),
+            None => timestamp.to_string(),
+        };
+        let file = format!("{}-{}-{}.csv", name, target, time).replace(['/', '\\', ':', ' '], "_");
+        Ok(Path::new(dir).join(file))
+    }
+
+    fn arg_value(args: &[String], flag: &str) -> Option<String> {
+        args.iter().position(|a| a == flag).and_then(|position| args.get(position + 1)).cloned()
+    }
+
+    /// the positional arguments, skipping flags and their values
+    fn positional(args: &[String]) -> Vec<String> {
+        let mut positional = vec![];
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--excel" => {}
+                flag if flag.starts_with("--") => {
+                    args.next();
+                }
+                _ => positional.push(arg.clone()),
+            }
+        }
+        positional
+    }
+
+    /// runs `snmp_monitor export ...` with the arguments after `export`, returning the exit code
+    pub fn cli(args: &[String], config: &Config) -> i32 {
+        match run(args, config) {
+            Ok(()) => 0,
+            Err(err) => {
+                eprintln!("{}\n{}", err, USAGE);
+                1
+            }
+        }
+    }
+
+    fn run(args: &[String], config: &Config) -> Result<(), String> {
+        let positional = positional(args);
+        let target = arg_value(args, "--target").ok_or("missing --target")?;
+        let excel = args.iter().any(|a| a == "--excel");
+        let parse = |flag: &str| arg_value(args, flag).map(|a| a.parse::<i64>().map_err(|_| format!("{} takes a unix time", flag))).transpose();
+        // only create the output file once there is something to write
+        let output = || -> Result<Box<dyn Write>, String> {
+            match arg_value(args, "--out") {
+                Some(path) => Ok(Box::new(File::create(&path).map_err(|err| format!("could not create {}: {}", path, err))?)),
+                None => Ok(Box::new(io::stdout())),
+            }
+        };
+
+        match positional.first().map(|a| a.as_str()) {
+            Some("table") => {
+                let name = positional.get(1).ok_or("missing table name")?;
+                let at = parse("--at")?.unwrap_or(i64::MAX);
+                // only the snapshot that is exported gets parsed
+                let snapshot = MibLogModule::index_snapshots(&config.log, &target)
+                    .into_iter()
+                    .rev()
+                    .find(|snapshot| snapshot.timestamp <= at)
+                    .ok_or(format!("no logged poll of {} at or before that time", target))?;
+                let object = MibLogModule::read_snapshot(&snapshot).map_err(|err| format!("could not read {}: {}", snapshot.path.display(), err))?;
+                let columns = object.table(name).ok_or(format!("unknown table {}", name))?;
+                write_table(output()?, &object, &columns, excel).map_err(|err| err.to_string())
+            }
+            Some("series") => {
+                let names = &positional[1..];
+                if names.is_empty() {
+                    return Err("missing series name".to_owned());
+                }
+                let from = parse("--from")?.unwrap_or(0);
+                let to = parse("--to")?.unwrap_or(i64::MAX);
+                let mut tsdb = Tsdb::open(config.storage.clone()).map_err(|err| err.to_string())?;
+                let known = tsdb.series(&target).map_err(|err| err.to_string())?.ok_or(format!("nothing is stored for {}", target))?;
+                let mut series = vec![];
+                for name in names {
+                    // a name covers the series of every instance of that object
+                    let matching = known.iter().filter(|(key, series_name)| key == name || series_name == name).collect::<Vec<&(String, String)>>();
+                    if matching.is_empty() {
+                        return Err(format!("{} has no series {}", target, name));
+                    }
+                    for (key, series_name) in matching {
+                        let points = tsdb.query(&target, key, from, to).map_err(|err| err.to_string())?;
+                        series.push((format!("{} {}", series_name, key), points));
+                    }
+                }
+                write_series(output()?, &target, &series, excel).map_err(|err| err.to_string())
+            }
+            _ => Err("export what?".to_owned()),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::{mvinti32, mvintu32, mvstring};
+
+        #[test]
+        fn test_write_table() {
+            let mut object = MibObject::new();
+            object.target = "192.0.2.1".to_owned();
+            object.timestamp = 1700000000;
+            let table = &mut object.interfaces.ifTable;
+            if let MibValue::inti32(mvinti32 { value, index, .. }) = &mut table.ifIndex {
+                *value = vec![1, 2];
+                *index = vec![vec![1], vec![2]];
+            }
+            if let MibValue::string(mvstring { value, index, .. }) = &mut table.ifDescr {
+                *value = vec!["lo".to_owned(), "eth0, uplink".to_owned()];
+                *index = vec![vec![1], vec![2]];
+            }
+            // a column missing the first row must not shift the second into it
+            if let MibValue::intu32(mvintu32 { value, index, .. }) = &mut table.ifInOctets {
+                *value = vec![20];
+                *index = vec![vec![2]];
+            }
+            let table = &object.interfaces.ifTable;
+            let columns = vec![&table.ifIndex, &table.ifDescr, &table.ifInOctets];
+
+            let mut output = vec![];
+            write_table(&mut output, &object, &columns, true).unwrap();
+            assert!(output.starts_with(BOM));
+            let text = String::from_utf8(output[BOM.len()..].to_vec()).unwrap();
+            let lines = text.lines().collect::<Vec<&str>>();
+            assert_eq!(lines[0], "time,timestamp,target,index,ifIndex,ifDescr,ifInOctets [octets]");
+            assert!(lines[1].ends_with(",1700000000,192.0.2.1,1,1,lo,"));
+            assert!(lines[2].ends_with(",1700000000,192.0.2.1,2,2,\"eth0, uplink\",20"));
+        }
+
+        #[test]
+        fn test_write_series() {
+            let series = vec![
+                ("ifInOctets".to_owned(), vec![(100, 1), (130, 5)]),
+                ("sysUpTime".to_owned(), vec![(130, 42)]),
+            ];
+            let mut output = vec![];
+            write_series(&mut output, "192.0.2.1", &series, false).unwrap();
+            let text = String::from_utf8(output).unwrap();
+            let lines = text.lines().collect::<Vec<&str>>();
+            assert_eq!(lines[0], "time,timestamp,target,ifInOctets [octets],sysUpTime [1/100 s]");
+            assert!(lines[1].ends_with(",100,192.0.2.1,1,"));
+            assert!(lines[2].ends_with(",130,192.0.2.1,5,42"));
+        }
+    }
+}
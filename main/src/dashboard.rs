@@ -0,0 +1,218 @@
+pub mod DashboardModule {
+    use chrono::Utc;
+    use egui::Rect;
+    use egui_dock::{DockState, Node};
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    use crate::series::SeriesModule::{Side, Style};
+    use crate::timeaxis::TimeAxisModule::Window;
+
+    /// the dashboard saved on exit and restored at start
+    pub const AUTOSAVE: &str = "last session";
+
+    /// one series of a saved plot, the instance it follows and how its history is looked up
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct SavedSeries {
+        pub name: String,
+        pub oid: Vec<u16>,
+        pub key: String,
+        #[serde(default)]
+        pub percent_of: Option<(Vec<u16>, String)>,
+        #[serde(default)]
+        pub unit: String,
+        #[serde(default)]
+        pub side: Side,
+    }
+
+    /// a series computed from an expression over polled values
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct SavedDerived {
+        pub name: String,
+        pub expr: String,
+        #[serde(default)]
+        pub unit: String,
+        #[serde(default)]
+        pub side: Side,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct SavedPlot {
+        pub series: Vec<SavedSeries>,
+        pub draggable: bool,
+        pub axes: bool,
+        pub scroll: bool,
+        pub zoom: bool,
+        pub legend: bool,
+        pub grid: bool,
+        pub window: Window,
+        pub follow: bool,
+        pub from: String,
+        pub to: String,
+        #[serde(default)]
+        pub derived: Vec<SavedDerived>,
+        #[serde(default)]
+        pub style: Style,
+    }
+
+    /// what a session shows: the target, the arrangement of the tabs and the plots in them
+    #[derive(Clone, Serialize, Deserialize)]
+    pub struct Dashboard {
+        pub name: String,
+        pub target: String,
+        /// when the dashboard was saved, as a unix timestamp
+        pub saved: i64,
+        pub layout: DockState<String>,
+        pub plots: BTreeMap<String, SavedPlot>,
+    }
+
+    impl Dashboard {
+        pub fn new(name: &str, target: &str, mut layout: DockState<String>, plots: BTreeMap<String, SavedPlot>) -> Self {
+            // nodes not drawn yet have infinite rects, which JSON cannot hold; they are laid out again when shown
+            for (_, node) in layout.iter_all_nodes_mut() {
+                let rects = match node {
+                    Node::Leaf { rect, viewport, .. } => vec![rect, viewport],
+                    Node::Vertical { rect, .. } | Node::Horizontal { rect, .. } => vec![rect],
+                    Node::Empty => vec![],
+                };
+                rects.into_iter().filter(|a| !a.is_finite()).for_each(|a| *a = Rect::ZERO);
+            }
+            Dashboard { name: name.to_owned(), target: target.to_owned(), saved: Utc::now().timestamp(), layout, plots }
+        }
+
+        /// every tab of the layout, docked or in a window
+        pub fn tabs(&self) -> Vec<String> {
+            self.layout.iter_all_tabs().map(|(_, tab)| tab.clone()).collect()
+        }
+    }
+
+    /// what the dashboards tab asked for, carried out where the dock layout is at hand
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Action {
+        /// saves the current session under the name
+        Save(String),
+        Load(String),
+        Delete(String),
+        Export(String),
+        /// adds the dashboard exported to the path
+        Import(String),
+    }
+
+    /// the dashboards of `DashboardConfig::file` by name
+    #[derive(Default, Serialize, Deserialize)]
+    pub struct Dashboards {
+        pub dashboards: BTreeMap<String, Dashboard>,
+    }
+
+    impl Dashboards {
+        /// the saved dashboards, none when the file is missing or unreadable; a file that does
+        /// not parse is moved to `<path>.bak` so that saving does not overwrite it
+        pub fn load(path: &str) -> Self {
+            match fs::read_to_string(path) {
+                Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|err| {
+                    let backup = [path, ".bak"].concat();
+                    match fs::rename(path, &backup) {
+                        Ok(()) => println!("could not read dashboards from {}, moved it to {}: {}", path, backup, err),
+                        Err(rename_err) => println!("could not read dashboards from {}: {}, nor move it aside: {}", path, err, rename_err),
+                    }
+                    Dashboards::default()
+                }),
+                Err(_) => Dashboards::default(),
+            }
+        }
+
+        pub fn save(&self, path: &str) -> io::Result<()> {
+            if let Some(parent) = Path::new(path).parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let tmp = [path, ".tmp"].concat();
+            fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+            fs::rename(&tmp, path)
+        }
+
+        /// adds `dashboard`, replacing the one of the same name
+        pub fn insert(&mut self, dashboard: Dashboard) {
+            self.dashboards.insert(dashboard.name.clone(), dashboard);
+        }
+    }
+
+    /// writes `dashboard` on its own to `<dir>/<name>.json`, to be imported elsewhere
+    pub fn export(dir: &str, dashboard: &Dashboard) -> io::Result<PathBuf> {
+        fs::create_dir_all(dir)?;
+        let file = dashboard.name.chars().map(|a| if a.is_alphanumeric() || a == '-' || a == '_' { a } else { '_' }).collect::<String>();
+        let path = Path::new(dir).join(format!("{}.json", file));
+        fs::write(&path, serde_json::to_vec_pretty(dashboard)?)?;
+        Ok(path)
+    }
+
+    /// a dashboard written by `export`
+    pub fn import(path: &str) -> Result<Dashboard, String> {
+        let contents = fs::read_to_string(path.trim()).map_err(|err| err.to_string())?;
+        serde_json::from_str(&contents).map_err(|err| err.to_string())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use egui_dock::NodeIndex;
+
+        fn dashboard(name: &str) -> Dashboard {
+            let mut layout = DockState::new(vec!["ifTable".to_owned(), "traffic".to_owned()]);
+            layout.main_surface_mut().split_right(NodeIndex::root(), 0.6, vec!["alerts".to_owned()]);
+            let plot = SavedPlot {
+                series: vec![SavedSeries { name: "ifInOctets.3".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 10, 3], key: "1.3.6.1.2.1.2.2.1.10.3".to_owned(), percent_of: None, unit: "B".to_owned(), side: Side::Left }],
+                draggable: true,
+                axes: true,
+                scroll: true,
+                zoom: true,
+                legend: true,
+                grid: false,
+                window: Window::Day,
+                follow: true,
+                from: String::new(),
+                to: String::new(),
+                derived: vec![SavedDerived { name: "in".to_owned(), expr: "rate(ifInOctets[3])*8".to_owned(), unit: "bit/s".to_owned(), side: Side::Right }],
+                style: Style::Stacked,
+            };
+            Dashboard::new(name, "10.0.0.1", layout, BTreeMap::from([("traffic".to_owned(), plot)]))
+        }
+
+        #[test]
+        fn test_save_and_load() {
+            let dir = std::env::temp_dir().join(format!("snmp-monitor-dashboards-{}", std::process::id()));
+            let path = dir.join("dashboards.json").to_string_lossy().into_owned();
+            let mut dashboards = Dashboards::load(&path);
+            assert!(dashboards.dashboards.is_empty());
+            dashboards.insert(dashboard("core"));
+            dashboards.insert(dashboard(AUTOSAVE));
+            dashboards.save(&path).unwrap();
+
+            let loaded = Dashboards::load(&path);
+            assert_eq!(loaded.dashboards.keys().collect::<Vec<&String>>(), vec!["core", AUTOSAVE]);
+            let core = &loaded.dashboards["core"];
+            assert_eq!(core.target, "10.0.0.1");
+            assert_eq!(core.tabs(), vec!["ifTable", "traffic", "alerts"]);
+            assert_eq!(core.plots["traffic"], dashboard("core").plots["traffic"]);
+            // plots saved before derived series and styles existed still load
+            let mut old = serde_json::to_value(&core.plots["traffic"]).unwrap();
+            old.as_object_mut().unwrap().retain(|key, _| key != "derived" && key != "style");
+            assert_eq!(serde_json::from_value::<SavedPlot>(old).unwrap().style, Style::Lines);
+
+            let exported = export(&dir.to_string_lossy(), &loaded.dashboards[AUTOSAVE]).unwrap();
+            assert!(exported.ends_with("last_session.json"));
+            let imported = import(&exported.to_string_lossy()).unwrap();
+            assert_eq!((imported.name.as_str(), imported.tabs().len()), (AUTOSAVE, 3));
+            assert!(import(&dir.join("missing.json").to_string_lossy()).is_err());
+
+            // a file that does not parse is kept aside rather than saved over
+            fs::write(&path, "{ not json").unwrap();
+            assert!(Dashboards::load(&path).dashboards.is_empty());
+            assert_eq!(fs::read_to_string([path.as_str(), ".bak"].concat()).unwrap(), "{ not json");
+            assert!(!Path::new(&path).exists());
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+}
@@ -0,0 +1,290 @@
+pub mod DiscoveryModule {
+    use csnmp::{ObjectIdentifier, Snmp2cClient};
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::Semaphore;
+    use tokio::task::JoinSet;
+    use tokio::time;
+
+    use crate::config::ConfigModule::{DiscoveryConfig, ProfileRule, TargetConfig};
+
+    const SYS_OBJECT_ID: &str = "1.3.6.1.2.1.1.2.0";
+    const SYS_NAME: &str = "1.3.6.1.2.1.1.5.0";
+
+    /// ranges larger than this are refused rather than swept
+    const MAX_ADDRESSES: usize = 65536;
+
+    /// enterprise numbers under 1.3.6.1.4.1 of common vendors
+    const VENDORS: &[(u32, &str)] = &[
+        (2, "IBM"),
+        (9, "Cisco"),
+        (11, "HP"),
+        (43, "3Com"),
+        (311, "Microsoft"),
+        (674, "Dell"),
+        (1916, "Extreme"),
+        (1991, "Brocade"),
+        (2011, "Huawei"),
+        (2636, "Juniper"),
+        (3375, "F5"),
+        (6527, "Nokia"),
+        (6876, "VMware"),
+        (8072, "Net-SNMP"),
+        (11863, "TP-Link"),
+        (12356, "Fortinet"),
+        (14988, "MikroTik"),
+        (25461, "Palo Alto"),
+        (25506, "H3C"),
+        (30065, "Arista"),
+        (41112, "Ubiquiti"),
+    ];
+
+    /// a device that answered a probe
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Responder {
+        pub address: SocketAddr,
+        pub community: String,
+        pub sys_object_id: String,
+        pub sys_name: String,
+    }
+
+    /// every address of a CIDR block (without network and broadcast address), an inclusive
+    /// `first-last` range or a single host
+    pub fn expand(range: &str) -> Result<Vec<IpAddr>, String> {
+        let range = range.trim();
+        let invalid = || format!("invalid range '{}'", range);
+        // the range is sized before any address is collected, so huge ones cost nothing to refuse
+        let (first, last) = if let Some((network, prefix)) = range.split_once('/') {
+            let network = Ipv4Addr::from_str(network).map_err(|_| invalid())?;
+            let prefix = prefix.parse::<u32>().ok().filter(|a| *a <= 32).ok_or_else(invalid)?;
+            let size = 1u64 << (32 - prefix);
+            let first = u32::from(network) as u64 & !(size - 1);
+            match prefix {
+                31 | 32 => (first, first + size - 1),
+                _ => (first + 1, first + size - 2),
+            }
+        } else if let Some((first, last)) = range.split_once('-') {
+            let first = u32::from(Ipv4Addr::from_str(first.trim()).map_err(|_| invalid())?) as u64;
+            let last = u32::from(Ipv4Addr::from_str(last.trim()).map_err(|_| invalid())?) as u64;
+            if last < first {
+                return Err(format!("range '{}' ends before it starts", range));
+            }
+            (first, last)
+        } else {
+            return IpAddr::from_str(range).map(|ip| vec![ip]).map_err(|_| invalid());
+        };
+        if last - first + 1 > MAX_ADDRESSES as u64 {
+            return Err(format!("range '{}' has more than {} addresses", range, MAX_ADDRESSES));
+        }
+        Ok((first..=last).map(|a| IpAddr::V4(Ipv4Addr::from(a as u32))).collect())
+    }
+
+    /// asks `address` for its sysObjectID and sysName with each community until one is answered
+    pub async fn probe(address: SocketAddr, communities: &[String], timeout: Duration) -> Option<Responder> {
+        let bind = match address {
+            SocketAddr::V4(_) => "0.0.0.0:0",
+            SocketAddr::V6(_) => "[::]:0",
+        };
+        let oids = [ObjectIdentifier::from_str(SYS_OBJECT_ID).ok()?, ObjectIdentifier::from_str(SYS_NAME).ok()?];
+        for community in communities {
+            let client = match Snmp2cClient::new(address, community.as_bytes().to_vec(), bind.parse().ok(), Some(timeout)).await {
+                Ok(client) => client,
+                Err(_) => continue,
+            };
+            if let Ok(values) = client.get_multiple(oids).await {
+                return Some(Responder {
+                    address,
+                    community: community.clone(),
+                    sys_object_id: values.get(&oids[0]).and_then(|a| a.as_oid()).map(|a| a.to_string()).unwrap_or_default(),
+                    sys_name: values.get(&oids[1]).and_then(|a| a.as_bytes()).map(|a| String::from_utf8_lossy(a).into_owned()).unwrap_or_default(),
+                });
+            }
+        }
+        None
+    }
+
+    /// probes every address of every range, at most `concurrency` at once and `rate_per_sec`
+    /// started per second, and returns those that answered ordered by address
+    pub async fn sweep(config: &DiscoveryConfig) -> Vec<Responder> {
+        let mut addresses = vec![];
+        for range in &config.ranges {
+            match expand(range) {
+                Ok(range) => addresses.extend(range),
+                Err(err) => println!("skipping discovery of {}", err),
+            }
+        }
+        addresses.sort();
+        addresses.dedup();
+        let permits = Arc::new(Semaphore::new(config.concurrency.max(1)));
+        let mut pace = match config.rate_per_sec {
+            0 => None,
+            rate => Some(time::interval(Duration::from_secs_f64(1.0 / rate as f64))),
+        };
+        let communities = Arc::new(config.communities.clone());
+        let timeout = Duration::from_millis(config.timeout_ms);
+        let mut probes = JoinSet::new();
+        for ip in addresses {
+            let permit = permits.clone().acquire_owned().await.expect("discovery semaphore closed");
+            if let Some(pace) = pace.as_mut() {
+                pace.tick().await;
+            }
+            let communities = communities.clone();
+            let address = SocketAddr::new(ip, config.port);
+            probes.spawn(async move {
+                let responder = probe(address, &communities, timeout).await;
+                drop(permit);
+                responder
+            });
+        }
+        let mut responders = vec![];
+        while let Some(result) = probes.join_next().await {
+            if let Ok(Some(responder)) = result {
+                responders.push(responder);
+            }
+        }
+        responders.sort_by_key(|a| a.address);
+        responders
+    }
+
+    /// the vendor owning the enterprise subtree `sys_object_id` lies in
+    pub fn vendor(sys_object_id: &str) -> &'static str {
+        let enterprise = sys_object_id.strip_prefix("1.3.6.1.4.1.").and_then(|a| a.split('.').next()).and_then(|a| a.parse::<u32>().ok());
+        match enterprise.and_then(|number| VENDORS.iter().find(|a| a.0 == number)) {
+            Some((_, vendor)) => vendor,
+            None => "",
+        }
+    }
+
+    /// the profile of the longest rule that `sys_object_id` starts with, `default` without one
+    pub fn classify(sys_object_id: &str, rules: &[ProfileRule]) -> String {
+        rules
+            .iter()
+            .filter(|rule| sys_object_id == rule.sys_object_id || sys_object_id.starts_with(&[rule.sys_object_id.as_str(), "."].concat()))
+            .max_by_key(|rule| rule.sys_object_id.len())
+            .map(|rule| rule.profile.clone())
+            .unwrap_or("default".to_owned())
+    }
+
+    impl Responder {
+        pub fn target(&self, rules: &[ProfileRule]) -> TargetConfig {
+            let address = match self.address.port() {
+                161 => self.address.ip().to_string(),
+                _ => self.address.to_string(),
+            };
+            TargetConfig {
+                profile: classify(&self.sys_object_id, rules),
+                vendor: vendor(&self.sys_object_id).to_owned(),
+                sys_name: self.sys_name.clone(),
+                sys_object_id: self.sys_object_id.clone(),
+                ..TargetConfig::new(&address, &self.community)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use csnmp::message::{BindingValue, ErrorStatus, InnerPdu, Snmp2cMessage, Snmp2cPdu, VariableBinding};
+        use csnmp::ObjectValue;
+        use tokio::net::UdpSocket;
+
+        /// answers gets for sysObjectID and sysName on `ip` when asked with `community`
+        async fn agent(ip: &str, port: u16, community: &str, sys_object_id: &str, sys_name: &str) {
+            let socket = UdpSocket::bind((ip, port)).await.unwrap();
+            let community = community.as_bytes().to_vec();
+            let sys_object_id = ObjectIdentifier::from_str(sys_object_id).unwrap();
+            let sys_name = sys_name.as_bytes().to_vec();
+            tokio::spawn(async move {
+                let mut buffer = [0u8; 2048];
+                loop {
+                    let (len, peer) = socket.recv_from(&mut buffer).await.unwrap();
+                    let request = match Snmp2cMessage::try_from_bytes(&buffer[..len]) {
+                        Ok(request) if request.community == community => request,
+                        _ => continue,
+                    };
+                    let pdu = match request.pdu {
+                        Snmp2cPdu::GetRequest(pdu) => pdu,
+                        _ => continue,
+                    };
+                    let variable_bindings = pdu
+                        .variable_bindings
+                        .iter()
+                        .map(|binding| {
+                            let value = match binding.name.to_string().as_str() {
+                                SYS_OBJECT_ID => BindingValue::Value(ObjectValue::ObjectId(sys_object_id)),
+                                SYS_NAME => BindingValue::Value(ObjectValue::String(sys_name.clone())),
+                                _ => BindingValue::NoSuchObject,
+                            };
+                            VariableBinding { name: binding.name, value }
+                        })
+                        .collect();
+                    let response = Snmp2cMessage {
+                        version: request.version,
+                        community: request.community,
+                        pdu: Snmp2cPdu::Response(InnerPdu {
+                            request_id: pdu.request_id,
+                            error_status: ErrorStatus::NoError,
+                            error_index: 0,
+                            variable_bindings,
+                        }),
+                    };
+                    socket.send_to(&response.to_bytes().unwrap(), peer).await.unwrap();
+                }
+            });
+        }
+
+        #[test]
+        fn test_expand() {
+            assert_eq!(expand("10.0.0.0/30").unwrap(), vec![IpAddr::from([10, 0, 0, 1]), IpAddr::from([10, 0, 0, 2])]);
+            assert_eq!(expand("10.0.0.7/31").unwrap().len(), 2);
+            assert_eq!(expand("10.0.0.250-10.0.1.4").unwrap().len(), 11);
+            assert_eq!(expand("::1").unwrap(), vec![IpAddr::from_str("::1").unwrap()]);
+            assert!(expand("10.0.0.0/8").is_err());
+            assert!(expand("0.0.0.0/0").is_err());
+            assert!(expand("10.0.1.4-10.0.0.250").is_err());
+            assert!(expand("10.0.0.0/33").is_err());
+        }
+
+        #[test]
+        fn test_classify() {
+            let rules = vec![
+                ProfileRule { sys_object_id: "1.3.6.1.4.1.9".to_owned(), profile: "cisco".to_owned() },
+                ProfileRule { sys_object_id: "1.3.6.1.4.1.9.1.1745".to_owned(), profile: "core".to_owned() },
+            ];
+            assert_eq!(classify("1.3.6.1.4.1.9.1.1745", &rules), "core");
+            assert_eq!(classify("1.3.6.1.4.1.9.1.516", &rules), "cisco");
+            assert_eq!(classify("1.3.6.1.4.1.99", &rules), "default");
+            assert_eq!(vendor("1.3.6.1.4.1.2636.1.1.1.2.29"), "Juniper");
+            assert_eq!(vendor("1.3.6.1.2.1"), "");
+        }
+
+        #[tokio::test]
+        async fn test_sweep() {
+            // find a free port on the first address and run the stand-in agents on it
+            let port = UdpSocket::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap().port();
+            agent("127.0.0.1", port, "public", "1.3.6.1.4.1.8072.3.2.10", "linux").await;
+            agent("127.0.0.3", port, "secret", "1.3.6.1.4.1.9.1.1745", "core-1").await;
+
+            let config = DiscoveryConfig {
+                ranges: vec!["127.0.0.0/30".to_owned(), "127.0.0.3".to_owned()],
+                communities: vec!["public".to_owned(), "secret".to_owned()],
+                port,
+                rate_per_sec: 0,
+                timeout_ms: 200,
+                rules: vec![ProfileRule { sys_object_id: "1.3.6.1.4.1.9".to_owned(), profile: "cisco".to_owned() }],
+                ..DiscoveryConfig::default()
+            };
+            let responders = sweep(&config).await;
+            assert_eq!(responders.len(), 2);
+            assert_eq!(responders[0].sys_name, "linux");
+            assert_eq!(responders[1].community, "secret");
+
+            let target = responders[1].target(&config.rules);
+            assert_eq!(target.address, format!("127.0.0.3:{}", port));
+            assert_eq!((target.vendor.as_str(), target.profile.as_str()), ("Cisco", "cisco"));
+            assert_eq!(target.sys_object_id, "1.3.6.1.4.1.9.1.1745");
+        }
+    }
+}
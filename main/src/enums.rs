@@ -0,0 +1,37 @@
+pub mod EnumModule {
+    /// the labels of the enumerated INTEGER objects known by name, numbered from 1
+    const LABELS: &[(&str, &[&str])] = &[
+        ("ifAdminStatus", &["up", "down", "testing"]),
+        ("ifOperStatus", &["up", "down", "testing", "unknown", "dormant", "notPresent", "lowerLayerDown"]),
+        ("ipForwarding", &["forwarding", "notForwarding"]),
+        (
+            "tcpConnState",
+            &[
+                "closed", "listen", "synSent", "synReceived", "established", "finWait1",
+                "finWait2", "closeWait", "lastAck", "closing", "timeWait", "deleteTCB",
+            ],
+        ),
+        ("egpNeighState", &["idle", "acquisition", "down", "up", "cease"]),
+        ("snmpEnableAuthenTraps", &["enabled", "disabled"]),
+    ];
+
+    /// the numeric value of the label `label` of the named object, e.g. 1 for `up` of
+    /// `ifOperStatus`; `None` when the object has no known labels or none by that name
+    pub fn value(name: &str, label: &str) -> Option<i64> {
+        let (_, labels) = LABELS.iter().find(|a| a.0 == name)?;
+        labels.iter().position(|a| *a == label).map(|a| a as i64 + 1)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_value() {
+            assert_eq!(value("ifAdminStatus", "down"), Some(2));
+            assert_eq!(value("tcpConnState", "deleteTCB"), Some(12));
+            assert_eq!(value("ifAdminStatus", "dormant"), None);
+            assert_eq!(value("ifAlias", "true"), None);
+        }
+    }
+}
@@ -0,0 +1,304 @@
+pub mod ExportModule {
+    use std::collections::{HashMap, VecDeque};
+    use std::io;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::fs::OpenOptions;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::{TcpStream, UdpSocket};
+    use tokio::sync::mpsc::{self, Sender};
+    use tokio::task;
+    use tokio::time::timeout;
+
+    use crate::config::ConfigModule::{ExportConfig, ExportFormat, ExportTransport};
+    use crate::metrics::MetricsModule::{metric_kind, oid_string, Labeller};
+    use crate::mibobject::MibModule::MibObject;
+
+    /// how long connecting or writing may take before the destination counts as unreachable
+    const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+    /// polls waiting for the exporters, further polls are dropped until they catch up
+    const QUEUE_POLLS: usize = 16;
+
+    /// the fields of one table row, or one scalar, in one measurement
+    struct Point {
+        measurement: String,
+        tags: Vec<(String, String)>,
+        /// name, value and whether the value is a counter
+        fields: Vec<(String, i64, bool)>,
+    }
+
+    /// sends every poll to one Influx, Graphite or StatsD destination, buffering while it is down
+    pub struct Exporter {
+        config: ExportConfig,
+        buffer: VecDeque<String>,
+        stream: Option<TcpStream>,
+        socket: Option<UdpSocket>,
+        /// the last value of every counter sent to StatsD, which only takes the increase
+        counters: HashMap<String, i64>,
+    }
+
+    /// runs `exporters` on a task of their own, so a slow or unreachable destination never holds up
+    /// polling; polls arriving while the queue is full are dropped
+    pub fn spawn(mut exporters: Vec<Exporter>) -> Sender<Arc<MibObject>> {
+        let (sender, mut receiver) = mpsc::channel::<Arc<MibObject>>(QUEUE_POLLS);
+        task::spawn(async move {
+            while let Some(object) = receiver.recv().await {
+                for exporter in exporters.iter_mut() {
+                    exporter.export(&object).await;
+                }
+            }
+        });
+        sender
+    }
+
+    impl Exporter {
+        pub fn new(config: ExportConfig) -> Self {
+            Exporter { config, buffer: VecDeque::new(), stream: None, socket: None, counters: HashMap::new() }
+        }
+
+        /// the measurement of the value `name` with the OID `oid`, its own name when unmapped
+        fn measurement(&self, name: &str, oid: &str) -> String {
+            self.config
+                .mappings
+                .iter()
+                .find(|mapping| mapping.object == name || oid == mapping.object || oid.starts_with(&[mapping.object.as_str(), "."].concat()))
+                .map(|mapping| mapping.measurement.clone())
+                .unwrap_or(name.to_owned())
+        }
+
+        /// every numeric value of `object`, with the columns of one table row sharing a point
+        fn points(&self, object: &MibObject) -> Vec<Point> {
+            let labeller = Labeller::new(object);
+            let mut points: Vec<Point> = vec![];
+            let mut positions: HashMap<(String, Vec<(String, String)>), usize> = HashMap::new();
+            for value in object.values() {
+                let name = value.get_name();
+                let counter = metric_kind(value) == "counter";
+                let measurement = self.measurement(&name, &oid_string(&value.get_oid()));
+                for (position, number) in value.val_as_mvinti64().unwrap_or_default().into_iter().enumerate() {
+                    let tags = labeller.labels(value, position).into_iter().filter(|a| !a.1.is_empty()).collect::<Vec<(String, String)>>();
+                    let position = *positions.entry((measurement.clone(), tags.clone())).or_insert_with(|| {
+                        points.push(Point { measurement: measurement.clone(), tags, fields: vec![] });
+                        points.len() - 1
+                    });
+                    points[position].fields.push((name.clone(), number, counter));
+                }
+            }
+            points
+        }
+
+        /// renders `object` in the configured format, one metric per line; StatsD counters are sent
+        /// as their increase since the previous poll, so they start with the second one
+        pub fn lines(&mut self, object: &MibObject) -> Vec<String> {
+            let timestamp = object.timestamp;
+            let points = self.points(object);
+            match self.config.format {
+                ExportFormat::Influx => points
+                    .iter()
+                    .map(|point| {
+                        let tags = point.tags.iter().map(|(key, value)| format!(",{}={}", influx_escape(key), influx_escape(value))).collect::<String>();
+                        let fields = point
+                            .fields
+                            .iter()
+                            .map(|(key, value, _)| format!("{}={}i", influx_escape(key), value))
+                            .collect::<Vec<String>>()
+                            .join(",");
+                        format!("{}{} {} {}", influx_escape(&point.measurement), tags, fields, timestamp * 1_000_000_000)
+                    })
+                    .collect(),
+                ExportFormat::Graphite => points
+                    .iter()
+                    .flat_map(|point| {
+                        let tags = point.tags.iter().map(|(key, value)| format!(";{}={}", path_escape(key), path_escape(value))).collect::<String>();
+                        point
+                            .fields
+                            .iter()
+                            .map(|(field, value, _)| format!("{}{} {} {}", self.path(&point.measurement, field), tags, value, timestamp))
+                            .collect::<Vec<String>>()
+                    })
+                    .collect(),
+                ExportFormat::Statsd => {
+                    let mut lines = vec![];
+                    for point in points {
+                        let tags = point.tags.iter().map(|(key, value)| format!("{}:{}", path_escape(key), path_escape(value))).collect::<Vec<String>>().join(",");
+                        for (field, value, counter) in point.fields {
+                            let path = self.path(&point.measurement, &field);
+                            if !counter {
+                                lines.push(format!("{}:{}|g|#{}", path, value, tags));
+                                continue;
+                            }
+                            // a counter that went down wrapped or was reset, its increase is unknown
+                            let previous = self.counters.insert([path.as_str(), "|", tags.as_str()].concat(), value);
+                            if let Some(increase) = previous.map(|previous| value - previous).filter(|a| *a >= 0) {
+                                lines.push(format!("{}:{}|c|#{}", path, increase, tags));
+                            }
+                        }
+                    }
+                    lines
+                }
+            }
+        }
+
+        /// `prefix.measurement.field`, leaving out the field when it names the measurement
+        fn path(&self, measurement: &str, field: &str) -> String {
+            let mut path = vec![self.config.prefix.as_str(), measurement];
+            if field != measurement {
+                path.push(field);
+            }
+            path.into_iter().filter(|a| !a.is_empty()).map(path_escape).collect::<Vec<String>>().join(".")
+        }
+
+        /// queues `object` and sends everything buffered so far
+        pub async fn export(&mut self, object: &MibObject) {
+            let lines = self.lines(object);
+            self.buffer.extend(lines);
+            let excess = self.buffer.len().saturating_sub(self.config.buffer_lines);
+            if excess > 0 {
+                self.buffer.drain(..excess);
+                println!("export buffer for {} is full, dropped the oldest {} lines", self.config.address, excess);
+            }
+            if let Err(err) = self.flush().await {
+                println!("could not export to {}, {} lines buffered: {}", self.config.address, self.buffer.len(), err);
+            }
+        }
+
+        async fn flush(&mut self) -> io::Result<()> {
+            while !self.buffer.is_empty() {
+                let count = self.config.batch_lines.clamp(1, self.buffer.len());
+                let batch = self.buffer.iter().take(count).map(|line| [line.as_str(), "\n"].concat()).collect::<String>();
+                self.write(batch.as_bytes()).await?;
+                self.buffer.drain(..count);
+            }
+            Ok(())
+        }
+
+        async fn write(&mut self, data: &[u8]) -> io::Result<()> {
+            let address = self.config.address.clone();
+            match self.config.transport {
+                ExportTransport::Udp => {
+                    if self.socket.is_none() {
+                        self.socket = Some(UdpSocket::bind("0.0.0.0:0").await?);
+                    }
+                    self.socket.as_ref().unwrap().send_to(data, address).await?;
+                }
+                ExportTransport::Tcp => {
+                    if self.stream.is_none() {
+                        let stream = timeout(WRITE_TIMEOUT, TcpStream::connect(address)).await.map_err(|_| io::ErrorKind::TimedOut)??;
+                        self.stream = Some(stream);
+                    }
+                    let result = match timeout(WRITE_TIMEOUT, self.stream.as_mut().unwrap().write_all(data)).await {
+                        Ok(result) => result,
+                        Err(_) => Err(io::ErrorKind::TimedOut.into()),
+                    };
+                    if result.is_err() {
+                        // reconnect on the next flush
+                        self.stream = None;
+                    }
+                    result?;
+                }
+                ExportTransport::File => {
+                    OpenOptions::new().append(true).create(true).open(address).await?.write_all(data).await?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn influx_escape(value: &str) -> String {
+        value.replace('\\', "\\\\").replace(',', "\\,").replace('=', "\\=").replace(' ', "\\ ")
+    }
+
+    /// graphite paths and tags, and statsd tags, cannot contain these characters
+    fn path_escape(value: &str) -> String {
+        value.replace([' ', ';', '~', ',', '|', '#'], "_")
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::config::ConfigModule::ExportMapping;
+        use crate::mibobject::MibModule::{mvinti32, mvintu32, mvstring, MibValue};
+        use tokio::io::AsyncReadExt;
+        use tokio::net::TcpListener;
+
+        fn config(format: ExportFormat, transport: ExportTransport, address: &str) -> ExportConfig {
+            ExportConfig {
+                format,
+                transport,
+                address: address.to_owned(),
+                prefix: "snmp".to_owned(),
+                batch_lines: 2,
+                buffer_lines: 100,
+                mappings: vec![ExportMapping { object: "1.3.6.1.2.1.2.2.1".to_owned(), measurement: "interfaces".to_owned() }],
+            }
+        }
+
+        fn object() -> MibObject {
+            let mut object = MibObject::new();
+            object.target = "192.0.2.1".to_owned();
+            object.timestamp = 1700000000;
+            if let MibValue::intu32(mvintu32 { value, .. }) = &mut object.system.sysUpTime {
+                *value = vec![4200];
+            }
+            let table = &mut object.interfaces.ifTable;
+            if let MibValue::inti32(mvinti32 { value, index, .. }) = &mut table.ifIndex {
+                *value = vec![2];
+                *index = vec![vec![2]];
+            }
+            if let MibValue::string(mvstring { value, .. }) = &mut table.ifDescr {
+                *value = vec!["eth 0".to_owned()];
+            }
+            if let MibValue::intu32(mvintu32 { value, index, .. }) = &mut table.ifInOctets {
+                *value = vec![20];
+                *index = vec![vec![2]];
+            }
+            object
+        }
+
+        #[test]
+        fn test_formats() {
+            let object = object();
+            let influx = Exporter::new(config(ExportFormat::Influx, ExportTransport::File, "")).lines(&object);
+            assert_eq!(influx, vec![
+                "sysUpTime,target=192.0.2.1 sysUpTime=4200i 1700000000000000000",
+                "interfaces,target=192.0.2.1,ifIndex=2,ifDescr=eth\\ 0 ifIndex=2i,ifInOctets=20i 1700000000000000000",
+            ]);
+
+            let graphite = Exporter::new(config(ExportFormat::Graphite, ExportTransport::File, "")).lines(&object);
+            assert_eq!(graphite[0], "snmp.sysUpTime;target=192.0.2.1 4200 1700000000");
+            assert_eq!(graphite[2], "snmp.interfaces.ifInOctets;target=192.0.2.1;ifIndex=2;ifDescr=eth_0 20 1700000000");
+
+            let mut statsd = Exporter::new(config(ExportFormat::Statsd, ExportTransport::File, ""));
+            assert_eq!(statsd.lines(&object), vec![
+                "snmp.sysUpTime:4200|g|#target:192.0.2.1",
+                "snmp.interfaces.ifIndex:2|g|#target:192.0.2.1,ifIndex:2,ifDescr:eth_0",
+            ]);
+            let mut later = object.clone();
+            if let MibValue::intu32(mvintu32 { value, .. }) = &mut later.interfaces.ifTable.ifInOctets {
+                *value = vec![50];
+            }
+            assert_eq!(statsd.lines(&later)[2], "snmp.interfaces.ifInOctets:30|c|#target:192.0.2.1,ifIndex:2,ifDescr:eth_0");
+        }
+
+        #[tokio::test]
+        async fn test_buffers_while_unreachable() {
+            // find a free port and leave it closed so the first export fails
+            let address = TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap().to_string();
+            let mut exporter = Exporter::new(config(ExportFormat::Graphite, ExportTransport::Tcp, &address));
+            let object = object();
+            exporter.export(&object).await;
+            assert_eq!(exporter.buffer.len(), 3);
+
+            let listener = TcpListener::bind(&address).await.unwrap();
+            exporter.export(&object).await;
+            assert!(exporter.buffer.is_empty());
+            drop(exporter);
+
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut received = String::new();
+            stream.read_to_string(&mut received).await.unwrap();
+            assert_eq!(received.lines().count(), 6);
+            assert_eq!(received.lines().next().unwrap(), "snmp.sysUpTime;target=192.0.2.1 4200 1700000000");
+        }
+    }
+}
@@ -0,0 +1,206 @@
+pub mod HostResourcesModule {
+    use std::collections::HashMap;
+
+    use crate::mibobject::MibModule::{row_number, table_maps, MibObject};
+
+    /// hrStorageTypes (1.3.6.1.2.1.25.2.1) by their last sub-identifier
+    const STORAGE_TYPES: &[&str] = &[
+        "other", "ram", "virtualMemory", "fixedDisk", "removableDisk", "floppyDisk", "compactDisc", "ramDisk", "flashMemory", "networkDisk",
+    ];
+    /// hrDeviceTypes (1.3.6.1.2.1.25.3.1) by their last sub-identifier
+    const DEVICE_TYPES: &[&str] = &[
+        "other", "unknown", "processor", "network", "printer", "diskStorage", "", "", "", "video", "audio", "coprocessor", "keyboard",
+        "modem", "parallelPort", "pointing", "serialPort", "tape", "clock", "volatileMemory", "nonVolatileMemory",
+    ];
+    const DEVICE_STATUSES: &[&str] = &["unknown", "running", "warning", "testing", "down"];
+    const RUN_TYPES: &[&str] = &["unknown", "operatingSystem", "deviceDriver", "application"];
+    const RUN_STATUSES: &[&str] = &["running", "runnable", "notRunnable", "invalid"];
+
+    /// one hrStorageTable entry in bytes
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Storage {
+        pub index: String,
+        pub kind: String,
+        pub descr: String,
+        pub size: u64,
+        pub used: u64,
+        pub percent: Option<f64>,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Device {
+        pub index: String,
+        pub kind: String,
+        pub descr: String,
+        pub status: String,
+        pub errors: String,
+        /// hrProcessorLoad when the device is a processor
+        pub load: Option<i64>,
+    }
+
+    /// one running program from hrSWRunTable and hrSWRunPerfTable
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Process {
+        pub index: String,
+        pub name: String,
+        pub path: String,
+        pub parameters: String,
+        pub kind: String,
+        pub status: String,
+        /// CPU time used since the program started, in 1/100 s
+        pub cpu_time: i64,
+        /// percent of one processor used since the previous poll
+        pub cpu_percent: Option<f64>,
+        /// bytes of real memory allocated
+        pub memory: u64,
+    }
+
+    /// the name of the 1-based enumeration value `value` out of `names`
+    fn name(names: &[&str], value: Option<&String>) -> String {
+        let value = value.cloned().unwrap_or_default();
+        value.parse::<usize>().ok().and_then(|a| names.get(a.wrapping_sub(1))).filter(|a| !a.is_empty()).map(|a| a.to_string()).unwrap_or(value)
+    }
+
+    /// the name of a type registered under `types`, or its OID when it is not one of them
+    fn type_name(names: &[&str], types: &str, oid: Option<&String>) -> String {
+        let oid = oid.cloned().unwrap_or_default();
+        match oid.strip_prefix(types).and_then(|a| a.strip_prefix('.')) {
+            Some(last) => name(names, Some(&last.to_owned())),
+            None => oid,
+        }
+    }
+
+    /// every storage area with its size and use in bytes, allocation units multiplied out
+    pub fn storage(object: &MibObject) -> Vec<Storage> {
+        table_maps(&object.host.hrStorageTable.values())
+            .into_iter()
+            .map(|(index, row)| {
+                let units = row_number(&row, "hrStorageAllocationUnits").max(0) as u64;
+                // Integer32 sizes of very large disks wrap negative on some agents
+                let (size, used) = (row_number(&row, "hrStorageSize") as u32 as u64 * units, row_number(&row, "hrStorageUsed") as u32 as u64 * units);
+                Storage {
+                    index,
+                    kind: type_name(STORAGE_TYPES, "1.3.6.1.2.1.25.2.1", row.get("hrStorageType")),
+                    descr: row.get("hrStorageDescr").cloned().unwrap_or_default(),
+                    size,
+                    used,
+                    percent: (size > 0).then(|| used as f64 * 100.0 / size as f64),
+                }
+            })
+            .collect()
+    }
+
+    /// every device, with the load of the processors among them
+    pub fn devices(object: &MibObject) -> Vec<Device> {
+        let loads = table_maps(&object.host.hrProcessorTable.values())
+            .into_iter()
+            .map(|(index, row)| (index, row_number(&row, "hrProcessorLoad")))
+            .collect::<HashMap<String, i64>>();
+        table_maps(&object.host.hrDeviceTable.values())
+            .into_iter()
+            .map(|(index, row)| Device {
+                kind: type_name(DEVICE_TYPES, "1.3.6.1.2.1.25.3.1", row.get("hrDeviceType")),
+                descr: row.get("hrDeviceDescr").cloned().unwrap_or_default(),
+                status: name(DEVICE_STATUSES, row.get("hrDeviceStatus")),
+                errors: row.get("hrDeviceErrors").cloned().unwrap_or_default(),
+                load: loads.get(&index).copied(),
+                index,
+            })
+            .collect()
+    }
+
+    /// every running program, its CPU use since `previous` when it was running then too
+    pub fn processes(previous: Option<&MibObject>, current: &MibObject) -> Vec<Process> {
+        let perf = |object: &MibObject| {
+            table_maps(&object.host.hrSWRunPerfTable.values()).into_iter().collect::<HashMap<String, HashMap<String, String>>>()
+        };
+        let now = perf(current);
+        let then = previous.map(|previous| (perf(previous), previous.timestamp));
+        let seconds = (current.timestamp - then.as_ref().map(|a| a.1).unwrap_or(current.timestamp)) as f64;
+        table_maps(&current.host.hrSWRunTable.values())
+            .into_iter()
+            .map(|(index, row)| {
+                let perf = now.get(&index).cloned().unwrap_or_default();
+                let cpu_time = row_number(&perf, "hrSWRunPerfCPU");
+                // an index reused by a program started since would show its CPU time going back
+                let cpu_percent = then
+                    .as_ref()
+                    .and_then(|(then, _)| then.get(&index))
+                    .map(|then| cpu_time - row_number(then, "hrSWRunPerfCPU"))
+                    .filter(|used| *used >= 0 && seconds > 0.0)
+                    .map(|used| used as f64 / seconds);
+                Process {
+                    name: row.get("hrSWRunName").cloned().unwrap_or_default(),
+                    path: row.get("hrSWRunPath").cloned().unwrap_or_default(),
+                    parameters: row.get("hrSWRunParameters").cloned().unwrap_or_default(),
+                    kind: name(RUN_TYPES, row.get("hrSWRunType")),
+                    status: name(RUN_STATUSES, row.get("hrSWRunStatus")),
+                    cpu_time,
+                    cpu_percent,
+                    memory: row_number(&perf, "hrSWRunPerfMem").max(0) as u64 * 1024,
+                    index,
+                }
+            })
+            .collect()
+    }
+
+    /// `bytes` with a binary prefix
+    pub fn format_bytes(bytes: u64) -> String {
+        let prefixes = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+        let exponent = ((bytes.max(1) as f64).log2() / 10.0) as usize;
+        let exponent = exponent.min(prefixes.len() - 1);
+        format!("{:.1} {}", bytes as f64 / 1024f64.powi(exponent as i32), prefixes[exponent])
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::testing::{set_int, set_string};
+        use crate::mibobject::MibModule::{mvoid, MibValue};
+
+        #[test]
+        fn test_storage() {
+            let mut object = MibObject::new();
+            let table = &mut object.host.hrStorageTable;
+            let index = vec![vec![1], vec![31]];
+            if let MibValue::oid(mvoid { value, index: old, .. }) = &mut table.hrStorageType {
+                (*value, *old) = (vec![vec![1, 3, 6, 1, 2, 1, 25, 2, 1, 2], vec![1, 3, 6, 1, 2, 1, 25, 2, 1, 4]], index.clone());
+            }
+            set_string(&mut table.hrStorageDescr, vec!["Physical memory", "/"], index.clone());
+            set_int(&mut table.hrStorageAllocationUnits, vec![1024, 4096], index.clone());
+            set_int(&mut table.hrStorageSize, vec![8_000_000, 25_000_000], index.clone());
+            set_int(&mut table.hrStorageUsed, vec![2_000_000, 20_000_000], index);
+
+            let storage = storage(&object);
+            assert_eq!((storage[0].kind.as_str(), storage[0].size), ("ram", 8_192_000_000));
+            assert_eq!(storage[0].percent, Some(25.0));
+            assert_eq!((storage[1].kind.as_str(), storage[1].used), ("fixedDisk", 81_920_000_000));
+            assert_eq!(storage[1].percent, Some(80.0));
+            assert_eq!(format_bytes(storage[1].size), "95.4 GiB");
+        }
+
+        #[test]
+        fn test_processes() {
+            let poll = |timestamp: i64, cpu: Vec<i32>| {
+                let mut object = MibObject::new();
+                object.timestamp = timestamp;
+                let index = vec![vec![1], vec![812]];
+                set_string(&mut object.host.hrSWRunTable.hrSWRunName, vec!["systemd", "postgres"], index.clone());
+                set_int(&mut object.host.hrSWRunTable.hrSWRunStatus, vec![2, 1], index.clone());
+                set_int(&mut object.host.hrSWRunPerfTable.hrSWRunPerfCPU, cpu, index.clone());
+                set_int(&mut object.host.hrSWRunPerfTable.hrSWRunPerfMem, vec![12_000, 250_000], index);
+                object
+            };
+            let previous = poll(1000, vec![500, 10_000]);
+            let current = poll(1010, vec![505, 10_500]);
+
+            let rows = processes(Some(&previous), &current);
+            assert_eq!((rows[0].name.as_str(), rows[0].status.as_str()), ("systemd", "runnable"));
+            assert_eq!(rows[0].cpu_percent, Some(0.5));
+            // 5 s of CPU time in 10 s is half of a processor
+            assert_eq!(rows[1].cpu_percent, Some(50.0));
+            assert_eq!(rows[1].memory, 256_000_000);
+            assert_eq!(processes(None, &current)[1].cpu_percent, None);
+        }
+    }
+}
@@ -0,0 +1,414 @@
+pub mod HttpModule {
+    use std::collections::BTreeMap;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::sync::broadcast::{self, error::RecvError};
+
+    use crate::api::ApiModule;
+    use crate::metrics::MetricsModule::{self, Format};
+    use crate::mibobject::MibModule::MibObject;
+    use crate::tsdb::TsdbModule::Tsdb;
+
+    /// requests with a larger head or body are rejected
+    const MAX_REQUEST_BYTES: usize = 1024 * 1024;
+
+    /// how often an idle event stream sends a comment, so that gone clients are noticed
+    const KEEPALIVE: Duration = Duration::from_secs(15);
+
+    /// the latest polled object of every target, keyed by target
+    pub type Latest = Arc<Mutex<BTreeMap<String, MibObject>>>;
+
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub query: String,
+        pub headers: Vec<(String, String)>,
+        pub body: Vec<u8>,
+    }
+
+    pub struct Response {
+        pub status: u16,
+        pub content_type: String,
+        pub body: Vec<u8>,
+    }
+
+    impl Request {
+        /// the value of the header `name`, compared case-insensitively
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter().find(|a| a.0.eq_ignore_ascii_case(name)).map(|a| a.1.as_str())
+        }
+
+        /// the decoded `key=value` pairs of the query string
+        pub fn params(&self) -> Vec<(String, String)> {
+            self.query
+                .split('&')
+                .filter(|a| !a.is_empty())
+                .map(|pair| {
+                    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    (percent_decode(key), percent_decode(value))
+                })
+                .collect()
+        }
+
+        /// the first value of the query parameter `name`
+        pub fn param(&self, name: &str) -> Option<String> {
+            self.params().into_iter().find(|a| a.0 == name).map(|a| a.1)
+        }
+    }
+
+    fn percent_decode(text: &str) -> String {
+        let bytes = text.as_bytes();
+        let mut decoded = vec![];
+        let mut position = 0;
+        while position < bytes.len() {
+            let hex = text.get(position + 1..position + 3).and_then(|a| u8::from_str_radix(a, 16).ok());
+            match (bytes[position], hex) {
+                (b'%', Some(byte)) => {
+                    decoded.push(byte);
+                    position += 3;
+                    continue;
+                }
+                (b'+', _) => decoded.push(b' '),
+                (byte, _) => decoded.push(byte),
+            }
+            position += 1;
+        }
+        String::from_utf8_lossy(&decoded).into_owned()
+    }
+
+    impl Response {
+        pub fn new(status: u16, content_type: &str, body: Vec<u8>) -> Self {
+            Response { status, content_type: content_type.to_owned(), body }
+        }
+
+        pub fn text(status: u16, text: &str) -> Self {
+            Response::new(status, "text/plain; charset=utf-8", [text, "\n"].concat().into_bytes())
+        }
+
+        pub fn json(status: u16, value: &serde_json::Value) -> Self {
+            Response::new(status, "application/json", value.to_string().into_bytes())
+        }
+    }
+
+    fn reason(status: u16) -> &'static str {
+        match status {
+            200 => "OK",
+            204 => "No Content",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            _ => "Internal Server Error",
+        }
+    }
+
+    /// the monitor's embedded HTTP server
+    #[derive(Clone)]
+    pub struct Server {
+        latest: Latest,
+        tsdb: Arc<Mutex<Tsdb>>,
+        polls: broadcast::Sender<Arc<MibObject>>,
+    }
+
+    impl Server {
+        pub fn new(latest: Latest, tsdb: Arc<Mutex<Tsdb>>) -> Self {
+            Server { latest, tsdb, polls: broadcast::channel(16).0 }
+        }
+
+        /// every object sent here is streamed to the clients of `/api/stream`
+        pub fn polls(&self) -> broadcast::Sender<Arc<MibObject>> {
+            self.polls.clone()
+        }
+
+        /// accepts connections on `listen` until the runtime shuts down
+        pub async fn serve(self, listen: String) -> io::Result<()> {
+            let listener = TcpListener::bind(&listen).await?;
+            println!("serving http on {}", listen);
+            self.accept(listener).await
+        }
+
+        async fn accept(self, listener: TcpListener) -> io::Result<()> {
+            loop {
+                let (stream, _) = listener.accept().await?;
+                let server = self.clone();
+                tokio::spawn(async move {
+                    if let Err(err) = server.connection(stream).await {
+                        println!("http connection failed: {}", err);
+                    }
+                });
+            }
+        }
+
+        async fn connection(&self, stream: TcpStream) -> io::Result<()> {
+            let mut reader = BufReader::new(stream);
+            let response = match read_request(&mut reader).await {
+                Ok(request) if request.method == "GET" && request.path == "/api/stream" => {
+                    return self.stream(reader.into_inner(), request.param("target")).await;
+                }
+                Ok(request) => self.route(&request),
+                Err(err) if err.kind() == io::ErrorKind::InvalidData => Response::text(413, &err.to_string()),
+                Err(err) => Response::text(400, &err.to_string()),
+            };
+            let mut stream = reader.into_inner();
+            let head = format!(
+                "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                response.status,
+                reason(response.status),
+                response.content_type,
+                response.body.len()
+            );
+            stream.write_all(head.as_bytes()).await?;
+            stream.write_all(&response.body).await?;
+            stream.shutdown().await
+        }
+
+        /// sends every new poll, of `target` or of all targets, as a server-sent `poll` event
+        /// carrying the object as JSON until the client goes away
+        async fn stream(&self, mut stream: TcpStream, target: Option<String>) -> io::Result<()> {
+            let mut polls = self.polls.subscribe();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n")
+                .await?;
+            loop {
+                let object = match tokio::time::timeout(KEEPALIVE, polls.recv()).await {
+                    Ok(Ok(object)) => object,
+                    Ok(Err(RecvError::Lagged(_))) => continue,
+                    Ok(Err(RecvError::Closed)) => return Ok(()),
+                    Err(_) => {
+                        stream.write_all(b": keepalive\n\n").await?;
+                        continue;
+                    }
+                };
+                if target.as_ref().is_some_and(|target| *target != object.target) {
+                    continue;
+                }
+                let data = serde_json::to_string(object.as_ref()).unwrap_or_default();
+                stream.write_all(format!("event: poll\nid: {}\ndata: {}\n\n", object.timestamp, data).as_bytes()).await?;
+            }
+        }
+
+        fn route(&self, request: &Request) -> Response {
+            match (request.method.as_str(), request.path.as_str()) {
+                ("GET", "/metrics") => {
+                    let format = match request.header("accept") {
+                        Some(accept) if accept.contains("application/openmetrics-text") => Format::OpenMetrics,
+                        _ => Format::Text,
+                    };
+                    let latest = self.latest.lock().unwrap();
+                    let body = MetricsModule::render(&latest.values().collect::<Vec<&MibObject>>(), format);
+                    Response::new(200, format.content_type(), body.into_bytes())
+                }
+                (_, "/metrics") => Response::text(405, "method not allowed"),
+                (_, path) if path.starts_with("/api/") => ApiModule::route(&self.latest, &self.tsdb, request),
+                _ => Response::text(404, "not found"),
+            }
+        }
+    }
+
+    async fn read_request(reader: &mut BufReader<TcpStream>) -> io::Result<Request> {
+        let mut head = vec![];
+        let mut size = 0;
+        loop {
+            let mut line = String::new();
+            // a line never buffers more than the rest of the limit, however long the client makes it
+            size += (&mut *reader).take((MAX_REQUEST_BYTES + 1 - size) as u64).read_line(&mut line).await?;
+            if size > MAX_REQUEST_BYTES {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "request head too large"));
+            }
+            let line = line.trim_end().to_owned();
+            if line.is_empty() {
+                break;
+            }
+            head.push(line);
+        }
+        let mut request_line = head.first().map(|a| a.split_whitespace()).ok_or(io::ErrorKind::UnexpectedEof)?;
+        let method = request_line.next().unwrap_or_default().to_owned();
+        let target = request_line.next().ok_or(io::Error::other("missing request target"))?;
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let headers = head[1..]
+            .iter()
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_owned(), value.trim().to_owned()))
+            .collect::<Vec<(String, String)>>();
+
+        let mut request = Request { method, path: path.to_owned(), query: query.to_owned(), headers, body: vec![] };
+        let length = request.header("content-length").and_then(|a| a.parse::<usize>().ok()).unwrap_or(0);
+        if length > MAX_REQUEST_BYTES {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "request body too large"));
+        }
+        request.body = vec![0; length];
+        reader.read_exact(&mut request.body).await?;
+        Ok(request)
+    }
+
+    /// sends `body` to a plain `http://` url and fails unless the answer is a 2xx status
+    pub async fn send(method: &str, url: &str, headers: &BTreeMap<String, String>, content_type: &str, body: &[u8]) -> io::Result<()> {
+        let rest = url.strip_prefix("http://").ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, format!("only http:// urls are supported, got '{}'", url))
+        })?;
+        let (host, path) = match rest.find('/') {
+            Some(position) => (&rest[..position], &rest[position..]),
+            None => (rest, "/"),
+        };
+        let address = match host.contains(':') {
+            true => host.to_owned(),
+            false => [host, ":80"].concat(),
+        };
+
+        let mut request = format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+            method,
+            path,
+            host,
+            content_type,
+            body.len()
+        );
+        headers.iter().for_each(|(name, value)| request.push_str(&format!("{}: {}\r\n", name, value)));
+        request.push_str("\r\n");
+
+        let mut stream = TcpStream::connect(address).await?;
+        stream.write_all(request.as_bytes()).await?;
+        stream.write_all(body).await?;
+        let mut status = String::new();
+        BufReader::new(stream).read_line(&mut status).await?;
+        match status.split_whitespace().nth(1).and_then(|code| code.parse::<u16>().ok()) {
+            Some(code) if (200..300).contains(&code) => Ok(()),
+            _ => Err(io::Error::other(format!("{} answered '{}'", host, status.trim()))),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::config::ConfigModule::StorageConfig;
+
+        async fn get(address: &str, path: &str, accept: &str) -> String {
+            let mut stream = TcpStream::connect(address).await.unwrap();
+            let request = format!("GET {} HTTP/1.1\r\nHost: {}\r\nAccept: {}\r\n\r\n", path, address, accept);
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        }
+
+        fn tsdb(name: &str) -> (std::path::PathBuf, Tsdb) {
+            let dir = std::env::temp_dir().join(format!("snmp-monitor-http-{}-{}", name, std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            let config = StorageConfig { data_dir: dir.to_string_lossy().into_owned(), ..StorageConfig::default() };
+            (dir, Tsdb::open(config).unwrap())
+        }
+
+        #[tokio::test]
+        async fn test_metrics_endpoint() {
+            let mut object = MibObject::new();
+            object.target = "192.0.2.1".to_owned();
+            let latest: Latest = Arc::new(Mutex::new(BTreeMap::new()));
+            latest.lock().unwrap().insert(object.target.clone(), object);
+
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let address = listener.local_addr().unwrap().to_string();
+            tokio::spawn(Server::new(latest, Arc::new(Mutex::new(tsdb("metrics").1))).accept(listener));
+
+            let response = get(&address, "/metrics", "application/openmetrics-text;version=1.0.0,text/plain;q=0.5").await;
+            assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+            assert!(response.contains("Content-Type: application/openmetrics-text; version=1.0.0"));
+            assert!(response.contains("snmp_last_poll_timestamp_seconds{target=\"192.0.2.1\"}"));
+            assert!(response.ends_with("# EOF\n"));
+
+            let response = get(&address, "/metrics", "*/*").await;
+            assert!(response.contains("Content-Type: text/plain; version=0.0.4"));
+            assert!(get(&address, "/missing", "*/*").await.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+            // a header line longer than the limit is refused before it ends
+            let mut stream = TcpStream::connect(&address).await.unwrap();
+            let head = b"GET /metrics HTTP/1.1\r\nX-Long: ";
+            stream.write_all(head).await.unwrap();
+            stream.write_all(&vec![b'a'; MAX_REQUEST_BYTES + 1 - head.len()]).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            assert!(response.starts_with("HTTP/1.1 413"));
+        }
+
+        fn body(response: &str) -> serde_json::Value {
+            serde_json::from_str(response.split_once("\r\n\r\n").unwrap().1).unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_api() {
+            use crate::mibobject::MibModule::{mvinti32, mvstring, MibValue};
+
+            let mut object = MibObject::new();
+            object.target = "192.0.2.1".to_owned();
+            object.timestamp = 1700000000;
+            let table = &mut object.interfaces.ifTable;
+            if let MibValue::inti32(mvinti32 { value, index, .. }) = &mut table.ifIndex {
+                *value = vec![1, 2, 3];
+                *index = vec![vec![1], vec![2], vec![3]];
+            }
+            if let MibValue::string(mvstring { value, index, .. }) = &mut table.ifDescr {
+                *value = vec!["lo".to_owned(), "eth 0".to_owned(), "eth1".to_owned()];
+                *index = vec![vec![1], vec![2], vec![3]];
+            }
+            let (dir, mut tsdb) = tsdb("api");
+            for timestamp in [1700000000, 1700000030] {
+                object.timestamp = timestamp;
+                tsdb.append(&object.target, timestamp, &object.samples()).unwrap();
+            }
+            let key = object.interfaces.ifTable.ifIndex.samples()[1].key.clone();
+            let latest: Latest = Arc::new(Mutex::new(BTreeMap::new()));
+            latest.lock().unwrap().insert(object.target.clone(), object);
+
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let address = listener.local_addr().unwrap().to_string();
+            let server = Server::new(latest, Arc::new(Mutex::new(tsdb)));
+            let polls = server.polls();
+            tokio::spawn(server.accept(listener));
+
+            let targets = body(&get(&address, "/api/targets", "*/*").await);
+            assert_eq!(targets["items"][0]["target"], "192.0.2.1");
+            assert_eq!(targets["items"][0]["last_poll"], 1700000030);
+
+            let values = body(&get(&address, "/api/targets/192.0.2.1/values/ifDescr?instance=2", "*/*").await);
+            assert_eq!(values["total"], 1);
+            assert_eq!(values["items"][0]["oid"], "1.3.6.1.2.1.2.2.1.2.2");
+            assert_eq!(values["items"][0]["value"], "eth 0");
+            let values = body(&get(&address, "/api/targets/192.0.2.1/values?oid=1.3.6.1.2.1.2.2.1.1&offset=1&limit=1", "*/*").await);
+            assert_eq!((values["total"].clone(), values["items"][0]["number"].clone()), (3.into(), 2.into()));
+
+            let rows = body(&get(&address, "/api/targets/192.0.2.1/tables/ifTable?ifDescr=eth+0", "*/*").await);
+            assert_eq!(rows["total"], 1);
+            assert_eq!(rows["items"][0]["index"], "2");
+            assert_eq!(rows["items"][0]["values"]["ifIndex"], "2");
+            assert!(get(&address, "/api/targets/192.0.2.1/tables/nope", "*/*").await.starts_with("HTTP/1.1 404"));
+
+            let points = body(&get(&address, &format!("/api/targets/192.0.2.1/series/{}?from=1700000010", key), "*/*").await);
+            assert_eq!(points["items"], serde_json::json!([{ "timestamp": 1700000030, "value": 2 }]));
+            assert!(get(&address, &format!("/api/targets/192.0.2.1/series/{}?resolution=1d", key), "*/*").await.starts_with("HTTP/1.1 400"));
+
+            let mut stream = TcpStream::connect(&address).await.unwrap();
+            stream.write_all(b"GET /api/stream?target=192.0.2.1 HTTP/1.1\r\n\r\n").await.unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut line = String::new();
+            reader.read_line(&mut line).await.unwrap();
+            assert_eq!(line, "HTTP/1.1 200 OK\r\n");
+            while line != "\r\n" {
+                line.clear();
+                reader.read_line(&mut line).await.unwrap();
+            }
+            let mut polled = MibObject::new();
+            polled.target = "192.0.2.1".to_owned();
+            polled.timestamp = 1700000060;
+            assert_eq!(polls.send(Arc::new(polled)).ok(), Some(1));
+            line.clear();
+            reader.read_line(&mut line).await.unwrap();
+            assert_eq!(line, "event: poll\n");
+            line.clear();
+            reader.read_line(&mut line).await.unwrap();
+            assert_eq!(line, "id: 1700000060\n");
+            let _ = std::fs::remove_dir_all(&dir);
+        }
+    }
+}
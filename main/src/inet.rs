@@ -0,0 +1,158 @@
+pub mod InetModule {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    /// one object of a table's INDEX clause
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum IndexPart {
+        /// a single sub-identifier, such as an ifIndex or a port
+        Integer,
+        /// an InetAddressType followed by the InetAddress it qualifies, length first
+        Address,
+        /// an OBJECT IDENTIFIER, length first
+        Oid,
+    }
+
+    use IndexPart::{Address, Integer, Oid};
+
+    /// the INDEX clause of every known table indexed by InetAddress, as `(table oid, [(label,
+    /// part)])`; each InetAddressType is folded into the address it qualifies
+    const TABLE_INDEXES: &[(&str, &[(&str, IndexPart)])] = &[
+        ("1.3.6.1.2.1.4.34", &[("ipAddressAddr", Address)]),
+        ("1.3.6.1.2.1.4.35", &[("ipNetToPhysicalIfIndex", Integer), ("ipNetToPhysicalNetAddress", Address)]),
+        (
+            "1.3.6.1.2.1.4.24.7",
+            &[("inetCidrRouteDest", Address), ("inetCidrRoutePfxLen", Integer), ("inetCidrRoutePolicy", Oid), ("inetCidrRouteNextHop", Address)],
+        ),
+        (
+            "1.3.6.1.2.1.6.19",
+            &[
+                ("tcpConnectionLocalAddress", Address),
+                ("tcpConnectionLocalPort", Integer),
+                ("tcpConnectionRemAddress", Address),
+                ("tcpConnectionRemPort", Integer),
+            ],
+        ),
+        ("1.3.6.1.2.1.6.20", &[("tcpListenerLocalAddress", Address), ("tcpListenerLocalPort", Integer)]),
+        (
+            "1.3.6.1.2.1.7.7",
+            &[
+                ("udpEndpointLocalAddress", Address),
+                ("udpEndpointLocalPort", Integer),
+                ("udpEndpointRemoteAddress", Address),
+                ("udpEndpointRemotePort", Integer),
+                ("udpEndpointInstance", Integer),
+            ],
+        ),
+    ];
+
+    /// InetAddressType values
+    const UNKNOWN: u32 = 0;
+    const IPV4: u32 = 1;
+    const IPV6: u32 = 2;
+    const IPV4Z: u32 = 3;
+    const IPV6Z: u32 = 4;
+    const DNS: u32 = 16;
+
+    /// the INDEX clause of the table at `table_oid` when it is indexed by InetAddress
+    pub fn table_index(table_oid: &str) -> Option<&'static [(&'static str, IndexPart)]> {
+        TABLE_INDEXES.iter().find(|a| a.0 == table_oid).map(|a| a.1)
+    }
+
+    /// the text of an InetAddress of type `kind` given one octet per sub-identifier: dotted
+    /// for IPv4, RFC 5952 for IPv6, `%zone` after scoped addresses and `*` for the unknown
+    /// empty address listeners bound to every address use
+    pub fn inet_address(kind: u32, octets: &[u32]) -> Option<String> {
+        let bytes = octets.iter().map(|a| u8::try_from(*a).ok()).collect::<Option<Vec<u8>>>()?;
+        let ipv4 = |bytes: &[u8]| Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]);
+        let ipv6 = |bytes: &[u8]| Ipv6Addr::from(<[u8; 16]>::try_from(bytes).unwrap());
+        let zone = |bytes: &[u8]| u32::from_be_bytes(<[u8; 4]>::try_from(bytes).unwrap());
+        match (kind, bytes.len()) {
+            (UNKNOWN, 0) => Some("*".to_owned()),
+            (IPV4, 4) => Some(ipv4(&bytes).to_string()),
+            (IPV6, 16) => Some(ipv6(&bytes).to_string()),
+            (IPV4Z, 8) => Some(format!("{}%{}", ipv4(&bytes[..4]), zone(&bytes[4..]))),
+            (IPV6Z, 20) => Some(format!("{}%{}", ipv6(&bytes[..16]), zone(&bytes[16..]))),
+            (DNS, _) => String::from_utf8(bytes).ok(),
+            _ => None,
+        }
+    }
+
+    /// splits the instance suffix of a row into the objects of its INDEX clause, `None` when
+    /// the suffix does not fit the clause
+    pub fn decode(parts: &[(&str, IndexPart)], index: &[u32]) -> Option<Vec<String>> {
+        let mut rest = index;
+        let mut values = vec![];
+        for (_, part) in parts {
+            let (first, tail) = rest.split_first()?;
+            let value = match part {
+                Integer => {
+                    rest = tail;
+                    first.to_string()
+                }
+                Address => {
+                    let (len, tail) = tail.split_first()?;
+                    let octets = tail.get(..*len as usize)?;
+                    rest = &tail[octets.len()..];
+                    inet_address(*first, octets)?
+                }
+                Oid => {
+                    let arcs = tail.get(..*first as usize)?;
+                    rest = &tail[arcs.len()..];
+                    arcs.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".")
+                }
+            };
+            values.push(value);
+        }
+        rest.is_empty().then_some(values)
+    }
+
+    /// the index of a row of the table at `table_oid` as labels named after its INDEX clause,
+    /// when the table is indexed by InetAddress and the index decodes
+    pub fn index_labels(table_oid: &str, index: &[u32]) -> Option<Vec<(String, String)>> {
+        let parts = table_index(table_oid)?;
+        let values = decode(parts, index)?;
+        Some(parts.iter().map(|a| a.0.to_owned()).zip(values).collect())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_decode() {
+            // 10.0.0.1:22 to 192.0.2.9:50000
+            let index = [1, 4, 10, 0, 0, 1, 22, 1, 4, 192, 0, 2, 9, 50000];
+            let parts = table_index("1.3.6.1.2.1.6.19").unwrap();
+            assert_eq!(decode(parts, &index).unwrap(), vec!["10.0.0.1", "22", "192.0.2.9", "50000"]);
+
+            // fe80::1%2 listening on 123, and a dual-stack listener on every address
+            let mut index = vec![4, 20, 0xfe, 0x80];
+            index.extend([0; 13]);
+            index.extend([1, 0, 0, 0, 2, 123]);
+            let parts = table_index("1.3.6.1.2.1.6.20").unwrap();
+            assert_eq!(decode(parts, &index).unwrap(), vec!["fe80::1%2", "123"]);
+            assert_eq!(decode(parts, &[0, 0, 22]).unwrap(), vec!["*", "22"]);
+
+            // ::/0 via 2001:db8::1 with the null policy
+            let mut index = vec![2, 16];
+            index.extend([0; 16]);
+            index.extend([0, 2, 0, 0, 2, 16, 0x20, 0x01, 0x0d, 0xb8]);
+            index.extend([0; 11]);
+            index.push(1);
+            let parts = table_index("1.3.6.1.2.1.4.24.7").unwrap();
+            assert_eq!(decode(parts, &index).unwrap(), vec!["::", "0", "0.0", "2001:db8::1"]);
+
+            // a truncated address or a leftover sub-identifier does not decode
+            assert_eq!(decode(table_index("1.3.6.1.2.1.4.34").unwrap(), &[1, 4, 10, 0, 0]), None);
+            assert_eq!(decode(table_index("1.3.6.1.2.1.4.34").unwrap(), &[1, 4, 10, 0, 0, 1, 7]), None);
+            assert_eq!(decode(table_index("1.3.6.1.2.1.4.34").unwrap(), &[1, 4, 10, 0, 0, 256]), None);
+        }
+
+        #[test]
+        fn test_index_labels() {
+            let labels = index_labels("1.3.6.1.2.1.4.35", &[3, 1, 4, 192, 0, 2, 1]).unwrap();
+            assert_eq!(labels, vec![("ipNetToPhysicalIfIndex".to_owned(), "3".to_owned()), ("ipNetToPhysicalNetAddress".to_owned(), "192.0.2.1".to_owned())]);
+            assert_eq!(index_labels("1.3.6.1.2.1.2.2", &[3]), None);
+        }
+    }
+}
@@ -0,0 +1,311 @@
+pub mod InventoryModule {
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+    use std::fmt;
+    use std::fs::{self, OpenOptions};
+    use std::io::{self, Write};
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+
+    use crate::config::ConfigModule::InventoryConfig;
+    use crate::mibobject::MibModule::{table_maps, MibObject};
+
+    /// how many hardware changes are kept for the inventory tab
+    const RECENT: usize = 200;
+    /// PhysicalClass by its value
+    const CLASSES: &[&str] = &[
+        "other", "unknown", "chassis", "backplane", "container", "powerSupply", "fan", "sensor", "module", "port", "stack", "cpu",
+        "energyObject", "battery", "storageDrive",
+    ];
+    /// TruthValue true
+    const TRUE: &str = "1";
+
+    /// one entPhysicalTable entry with the entities it contains, ordered by their position
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Component {
+        pub index: String,
+        pub class: String,
+        pub name: String,
+        pub descr: String,
+        pub vendor: String,
+        pub model: String,
+        pub serial: String,
+        pub hardware_rev: String,
+        pub firmware_rev: String,
+        pub software_rev: String,
+        /// whether the entity is a field replaceable unit
+        pub fru: bool,
+        /// entPhysicalParentRelPos, -1 when the entity has no place among its siblings
+        pub position: i64,
+        pub children: Vec<Component>,
+    }
+
+    impl Component {
+        /// the name of the entity, its description when it has none
+        pub fn label(&self) -> &str {
+            if self.name.is_empty() { &self.descr } else { &self.name }
+        }
+    }
+
+    /// what identifies a piece of hardware between polls, entPhysicalIndex being free to change
+    /// when a device restarts
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+    pub struct Part {
+        /// the label of the entity containing it
+        pub location: String,
+        pub class: String,
+        pub name: String,
+        pub model: String,
+        pub serial: String,
+    }
+
+    impl fmt::Display for Part {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} {}", self.class, self.name)?;
+            match (self.model.is_empty(), self.serial.is_empty()) {
+                (false, false) => write!(f, " ({}, serial {})", self.model, self.serial)?,
+                (false, true) => write!(f, " ({})", self.model)?,
+                (true, false) => write!(f, " (serial {})", self.serial)?,
+                (true, true) => {}
+            }
+            match self.location.is_empty() {
+                true => Ok(()),
+                false => write!(f, " in {}", self.location),
+            }
+        }
+    }
+
+    /// hardware that appeared on or disappeared from a target between two of its polls
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct InventoryChange {
+        pub timestamp: i64,
+        pub target: String,
+        pub added: bool,
+        pub part: Part,
+    }
+
+    impl fmt::Display for InventoryChange {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "hardware {} on {}: {}", if self.added { "added" } else { "removed" }, self.target, self.part)
+        }
+    }
+
+    pub type Inventory = Arc<Mutex<InventoryTracker>>;
+
+    /// the hardware tree of `object`, rebuilt from entPhysicalContainedIn; entities whose
+    /// container the agent does not list are roots
+    pub fn tree(object: &MibObject) -> Vec<Component> {
+        let rows = table_maps(&object.entity.entPhysicalTable.values());
+        let indexes = rows.iter().map(|a| a.0.clone()).collect::<HashSet<String>>();
+
+        let mut children: HashMap<String, Vec<&(String, HashMap<String, String>)>> = HashMap::new();
+        for row in &rows {
+            let parent = row.1.get("entPhysicalContainedIn").filter(|a| indexes.contains(*a) && **a != row.0).cloned().unwrap_or_default();
+            children.entry(parent).or_default().push(row);
+        }
+        build(&mut children, "")
+    }
+
+    /// the components contained in `parent`, taking them out of `children` so that a
+    /// containment loop cannot recurse forever
+    fn build(children: &mut HashMap<String, Vec<&(String, HashMap<String, String>)>>, parent: &str) -> Vec<Component> {
+        let rows = children.remove(parent).unwrap_or_default();
+        let mut components = rows
+            .into_iter()
+            .map(|(index, row)| {
+                let text = |column: &str| row.get(column).cloned().unwrap_or_default();
+                let class = text("entPhysicalClass");
+                Component {
+                    index: index.clone(),
+                    class: class.parse::<usize>().ok().and_then(|a| CLASSES.get(a.wrapping_sub(1))).map(|a| a.to_string()).unwrap_or(class),
+                    name: text("entPhysicalName"),
+                    descr: text("entPhysicalDescr"),
+                    vendor: text("entPhysicalMfgName"),
+                    model: text("entPhysicalModelName"),
+                    serial: text("entPhysicalSerialNum").trim().to_owned(),
+                    hardware_rev: text("entPhysicalHardwareRev"),
+                    firmware_rev: text("entPhysicalFirmwareRev"),
+                    software_rev: text("entPhysicalSoftwareRev"),
+                    fru: text("entPhysicalIsFRU") == TRUE,
+                    position: text("entPhysicalParentRelPos").parse::<i64>().unwrap_or(-1),
+                    children: build(children, index),
+                }
+            })
+            .collect::<Vec<Component>>();
+        components.sort_by_key(|a| (a.position, a.index.parse::<u32>().unwrap_or(u32::MAX)));
+        components
+    }
+
+    /// every entity of the tree as a part, located in its container
+    pub fn parts(tree: &[Component]) -> Vec<Part> {
+        fn visit(components: &[Component], location: &str, parts: &mut Vec<Part>) {
+            for component in components {
+                parts.push(Part {
+                    location: location.to_owned(),
+                    class: component.class.clone(),
+                    name: component.label().to_owned(),
+                    model: component.model.clone(),
+                    serial: component.serial.clone(),
+                });
+                visit(&component.children, component.label(), parts);
+            }
+        }
+        let mut parts = vec![];
+        visit(tree, "", &mut parts);
+        parts.sort();
+        parts
+    }
+
+    /// the parts of `current` missing from `previous` and the other way round, counting
+    /// identical parts such as unlabelled fans one by one
+    fn diff(previous: &[Part], current: &[Part]) -> Vec<(bool, Part)> {
+        let mut counts: BTreeMap<&Part, i64> = BTreeMap::new();
+        for part in current {
+            *counts.entry(part).or_default() += 1;
+        }
+        for part in previous {
+            *counts.entry(part).or_default() -= 1;
+        }
+        counts
+            .into_iter()
+            .flat_map(|(part, count)| std::iter::repeat((count > 0, part.clone())).take(count.unsigned_abs() as usize))
+            .collect()
+    }
+
+    /// remembers the hardware every target reported, across restarts, to tell what was added
+    /// and removed
+    pub struct InventoryTracker {
+        config: InventoryConfig,
+        parts: HashMap<String, Vec<Part>>,
+        recent: VecDeque<InventoryChange>,
+    }
+
+    impl InventoryTracker {
+        /// restores the stored inventory of every target
+        pub fn new(config: InventoryConfig) -> Self {
+            let parts = match fs::read_to_string(&config.state_file) {
+                Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|err| {
+                    println!("could not parse {}, starting without an inventory: {}", config.state_file, err);
+                    HashMap::new()
+                }),
+                Err(_) => HashMap::new(),
+            };
+            InventoryTracker { config, parts, recent: VecDeque::new() }
+        }
+
+        /// the hardware of `object` added or removed since the inventory last stored for its
+        /// target, nothing the first time the target is seen; a poll without an
+        /// entPhysicalTable is taken as the agent not answering rather than the hardware gone
+        pub fn update(&mut self, object: &MibObject) -> Vec<InventoryChange> {
+            let current = parts(&tree(object));
+            if current.is_empty() {
+                return vec![];
+            }
+            let changes = match self.parts.get(&object.target) {
+                Some(previous) if *previous == current => return vec![],
+                Some(previous) => diff(previous, &current)
+                    .into_iter()
+                    .map(|(added, part)| InventoryChange { timestamp: object.timestamp, target: object.target.clone(), added, part })
+                    .collect(),
+                None => vec![],
+            };
+            self.parts.insert(object.target.clone(), current);
+            for change in &changes {
+                self.recent.push_front(change.clone());
+            }
+            self.recent.truncate(RECENT);
+            if let Err(err) = self.persist(&changes) {
+                println!("could not store the inventory: {}", err);
+            }
+            changes
+        }
+
+        /// the latest hardware changes, newest first
+        pub fn recent(&self) -> Vec<InventoryChange> {
+            self.recent.iter().cloned().collect()
+        }
+
+        fn persist(&self, changes: &[InventoryChange]) -> io::Result<()> {
+            for path in [&self.config.state_file, &self.config.event_log] {
+                if let Some(parent) = Path::new(path).parent() {
+                    fs::create_dir_all(parent)?;
+                }
+            }
+            let tmp = [self.config.state_file.as_str(), ".tmp"].concat();
+            fs::write(&tmp, serde_json::to_vec(&self.parts)?)?;
+            fs::rename(&tmp, &self.config.state_file)?;
+
+            if !changes.is_empty() {
+                let mut log = OpenOptions::new().append(true).create(true).open(&self.config.event_log)?;
+                for change in changes {
+                    log.write_all([serde_json::to_string(change)?, "\n".to_owned()].concat().as_bytes())?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::testing::{set_int, set_string};
+
+        /// a chassis with two power supply bays and a line card, `psus` naming the serials of
+        /// the supplies fitted
+        fn switch(psus: &[&str]) -> MibObject {
+            let mut object = MibObject::new();
+            object.target = "192.0.2.1".to_owned();
+            object.timestamp = 1000;
+            let mut rows = vec![(1, 0, 3, -1, "Chassis", "WS-C3850", "FOC1"), (2, 1, 5, 2, "PSU bay 2", "", ""), (3, 1, 5, 1, "PSU bay 1", "", "")];
+            rows.push((4, 1, 9, 0, "Slot 1", "C3850-NM-4-10G", "FOC2"));
+            for (bay, serial) in psus.iter().enumerate() {
+                rows.push((10 + bay as i32, 3 - bay as i32, 6, 0, "Power Supply", "PWR-C1-350WAC", serial));
+            }
+            let index = rows.iter().map(|a| vec![a.0 as u32]).collect::<Vec<Vec<u32>>>();
+            let table = &mut object.entity.entPhysicalTable;
+            set_int(&mut table.entPhysicalContainedIn, rows.iter().map(|a| a.1).collect(), index.clone());
+            set_int(&mut table.entPhysicalClass, rows.iter().map(|a| a.2).collect(), index.clone());
+            set_int(&mut table.entPhysicalParentRelPos, rows.iter().map(|a| a.3).collect(), index.clone());
+            set_string(&mut table.entPhysicalName, rows.iter().map(|a| a.4).collect(), index.clone());
+            set_string(&mut table.entPhysicalModelName, rows.iter().map(|a| a.5).collect(), index.clone());
+            set_string(&mut table.entPhysicalSerialNum, rows.iter().map(|a| a.6).collect(), index.clone());
+            set_int(&mut table.entPhysicalIsFRU, rows.iter().map(|a| if a.5.is_empty() { 2 } else { 1 }).collect(), index.clone());
+            object
+        }
+
+        #[test]
+        fn test_tree() {
+            let tree = tree(&switch(&["PSU1", "PSU2"]));
+            assert_eq!(tree.len(), 1);
+            assert_eq!((tree[0].class.as_str(), tree[0].model.as_str()), ("chassis", "WS-C3850"));
+            let children = tree[0].children.iter().map(|a| a.label()).collect::<Vec<&str>>();
+            assert_eq!(children, vec!["Slot 1", "PSU bay 1", "PSU bay 2"]);
+            let psu = &tree[0].children[1].children[0];
+            assert_eq!((psu.class.as_str(), psu.serial.as_str(), psu.fru), ("powerSupply", "PSU1", true));
+        }
+
+        #[test]
+        fn test_changes() {
+            let dir = std::env::temp_dir().join(format!("snmp-monitor-inventory-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            let config = InventoryConfig {
+                state_file: dir.join("inventory.json").to_string_lossy().into_owned(),
+                event_log: dir.join("inventory.log").to_string_lossy().into_owned(),
+            };
+            let mut tracker = InventoryTracker::new(config.clone());
+            assert!(tracker.update(&switch(&["PSU1", "PSU2"])).is_empty());
+
+            // the stored inventory outlives a restart
+            let mut tracker = InventoryTracker::new(config.clone());
+            let changes = tracker.update(&switch(&["PSU1"]));
+            assert_eq!(changes.len(), 1);
+            assert_eq!(changes[0].to_string(), "hardware removed on 192.0.2.1: powerSupply Power Supply (PWR-C1-350WAC, serial PSU2) in PSU bay 2");
+
+            let changes = tracker.update(&switch(&["PSU1", "PSU3"]));
+            assert_eq!((changes[0].added, changes[0].part.serial.as_str()), (true, "PSU3"));
+            assert_eq!(tracker.recent().len(), 2);
+            assert_eq!(fs::read_to_string(&config.event_log).unwrap().lines().count(), 2);
+            fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+}
@@ -1,20 +1,74 @@
 #![allow(non_snake_case, non_camel_case_types)]
 
+mod alert;
+mod api;
+mod bridge;
+mod browser;
+mod config;
+mod csvexport;
+mod dashboard;
+mod discovery;
+mod enums;
+mod export;
+mod hostresources;
+mod http;
+mod inet;
+mod inventory;
+mod miblog;
+mod metrics;
 mod mibobject;
+mod neighbours;
+mod notify;
+mod replay;
+mod series;
+mod set;
+mod targets;
+mod timeaxis;
+mod topology;
+mod tsdb;
+mod ucd;
+mod utilisation;
+mod vendor;
 
-use chrono::Utc;
-use egui_plot::{PlotPoints, Line, Plot, Legend};
-use mibobject::MibModule::{MibObject, MibValue};
+use chrono::{TimeZone, Utc};
+use egui_plot::{AxisHints, HPlacement, PlotPoints, Line, Plot, Legend, PlotBounds};
+use alert::AlertModule::{AlertEngine, AlertStatus};
+use bridge::BridgeModule::{self, MacLocation};
+use browser::BrowserModule::{self, BrowseRequest, BrowseResult, Browser, MibTree, Operation, SortBy, Varbind};
+use config::ConfigModule::{AlertRule, Config, DashboardConfig, LogConfig, Severity, TargetConfig, CONFIG_PATH};
+use csvexport::CsvExportModule;
+use dashboard::DashboardModule::{self, Action as DashboardAction, Dashboard, Dashboards, SavedDerived, SavedPlot, SavedSeries, AUTOSAVE};
+use discovery::DiscoveryModule;
+use export::ExportModule::{self, Exporter};
+use hostresources::HostResourcesModule;
+use http::HttpModule::{self, Latest, Server};
+use inventory::InventoryModule::{self, Component, Inventory, InventoryTracker};
+use metrics::MetricsModule::{self, Format};
+use miblog::MibLogModule::{self, MibLog};
+use mibobject::MibModule::{egui_rows_show, CellMenu, MibObject, MibValue, PlotCell};
+use neighbours::NeighboursModule::{self, LinkTracker, Links};
+use notify::NotifyModule::Notifier;
+use replay::ReplayModule::{self, Replay};
+use series::SeriesModule::{self, Derived, Scale, Side, Style as PlotStyle};
+use set::SetModule::{self, Edit, SetOutcome, SetRequest};
+use targets::TargetsModule::{TargetList, Targets};
+use timeaxis::TimeAxisModule::{self, Window as TimeWindow};
+use topology::TopologyModule::{self, Topology, TopologyView};
+use tsdb::TsdbModule::Tsdb;
+use ucd::UcdModule;
+use utilisation::UtilisationModule;
+use vendor::VendorModule::{self, PlotDef, VendorProfile};
 use serde::Deserializer;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs::{File, OpenOptions, self};
 use std::io::{BufWriter, Write, BufReader, BufRead, LineWriter, Read};
 use std::process::exit;
 use std::str::FromStr;
-use std::net::{IpAddr, SocketAddr};
+use std::net::SocketAddr;
 
 use std::sync::mpsc::{Sender, Receiver};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use async_trait::async_trait;
 use csv::Error;
@@ -31,6 +85,11 @@ use eframe::{egui, AppCreator};
 use csnmp::{Snmp2cClient, ObjectValue, client, ObjectIdentifier};
 
 
+/// how long one SNMP request of a poll may take before the target counts as unreachable
+const POLL_TIMEOUT: Duration = Duration::from_secs(5);
+/// how many points of stored history a series loads at most, coarser rollups are read beyond it
+const PLOT_POINTS: usize = 2000;
+
 struct SnmpMonitorApp {
     name: String,
     target_ip: String,
@@ -39,7 +98,10 @@ struct SnmpMonitorApp {
     target_sender: Sender<(SocketAddr, String)>,
     context: MyContext,
     new_plot_window_manager: NewPlotWindowManager,
-    tabs_tree: DockState<String>
+    tabs_tree: DockState<String>,
+    tsdb: Arc<Mutex<Tsdb>>,
+    log_config: LogConfig,
+    dashboard_config: DashboardConfig,
 }
 
 struct MyContext {
@@ -48,8 +110,58 @@ struct MyContext {
     pub style: Option<Style>,
     open_tabs: HashSet<String>,
     object: Option<MibObject>,
+    /// the poll of the shown target before `object`, to compute rates from
+    previous: Option<MibObject>,
+    replay: Option<Replay>,
+    alerts: Arc<Mutex<AlertEngine>>,
     plots: HashMap<String, PlotContext>,
     new_plot_name: String,
+    csv_dir: String,
+    targets: Targets,
+    latest: Latest,
+    /// the target whose polls are shown, the first one polled until another is picked
+    shown_target: String,
+    /// wakes the discovery task, `None` when there is nothing to discover
+    discover: Option<Arc<tokio::sync::Notify>>,
+    topology: TopologyView,
+    topology_graph: Topology,
+    links: Links,
+    links_graph: Topology,
+    links_view: TopologyView,
+    inventory: Inventory,
+    /// the MAC address searched for in the forwarding databases of every target
+    mac_query: String,
+    /// where `mac_query` was found, searched again only when it changes
+    mac_found: Option<Vec<MacLocation>>,
+    /// the ifIndex of the ifTable row to scroll to the next time the table is shown
+    if_row: Option<String>,
+    /// tabs a tab asked to open, opened after the dock is drawn
+    to_open: Vec<String>,
+    tsdb: Arc<Mutex<Tsdb>>,
+    vendors: Vec<VendorProfile>,
+    /// writable instances with the values typed for them, sent together in one SET
+    edits: Vec<Edit>,
+    /// the instance typed in the set tab to add to `edits`
+    edit_instance: String,
+    /// whether the set tab asks to confirm sending `edits`
+    confirm_set: bool,
+    set_sender: Sender<SetRequest>,
+    set_outcomes: Receiver<SetOutcome>,
+    last_set: Option<SetOutcome>,
+    mib_tree: MibTree,
+    browser: Browser,
+    browse_sender: Sender<BrowseRequest>,
+    browse_results: Receiver<BrowseResult>,
+    /// asks the poll task to also poll a browsed value on a target, so it can be plotted
+    pin_sender: Sender<(String, MibValue)>,
+    dashboards: Dashboards,
+    /// the name the current session is saved under from the dashboards tab
+    dashboard_name: String,
+    /// the path of an exported dashboard to import
+    dashboard_import: String,
+    dashboard_action: Option<DashboardAction>,
+    /// the outcome of the last action worth telling the user about
+    status: String,
 
     show_close_buttons: bool,
     show_add_buttons: bool,
@@ -69,6 +181,19 @@ struct PlotContext {
     zoom: bool,
     legend: bool,
     grid: bool,
+    window: TimeWindow,
+    /// keeps the window ending at the latest poll
+    follow: bool,
+    /// the local times a custom window starts and ends at, as typed
+    from: String,
+    to: String,
+    derived: Vec<Derived>,
+    style: PlotStyle,
+    /// the name, expression and unit of the derived series being typed
+    new_series: (String, String, String),
+    /// the span of time drawn in the last frame, what the series are clipped to while the plot
+    /// does not follow a window
+    shown: Option<(i64, i64)>,
 }
 
 struct NewPlotWindowManager {
@@ -81,30 +206,49 @@ struct NewPlotWindowManager {
 struct Plottable {
     name: String,
     oid: Vec<u16>,
+    key: String,
     points: Vec<(i64, i64)>,
     points_max: (i64, i64),
+    /// the instance oid and series key of the value that is 100 %, to plot `oid` as a
+    /// percentage of it
+    percent_of: Option<(Vec<u16>, String)>,
+    unit: String,
+    side: Side,
 }
 
 impl Plottable {
     fn new(val: MibValue) -> Option<Self> {
+        let key = val.samples().first()?.key.clone();
         match val {
             MibValue::inti32(mvinti32) => Some(Plottable { 
                 name: mvinti32.name, 
                 oid: mvinti32.oid,
+                key,
                 points: vec![],
                 points_max: (0, 0),
+                percent_of: None,
+                unit: String::new(),
+                side: Side::Left,
             }),
             MibValue::intu32(mvintu32) => Some(Plottable { 
                 name: mvintu32.name, 
                 oid: mvintu32.oid,
+                key,
                 points: vec![],
                 points_max: (0, 0),
+                percent_of: None,
+                unit: String::new(),
+                side: Side::Left,
             }),
             MibValue::intu64(mvintu64) => Some(Plottable { 
                 name: mvintu64.name, 
                 oid: mvintu64.oid,
+                key,
                 points: vec![],
                 points_max: (0, 0),
+                percent_of: None,
+                unit: String::new(),
+                side: Side::Left,
             }),
             _ => None,
         }
@@ -115,6 +259,66 @@ impl Plottable {
         if self.points_max.1 < point.1 { self.points_max.1 = point.1 }
         self.points.push(point);
     }
+
+    /// builds a plottable for `val` and fills it with the history stored for `target` over `range`
+    fn load(val: MibValue, tsdb: &Mutex<Tsdb>, target: &str, range: (i64, i64)) -> Option<Self> {
+        Some(Plottable::new(val)?.history(tsdb, target, range))
+    }
+
+    /// plots the instance of a table column at `position` in `val`, `None` when it is not numeric
+    /// or an arc of its index does not fit the u16 OIDs of plots
+    fn instance(name: String, val: &MibValue, position: usize) -> Option<Self> {
+        let key = val.samples().into_iter().nth(position)?.key;
+        let index = val.instance_index(position).into_iter().map(u16::try_from).collect::<Result<Vec<u16>, _>>().ok()?;
+        let oid = val.get_oid().into_iter().chain(index).collect();
+        Some(Plottable { name, oid, key, points: vec![], points_max: (0, 0), percent_of: None, unit: String::new(), side: Side::Left })
+    }
+
+    /// fills the plottable with the history stored for `target` between `from` and `to`
+    fn history(mut self, tsdb: &Mutex<Tsdb>, target: &str, (from, to): (i64, i64)) -> Self {
+        let mut tsdb = tsdb.lock().unwrap();
+        let points = tsdb.query_span(target, &self.key, from, to, PLOT_POINTS).and_then(|points| match &self.percent_of {
+            Some((_, key)) => {
+                let wholes = tsdb.query_span(target, key, from, to, PLOT_POINTS)?.into_iter().collect::<HashMap<i64, i64>>();
+                Ok(points.into_iter().filter_map(|(timestamp, value)| Some((timestamp, percent(value, *wholes.get(&timestamp)?)?))).collect())
+            }
+            None => Ok(points),
+        });
+        match points {
+            Ok(points) => points.into_iter().for_each(|point| self.add(point)),
+            Err(err) => println!("could not load history of {} for {}: {}", self.key, target, err),
+        }
+        self
+    }
+
+    /// the plotted value in `object`, `None` when the object does not have it
+    fn value(&self, object: &MibObject) -> Option<i64> {
+        let first = |oid: &Vec<u16>| object.find_oid(oid.clone())?.val_as_mvinti64()?.first().copied();
+        match &self.percent_of {
+            Some((oid, _)) => percent(first(&self.oid)?, first(oid)?),
+            None => first(&self.oid),
+        }
+    }
+}
+
+impl Plottable {
+    fn saved(&self) -> SavedSeries {
+        SavedSeries { name: self.name.clone(), oid: self.oid.clone(), key: self.key.clone(), percent_of: self.percent_of.clone(), unit: self.unit.clone(), side: self.side }
+    }
+
+    fn restore(series: &SavedSeries) -> Self {
+        Plottable { name: series.name.clone(), oid: series.oid.clone(), key: series.key.clone(), points: vec![], points_max: (0, 0), percent_of: series.percent_of.clone(), unit: series.unit.clone(), side: series.side }
+    }
+}
+
+fn percent(value: i64, whole: i64) -> Option<i64> {
+    (whole > 0).then(|| value * 100 / whole)
+}
+
+/// the span of history to load for a series shown in `plot`, the default window for a new plot
+fn history_range(plot: Option<&PlotContext>) -> (i64, i64) {
+    let now = Utc::now().timestamp();
+    plot.and_then(|plot| plot.range(now)).unwrap_or((now - TimeWindow::default().secs().unwrap_or(3600), now))
 }
 
 impl PlotContext {
@@ -127,9 +331,94 @@ impl PlotContext {
             zoom: true,
             legend: true,
             grid: true,
+            window: TimeWindow::default(),
+            follow: true,
+            from: TimeAxisModule::format_local(Utc::now().timestamp() - 86400),
+            to: TimeAxisModule::format_local(Utc::now().timestamp()),
+            derived: vec![],
+            style: PlotStyle::default(),
+            new_series: Default::default(),
+            shown: None,
+        }
+    }
+}
+impl PlotContext {
+    fn saved(&self) -> SavedPlot {
+        SavedPlot {
+            series: self.plottables.iter().map(|a| a.saved()).collect(),
+            draggable: self.draggable,
+            axes: self.axes,
+            scroll: self.scroll,
+            zoom: self.zoom,
+            legend: self.legend,
+            grid: self.grid,
+            window: self.window,
+            follow: self.follow,
+            from: self.from.clone(),
+            to: self.to.clone(),
+            derived: self.derived.iter().map(|a| SavedDerived { name: a.name.clone(), expr: a.text.clone(), unit: a.unit.clone(), side: a.side }).collect(),
+            style: self.style,
         }
     }
+
+    /// the saved plot filled with the history stored for `target`
+    fn restore(saved: &SavedPlot, tsdb: &Mutex<Tsdb>, target: &str) -> Self {
+        let mut plotcontext = PlotContext {
+            plottables: saved.series.iter().map(Plottable::restore).collect(),
+            draggable: saved.draggable,
+            axes: saved.axes,
+            scroll: saved.scroll,
+            zoom: saved.zoom,
+            legend: saved.legend,
+            grid: saved.grid,
+            window: saved.window,
+            follow: saved.follow,
+            from: saved.from.clone(),
+            to: saved.to.clone(),
+            derived: saved
+                .derived
+                .iter()
+                .filter_map(|a| match Derived::new(&a.name, &a.expr, &a.unit, a.side) {
+                    Ok(derived) => Some(derived),
+                    Err(err) => {
+                        println!("skipping derived series {}: {}", a.name, err);
+                        None
+                    }
+                })
+                .collect(),
+            style: saved.style,
+            new_series: Default::default(),
+            shown: None,
+        };
+        plotcontext.reload(tsdb, target);
+        plotcontext
+    }
+
+    /// the span the window shows at `now`, `None` while a custom window is not a valid span
+    fn range(&self, now: i64) -> Option<(i64, i64)> {
+        match self.window.secs() {
+            Some(secs) => Some((now - secs, now)),
+            None => match (TimeAxisModule::parse_local(&self.from), TimeAxisModule::parse_local(&self.to)) {
+                (Some(from), Some(to)) if from < to => Some((from, to)),
+                _ => None,
+            },
+        }
+    }
+
+    /// refills every series with the history stored for `target` over the window shown, at the
+    /// resolution that suits its span
+    fn reload(&mut self, tsdb: &Mutex<Tsdb>, target: &str) {
+        let range = history_range(Some(self));
+        self.plottables = self.plottables.iter().map(|a| Plottable::restore(&a.saved()).history(tsdb, target, range)).collect();
+        self.derived = self
+            .derived
+            .iter()
+            .filter_map(|a| Derived::new(&a.name, &a.text, &a.unit, a.side).ok())
+            .map(|a| a.history(tsdb, target, range, PLOT_POINTS))
+            .collect();
+    }
 }
+
 impl TabViewer for MyContext {
     type Tab = String;
 
@@ -138,32 +427,1096 @@ impl TabViewer for MyContext {
     }
 
     fn ui(&mut self, ui: &mut Ui, tab: &mut Self::Tab) {
+        let mut menu = CellMenu { plots: self.plots.keys().cloned().collect(), picked: None };
         match tab.as_str() {
-            "ifTable" => self.object.clone().unwrap().interfaces.ifTable.egui_table_show(ui),
-            "atTable" => self.object.clone().unwrap().at.atTable.egui_table_show(ui),
-            "ipAddrTable" => self.object.clone().unwrap().ip.ipAddrTable.egui_table_show(ui),
-            "ipRouteTable" => self.object.clone().unwrap().ip.ipRouteTable.egui_table_show(ui),
-            "ipNetToMediaTable" => self.object.clone().unwrap().ip.ipNetToMediaTable.egui_table_show(ui),
-            "tcpConnTable" => self.object.clone().unwrap().tcp.tcpConnTable.egui_table_show(ui),
-            "udpTable" => self.object.clone().unwrap().udp.udpTable.egui_table_show(ui),
-            "egpNeighTable" => self.object.clone().unwrap().egp.egpNeighTable.egui_table_show(ui),
+            "ifTable" => {
+                let if_row = self.if_row.take();
+                let object = self.shown_object().unwrap();
+                let row = if_row.and_then(|if_index| object.interfaces.ifTable.ifIndex.display_values().iter().position(|a| *a == if_index));
+                object.interfaces.ifTable.egui_table_show(ui, &mut menu, row);
+            }
+            "ifXTable" => self.shown_object().unwrap().interfaces.ifXTable.egui_table_show(ui, &mut menu),
+            "utilisation" => self.utilisation_show(ui),
+            "host" => self.host_show(ui),
+            "hrStorageTable" => self.shown_object().unwrap().host.hrStorageTable.egui_table_show(ui, &mut menu),
+            "hrDeviceTable" => self.shown_object().unwrap().host.hrDeviceTable.egui_table_show(ui, &mut menu),
+            "hrProcessorTable" => self.shown_object().unwrap().host.hrProcessorTable.egui_table_show(ui, &mut menu),
+            "hrSWRunTable" => self.shown_object().unwrap().host.hrSWRunTable.egui_table_show(ui, &mut menu),
+            "hrSWRunPerfTable" => self.shown_object().unwrap().host.hrSWRunPerfTable.egui_table_show(ui, &mut menu),
+            "ucd" => self.ucd_show(ui),
+            "laTable" => self.shown_object().unwrap().ucd.laTable.egui_table_show(ui, &mut menu),
+            "dskTable" => self.shown_object().unwrap().ucd.dskTable.egui_table_show(ui, &mut menu),
+            "nsExtendOutput1Table" => self.shown_object().unwrap().ucd.nsExtendOutput1Table.egui_table_show(ui, &mut menu),
+            "atTable" => self.shown_object().unwrap().at.atTable.egui_table_show(ui, &mut menu),
+            "ipAddrTable" => self.shown_object().unwrap().ip.ipAddrTable.egui_table_show(ui, &mut menu),
+            "ipRouteTable" => self.shown_object().unwrap().ip.ipRouteTable.egui_table_show(ui, &mut menu),
+            "ipNetToMediaTable" => self.shown_object().unwrap().ip.ipNetToMediaTable.egui_table_show(ui, &mut menu),
+            "tcpConnTable" => self.shown_object().unwrap().tcp.tcpConnTable.egui_table_show(ui, &mut menu),
+            "udpTable" => self.shown_object().unwrap().udp.udpTable.egui_table_show(ui, &mut menu),
+            "ipAddressTable" => self.shown_object().unwrap().ip.ipAddressTable.egui_table_show(ui, &mut menu),
+            "ipNetToPhysicalTable" => self.shown_object().unwrap().ip.ipNetToPhysicalTable.egui_table_show(ui, &mut menu),
+            "inetCidrRouteTable" => self.shown_object().unwrap().ip.inetCidrRouteTable.egui_table_show(ui, &mut menu),
+            "tcpConnectionTable" => self.shown_object().unwrap().tcp.tcpConnectionTable.egui_table_show(ui, &mut menu),
+            "tcpListenerTable" => self.shown_object().unwrap().tcp.tcpListenerTable.egui_table_show(ui, &mut menu),
+            "udpEndpointTable" => self.shown_object().unwrap().udp.udpEndpointTable.egui_table_show(ui, &mut menu),
+            "egpNeighTable" => self.shown_object().unwrap().egp.egpNeighTable.egui_table_show(ui, &mut menu),
+            "lldpLocPortTable" => self.shown_object().unwrap().lldp.lldpLocPortTable.egui_table_show(ui, &mut menu),
+            "lldpRemTable" => self.shown_object().unwrap().lldp.lldpRemTable.egui_table_show(ui, &mut menu),
+            "cdpCacheTable" => self.shown_object().unwrap().cdp.cdpCacheTable.egui_table_show(ui, &mut menu),
+            "entPhysicalTable" => self.shown_object().unwrap().entity.entPhysicalTable.egui_table_show(ui, &mut menu),
+            "dot1dBasePortTable" => self.shown_object().unwrap().bridge.dot1dBasePortTable.egui_table_show(ui, &mut menu),
+            "dot1dTpFdbTable" => self.shown_object().unwrap().bridge.dot1dTpFdbTable.egui_table_show(ui, &mut menu),
+            "dot1qTpFdbTable" => self.shown_object().unwrap().bridge.dot1qTpFdbTable.egui_table_show(ui, &mut menu),
+            "dot1qVlanStaticTable" => self.shown_object().unwrap().bridge.dot1qVlanStaticTable.egui_table_show(ui, &mut menu),
+            "bridge" => self.bridge_show(ui),
+            "vendor" => self.vendor_show(ui),
+            "set" => self.set_show(ui),
+            "mib browser" => self.browser_show(ui),
+            "dashboards" => self.dashboards_show(ui),
+            "inventory" => self.inventory_show(ui),
+            "history diff" => self.diff_show(ui),
+            "alerts" => self.alerts_show(ui),
+            "targets" => self.targets_show(ui),
+            "topology" => {
+                let graph = self.topology_graph.lock().unwrap().clone();
+                self.topology.show(ui, &graph);
+            }
+            "neighbours" => self.neighbours_show(ui),
+            "links" => {
+                let graph = self.links_graph.lock().unwrap().clone();
+                self.links_view.show(ui, &graph);
+            }
             _ => {
-                if self.plots.keys().any(|name| name.eq(tab.as_str())) {
-                    self.plot(ui, tab.to_string(),  self.plots.get(tab.as_str()).unwrap().clone());
+                let object = self.shown_object();
+                if let Some(columns) = object.as_ref().and_then(|object| object.vendor.table(tab)) {
+                    egui_rows_show(ui, &columns, &mut menu);
+                } else if self.plots.keys().any(|name| name.eq(tab.as_str())) {
+                    self.plot(ui, tab);
                 } else {
                     ui.label(tab.as_str());
                     ui.label("we dont know what to put here");
                 }
             }
         }
+        if let Some(cell) = menu.picked {
+            self.plot_cell(cell);
+        }
+    }
+
+    fn context_menu(&mut self, ui: &mut Ui, tab: &mut Self::Tab, _surface: SurfaceIndex, _node: NodeIndex) {
+        let is_table = self.shown_object().is_some_and(|obj| obj.table(tab).is_some());
+        if !is_table && !self.plots.contains_key(tab.as_str()) {
+            return;
+        }
+        for (label, excel) in [("export CSV", false), ("export CSV for Excel", true)] {
+            if ui.button(label).clicked() {
+                self.status = match self.export_csv(tab, excel) {
+                    Ok(path) => format!("exported {} to {}", tab, path.display()),
+                    Err(err) => format!("could not export {}: {}", tab, err),
+                };
+                println!("{}", self.status);
+                ui.close_menu();
+            }
+        }
     }
 }
 
 impl MyContext {
-    fn plot(&mut self, ui: &mut Ui, tab: String, mut plotcontext: PlotContext) {
-        // println!("rendering plot called {} with {} plottables", tab, plotcontext.plottables.len());
-        // println!("{:?}: {:?}", tab, plottables);
-        Plot::new(tab).legend(Legend::default())
+    /// the snapshot being replayed, or the latest polled object when not replaying
+    fn shown_object(&self) -> Option<&MibObject> {
+        match self.replay.as_ref().and_then(|replay| replay.current()) {
+            Some(snapshot) => Some(snapshot),
+            None => self.object.as_ref(),
+        }
+    }
+
+    /// the shown object and the poll of the same target before it, both from the log in
+    /// history mode
+    fn shown_pair(&self) -> Option<(&MibObject, &MibObject)> {
+        let (previous, current) = match self.replay.as_ref().filter(|replay| replay.current().is_some()) {
+            Some(replay) => (replay.previous(), replay.current()),
+            None => (self.previous.as_ref(), self.object.as_ref()),
+        };
+        Some((previous?, current?)).filter(|(previous, current)| previous.target == current.target)
+    }
+
+    /// writes the table or plot shown in `tab` to a new file in the export directory
+    fn export_csv(&self, tab: &str, excel: bool) -> std::io::Result<std::path::PathBuf> {
+        let object = self.shown_object().ok_or(std::io::Error::other("nothing polled yet"))?;
+        let path = CsvExportModule::export_path(&self.csv_dir, tab, &object.target, object.timestamp)?;
+        let file = File::create(&path)?;
+        match object.table(tab) {
+            Some(columns) => CsvExportModule::write_table(file, &object, &columns, excel)?,
+            None => {
+                let plotcontext = self.plots.get(tab).ok_or(std::io::Error::other("no such plot"))?;
+                let series = plotcontext
+                    .plottables
+                    .iter()
+                    .map(|plottable| (plottable.name.clone(), plottable.points.clone()))
+                    .collect::<Vec<(String, Vec<(i64, i64)>)>>();
+                CsvExportModule::write_series(file, &object.target, &series, excel)?
+            }
+        }
+        Ok(path)
+    }
+
+    fn alerts_show(&mut self, ui: &mut Ui) {
+        let (active, recent) = {
+            let engine = self.alerts.lock().unwrap();
+            (engine.active(), engine.recent())
+        };
+        egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            ui.heading(format!("active alerts ({})", active.len()));
+            egui::Grid::new("active_alerts").striped(true).show(ui, |ui| {
+                ["severity", "status", "since", "target", "rule", "instance", "value"].iter().for_each(|a| { ui.strong(*a); });
+                ui.end_row();
+                active.iter().for_each(|alert| {
+                    ui.colored_label(severity_color(alert.severity), format!("{:?}", alert.severity));
+                    match alert.status {
+                        AlertStatus::Firing => ui.strong("firing"),
+                        _ => ui.label(format!("{:?}", alert.status).to_lowercase()),
+                    };
+                    ui.label(format_timestamp(alert.since));
+                    ui.label(&alert.target);
+                    ui.label(&alert.rule);
+                    ui.label(&alert.instance);
+                    ui.label(&alert.value);
+                    ui.end_row();
+                });
+            });
+            ui.separator();
+            ui.collapsing(format!("recent events ({})", recent.len()), |ui| {
+                recent.iter().for_each(|event| {
+                    ui.colored_label(severity_color(event.severity), format!("{} {}", format_timestamp(event.timestamp), event.message));
+                });
+            });
+        });
+    }
+
+    fn targets_show(&mut self, ui: &mut Ui) {
+        let (targets, discovery_status) = {
+            let list = self.targets.lock().unwrap();
+            (list.targets().to_vec(), list.discovery_status.clone())
+        };
+        if let Some(discover) = self.discover.as_ref() {
+            ui.horizontal(|ui| {
+                if ui.button("discover now").clicked() {
+                    discover.notify_one();
+                }
+                ui.label(discovery_status);
+            });
+        }
+        egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            egui::Grid::new("targets").striped(true).show(ui, |ui| {
+                ["", "address", "sysName", "vendor", "profile", "last poll"].iter().for_each(|a| { ui.strong(*a); });
+                ui.end_row();
+                for target in targets {
+                    let ip = target.socket_addr().map(|a| a.ip().to_string()).unwrap_or(target.address.clone());
+                    let last_poll = self.latest.lock().unwrap().get(&ip).map(|a| a.timestamp);
+                    if ui.add_enabled(ip != self.shown_target, egui::Button::new("show")).clicked() {
+                        // the object is only copied out of the latest polls when it is going to be shown
+                        self.object = self.latest.lock().unwrap().get(&ip).cloned();
+                        self.shown_target = ip;
+                    }
+                    ui.label(&target.address);
+                    ui.label(&target.sys_name);
+                    ui.label(&target.vendor);
+                    ui.label(&target.profile);
+                    ui.label(last_poll.map(format_timestamp).unwrap_or("never".to_owned()));
+                    ui.end_row();
+                }
+            });
+        });
+    }
+
+    /// the LLDP and CDP neighbours of the shown target, each local port linking to its ifTable
+    /// row, and the latest link changes of every target
+    fn neighbours_show(&mut self, ui: &mut Ui) {
+        let neighbours = self.shown_object().map(|object| NeighboursModule::neighbours(&object)).unwrap_or_default();
+        ui.horizontal(|ui| {
+            ui.heading(format!("neighbours of {} ({})", self.shown_target, neighbours.len()));
+            if ui.button("link graph").clicked() {
+                self.to_open.push("links".to_owned());
+            }
+        });
+        egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            egui::Grid::new("neighbours").striped(true).show(ui, |ui| {
+                for heading in ["port", "ifIndex", "neighbour", "remote port", "address", "protocol", "description"] {
+                    ui.strong(heading);
+                }
+                ui.end_row();
+                for neighbour in &neighbours {
+                    ui.label(&neighbour.port);
+                    match &neighbour.if_index {
+                        Some(if_index) => {
+                            if ui.link(if_index).on_hover_text("show in ifTable").clicked() {
+                                self.if_row = Some(if_index.clone());
+                                self.to_open.push("ifTable".to_owned());
+                            }
+                        }
+                        None => { ui.label(&neighbour.local_port); }
+                    }
+                    ui.label(&neighbour.remote);
+                    ui.label(&neighbour.remote_port);
+                    ui.label(neighbour.address.as_deref().unwrap_or_default());
+                    ui.label(neighbour.protocol);
+                    ui.label(&neighbour.description);
+                    ui.end_row();
+                }
+            });
+            let changes = self.links.lock().unwrap().recent();
+            ui.collapsing(format!("link changes ({})", changes.len()), |ui| {
+                for change in changes {
+                    let color = if change.added { egui::Color32::GREEN } else { egui::Color32::RED };
+                    ui.colored_label(color, format!("{} {}", format_timestamp(change.timestamp), change));
+                }
+            });
+        });
+    }
+
+    /// a MAC address search across the forwarding databases of every target, followed by the
+    /// VLANs and forwarding database of the shown target
+    fn bridge_show(&mut self, ui: &mut Ui) {
+        let changed = ui.horizontal(|ui| {
+            ui.label("find MAC");
+            ui.text_edit_singleline(&mut self.mac_query).on_hover_text("a whole MAC address or its first octets, in any notation").changed()
+        }).inner;
+        if changed {
+            self.mac_found = BridgeModule::find_mac(&self.latest.lock().unwrap().values().collect::<Vec<&MibObject>>(), &self.mac_query);
+        }
+        egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            match &self.mac_found {
+                None if !self.mac_query.trim().is_empty() => { ui.label("not a MAC address"); }
+                None => {}
+                Some(found) if found.is_empty() => { ui.label("no switch has learned the address"); }
+                Some(found) => {
+                    egui::Grid::new("mac search").striped(true).show(ui, |ui| {
+                        for heading in ["MAC", "device", "port", "VLAN", "status", "MACs on port"] {
+                            ui.strong(heading);
+                        }
+                        ui.end_row();
+                        for location in found {
+                            let entry = &location.entry;
+                            ui.label(&entry.mac);
+                            ui.label(&location.device).on_hover_text(&location.target);
+                            match &entry.if_index {
+                                Some(if_index) => {
+                                    if ui.link(&entry.port).on_hover_text("show in ifTable").clicked() {
+                                        self.object = self.latest.lock().unwrap().get(&location.target).cloned();
+                                        self.shown_target = location.target.clone();
+                                        self.if_row = Some(if_index.clone());
+                                        self.to_open.push("ifTable".to_owned());
+                                    }
+                                }
+                                None => { ui.label(&entry.port); }
+                            }
+                            ui.label(entry.vlan.map(|vlan| format!("{} {}", vlan, location.vlan_name).trim().to_owned()).unwrap_or_default());
+                            ui.label(&entry.status);
+                            ui.label(location.port_macs.to_string());
+                            ui.end_row();
+                        }
+                    });
+                }
+            }
+
+            let object = self.shown_object();
+            let vlans = object.map(BridgeModule::vlans).unwrap_or_default();
+            ui.collapsing(format!("VLANs of {} ({})", self.shown_target, vlans.len()), |ui| {
+                egui::Grid::new("vlans").striped(true).show(ui, |ui| {
+                    for heading in ["VLAN", "name", "ports", "untagged"] {
+                        ui.strong(heading);
+                    }
+                    ui.end_row();
+                    for vlan in vlans {
+                        ui.label(vlan.id.to_string());
+                        ui.label(&vlan.name);
+                        ui.label(vlan.ports.join(", "));
+                        ui.label(vlan.untagged.join(", "));
+                        ui.end_row();
+                    }
+                });
+            });
+            let entries = object.map(BridgeModule::fdb).unwrap_or_default();
+            ui.collapsing(format!("forwarding database of {} ({})", self.shown_target, entries.len()), |ui| {
+                egui::Grid::new("fdb").striped(true).show(ui, |ui| {
+                    for heading in ["MAC", "VLAN", "port", "status"] {
+                        ui.strong(heading);
+                    }
+                    ui.end_row();
+                    for entry in entries {
+                        ui.label(&entry.mac);
+                        ui.label(entry.vlan.map(|a| a.to_string()).unwrap_or_default());
+                        ui.label(&entry.port);
+                        ui.label(&entry.status);
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+    }
+
+    /// the ENTITY-MIB hardware tree of the shown target and the latest hardware changes of
+    /// every target
+    fn inventory_show(&mut self, ui: &mut Ui) {
+        let tree = self.shown_object().map(|object| InventoryModule::tree(&object)).unwrap_or_default();
+        ui.heading(format!("hardware of {}", self.shown_target));
+        egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            if tree.is_empty() {
+                ui.label("the target has no entPhysicalTable");
+            }
+            for component in &tree {
+                component_show(ui, component);
+            }
+            let changes = self.inventory.lock().unwrap().recent();
+            ui.collapsing(format!("hardware changes ({})", changes.len()), |ui| {
+                for change in changes {
+                    let color = if change.added { egui::Color32::GREEN } else { egui::Color32::RED };
+                    ui.colored_label(color, format!("{} {}", format_timestamp(change.timestamp), change));
+                }
+            });
+        });
+    }
+
+    /// the traffic of every interface of the shown target over its last two polls
+    fn utilisation_show(&mut self, ui: &mut Ui) {
+        let (previous, current) = match self.shown_pair() {
+            Some(pair) => pair,
+            None => {
+                ui.label("waiting for a second poll of the target");
+                return;
+            }
+        };
+        let rows = UtilisationModule::utilisation(previous, current);
+        ui.label(format!("{} to {}", format_timestamp(previous.timestamp), format_timestamp(current.timestamp)));
+        let rate = |rate: Option<f64>| rate.map(UtilisationModule::format_rate).unwrap_or("-".to_owned());
+        let percent = |percent: Option<f64>| percent.map(|a| format!("{:.1} %", a)).unwrap_or("-".to_owned());
+        egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            egui::Grid::new("utilisation").striped(true).show(ui, |ui| {
+                for heading in ["ifIndex", "name", "alias", "speed", "in", "in %", "out", "out %", "counters"] {
+                    ui.strong(heading);
+                }
+                ui.end_row();
+                for row in &rows {
+                    ui.label(&row.if_index);
+                    ui.label(&row.name);
+                    ui.label(&row.alias);
+                    ui.label(rate(row.speed.map(|a| a as f64)));
+                    ui.label(rate(row.in_rate));
+                    ui.add(egui::ProgressBar::new((row.in_percent.unwrap_or_default() / 100.0) as f32).desired_width(80.0).text(percent(row.in_percent)));
+                    ui.label(rate(row.out_rate));
+                    ui.add(egui::ProgressBar::new((row.out_percent.unwrap_or_default() / 100.0) as f32).desired_width(80.0).text(percent(row.out_percent)));
+                    ui.label(if row.high_capacity { "64-bit" } else { "32-bit" });
+                    ui.end_row();
+                }
+            });
+        });
+    }
+
+    /// the HOST-RESOURCES-MIB view of the shown target: processor load, storage in bytes,
+    /// devices and the process list
+    fn host_show(&mut self, ui: &mut Ui) {
+        let object = match self.shown_object() {
+            Some(object) => object,
+            None => return,
+        };
+        let host = &object.host;
+        let first = |value: &MibValue| value.display_values().first().cloned().unwrap_or("-".to_owned());
+        let uptime = host.hrSystemUptime.val_as_mvinti64().unwrap_or_default().first().map(|a| a / 100).unwrap_or_default();
+        let memory = host.hrMemorySize.val_as_mvinti64().unwrap_or_default().first().map(|a| *a as u64 * 1024).unwrap_or_default();
+        let mut plot = false;
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "up {}d {:02}:{:02}, {} users, {} of at most {} processes, {} memory",
+                uptime / 86400,
+                uptime % 86400 / 3600,
+                uptime % 3600 / 60,
+                first(&host.hrSystemNumUsers),
+                first(&host.hrSystemProcesses),
+                first(&host.hrSystemMaxProcesses),
+                HostResourcesModule::format_bytes(memory),
+            ));
+            plot = ui.button("plot CPU and disk usage").clicked();
+        });
+        let devices = HostResourcesModule::devices(object);
+        let mut processes = HostResourcesModule::processes(self.shown_pair().map(|a| a.0), object);
+        processes.sort_by(|a, b| b.cpu_percent.unwrap_or_default().total_cmp(&a.cpu_percent.unwrap_or_default()).then(b.memory.cmp(&a.memory)));
+        egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            ui.collapsing("processors", |ui| {
+                for device in devices.iter().filter(|a| a.load.is_some()) {
+                    let load = device.load.unwrap_or_default();
+                    ui.add(egui::ProgressBar::new(load as f32 / 100.0).text(format!("{} {} %", device.descr, load)));
+                }
+            });
+            ui.collapsing("storage", |ui| {
+                egui::Grid::new("storage").striped(true).show(ui, |ui| {
+                    for heading in ["description", "type", "size", "used", ""] {
+                        ui.strong(heading);
+                    }
+                    ui.end_row();
+                    for storage in HostResourcesModule::storage(object) {
+                        ui.label(&storage.descr);
+                        ui.label(&storage.kind);
+                        ui.label(HostResourcesModule::format_bytes(storage.size));
+                        ui.label(HostResourcesModule::format_bytes(storage.used));
+                        let percent = storage.percent.unwrap_or_default();
+                        ui.add(egui::ProgressBar::new(percent as f32 / 100.0).desired_width(120.0).text(format!("{:.1} %", percent)));
+                        ui.end_row();
+                    }
+                });
+            });
+            ui.collapsing("devices", |ui| {
+                egui::Grid::new("devices").striped(true).show(ui, |ui| {
+                    for heading in ["index", "type", "description", "status", "errors"] {
+                        ui.strong(heading);
+                    }
+                    ui.end_row();
+                    for device in &devices {
+                        ui.label(&device.index);
+                        ui.label(&device.kind);
+                        ui.label(&device.descr);
+                        ui.label(&device.status);
+                        ui.label(&device.errors);
+                        ui.end_row();
+                    }
+                });
+            });
+            ui.collapsing(format!("processes ({})", processes.len()), |ui| {
+                egui::Grid::new("processes").striped(true).show(ui, |ui| {
+                    for heading in ["pid", "name", "CPU", "CPU time", "memory", "status", "type", "command"] {
+                        ui.strong(heading);
+                    }
+                    ui.end_row();
+                    for process in &processes {
+                        ui.label(&process.index);
+                        ui.label(&process.name);
+                        ui.label(process.cpu_percent.map(|a| format!("{:.1} %", a)).unwrap_or("-".to_owned()));
+                        ui.label(format!("{:.2} s", process.cpu_time as f64 / 100.0));
+                        ui.label(HostResourcesModule::format_bytes(process.memory));
+                        ui.label(&process.status);
+                        ui.label(&process.kind);
+                        ui.label([process.path.as_str(), process.parameters.as_str()].join(" ").trim());
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+        // adding plots needs self mutably, so the object is only copied when they are asked for
+        if let Some(object) = self.shown_object().filter(|_| plot).cloned() {
+            self.host_plots(&object);
+        }
+    }
+
+    /// opens a plot of the load of every processor and one of how full every fixed disk is,
+    /// both filled with the history stored for `object`'s target
+    fn host_plots(&mut self, object: &MibObject) {
+        let loads = &object.host.hrProcessorTable.hrProcessorLoad;
+        let cpu = (0..loads.display_values().len())
+            .filter_map(|position| {
+                let index = loads.instance_index(position).iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".");
+                Plottable::instance(format!("cpu {} %", index), loads, position)
+            })
+            .map(|plottable| plottable.history(&self.tsdb, &object.target, history_range(None)))
+            .collect::<Vec<Plottable>>();
+        let table = &object.host.hrStorageTable;
+        let position = |value: &MibValue, index: &str| value.positions().get(index).copied();
+        let disks = HostResourcesModule::storage(object)
+            .into_iter()
+            .filter(|storage| storage.kind == "fixedDisk")
+            .filter_map(|storage| {
+                let mut used = Plottable::instance(format!("{} %", storage.descr), &table.hrStorageUsed, position(&table.hrStorageUsed, &storage.index)?)?;
+                let size = Plottable::instance(String::new(), &table.hrStorageSize, position(&table.hrStorageSize, &storage.index)?)?;
+                used.percent_of = Some((size.oid, size.key));
+                Some(used.history(&self.tsdb, &object.target, history_range(None)))
+            })
+            .collect::<Vec<Plottable>>();
+        for (name, plottables) in [("cpu", cpu), ("disk usage", disks)] {
+            self.plots.insert(name.to_owned(), PlotContext::new(plottables));
+            self.to_open.push(name.to_owned());
+        }
+    }
+
+    /// the UCD-SNMP-MIB view of a Net-SNMP agent: load averages, CPU states since the previous
+    /// poll, memory, watched disks and the output of its extend commands
+    fn ucd_show(&mut self, ui: &mut Ui) {
+        let object = match self.shown_object() {
+            Some(object) => object,
+            None => return,
+        };
+        let bytes = HostResourcesModule::format_bytes;
+        let bar = |ui: &mut Ui, percent: f64, text: String| {
+            ui.add(egui::ProgressBar::new(percent as f32 / 100.0).desired_width(160.0).text(text));
+        };
+        let mut plot = false;
+        ui.horizontal(|ui| {
+            let loads = UcdModule::loads(object).into_iter().map(|a| format!("{:.2}", a.load)).collect::<Vec<String>>();
+            ui.label(format!("load average {}", loads.join(" ")));
+            plot = ui.button("plot load and memory").clicked();
+        });
+        let cpu = self.shown_pair().and_then(|(previous, current)| UcdModule::cpu(previous, current));
+        let memory = UcdModule::memory(object);
+        egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            ui.collapsing("CPU", |ui| match &cpu {
+                Some(states) => states.iter().for_each(|(state, percent)| bar(ui, *percent, format!("{} {:.1} %", state, percent))),
+                None => {
+                    ui.label("waiting for a second poll of the target");
+                }
+            });
+            ui.collapsing("memory", |ui| {
+                let percent = |part: u64, whole: u64| if whole > 0 { part as f64 * 100.0 / whole as f64 } else { 0.0 };
+                bar(ui, percent(memory.used(), memory.total), format!("used {} of {}", bytes(memory.used()), bytes(memory.total)));
+                ui.label(format!("{} free, {} buffers, {} cached, {} shared", bytes(memory.available), bytes(memory.buffer), bytes(memory.cached), bytes(memory.shared)));
+                let swap = memory.swap_total.saturating_sub(memory.swap_available);
+                bar(ui, percent(swap, memory.swap_total), format!("swap {} of {}", bytes(swap), bytes(memory.swap_total)));
+            });
+            ui.collapsing("disks", |ui| {
+                egui::Grid::new("ucd_disks").striped(true).show(ui, |ui| {
+                    for heading in ["path", "device", "size", "used", "", "inodes", "error"] {
+                        ui.strong(heading);
+                    }
+                    ui.end_row();
+                    for disk in UcdModule::disks(object) {
+                        ui.label(&disk.path);
+                        ui.label(&disk.device);
+                        ui.label(bytes(disk.total));
+                        ui.label(bytes(disk.used));
+                        bar(ui, disk.percent as f64, format!("{} %", disk.percent));
+                        ui.label(format!("{} %", disk.inode_percent));
+                        ui.label(&disk.error);
+                        ui.end_row();
+                    }
+                });
+            });
+            ui.collapsing("extend", |ui| {
+                for extend in UcdModule::extends(object) {
+                    ui.collapsing(format!("{} (exit {})", extend.token, extend.result), |ui| {
+                        ui.monospace(&extend.output);
+                    });
+                }
+            });
+        });
+        if let Some(object) = self.shown_object().filter(|_| plot).cloned() {
+            self.ucd_plots(&object);
+        }
+    }
+
+    /// opens a plot of the load averages and one of available real memory as a percentage of
+    /// the total, both filled with the history stored for `object`'s target
+    fn ucd_plots(&mut self, object: &MibObject) {
+        let loads = &object.ucd.laTable.laLoadInt;
+        let names = object.ucd.laTable.laNames.display_values();
+        let load = (0..loads.display_values().len())
+            .filter_map(|position| Plottable::instance(format!("{} x 100", names.get(position).cloned().unwrap_or_default()), loads, position))
+            .map(|plottable| plottable.history(&self.tsdb, &object.target, history_range(None)))
+            .collect::<Vec<Plottable>>();
+        let memory = Plottable::instance("available memory %".to_owned(), &object.ucd.memAvailReal, 0).and_then(|mut available| {
+            let total = Plottable::instance(String::new(), &object.ucd.memTotalReal, 0)?;
+            available.percent_of = Some((total.oid, total.key));
+            Some(available.history(&self.tsdb, &object.target, history_range(None)))
+        });
+        for (name, plottables) in [("load", load), ("memory", memory.into_iter().collect())] {
+            self.plots.insert(name.to_owned(), PlotContext::new(plottables));
+            self.to_open.push(name.to_owned());
+        }
+    }
+
+    /// the vendor profile applied to the shown target: its objects, tables, plots and alert rules
+    fn vendor_show(&mut self, ui: &mut Ui) {
+        let object = match self.shown_object() {
+            Some(object) => object,
+            None => return,
+        };
+        let profile = match self.vendors.iter().find(|a| a.name == object.vendor.profile) {
+            Some(profile) => profile.clone(),
+            None => {
+                ui.label("no vendor profile matches the sysObjectID of the target");
+                return;
+            }
+        };
+        ui.label(format!("profile {} for sysObjectID {} and below", profile.name, profile.sys_object_id));
+        let mut to_open = vec![];
+        let mut plot_to_open = None;
+        egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            ui.collapsing("objects", |ui| {
+                egui::Grid::new("vendor_objects").striped(true).show(ui, |ui| {
+                    for value in &object.vendor.objects {
+                        ui.label(value.get_name());
+                        ui.label(value.display_values().join(", "));
+                        ui.end_row();
+                    }
+                });
+            });
+            ui.collapsing("tables", |ui| {
+                for table in &object.vendor.tables {
+                    if ui.button(&table.name).clicked() {
+                        to_open.push(table.name.clone());
+                    }
+                }
+            });
+            ui.collapsing("plots", |ui| {
+                for plot in &profile.plots {
+                    if ui.button(&plot.name).clicked() {
+                        plot_to_open = Some(plot.clone());
+                    }
+                }
+            });
+            ui.collapsing("alert rules", |ui| {
+                egui::Grid::new("vendor_alerts").striped(true).show(ui, |ui| {
+                    for rule in &profile.alerts {
+                        ui.label(&rule.name);
+                        ui.monospace(&rule.expr);
+                        ui.colored_label(severity_color(rule.severity), format!("{:?}", rule.severity));
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+        self.to_open.extend(to_open);
+        if let Some(plot) = plot_to_open {
+            let object = self.shown_object().cloned().unwrap();
+            self.vendor_plot(&object, &plot);
+        }
+    }
+
+    /// opens `plot` with every instance of its objects, filled with the history stored for
+    /// `object`'s target
+    fn vendor_plot(&mut self, object: &MibObject, plot: &PlotDef) {
+        let values = object.vendor.values();
+        let plottables = plot
+            .objects
+            .iter()
+            .filter_map(|name| values.iter().find(|value| value.get_name() == *name))
+            .flat_map(|value| {
+                (0..value.display_values().len()).filter_map(|position| {
+                    let index = value.instance_index(position).iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".");
+                    Plottable::instance(format!("{}.{}", value.get_name(), index), value, position)
+                })
+            })
+            .map(|plottable| plottable.history(&self.tsdb, &object.target, history_range(None)))
+            .collect::<Vec<Plottable>>();
+        self.plots.insert(plot.name.clone(), PlotContext::new(plottables));
+        self.to_open.push(plot.name.clone());
+    }
+
+    /// the pending edits of writable objects, sent to the shown target in one SetRequest once
+    /// confirmed, so the agent applies all of them or none
+    fn set_show(&mut self, ui: &mut Ui) {
+        let target = match self.shown_object() {
+            Some(object) => object.target.clone(),
+            None => return,
+        };
+        if let Some(outcome) = &self.last_set {
+            let color = match outcome.error {
+                None => egui::Color32::GREEN,
+                Some(_) => egui::Color32::RED,
+            };
+            ui.colored_label(color, format!("{} {}", format_timestamp(outcome.timestamp), outcome.summary()));
+        }
+        ui.horizontal(|ui| {
+            ui.add(egui::TextEdit::singleline(&mut self.edit_instance).hint_text("instance, e.g. ifAdminStatus.3"));
+            if ui.button("add").clicked() {
+                match Edit::find(&self.shown_object().unwrap(), &self.edit_instance) {
+                    Ok(edit) => {
+                        self.edits.retain(|a| a.oid() != edit.oid());
+                        self.edits.push(edit);
+                        self.edit_instance.clear();
+                    }
+                    Err(err) => self.status = err,
+                }
+            }
+        });
+        let mut remove = None;
+        egui::Grid::new("set_edits").striped(true).show(ui, |ui| {
+            ["object", "current", "new", ""].iter().for_each(|a| { ui.strong(*a); });
+            ui.end_row();
+            for (position, edit) in self.edits.iter_mut().enumerate() {
+                ui.label(edit.name());
+                ui.label(edit.old());
+                ui.horizontal(|ui| {
+                    ui.text_edit_singleline(&mut edit.input);
+                    if let Err(err) = edit.parse() {
+                        ui.colored_label(egui::Color32::RED, err);
+                    }
+                });
+                if ui.button("remove").clicked() {
+                    remove = Some(position);
+                }
+                ui.end_row();
+            }
+        });
+        if let Some(position) = remove {
+            self.edits.remove(position);
+        }
+        let valid = !self.edits.is_empty() && self.edits.iter().all(|a| a.parse().is_ok());
+        if ui.add_enabled(valid, egui::Button::new(format!("set on {}", target))).clicked() {
+            self.confirm_set = true;
+        }
+        if !self.confirm_set {
+            return;
+        }
+        let mut open = true;
+        Window::new("confirm SET").collapsible(false).resizable(false).open(&mut open).show(ui.ctx(), |ui| {
+            ui.label(format!("write to {}:", target));
+            egui::Grid::new("set_confirm").show(ui, |ui| {
+                for edit in &self.edits {
+                    ui.label(edit.name());
+                    ui.label(format!("{} → {}", edit.old(), edit.input.trim()));
+                    ui.end_row();
+                }
+            });
+            ui.horizontal(|ui| {
+                if ui.button("apply").clicked() {
+                    match SetRequest::new(&target, &self.edits) {
+                        Ok(request) => {
+                            self.set_sender.send(request).expect("error sending set request");
+                            self.status = format!("sending SET to {}", target);
+                            self.edits.clear();
+                        }
+                        Err(err) => self.status = err,
+                    }
+                    self.confirm_set = false;
+                }
+                if ui.button("cancel").clicked() {
+                    self.confirm_set = false;
+                }
+            });
+        });
+        self.confirm_set &= open;
+    }
+
+    /// the saved dashboards, each loaded, exported or deleted from its row; what is clicked is
+    /// left in `dashboard_action` for the app, which holds the dock layout
+    fn dashboards_show(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.add(egui::TextEdit::singleline(&mut self.dashboard_name).hint_text("dashboard name"));
+            if ui.add_enabled(!self.dashboard_name.trim().is_empty(), egui::Button::new("save current")).clicked() {
+                self.dashboard_action = Some(DashboardAction::Save(self.dashboard_name.trim().to_owned()));
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.add(egui::TextEdit::singleline(&mut self.dashboard_import).hint_text("path of an exported dashboard"));
+            if ui.add_enabled(!self.dashboard_import.trim().is_empty(), egui::Button::new("import")).clicked() {
+                self.dashboard_action = Some(DashboardAction::Import(self.dashboard_import.trim().to_owned()));
+            }
+        });
+        ui.separator();
+        egui::Grid::new("dashboards").striped(true).show(ui, |ui| {
+            ["name", "target", "tabs", "plots", "saved", "", "", ""].iter().for_each(|a| { ui.strong(*a); });
+            ui.end_row();
+            for dashboard in self.dashboards.dashboards.values() {
+                ui.label(&dashboard.name);
+                ui.label(&dashboard.target);
+                ui.label(dashboard.tabs().len().to_string());
+                ui.label(dashboard.plots.len().to_string());
+                ui.label(format_timestamp(dashboard.saved));
+                if ui.button("load").clicked() {
+                    self.dashboard_action = Some(DashboardAction::Load(dashboard.name.clone()));
+                }
+                if ui.button("export").clicked() {
+                    self.dashboard_action = Some(DashboardAction::Export(dashboard.name.clone()));
+                }
+                if ui.button("delete").clicked() {
+                    self.dashboard_action = Some(DashboardAction::Delete(dashboard.name.clone()));
+                }
+                ui.end_row();
+            }
+        });
+    }
+
+    /// any OID or name sent to the shown target with get, getnext, walk or bulkwalk, next to the
+    /// tree of the names known
+    fn browser_show(&mut self, ui: &mut Ui) {
+        let target = match self.shown_object() {
+            Some(object) => object.target.clone(),
+            None => return,
+        };
+        ui.horizontal(|ui| {
+            let enter = ui.add(egui::TextEdit::singleline(&mut self.browser.input).hint_text("OID or name, e.g. ifDescr or 1.3.6.1.4.1.9")).lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            egui::ComboBox::from_id_source("browser_operation").selected_text(self.browser.operation.label()).show_ui(ui, |ui| {
+                for operation in Operation::ALL {
+                    ui.selectable_value(&mut self.browser.operation, operation, operation.label());
+                }
+            });
+            if ui.button("run").clicked() || enter {
+                match self.browser.request(&self.mib_tree, &target) {
+                    Ok(request) => {
+                        self.browser.status = format!("{} {} on {}...", request.operation.label(), self.mib_tree.name(request.oid.as_slice()), target);
+                        self.browse_sender.send(request).expect("error sending browse request");
+                    }
+                    Err(err) => self.browser.status = err,
+                }
+            }
+            ui.label(&self.browser.status);
+        });
+        egui::SidePanel::left("browser_tree").resizable(true).show_inside(ui, |ui| {
+            egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+                oid_tree_show(ui, &self.mib_tree, &[], &mut self.browser.input);
+            });
+        });
+        let mut pin = None;
+        let mut sort = None;
+        TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .column(Column::auto())
+            .column(Column::auto())
+            .column(Column::auto())
+            .column(Column::auto())
+            .column(Column::remainder())
+            .column(Column::auto())
+            .header(20.0, |mut header| {
+                for (label, by) in [("OID", Some(SortBy::Oid)), ("name", Some(SortBy::Name)), ("type", Some(SortBy::Type)), ("raw", None), ("value", Some(SortBy::Value))] {
+                    header.col(|ui| match by {
+                        Some(by) => {
+                            let arrow = match (self.browser.sort_by == by, self.browser.ascending) {
+                                (true, true) => " ⏶",
+                                (true, false) => " ⏷",
+                                _ => "",
+                            };
+                            if ui.button(format!("{}{}", label, arrow)).clicked() {
+                                sort = Some(by);
+                            }
+                        }
+                        None => { ui.strong(label); }
+                    });
+                }
+                header.col(|_| {});
+            })
+            .body(|body| {
+                body.rows(18.0, self.browser.rows.len(), |row_index, mut row| {
+                    let binding = &self.browser.rows[row_index];
+                    row.col(|ui| { ui.monospace(BrowserModule::dotted(&binding.oid)); });
+                    row.col(|ui| { ui.label(&binding.name); });
+                    row.col(|ui| { ui.label(binding.kind); });
+                    row.col(|ui| { ui.monospace(&binding.raw); });
+                    row.col(|ui| { ui.label(&binding.value); });
+                    row.col(|ui| {
+                        if binding.number.is_some() {
+                            ui.menu_button("plot", |ui| {
+                                for name in self.plots.keys() {
+                                    if ui.button(name).clicked() {
+                                        pin = Some((binding.clone(), name.clone()));
+                                        ui.close_menu();
+                                    }
+                                }
+                                if ui.button("new plot").clicked() {
+                                    pin = Some((binding.clone(), binding.name.clone()));
+                                    ui.close_menu();
+                                }
+                            });
+                        }
+                    });
+                });
+            });
+        if let Some(by) = sort {
+            self.browser.sort(by);
+        }
+        if let Some((binding, plot)) = pin {
+            self.browser_pin(&target, &binding, &plot);
+        }
+    }
+
+    /// plots the table cell picked from its context menu, keyed by column and instance index so it
+    /// follows its row wherever the agent puts it
+    fn plot_cell(&mut self, cell: PlotCell) {
+        let plottable = match Plottable::instance(cell.name.clone(), &cell.column, cell.position) {
+            Some(plottable) => plottable.history(&self.tsdb, &self.shown_target, history_range(self.plots.get(&cell.plot))),
+            None => {
+                self.status = format!("{} cannot be plotted, an arc of its index is above 65535", cell.name);
+                return;
+            }
+        };
+        match self.plots.get_mut(&cell.plot) {
+            Some(plotcontext) => plotcontext.plottables.push(plottable),
+            None => {
+                self.plots.insert(cell.plot.clone(), PlotContext::new(vec![plottable]));
+            }
+        }
+        self.to_open.push(cell.plot);
+    }
+
+    /// plots a browsed leaf in `plot`, from the polled value when the target is polled for it and
+    /// otherwise polling it from now on
+    fn browser_pin(&mut self, target: &str, binding: &Varbind, plot: &str) {
+        let object = match self.shown_object() {
+            Some(object) => object,
+            None => return,
+        };
+        let polled = object.values().into_iter().find_map(|value| {
+            let column = value.get_oid().iter().map(|a| *a as u32).collect::<Vec<u32>>();
+            let suffix = binding.oid.strip_prefix(&column[..])?;
+            let position = value.index().iter().position(|a| a == suffix)?;
+            Plottable::instance(binding.name.clone(), value, position)
+        });
+        let plottable = match polled {
+            Some(plottable) => plottable,
+            None => match (binding.pinned(&self.mib_tree), binding.oid.iter().map(|a| u16::try_from(*a).ok()).collect::<Option<Vec<u16>>>()) {
+                (Some(value), Some(oid)) => {
+                    self.pin_sender.send((target.to_owned(), value)).expect("error sending pinned value");
+                    Plottable { name: binding.name.clone(), oid, key: BrowserModule::dotted(&binding.oid), points: vec![], points_max: (0, 0), percent_of: None, unit: String::new(), side: Side::Left }
+                }
+                _ => {
+                    self.browser.status = format!("{} cannot be polled, an arc is above 65535", binding.name);
+                    return;
+                }
+            },
+        };
+        let plottable = plottable.history(&self.tsdb, target, history_range(self.plots.get(plot)));
+        match self.plots.get_mut(plot) {
+            Some(plotcontext) => plotcontext.plottables.push(plottable),
+            None => {
+                self.plots.insert(plot.to_owned(), PlotContext::new(vec![plottable]));
+            }
+        }
+        self.to_open.push(plot.to_owned());
+    }
+
+    fn diff_show(&mut self, ui: &mut Ui) {
+        let (then, now) = match (self.replay.as_ref().and_then(|replay| replay.current()), self.object.as_ref()) {
+            (Some(then), Some(now)) => (then, now),
+            _ => {
+                ui.label("pick a snapshot in history mode to compare against now");
+                return;
+            }
+        };
+        let diff = ReplayModule::diff(then, now);
+        ui.label(format!("{} against now ({})", format_timestamp(then.timestamp), format_timestamp(now.timestamp)));
+        egui::ScrollArea::vertical().auto_shrink(false).show(ui, |ui| {
+            ui.collapsing(format!("interfaces ({} appeared, {} disappeared)", diff.interfaces_added.len(), diff.interfaces_removed.len()), |ui| {
+                diff.interfaces_added.iter().for_each(|a| { ui.colored_label(egui::Color32::GREEN, format!("+ {}", a)); });
+                diff.interfaces_removed.iter().for_each(|a| { ui.colored_label(egui::Color32::RED, format!("- {}", a)); });
+            });
+            ui.collapsing(format!("routes ({} changed)", diff.routes.len()), |ui| {
+                change_grid(ui, "route_diff", &diff.routes);
+            });
+            ui.collapsing(format!("values ({} changed)", diff.values.len()), |ui| {
+                change_grid(ui, "value_diff", &diff.values);
+            });
+        });
+    }
+    /// the series of a plot with their unit and axis, and a row adding a series computed from
+    /// an expression over polled values
+    fn series_show(&mut self, ui: &mut Ui, tab: &str, plotcontext: &mut PlotContext) {
+        egui::CollapsingHeader::new(format!("series ({})", plotcontext.plottables.len() + plotcontext.derived.len())).id_source(("series", tab)).show(ui, |ui| {
+            let mut remove = None;
+            egui::Grid::new(("series grid", tab)).striped(true).show(ui, |ui| {
+                ["name", "reads", "unit", "axis", ""].iter().for_each(|a| { ui.strong(*a); });
+                ui.end_row();
+                let rows = plotcontext.plottables
+                    .iter_mut()
+                    .map(|a| (&a.name, a.key.clone(), &mut a.unit, &mut a.side))
+                    .chain(plotcontext.derived.iter_mut().map(|a| (&a.name, a.text.clone(), &mut a.unit, &mut a.side)));
+                for (position, (name, reads, unit, side)) in rows.enumerate() {
+                    ui.label(name);
+                    ui.label(reads);
+                    ui.add(egui::TextEdit::singleline(unit).desired_width(60.0));
+                    egui::ComboBox::from_id_source(("side", tab, position)).selected_text(side.label()).show_ui(ui, |ui| {
+                        Side::ALL.iter().for_each(|a| { ui.selectable_value(side, *a, a.label()); });
+                    });
+                    if ui.button("remove").clicked() {
+                        remove = Some(position);
+                    }
+                    ui.end_row();
+                }
+            });
+            match remove {
+                Some(position) if position < plotcontext.plottables.len() => { plotcontext.plottables.remove(position); }
+                Some(position) => { plotcontext.derived.remove(position - plotcontext.plottables.len()); }
+                None => {}
+            }
+            ui.horizontal(|ui| {
+                let (name, expr, unit) = &mut plotcontext.new_series;
+                ui.add(egui::TextEdit::singleline(name).hint_text("name").desired_width(100.0));
+                ui.add(egui::TextEdit::singleline(expr).hint_text("rate(ifInOctets[3]+ifOutOctets[3])*8/ifSpeed[3]*100").desired_width(320.0));
+                ui.add(egui::TextEdit::singleline(unit).hint_text("unit").desired_width(60.0));
+                if ui.add_enabled(!expr.trim().is_empty(), egui::Button::new("add")).clicked() {
+                    let names = self.object.as_ref().map(|object| object.samples().into_iter().map(|a| a.name).collect::<Vec<String>>()).unwrap_or_default();
+                    let derived = Derived::new(name, expr, unit, Side::Left).and_then(|derived| derived.check(&names).map(|_| derived));
+                    match derived {
+                        Ok(derived) => {
+                            plotcontext.derived.push(derived.history(&self.tsdb, &self.shown_target, history_range(Some(plotcontext)), PLOT_POINTS));
+                            plotcontext.new_series = Default::default();
+                        }
+                        Err(err) => self.status = format!("could not add {}: {}", expr.trim(), err),
+                    }
+                }
+            });
+        });
+    }
+
+    /// plots every series against time over the chosen window, following the latest polls
+    /// until the plot is dragged or zoomed
+    fn plot(&mut self, ui: &mut Ui, tab: &str) {
+        // lent out of `plots` while it is drawn, as showing the series needs the rest of self
+        let mut plotcontext = match self.plots.remove(tab) {
+            Some(plotcontext) => plotcontext,
+            None => return,
+        };
+        let now = Utc::now().timestamp();
+        let mut apply = plotcontext.follow;
+        let mut reload = false;
+        ui.horizontal(|ui| {
+            for window in TimeWindow::ALL {
+                if ui.selectable_label(plotcontext.window == window, window.label()).clicked() {
+                    reload = plotcontext.window != window;
+                    plotcontext.window = window;
+                    plotcontext.follow = window != TimeWindow::Custom;
+                    apply = true;
+                }
+            }
+            match plotcontext.window {
+                TimeWindow::Custom => {
+                    ui.add(egui::TextEdit::singleline(&mut plotcontext.from).hint_text("from 2024-01-31 14:00").desired_width(140.0));
+                    ui.add(egui::TextEdit::singleline(&mut plotcontext.to).hint_text("to 2024-01-31 18:00").desired_width(140.0));
+                    let show = ui.button("show").clicked();
+                    apply |= show;
+                    reload |= show;
+                }
+                _ => apply |= ui.toggle_value(&mut plotcontext.follow, "follow").clicked() && plotcontext.follow,
+            }
+        });
+        if reload {
+            plotcontext.reload(&self.tsdb, &self.shown_target);
+        }
+        let range = plotcontext.range(now);
+        self.series_show(ui, tab, &mut plotcontext);
+        // only the points in sight are drawn: the window about to be applied, or else what was
+        // drawn in the last frame
+        let (from, to) = range.filter(|_| apply).or(plotcontext.shown).or(range).unwrap_or((i64::MIN, i64::MAX));
+        // every series as drawn, with its unit and axis
+        let mut lines = plotcontext.plottables
+            .iter()
+            .map(|a| (a.name.clone(), a.unit.clone(), a.side, TimeAxisModule::clip(&a.points, from, to).iter().map(|(t, value)| (*t, *value as f64)).collect::<Vec<(i64, f64)>>()))
+            .chain(plotcontext.derived.iter().map(|a| (a.name.clone(), a.unit.clone(), a.side, TimeAxisModule::clip(&a.points, from, to).to_vec())))
+            .collect::<Vec<(String, String, Side, Vec<(i64, f64)>)>>();
+        if plotcontext.style == PlotStyle::Stacked {
+            for side in Side::ALL {
+                let positions = (0..lines.len()).filter(|a| lines[*a].2 == side).collect::<Vec<usize>>();
+                let stacked = SeriesModule::stack(&positions.iter().map(|a| lines[*a].3.clone()).collect::<Vec<Vec<(i64, f64)>>>());
+                positions.into_iter().zip(stacked).for_each(|(position, points)| lines[position].3 = points);
+            }
+        }
+        let values = |side: Side| lines.iter().filter(|a| a.2 == side).flat_map(|a| a.3.iter().map(|b| b.1)).collect::<Vec<f64>>();
+        let scale = Scale::new(&values(Side::Left), &values(Side::Right));
+        let place = move |side: Side, value: f64| match side {
+            Side::Left => value,
+            Side::Right => scale.apply(value),
+        };
+        let axes = Side::ALL
+            .into_iter()
+            .filter(|side| *side == Side::Left || lines.iter().any(|a| a.2 == *side))
+            .map(|side| {
+                let units = lines.iter().filter(|a| a.2 == side && !a.1.is_empty()).map(|a| a.1.as_str()).collect::<BTreeSet<&str>>();
+                let hints = AxisHints::default()
+                    .label(units.into_iter().collect::<Vec<&str>>().join(", "))
+                    .formatter(move |y, _, _| SeriesModule::format_value(if side == Side::Right { scale.invert(y) } else { y }, ""));
+                match side {
+                    Side::Left => hints,
+                    Side::Right => hints.placement(HPlacement::Right),
+                }
+            })
+            .collect::<Vec<AxisHints>>();
+        let units = lines.iter().map(|a| (a.0.clone(), (a.1.clone(), a.2))).collect::<HashMap<String, (String, Side)>>();
+        let shown = Plot::new(tab).legend(Legend::default())
                                 .allow_drag(plotcontext.draggable)
                                 .show_grid(plotcontext.grid)
                                 .clamp_grid(!plotcontext.grid)
@@ -173,15 +1526,52 @@ impl MyContext {
                                 .allow_boxed_zoom(false)
                                 .auto_bounds_x()
                                 .auto_bounds_y()
-                                // .show_axes(false)
-                                .show(ui, |plot_ui| { 
-                                    plotcontext.plottables.clone()
-                                            .into_iter()
-                                            .enumerate()
-                                            .for_each(|a| {
-                                                plot_ui.line(Line::new(a.1.points.into_iter().enumerate().map(|b| [(b.0 as f64 as f64), (b.1.1.clone() as f64 as f64) as f64] ).collect::<PlotPoints>()).name(plotcontext.plottables.get(a.0).unwrap().clone().name))
-                                            })
-                                }).response.context_menu(|ui| {
+                                .x_axis_formatter(|x, _, range| TimeAxisModule::label(x, range.end() - range.start()))
+                                .x_grid_spacer(TimeAxisModule::grid)
+                                .custom_y_axes(axes)
+                                .label_formatter(move |name, point| {
+                                    let (unit, side) = units.get(name).cloned().unwrap_or_default();
+                                    let value = if side == Side::Right { scale.invert(point.y) } else { point.y };
+                                    let value = format!("{} {}", (value * 1000.0).round() / 1000.0, unit);
+                                    format!("{}\n{}\n{}", name, format_timestamp(point.x.round() as i64), value.trim_end())
+                                })
+                                .show(ui, |plot_ui| {
+                                    if let Some((from, to)) = range.filter(|_| apply) {
+                                        // areas are filled down to zero, so it stays in sight
+                                        let start = match plotcontext.style {
+                                            PlotStyle::Lines => (f64::MAX, f64::MIN),
+                                            PlotStyle::Area | PlotStyle::Stacked => (place(Side::Left, 0.0), place(Side::Left, 0.0)),
+                                        };
+                                        let (min, max) = lines
+                                            .iter()
+                                            .flat_map(|(_, _, side, points)| points.iter().filter(|point| (from..=to).contains(&point.0)).map(move |point| place(*side, point.1)))
+                                            .fold(start, |(min, max), y| (min.min(y), max.max(y)));
+                                        let (min, max) = match min <= max {
+                                            true => (min - (max - min).max(1.0) * 0.05, max + (max - min).max(1.0) * 0.05),
+                                            false => (0.0, 1.0),
+                                        };
+                                        plot_ui.set_plot_bounds(PlotBounds::from_min_max([from as f64, min], [to as f64, max]));
+                                    }
+                                    for (position, (name, _, side, points)) in lines.iter().enumerate() {
+                                        // every run of a series in the colour egui would give the series
+                                        let color = egui::ecolor::Hsva::new((position as f32 * 0.618034).fract(), 0.85, 0.5, 1.0);
+                                        for segment in TimeAxisModule::segments(points) {
+                                            let segment = segment.into_iter().map(|[x, y]| [x, place(*side, y)]).collect::<Vec<[f64; 2]>>();
+                                            let line = Line::new(PlotPoints::from(segment)).name(name).color(color);
+                                            plot_ui.line(match plotcontext.style {
+                                                PlotStyle::Lines => line,
+                                                PlotStyle::Area | PlotStyle::Stacked => line.fill(place(*side, 0.0) as f32),
+                                            });
+                                        }
+                                    }
+                                });
+        let bounds = shown.transform.bounds();
+        plotcontext.shown = Some((bounds.min()[0].floor() as i64, bounds.max()[0].ceil() as i64));
+        let response = shown.response;
+        if response.dragged() || (response.hovered() && ui.input(|i| i.scroll_delta != egui::Vec2::ZERO || i.zoom_delta() != 1.0)) {
+            plotcontext.follow = false;
+        }
+        response.context_menu(|ui| {
                                     if ui.checkbox(&mut plotcontext.draggable, "is draggable").changed() ||
                                     ui.checkbox(&mut plotcontext.axes, "show axes").changed() ||
                                     ui.checkbox(&mut plotcontext.scroll, "is scrollable").changed() ||
@@ -190,17 +1580,173 @@ impl MyContext {
                                     ui.checkbox(&mut plotcontext.grid, "show grid").changed() {
                                         ui.close_menu();
                                     }
+                                    ui.separator();
+                                    for style in PlotStyle::ALL {
+                                        if ui.radio_value(&mut plotcontext.style, style, style.label()).clicked() {
+                                            ui.close_menu();
+                                        }
+                                    }
                                 });
+        self.plots.insert(tab.to_owned(), plotcontext);
+    }
+}
+
+/// the named arcs under `oid`, a click on one putting its OID in `input`
+fn oid_tree_show(ui: &mut Ui, tree: &MibTree, oid: &[u32], input: &mut String) {
+    for (arc, name) in tree.children(oid) {
+        let child = [oid, &[arc]].concat();
+        let label = format!("{}({})", name, arc);
+        let clicked = match tree.children(&child).is_empty() {
+            true => ui.selectable_label(false, label).clicked(),
+            false => egui::CollapsingHeader::new(label).id_source(&child).show(ui, |ui| oid_tree_show(ui, tree, &child, input)).header_response.clicked(),
+        };
+        if clicked {
+            *input = tree.name(&child);
+        }
+    }
+}
+
+fn severity_color(severity: Severity) -> egui::Color32 {
+    match severity {
+        Severity::Critical => egui::Color32::RED,
+        Severity::Warning => egui::Color32::YELLOW,
+        Severity::Info => egui::Color32::LIGHT_BLUE,
     }
 }
 
+fn change_grid(ui: &mut Ui, id: &str, changes: &[ReplayModule::Change]) {
+    egui::Grid::new(id).striped(true).show(ui, |ui| {
+        ui.strong("name");
+        ui.strong("then");
+        ui.strong("now");
+        ui.end_row();
+        changes.iter().for_each(|change| {
+            let color = match (change.then.is_empty(), change.now.is_empty()) {
+                (true, _) => egui::Color32::GREEN,
+                (_, true) => egui::Color32::RED,
+                _ => egui::Color32::YELLOW,
+            };
+            ui.label(&change.name);
+            ui.colored_label(color, &change.then);
+            ui.colored_label(color, &change.now);
+            ui.end_row();
+        });
+    });
+}
+
+/// one entity of the hardware tree, collapsible when it contains others
+fn component_show(ui: &mut Ui, component: &Component) {
+    let mut title = format!("{} {}", component.class, component.label());
+    if !component.model.is_empty() {
+        title.push_str(&format!(" ({})", component.model));
+    }
+    if component.fru {
+        title.push_str(" [FRU]");
+    }
+    let details = |ui: &mut Ui| {
+        egui::Grid::new(["entity", component.index.as_str()].concat()).show(ui, |ui| {
+            for (name, value) in [
+                ("index", &component.index),
+                ("description", &component.descr),
+                ("vendor", &component.vendor),
+                ("serial", &component.serial),
+                ("hardware", &component.hardware_rev),
+                ("firmware", &component.firmware_rev),
+                ("software", &component.software_rev),
+            ] {
+                if !value.is_empty() {
+                    ui.label(name);
+                    ui.label(value);
+                    ui.end_row();
+                }
+            }
+        });
+    };
+    match component.children.is_empty() {
+        true => {
+            ui.label(title).on_hover_ui(details);
+        }
+        false => {
+            egui::CollapsingHeader::new(title).id_source(["entity", component.index.as_str()].concat()).default_open(true).show(ui, |ui| {
+                details(ui);
+                for child in &component.children {
+                    component_show(ui, child);
+                }
+            });
+        }
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    match chrono::Local.timestamp_opt(timestamp, 0).single() {
+        Some(time) => time.format("%Y-%m-%d %T").to_string(),
+        None => timestamp.to_string(),
+    }
+}
+
+/// a poll walked on its own task, handed back to the poll loop to be stored
+struct Walked {
+    address: String,
+    sys_object_id: Option<String>,
+    object: MibObject,
+}
+
+/// a client of its own for one SET or browse request, so that it neither waits for the polls
+/// nor reads the answers meant for them
+async fn request_client(target: Option<TargetConfig>) -> Result<Snmp2cClient, String> {
+    let target = target.ok_or("is not polled")?;
+    let address = target.socket_addr().ok_or("is not an address")?;
+    Snmp2cClient::new(address, target.community.as_bytes().to_vec(), Some("0.0.0.0:0".parse().unwrap()), Some(POLL_TIMEOUT))
+        .await
+        .map_err(|err| format!("could not be reached: {}", err))
+}
+
 #[tokio::main]
 async fn main() {    
     println!("start");
+    let args = std::env::args().collect::<Vec<String>>();
+    if args.get(1).is_some_and(|a| a == "export") {
+        exit(CsvExportModule::cli(&args[2..], &Config::load(CONFIG_PATH)));
+    }
     let (mib_obj_sender, mib_obj_reciever): (Sender<MibObject>, Receiver<MibObject>) = std::sync::mpsc::channel();
     let (target_sender, target_reciever): (Sender<(SocketAddr, String)>, Receiver<(SocketAddr, String)>) = std::sync::mpsc::channel();
+    let (set_sender, set_reciever): (Sender<SetRequest>, Receiver<SetRequest>) = std::sync::mpsc::channel();
+    let (set_outcome_sender, set_outcomes): (Sender<SetOutcome>, Receiver<SetOutcome>) = std::sync::mpsc::channel();
+    let (browse_sender, browse_reciever): (Sender<BrowseRequest>, Receiver<BrowseRequest>) = std::sync::mpsc::channel();
+    let (browse_result_sender, browse_results): (Sender<BrowseResult>, Receiver<BrowseResult>) = std::sync::mpsc::channel();
+    let (pin_sender, pin_reciever): (Sender<(String, MibValue)>, Receiver<(String, MibValue)>) = std::sync::mpsc::channel();
 
-    fs::create_dir_all("/logs").expect("could not create directory");
+    let mut config = Config::load(CONFIG_PATH);
+    let vendors = VendorModule::load(&config.vendors.dir);
+    config.alerts.rules.extend(vendors.iter().flat_map(|profile| profile.alerts.iter().map(|rule| AlertRule { profile: profile.name.clone(), ..rule.clone() })));
+    let poll_vendors = Arc::new(vendors.clone());
+    let mib_tree = MibTree::new(&vendors, &config.mibs.dir);
+    let mut mib_log = MibLog::new(config.log.clone()).expect("could not create log directory");
+    let tsdb = Arc::new(Mutex::new(Tsdb::open(config.storage.clone()).expect("could not open time-series store")));
+    let poll_tsdb = tsdb.clone();
+    let log_config = config.log.clone();
+    let alerts = Arc::new(Mutex::new(AlertEngine::new(config.alerts.clone())));
+    let poll_alerts = alerts.clone();
+    let mut notifier = Notifier::new(config.notify.clone(), &config.alerts.rules);
+    let latest: Latest = Arc::new(Mutex::new(BTreeMap::new()));
+    let poll_latest = latest.clone();
+    let topology_graph: Topology = Arc::new(Mutex::new(Arc::default()));
+    let poll_topology = topology_graph.clone();
+    let links: Links = Arc::new(Mutex::new(LinkTracker::default()));
+    let poll_links = links.clone();
+    let links_graph: Topology = Arc::new(Mutex::new(Arc::default()));
+    let poll_links_graph = links_graph.clone();
+    let inventory: Inventory = Arc::new(Mutex::new(InventoryTracker::new(config.inventory.clone())));
+    let poll_inventory = inventory.clone();
+    let targets: Targets = Arc::new(Mutex::new(TargetList::load(&config)));
+    let poll_targets = targets.clone();
+    let discover = match config.discovery.ranges.is_empty() {
+        true => None,
+        false => Some(Arc::new(tokio::sync::Notify::new())),
+    };
+    let server = Server::new(latest.clone(), tsdb.clone());
+    let polls = server.polls();
+    let exports = ExportModule::spawn(config.exports.iter().cloned().map(Exporter::new).collect());
 
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default().with_title("SNMP Monitor".to_string()).with_app_id("SNMP_Monitor").with_min_inner_size([854.0,480.0]).with_maximized(false),
@@ -231,8 +1777,44 @@ async fn main() {
         style: None,
         open_tabs,
         object: None,
+        previous: None,
+        replay: None,
+        alerts,
         plots: HashMap::new(),
         new_plot_name: "".to_owned(),
+        csv_dir: config.csv.dir.clone(),
+        targets: targets.clone(),
+        latest,
+        shown_target: String::new(),
+        discover: discover.clone(),
+        topology: TopologyView::default(),
+        topology_graph,
+        links,
+        links_graph,
+        links_view: TopologyView::default(),
+        inventory,
+        mac_query: String::new(),
+        mac_found: None,
+        if_row: None,
+        to_open: vec![],
+        tsdb: tsdb.clone(),
+        vendors,
+        edits: vec![],
+        edit_instance: String::new(),
+        confirm_set: false,
+        set_sender,
+        set_outcomes,
+        last_set: None,
+        mib_tree,
+        browser: Browser::default(),
+        browse_sender,
+        browse_results,
+        pin_sender,
+        dashboards: Dashboards::load(&config.dashboards.file),
+        dashboard_name: String::new(),
+        dashboard_import: String::new(),
+        dashboard_action: None,
+        status: String::new(),
 
         show_window_close: true,
         show_window_collapse: true,
@@ -243,16 +1825,26 @@ async fn main() {
         allowed_splits: AllowedSplits::default(),
     };
 
-    let app: AppCreator = Box::new(|_| Box::new(SnmpMonitorApp { 
-        name: "SNMP_Monitor".to_owned(), 
-        target_ip: "127.0.0.1".to_owned(), 
-        community: "public".to_owned(), 
-        mib_obj_reciever: mib_obj_reciever, 
-        target_sender: target_sender,
-        context: context,
-        new_plot_window_manager: NewPlotWindowManager { open: false, show: false, value_to_add: None },
-        tabs_tree: dock_state,
-    }));
+    let dashboard_config = config.dashboards.clone();
+    let app: AppCreator = Box::new(|_| {
+        let mut app = SnmpMonitorApp { 
+            name: "SNMP_Monitor".to_owned(), 
+            target_ip: "127.0.0.1".to_owned(), 
+            community: "public".to_owned(), 
+            mib_obj_reciever: mib_obj_reciever, 
+            target_sender: target_sender,
+            context: context,
+            new_plot_window_manager: NewPlotWindowManager { open: false, show: false, value_to_add: None },
+            tabs_tree: dock_state,
+            tsdb,
+            log_config,
+            dashboard_config,
+        };
+        if let Some(dashboard) = app.context.dashboards.dashboards.get(AUTOSAVE).cloned() {
+            app.restore(&dashboard);
+        }
+        Box::new(app)
+    });
 
     println!("create task");
 
@@ -267,80 +1859,222 @@ async fn main() {
 
     println!("run task");
 
+    if !config.http.listen.is_empty() {
+        let listen = config.http.listen.clone();
+        runtime.spawn(async move {
+            if let Err(err) = server.serve(listen.clone()).await {
+                println!("http server on {} stopped: {}", listen, err);
+            }
+        });
+    }
+
+    if let Some(discover) = discover {
+        let discovery = config.discovery.clone();
+        runtime.spawn(async move {
+            loop {
+                let responders = DiscoveryModule::sweep(&discovery).await;
+                {
+                    let mut list = targets.lock().unwrap();
+                    let added = responders.iter().filter(|responder| list.add(responder.target(&discovery.rules))).count();
+                    list.discovery_status = format!("{} devices answered, {} new, last sweep {}", responders.len(), added, format_timestamp(Utc::now().timestamp()));
+                    println!("discovery: {}", list.discovery_status);
+                }
+                match discovery.interval_secs {
+                    0 => discover.notified().await,
+                    secs => {
+                        tokio::select! {
+                            _ = discover.notified() => {}
+                            _ = time::sleep(Duration::from_secs(secs)) => {}
+                        }
+                    }
+                }
+            }
+        });
+    }
+
     runtime.spawn(async move {
         println!("inside task");
-        let mut interval = time::interval(Duration::from_secs(30));
-
-        let mut target_ip = IpAddr::from_str("127.0.0.1").unwrap();
-        let mut community = "public".to_owned();
+        let mut interval = time::interval(Duration::from_secs(1));
+        let mut clients: HashMap<String, Arc<Snmp2cClient>> = HashMap::new();
+        let mut sys_object_ids: HashMap<String, String> = HashMap::new();
+        let mut pinned: HashMap<String, Vec<MibValue>> = HashMap::new();
+        // targets whose poll has not come back yet, skipped when they are due again
+        let mut polling: HashSet<String> = HashSet::new();
+        let (walked_sender, walked_reciever): (Sender<Walked>, Receiver<Walked>) = std::sync::mpsc::channel();
 
         // let currtime: DateTime<Local> = std::time::SystemTime::now().into();
         // let date = format!("{}", currtime.format("%Y_%m_%d %T"));
 
         // let mut writer = csv::WriterBuilder::new().from_path("monitor_log.csv").unwrap();
 
-        let sock_addr = SocketAddr::from((target_ip.to_owned(), 161));
-
-        let client_res = Snmp2cClient::new(
-            sock_addr,
-            community.as_bytes().to_vec().clone(),
-            Some("0.0.0.0:0".parse().unwrap()),
-            None,
-        ).await;
-        let mut client = client_res.expect("failed to create SNMP client");
         println!("start loop");
 
         'monitor_loop: loop {
-            println!("loop repeat");
             interval.tick().await;
             
-            match target_reciever.try_recv() {
-                Ok(target) => {
-                    println!("recieved target");
-                    let client_res = Snmp2cClient::new(
-                        target.0,
-                        target.1.as_bytes().to_vec().clone(),
-                        Some("0.0.0.0:0".parse().unwrap()),
-                        None,
-                    ).await;
-                    client = client_res.expect("failed to create SNMP client");
-                    println!("target is now {:?}", target);
-                },
-                Err(_) => {},
-            };
+            while let Ok(target) = target_reciever.try_recv() {
+                println!("recieved target");
+                let address = match target.0.port() {
+                    161 => target.0.ip().to_string(),
+                    _ => target.0.to_string(),
+                };
+                if poll_targets.lock().unwrap().add(TargetConfig::new(&address, &target.1)) {
+                    println!("now also polling {:?}", target);
+                }
+            }
 
-            let mut object = MibObject::new();
+            while let Ok(request) = set_reciever.try_recv() {
+                let target = poll_targets.lock().unwrap().targets().iter().find(|a| a.socket_addr().is_some_and(|address| address.ip().to_string() == request.target)).cloned();
+                let audit = config.audit.log.clone();
+                let set_outcome_sender = set_outcome_sender.clone();
+                task::spawn(async move {
+                    let outcome = match request_client(target).await {
+                        Ok(client) => SetModule::apply(&client, &request).await,
+                        Err(err) => SetOutcome::new(&request, Some(format!("{} {}", request.target, err))),
+                    };
+                    println!("{}", outcome.summary());
+                    if let Err(err) = SetModule::audit(&audit, &outcome) {
+                        println!("could not write the audit log: {}", err);
+                    }
+                    let _ = set_outcome_sender.send(outcome);
+                });
+            }
 
-            println!("sending snmp requests to {:?}", &client.target());
+            while let Ok(request) = browse_reciever.try_recv() {
+                let target = poll_targets.lock().unwrap().targets().iter().find(|a| a.socket_addr().is_some_and(|address| address.ip().to_string() == request.target)).cloned();
+                let browse_result_sender = browse_result_sender.clone();
+                task::spawn(async move {
+                    let result = match request_client(target).await {
+                        Ok(client) => BrowserModule::run(&client, request).await,
+                        Err(err) => BrowseResult { result: Err(format!("{} {}", request.target, err)), request },
+                    };
+                    let _ = browse_result_sender.send(result);
+                });
+            }
 
-            object.walk(&client).await;
-            
-            println!("got snmp responses");
+            while let Ok((target, value)) = pin_reciever.try_recv() {
+                let values = pinned.entry(target).or_default();
+                if !values.iter().any(|a| a.get_oid() == value.get_oid()) {
+                    values.push(value);
+                }
+            }
+
+            let due = poll_targets.lock().unwrap().due(Utc::now().timestamp());
+            for target in due {
+                if polling.contains(&target.address) {
+                    println!("{} is still being polled, skipping this poll", target.address);
+                    continue;
+                }
+                if !clients.contains_key(&target.address) {
+                    let address = match target.socket_addr() {
+                        Some(address) => address,
+                        None => {
+                            println!("cannot poll {}, not an address", target.address);
+                            continue;
+                        }
+                    };
+                    match Snmp2cClient::new(address, target.community.as_bytes().to_vec(), Some("0.0.0.0:0".parse().unwrap()), Some(POLL_TIMEOUT)).await {
+                        Ok(client) => clients.insert(target.address.clone(), Arc::new(client)),
+                        Err(err) => {
+                            println!("failed to create SNMP client for {}: {}", target.address, err);
+                            continue;
+                        }
+                    };
+                }
+                let client = clients[&target.address].clone();
+                let sys_object_id = match target.sys_object_id.is_empty() {
+                    true => sys_object_ids.get(&target.address).cloned(),
+                    false => Some(target.sys_object_id.clone()),
+                };
+                let pinned = pinned.get(&client.target().ip().to_string()).cloned().unwrap_or_default();
+                let vendors = poll_vendors.clone();
+                let walked_sender = walked_sender.clone();
+                polling.insert(target.address.clone());
+                task::spawn(async move {
+                    let sys_object_id = match sys_object_id {
+                        Some(sys_object_id) => Some(sys_object_id),
+                        None => VendorModule::sys_object_id(&client).await,
+                    };
+                    let mut object = MibObject::new();
+                    if let Some(profile) = sys_object_id.as_ref().and_then(|a| VendorModule::matching(&vendors, a)) {
+                        object.vendor = profile.vendor();
+                    }
+                    object.pinned = pinned;
+
+                    println!("sending snmp requests to {:?}", &client.target());
+
+                    if !object.walk(&client).await {
+                        println!("{:?} timed out, not walking the rest of it", client.target());
+                    }
+                    object.target = client.target().ip().to_string();
+
+                    println!("got snmp responses");
+
+                    let _ = walked_sender.send(Walked { address: target.address, sys_object_id, object });
+                });
+            }
+
+            let mut walked = false;
+            while let Ok(Walked { address, sys_object_id, object }) = walked_reciever.try_recv() {
+                walked = true;
+                polling.remove(&address);
+                if let Some(sys_object_id) = sys_object_id {
+                    sys_object_ids.insert(address, sys_object_id);
+                }
+
+                if let Err(err) = poll_tsdb.lock().unwrap().append(&object.target, object.timestamp, &object.samples()) {
+                    println!("could not store samples for {}: {}", object.target, err);
+                }
             
-            println!("object size: {}", std::mem::size_of_val(&object));
+                if !config.exports.is_empty() && exports.try_send(Arc::new(object.clone())).is_err() {
+                    println!("exporters are behind, not exporting the poll of {}", object.target);
+                }
 
-            let log_file_name = format!("logs/MIB-log-{}.log", str::replace(&client.target().ip().to_string(), ".", "-"));
+                let events = poll_alerts.lock().unwrap().evaluate(&object);
+                notifier.dispatch(&events);
 
-            println!("{}", log_file_name);
+                poll_latest.lock().unwrap().insert(object.target.clone(), object.clone());
+                let changes = poll_links.lock().unwrap().update(&object, &poll_latest.lock().unwrap().values().collect::<Vec<&MibObject>>());
+                changes.iter().for_each(|change| println!("{}", change));
+                let changes = poll_inventory.lock().unwrap().update(&object);
+                changes.iter().for_each(|change| println!("{}", change));
+                // fails only while nobody is streaming
+                let _ = polls.send(Arc::new(object.clone()));
+                if let Some(url) = config.metrics.push_url.clone() {
+                    let body = MetricsModule::render(&poll_latest.lock().unwrap().values().collect::<Vec<&MibObject>>(), Format::Text);
+                    task::spawn(async move {
+                        if let Err(err) = HttpModule::send("POST", &url, &BTreeMap::new(), Format::Text.content_type(), body.as_bytes()).await {
+                            println!("could not push metrics to {}: {}", url, err);
+                        }
+                    });
+                }
 
-            let log = OpenOptions::new()
-                                        .read(true)
-                                        .append(true)
-                                        .create(true)
-                                        .open(log_file_name)
-                                        .unwrap();
-    
-            let mut log_writer = LineWriter::new(log);
+                println!("object size: {}", std::mem::size_of_val(&object));
+
+                println!("{:?}", MibLogModule::log_path(&config.log, &object.target));
 
-            log_writer.write_all([serde_json::to_string(&object).unwrap(), "\n".to_owned()].concat().as_bytes()).unwrap();
+                if let Err(err) = mib_log.append(&object) {
+                    println!("could not write log for {}: {}", object.target, err);
+                }
+
+                // println!("{:?}, {:?}", &object.icmp.icmpOutMsgs.name, &object.icmp.icmpOutMsgs.value);
 
-            // println!("{:?}, {:?}", &object.icmp.icmpOutMsgs.name, &object.icmp.icmpOutMsgs.value);
+                // write!(log_writer, "{},\n", serde_json::to_string_pretty(&object).unwrap());
+                // write!(log_writer, "{},\n", serde_json::to_string(&object).unwrap()).unwrap();
+                // history_writer.flush().unwrap();
 
-            // write!(log_writer, "{},\n", serde_json::to_string_pretty(&object).unwrap());
-            // write!(log_writer, "{},\n", serde_json::to_string(&object).unwrap()).unwrap();
-            // history_writer.flush().unwrap();
+                mib_obj_sender.send(object).expect("msg");
+            }
 
-            mib_obj_sender.send(object).expect("msg");
+            if walked {
+                let (topology, links) = {
+                    let latest = poll_latest.lock().unwrap();
+                    let objects = latest.values().collect::<Vec<&MibObject>>();
+                    (Arc::new(TopologyModule::build(&objects)), Arc::new(NeighboursModule::graph(&objects)))
+                };
+                *poll_topology.lock().unwrap() = topology;
+                *poll_links_graph.lock().unwrap() = links;
+            }
         }
 
     });
@@ -350,7 +2084,82 @@ async fn main() {
     eframe::run_native("SNMP Monitor", options, app).unwrap();
 }
 
+impl SnmpMonitorApp {
+    /// the current session as a dashboard named `name`
+    fn dashboard(&self, name: &str) -> Dashboard {
+        let plots = self.context.plots.iter().map(|(name, plot)| (name.clone(), plot.saved())).collect();
+        Dashboard::new(name, &self.context.shown_target, self.tabs_tree.clone(), plots)
+    }
+
+    /// shows `dashboard` in place of the current session; its target is only shown when it is
+    /// polled, since nothing would be drawn until a poll of it arrives
+    fn restore(&mut self, dashboard: &Dashboard) {
+        self.tabs_tree = dashboard.layout.clone();
+        self.context.open_tabs = dashboard.tabs().into_iter().collect();
+        self.context.plots = dashboard.plots.iter().map(|(name, saved)| (name.clone(), PlotContext::restore(saved, &self.tsdb, &dashboard.target))).collect();
+        let latest = self.context.latest.lock().unwrap().get(&dashboard.target).cloned();
+        let polled = self.context.targets.lock().unwrap().targets().iter().any(|a| a.address == dashboard.target);
+        match latest {
+            Some(object) => {
+                self.context.shown_target = dashboard.target.clone();
+                self.context.previous = None;
+                self.context.object = Some(object);
+            }
+            None if polled && self.context.object.is_none() => self.context.shown_target = dashboard.target.clone(),
+            None => self.context.status = format!("{} is not polled, showing the dashboard for {}", dashboard.target, self.context.shown_target),
+        }
+    }
+
+    fn dashboard_action(&mut self, action: DashboardAction) {
+        let status = match action {
+            DashboardAction::Save(name) => {
+                let dashboard = self.dashboard(&name);
+                self.context.dashboards.insert(dashboard);
+                format!("saved dashboard {}", name)
+            }
+            DashboardAction::Load(name) => match self.context.dashboards.dashboards.get(&name).cloned() {
+                Some(dashboard) => {
+                    self.restore(&dashboard);
+                    return;
+                }
+                None => format!("no dashboard named {}", name),
+            },
+            DashboardAction::Delete(name) => {
+                self.context.dashboards.dashboards.remove(&name);
+                format!("deleted dashboard {}", name)
+            }
+            DashboardAction::Export(name) => match self.context.dashboards.dashboards.get(&name).map(|a| DashboardModule::export(&self.dashboard_config.export_dir, a)) {
+                Some(Ok(path)) => format!("exported dashboard {} to {}", name, path.display()),
+                Some(Err(err)) => format!("could not export dashboard {}: {}", name, err),
+                None => format!("no dashboard named {}", name),
+            },
+            DashboardAction::Import(path) => match DashboardModule::import(&path) {
+                Ok(dashboard) => {
+                    let name = dashboard.name.clone();
+                    self.context.dashboards.insert(dashboard);
+                    format!("imported dashboard {}", name)
+                }
+                Err(err) => format!("could not import {}: {}", path, err),
+            },
+        };
+        if let Err(err) = self.context.dashboards.save(&self.dashboard_config.file) {
+            println!("could not save dashboards: {}", err);
+        }
+        println!("{}", status);
+        self.context.status = status;
+    }
+}
+
 impl eframe::App for SnmpMonitorApp {
+    /// keeps the session as the autosaved dashboard, restored at the next start
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        let dashboard = self.dashboard(AUTOSAVE);
+        self.context.dashboards.insert(dashboard);
+        if let Err(err) = self.context.dashboards.save(&self.dashboard_config.file) {
+            println!("could not save dashboards: {}", err);
+        }
+    }
+
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         let win_dimentions: Option<(f32, f32)> = (ctx.input(|i| match i.viewport().outer_rect {
                 Some(rect) => Some((rect.width(), rect.height())),
@@ -358,20 +2167,35 @@ impl eframe::App for SnmpMonitorApp {
             })
         );
         match self.mib_obj_reciever.try_recv() {
+            // the other targets' polls are only kept in the latest map until they are picked
+            Ok(mibobj) if !self.context.shown_target.is_empty() && mibobj.target != self.context.shown_target => {},
             Ok(mibobj) => {
                 println!("recieved object");
-                if !self.context.plots.is_empty() {
-                    self.context.plots.clone().into_iter().for_each(|plot| {
-                        plot.1.plottables.into_iter().enumerate().for_each(|plottable| {
-                            self.context.plots.get_mut(&plot.0).unwrap().plottables.get_mut(plottable.0).unwrap().points.push((mibobj.timestamp, mibobj.find_oid(plottable.1.oid).unwrap().val_as_mvinti64().unwrap().first().unwrap().clone()));
-                            // plottable.1.points.push((mibobj.timestamp, mibobj.find_oid(plottable.1.oid).unwrap().val_as_mvinti64().unwrap().first().unwrap().clone()));
-                        })
-                    });
+                self.context.shown_target = mibobj.target.clone();
+                let samples = match self.context.plots.values().any(|plotcontext| !plotcontext.derived.is_empty()) {
+                    true => mibobj.samples(),
+                    false => vec![],
+                };
+                for plotcontext in self.context.plots.values_mut() {
+                    for plottable in plotcontext.plottables.iter_mut() {
+                        if let Some(value) = plottable.value(&mibobj) {
+                            plottable.points.push((mibobj.timestamp, value));
+                        }
+                    }
+                    plotcontext.derived.iter_mut().for_each(|derived| derived.add(mibobj.timestamp, &samples));
                 }
+                self.context.previous = self.context.object.take().filter(|object| object.target == mibobj.target);
                 self.context.object = Some(mibobj);
             },
             Err(_) => {},
         };
+        while let Ok(result) = self.context.browse_results.try_recv() {
+            self.context.browser.receive(&self.context.mib_tree, result);
+        }
+        while let Ok(outcome) = self.context.set_outcomes.try_recv() {
+            self.context.status = outcome.summary();
+            self.context.last_set = Some(outcome);
+        }
         if self.context.object.is_some() {
             egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
                 ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
@@ -379,10 +2203,73 @@ impl eframe::App for SnmpMonitorApp {
                     ui.add(egui::TextEdit::singleline(&mut self.community).hint_text("target community string"));
                     if win_dimentions.is_some() {
                         if ui.add(egui::Button::new("change target")).clicked() {
-                            self.target_sender.send((SocketAddr::from_str(&[&self.target_ip, ":161"].concat()).expect("couldnt convert ip to socketaddr"), self.community.to_owned())).expect("error sending target info");
+                            let address = SocketAddr::from_str(&[&self.target_ip, ":161"].concat()).expect("couldnt convert ip to socketaddr");
+                            self.target_sender.send((address, self.community.to_owned())).expect("error sending target info");
+                            self.context.shown_target = address.ip().to_string();
+                            if let Some(object) = self.context.latest.lock().unwrap().get(&self.context.shown_target) {
+                                self.context.object = Some(object.clone());
+                            }
                         }
                     }
+                    let firing = self.context.alerts.lock().unwrap().active().iter().filter(|a| a.status == AlertStatus::Firing).count();
+                    if ui.button(format!("alerts ({})", firing)).clicked() && !self.context.open_tabs.contains("alerts") {
+                        self.context.open_tabs.insert("alerts".to_owned());
+                        self.tabs_tree.main_surface_mut().push_to_focused_leaf("alerts".to_owned());
+                    }
+                    if ui.button(format!("targets ({})", self.context.targets.lock().unwrap().targets().len())).clicked() && !self.context.open_tabs.contains("targets") {
+                        self.context.open_tabs.insert("targets".to_owned());
+                        self.tabs_tree.main_surface_mut().push_to_focused_leaf("targets".to_owned());
+                    }
+                    if ui.button("topology").clicked() && !self.context.open_tabs.contains("topology") {
+                        self.context.open_tabs.insert("topology".to_owned());
+                        self.tabs_tree.main_surface_mut().push_to_focused_leaf("topology".to_owned());
+                    }
+                    if ui.button("neighbours").clicked() && !self.context.open_tabs.contains("neighbours") {
+                        self.context.open_tabs.insert("neighbours".to_owned());
+                        self.tabs_tree.main_surface_mut().push_to_focused_leaf("neighbours".to_owned());
+                    }
+                    if ui.button("dashboards").clicked() {
+                        self.context.to_open.push("dashboards".to_owned());
+                    }
+                    if ui.button("mib browser").clicked() {
+                        self.context.to_open.push("mib browser".to_owned());
+                    }
+                    if ui.button(format!("set ({})", self.context.edits.len())).clicked() {
+                        self.context.to_open.push("set".to_owned());
+                    }
+                    let mut replaying = self.context.replay.is_some();
+                    if ui.toggle_value(&mut replaying, "history").changed() {
+                        self.context.replay = match replaying {
+                            true => {
+                                let target = self.context.object.as_ref().map(|obj| obj.target.clone()).unwrap_or_default();
+                                Some(Replay::load(&self.log_config, &target))
+                            }
+                            false => None,
+                        };
+                    }
+                    if !self.context.status.is_empty() {
+                        ui.label(&self.context.status);
+                    }
                 });
+                if let Some(replay) = self.context.replay.as_mut() {
+                    ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
+                        match replay.snapshots.len() {
+                            0 => { ui.label(format!("no snapshots logged for {}", replay.target)); }
+                            len => {
+                                ui.add(Slider::new(&mut replay.position, 0..=len - 1).show_value(false));
+                                replay.seek();
+                                ui.label(format!("{} ({}/{})", format_timestamp(replay.snapshots[replay.position].timestamp), replay.position + 1, len));
+                            }
+                        }
+                        if ui.button("reload").clicked() {
+                            *replay = Replay::load(&self.log_config, &replay.target.clone());
+                        }
+                        if ui.button("diff against now").clicked() && !self.context.open_tabs.contains("history diff") {
+                            self.context.open_tabs.insert("history diff".to_owned());
+                            self.tabs_tree.main_surface_mut().push_to_focused_leaf("history diff".to_owned());
+                        }
+                    });
+                }
             });
             
             if self.new_plot_window_manager.show {
@@ -405,26 +2292,8 @@ impl eframe::App for SnmpMonitorApp {
                                 .main_surface_mut()
                                 .push_to_focused_leaf(self.context.new_plot_name.clone());
                             
-                            let file_path = format!("logs/MIB-log-{}.log", str::replace(&self.target_ip, ".", "-"));
-                            println!("{}", file_path);
-
-                            let snmp_log: Vec<MibObject> = BufReader::new(File::open(file_path).unwrap())
-                                                                        .lines()
-                                                                        .map(|line| line.unwrap())
-                                                                        .filter(|line| line != "")
-                                                                        .map(|line| serde_json::from_str::<MibObject>(&line).unwrap())
-                                                                        .collect::<Vec<MibObject>>();
-    
-                            snmp_log
-                                .clone()
-                                .into_iter()
-                                .for_each(|obj| println!("\n{:?}\n", obj.ip.ipForwarding));
-    
-                            let mut plottables: Vec<Plottable> = vec![Plottable::new(self.new_plot_window_manager.value_to_add.clone().unwrap()).unwrap()];
-                            snmp_log.into_iter().for_each(|obj| {
-                                println!("{:?}", self.new_plot_window_manager.value_to_add.clone().expect("couldnt clone oid").get_oid());
-                                plottables[0].add((obj.timestamp, obj.find_oid(self.new_plot_window_manager.value_to_add.clone().expect("couldnt clone oid").get_oid()).expect("couldnt find object by oid").val_as_mvinti64().expect("couldnt convert value to mvinti64").first().expect("no first value in vector").clone() ));
-                            });
+                            let target = self.context.object.as_ref().map(|obj| obj.target.clone()).unwrap_or_default();
+                            let plottables: Vec<Plottable> = Plottable::load(self.new_plot_window_manager.value_to_add.clone().unwrap(), &self.tsdb, &target, history_range(None)).into_iter().collect();
     
                             self.context
                                 .plots
@@ -437,7 +2306,18 @@ impl eframe::App for SnmpMonitorApp {
                 self.new_plot_window_manager.open = false;
             }
 
-            self.context.object.clone().unwrap().egui_show(ctx, self);
+            // the side panel draws with the whole app borrowed, so the shown object is lent out meanwhile
+            match self.context.replay.as_mut().and_then(|replay| replay.take()) {
+                Some(snapshot) => {
+                    snapshot.egui_show(ctx, self);
+                    self.context.replay.as_mut().unwrap().put(snapshot);
+                }
+                None => {
+                    let object = self.context.object.take().unwrap();
+                    object.egui_show(ctx, self);
+                    self.context.object = Some(object);
+                }
+            }
             
 
 
@@ -452,6 +2332,18 @@ impl eframe::App for SnmpMonitorApp {
                         .show_window_collapse_buttons(self.context.show_window_collapse)
                         .show_inside(ui, &mut self.context);
             });
+            if let Some(action) = self.context.dashboard_action.take() {
+                self.dashboard_action(action);
+            }
+            for tab in std::mem::take(&mut self.context.to_open) {
+                match self.tabs_tree.find_tab(&tab) {
+                    Some(found) => self.tabs_tree.set_active_tab(found),
+                    None => {
+                        self.context.open_tabs.insert(tab.clone());
+                        self.tabs_tree.main_surface_mut().push_to_focused_leaf(tab);
+                    }
+                }
+            }
         } else {
             egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
                 ui.heading("top panel");
@@ -0,0 +1,268 @@
+pub mod MetricsModule {
+    use std::collections::HashMap;
+
+    use crate::inet::InetModule;
+    use crate::mibobject::MibModule::{MibObject, MibValue};
+
+    /// unsigned values that are gauges or timeticks rather than counters
+    const GAUGES: &[&str] = &["sysUpTime", "ifSpeed", "ifLastChange", "ifOutQLen", "tcpCurrEstab", "ifHighSpeed", "ifCounterDiscontinuityTime", "hrSystemUptime", "hrSystemNumUsers", "hrSystemProcesses",
+        "ipAddressCreated", "ipAddressLastChanged", "ipNetToPhysicalLastUpdated", "inetCidrRouteAge", "inetCidrRouteNextHopAS", "tcpConnectionProcess", "tcpListenerProcess",
+        "udpEndpointProcess", "dskTotalLow", "dskTotalHigh", "dskAvailLow", "dskAvailHigh", "dskUsedLow", "dskUsedHigh",
+    ];
+
+    /// the INDEX clause of every known table as `(table oid, [(label, sub-identifiers)])`, a
+    /// length of 0 takes the rest of the instance suffix
+    const TABLE_INDEXES: &[(&str, &[(&str, usize)])] = &[
+        ("1.3.6.1.2.1.2.2", &[("ifIndex", 1)]),
+        ("1.3.6.1.2.1.31.1.1", &[("ifIndex", 1)]),
+        ("1.3.6.1.2.1.3.1", &[("atIfIndex", 1), ("atNetAddress", 0)]),
+        ("1.3.6.1.2.1.4.20", &[("ipAdEntAddr", 4)]),
+        ("1.3.6.1.2.1.4.21", &[("ipRouteDest", 4)]),
+        ("1.3.6.1.2.1.4.22", &[("ipNetToMediaIfIndex", 1), ("ipNetToMediaNetAddress", 4)]),
+        (
+            "1.3.6.1.2.1.6.13",
+            &[("tcpConnLocalAddress", 4), ("tcpConnLocalPort", 1), ("tcpConnRemAddress", 4), ("tcpConnRemPort", 1)],
+        ),
+        ("1.3.6.1.2.1.7.5", &[("udpLocalAddress", 4), ("udpLocalPort", 1)]),
+        ("1.3.6.1.2.1.8.5", &[("egpNeighAddr", 4)]),
+        ("1.0.8802.1.1.2.1.3.7", &[("lldpLocPortNum", 1)]),
+        ("1.0.8802.1.1.2.1.4.1", &[("lldpRemTimeMark", 1), ("lldpRemLocalPortNum", 1), ("lldpRemIndex", 1)]),
+        ("1.3.6.1.4.1.9.9.23.1.2.1", &[("cdpCacheIfIndex", 1), ("cdpCacheDeviceIndex", 1)]),
+        ("1.3.6.1.2.1.25.2.3", &[("hrStorageIndex", 1)]),
+        ("1.3.6.1.2.1.25.3.2", &[("hrDeviceIndex", 1)]),
+        ("1.3.6.1.2.1.25.3.3", &[("hrDeviceIndex", 1)]),
+        ("1.3.6.1.2.1.25.4.2", &[("hrSWRunIndex", 1)]),
+        ("1.3.6.1.2.1.25.5.1", &[("hrSWRunIndex", 1)]),
+        ("1.3.6.1.2.1.17.1.4", &[("dot1dBasePort", 1)]),
+        ("1.3.6.1.2.1.17.4.3", &[("dot1dTpFdbAddress", 6)]),
+        ("1.3.6.1.2.1.17.7.1.2.2", &[("dot1qFdbId", 1), ("dot1qTpFdbAddress", 6)]),
+        ("1.3.6.1.2.1.17.7.1.4.3", &[("dot1qVlanIndex", 1)]),
+        ("1.3.6.1.4.1.2021.10", &[("laIndex", 1)]),
+        ("1.3.6.1.4.1.2021.9", &[("dskIndex", 1)]),
+        ("1.3.6.1.4.1.8072.1.3.2.3", &[("nsExtendToken", 0)]),
+    ];
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum Format {
+        /// OpenMetrics 1.0, what Prometheus asks for when it scrapes
+        OpenMetrics,
+        /// the classic Prometheus text format 0.0.4, understood by the Pushgateway
+        Text,
+    }
+
+    impl Format {
+        pub fn content_type(&self) -> &'static str {
+            match self {
+                Format::OpenMetrics => "application/openmetrics-text; version=1.0.0; charset=utf-8",
+                Format::Text => "text/plain; version=0.0.4; charset=utf-8",
+            }
+        }
+    }
+
+    struct Family {
+        name: String,
+        kind: &'static str,
+        help: String,
+        samples: Vec<String>,
+    }
+
+    pub fn oid_string(oid: &[u16]) -> String {
+        oid.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".")
+    }
+
+    fn escape(value: &str) -> String {
+        value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+    }
+
+    pub fn metric_kind(value: &MibValue) -> &'static str {
+        match value {
+            MibValue::intu32(_) | MibValue::intu64(_) if !GAUGES.contains(&value.get_name().as_str()) => "counter",
+            _ => "gauge",
+        }
+    }
+
+    /// splits the instance suffix of a table row into labels named after the table's INDEX
+    fn index_labels(value: &MibValue, index: &[u32]) -> Vec<(String, String)> {
+        let oid = value.get_oid();
+        let table = oid_string(&oid[..oid.len().saturating_sub(2)]);
+        let join = |parts: &[u32]| parts.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".");
+        if let Some(labels) = InetModule::index_labels(&table, index) {
+            return labels;
+        }
+        match TABLE_INDEXES.iter().find(|a| a.0 == table) {
+            Some((_, columns)) => {
+                let mut rest = index;
+                columns
+                    .iter()
+                    .map(|(label, len)| {
+                        let len = match *len {
+                            0 => rest.len(),
+                            len => len.min(rest.len()),
+                        };
+                        let (part, remaining) = rest.split_at(len);
+                        rest = remaining;
+                        (label.to_string(), join(part))
+                    })
+                    .collect()
+            }
+            None => vec![("index".to_owned(), join(index))],
+        }
+    }
+
+    /// labels every value instance of one polled object with its target and table index
+    pub struct Labeller {
+        target_labels: Vec<(String, String)>,
+        descrs: HashMap<String, String>,
+    }
+
+    impl Labeller {
+        pub fn new(object: &MibObject) -> Self {
+            let sys_name = object.system.sysName.display_values().first().cloned().unwrap_or_default();
+            let table = &object.interfaces.ifTable;
+            let descrs = table
+                .ifIndex
+                .val_as_mvinti64()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|a| a.to_string())
+                .zip(table.ifDescr.display_values())
+                .collect::<HashMap<String, String>>();
+            Labeller {
+                target_labels: vec![("target".to_owned(), object.target.clone()), ("sysName".to_owned(), sys_name)],
+                descrs,
+            }
+        }
+
+        /// `target` and `sysName`, followed by the index labels of table rows and `ifDescr` for
+        /// interface tables
+        pub fn labels(&self, value: &MibValue, position: usize) -> Vec<(String, String)> {
+            let index = value.instance_index(position);
+            let mut labels = self.target_labels.clone();
+            if index != [0] {
+                labels.extend(index_labels(value, &index));
+            }
+            if let Some(descr) = labels.iter().find(|a| a.0 == "ifIndex").and_then(|a| self.descrs.get(&a.1)) {
+                labels.push(("ifDescr".to_owned(), descr.clone()));
+            }
+            labels
+        }
+    }
+
+    /// renders the numeric values of every object, one family per MIB object name
+    pub fn render(objects: &[&MibObject], format: Format) -> String {
+        let mut families: Vec<Family> = vec![];
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut add = |name: &str, kind: &'static str, help: String, sample: String| {
+            let position = *positions.entry(name.to_owned()).or_insert_with(|| {
+                families.push(Family { name: name.to_owned(), kind, help, samples: vec![] });
+                families.len() - 1
+            });
+            families[position].samples.push(sample);
+        };
+
+        for object in objects {
+            let labeller = Labeller::new(object);
+            for value in object.values() {
+                let numbers = match value.val_as_mvinti64() {
+                    Some(numbers) => numbers,
+                    None => continue,
+                };
+                let name = value.get_name();
+                let kind = metric_kind(value);
+                let sample_name = match kind {
+                    "counter" => [name.as_str(), "_total"].concat(),
+                    _ => name.clone(),
+                };
+                let family_name = match format {
+                    Format::OpenMetrics => name.clone(),
+                    Format::Text => sample_name.clone(),
+                };
+                for (position, number) in numbers.into_iter().enumerate() {
+                    let labels = labeller
+                        .labels(value, position)
+                        .iter()
+                        .map(|(label, value)| format!("{}=\"{}\"", label, escape(value)))
+                        .collect::<Vec<String>>()
+                        .join(",");
+                    add(&family_name, kind, format!("{} {}", name, oid_string(&value.get_oid())), format!("{}{{{}}} {}", sample_name, labels, number));
+                }
+            }
+            add(
+                "snmp_last_poll_timestamp_seconds",
+                "gauge",
+                "unix time of the latest poll".to_owned(),
+                format!("snmp_last_poll_timestamp_seconds{{target=\"{}\"}} {}", escape(&object.target), object.timestamp),
+            );
+        }
+
+        let mut output = String::new();
+        for family in families {
+            output.push_str(&format!("# TYPE {} {}\n# HELP {} {}\n", family.name, family.kind, family.name, family.help));
+            family.samples.iter().for_each(|sample| {
+                output.push_str(sample);
+                output.push('\n');
+            });
+        }
+        if format == Format::OpenMetrics {
+            output.push_str("# EOF\n");
+        }
+        output
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::{mvinti32, mvintu32, mvipv4, mvstring};
+
+        fn object() -> MibObject {
+            let mut object = MibObject::new();
+            object.target = "192.0.2.1".to_owned();
+            object.timestamp = 1700000000;
+            if let MibValue::string(mvstring { value, .. }) = &mut object.system.sysName {
+                *value = vec!["core-\"1\"".to_owned()];
+            }
+            if let MibValue::intu32(mvintu32 { value, .. }) = &mut object.system.sysUpTime {
+                *value = vec![4200];
+            }
+            let table = &mut object.interfaces.ifTable;
+            if let MibValue::inti32(mvinti32 { value, index, .. }) = &mut table.ifIndex {
+                *value = vec![1, 2];
+                *index = vec![vec![1], vec![2]];
+            }
+            if let MibValue::string(mvstring { value, .. }) = &mut table.ifDescr {
+                *value = vec!["lo".to_owned(), "eth0".to_owned()];
+            }
+            if let MibValue::intu32(mvintu32 { value, index, .. }) = &mut table.ifInOctets {
+                *value = vec![10, 20];
+                *index = vec![vec![1], vec![2]];
+            }
+            if let MibValue::ipv4(mvipv4 { value, .. }) = &mut object.ip.ipRouteTable.ipRouteDest {
+                *value = vec![(10, 0, 0, 0)];
+            }
+            if let MibValue::inti32(mvinti32 { value, index, .. }) = &mut object.ip.ipRouteTable.ipRouteMetric1 {
+                *value = vec![5];
+                *index = vec![vec![10, 0, 0, 0]];
+            }
+            object
+        }
+
+        #[test]
+        fn test_render() {
+            let object = object();
+            let open_metrics = render(&[&object], Format::OpenMetrics);
+            let target = "target=\"192.0.2.1\",sysName=\"core-\\\"1\\\"\"";
+            assert!(open_metrics.contains("# TYPE sysUpTime gauge\n# HELP sysUpTime sysUpTime 1.3.6.1.2.1.1.3\n"));
+            assert!(open_metrics.contains(&format!("sysUpTime{{{}}} 4200\n", target)));
+            assert!(open_metrics.contains("# TYPE ifInOctets counter\n"));
+            assert!(open_metrics.contains(&format!("ifInOctets_total{{{},ifIndex=\"2\",ifDescr=\"eth0\"}} 20\n", target)));
+            assert!(open_metrics.contains(&format!("ipRouteMetric1{{{},ipRouteDest=\"10.0.0.0\"}} 5\n", target)));
+            assert!(open_metrics.contains("snmp_last_poll_timestamp_seconds{target=\"192.0.2.1\"} 1700000000\n"));
+            assert!(open_metrics.ends_with("# EOF\n"));
+            assert_eq!(open_metrics.matches("# TYPE ifInOctets ").count(), 1);
+
+            let text = render(&[&object], Format::Text);
+            assert!(text.contains("# TYPE ifInOctets_total counter\n"));
+            assert!(!text.contains("# EOF"));
+        }
+    }
+}
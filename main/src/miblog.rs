@@ -0,0 +1,350 @@
+pub mod MibLogModule {
+    use flate2::read::GzDecoder;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::collections::HashMap;
+    use std::fs::{self, File, OpenOptions};
+    use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+    use std::path::{Path, PathBuf};
+
+    use crate::config::ConfigModule::{FsyncPolicy, LogConfig};
+    use crate::mibobject::MibModule::MibObject;
+    use serde::Deserialize;
+
+    /// the open, active log file of one target
+    struct LogWriter {
+        file: File,
+        size: u64,
+        started: Option<i64>,
+        last_sync: i64,
+    }
+
+    /// append-only JSON-lines log of every polled `MibObject`, rotated by size and age
+    pub struct MibLog {
+        config: LogConfig,
+        writers: HashMap<String, LogWriter>,
+    }
+
+    impl MibLog {
+        pub fn new(config: LogConfig) -> io::Result<Self> {
+            fs::create_dir_all(&config.dir)?;
+            Ok(MibLog { config, writers: HashMap::new() })
+        }
+
+        pub fn append(&mut self, object: &MibObject) -> io::Result<()> {
+            let line = [serde_json::to_string(object)?, "\n".to_owned()].concat();
+            let target = object.target.clone();
+
+            if !self.writers.contains_key(&target) {
+                let writer = self.open_writer(&target)?;
+                self.writers.insert(target.clone(), writer);
+            }
+
+            let writer = self.writers.get(&target).unwrap();
+            let too_big = writer.size > 0 && writer.size + line.len() as u64 > self.config.max_bytes;
+            let too_old = match writer.started {
+                Some(started) => object.timestamp - started >= self.config.max_age_hours * 3600,
+                None => false,
+            };
+            if too_big || too_old {
+                self.rotate(&target, object.timestamp)?;
+                let writer = self.open_writer(&target)?;
+                self.writers.insert(target.clone(), writer);
+            }
+
+            let policy = self.config.fsync;
+            let interval = self.config.fsync_interval_secs;
+            let writer = self.writers.get_mut(&target).unwrap();
+            writer.file.write_all(line.as_bytes())?;
+            writer.size += line.len() as u64;
+            writer.started.get_or_insert(object.timestamp);
+            let sync = match policy {
+                FsyncPolicy::Always => true,
+                FsyncPolicy::Interval => object.timestamp - writer.last_sync >= interval,
+                FsyncPolicy::Never => false,
+            };
+            if sync {
+                writer.file.sync_data()?;
+                writer.last_sync = object.timestamp;
+            }
+            Ok(())
+        }
+
+        fn open_writer(&self, target: &str) -> io::Result<LogWriter> {
+            let path = log_path(&self.config, target);
+            let size = recover(&path)?;
+            let started = match File::open(&path) {
+                Ok(file) => BufReader::new(file)
+                    .lines()
+                    .next()
+                    .and_then(|line| line.ok())
+                    .and_then(|line| serde_json::from_str::<serde_json::Value>(&line).ok())
+                    .and_then(|value| value["timestamp"].as_i64()),
+                Err(_) => None,
+            };
+            let file = OpenOptions::new().append(true).create(true).open(&path)?;
+            Ok(LogWriter { file, size, started, last_sync: started.unwrap_or_default() })
+        }
+
+        /// moves the active log aside under its rotation timestamp, compressing it if configured;
+        /// a sequence number follows the timestamp when the log was already rotated that second
+        fn rotate(&mut self, target: &str, now: i64) -> io::Result<()> {
+            if let Some(writer) = self.writers.remove(target) {
+                writer.file.sync_all()?;
+            }
+            let path = log_path(&self.config, target);
+            let rotated = (0..)
+                .map(|sequence| match sequence {
+                    0 => self.config.dir_path().join(format!("{}.{}.log", log_stem(target), now)),
+                    _ => self.config.dir_path().join(format!("{}.{}.{}.log", log_stem(target), now, sequence)),
+                })
+                .find(|rotated| !rotated.exists() && !rotated.with_extension("log.gz").exists())
+                .unwrap();
+            fs::rename(&path, &rotated)?;
+            if self.config.gzip {
+                let mut encoder = GzEncoder::new(File::create(rotated.with_extension("log.gz"))?, Compression::default());
+                io::copy(&mut File::open(&rotated)?, &mut encoder)?;
+                encoder.finish()?.sync_all()?;
+                fs::remove_file(&rotated)?;
+            }
+            println!("rotated {:?}", path);
+            self.apply_retention(target, now)
+        }
+
+        /// deletes rotated logs beyond the configured count or age, oldest first
+        fn apply_retention(&self, target: &str, now: i64) -> io::Result<()> {
+            let rotated = rotated_logs(&self.config, target);
+            let excess = rotated.len().saturating_sub(self.config.retention_files);
+            for (index, (rotated_at, path)) in rotated.into_iter().enumerate() {
+                if index < excess || now - rotated_at > self.config.retention_days * 86400 {
+                    fs::remove_file(&path)?;
+                    println!("removed expired log {:?}", path);
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl LogConfig {
+        fn dir_path(&self) -> PathBuf {
+            PathBuf::from(&self.dir)
+        }
+    }
+
+    fn log_stem(target: &str) -> String {
+        format!("MIB-log-{}", target.replace(['.', ':'], "-"))
+    }
+
+    /// the active log file of `target`
+    pub fn log_path(config: &LogConfig, target: &str) -> PathBuf {
+        config.dir_path().join(format!("{}.log", log_stem(target)))
+    }
+
+    /// rotated logs of `target` as `(rotation timestamp, path)`, oldest first
+    fn rotated_logs(config: &LogConfig, target: &str) -> Vec<(i64, PathBuf)> {
+        let prefix = format!("{}.", log_stem(target));
+        let mut rotated = match fs::read_dir(config.dir_path()) {
+            Ok(dir) => dir
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_string_lossy().into_owned();
+                    let mut parts = name.strip_prefix(&prefix)?.split('.');
+                    let rotated_at = parts.next()?.parse::<i64>().ok()?;
+                    let sequence = parts.next().and_then(|a| a.parse::<u32>().ok()).unwrap_or(0);
+                    Some((rotated_at, sequence, entry.path()))
+                })
+                .collect::<Vec<(i64, u32, PathBuf)>>(),
+            Err(_) => vec![],
+        };
+        rotated.sort();
+        rotated.into_iter().map(|(rotated_at, _, path)| (rotated_at, path)).collect()
+    }
+
+    /// cuts off a partial trailing record left behind by a crash mid-write and returns the
+    /// resulting file size
+    pub fn recover(path: &Path) -> io::Result<u64> {
+        let mut file = match OpenOptions::new().read(true).write(true).open(path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(err),
+        };
+        let len = file.seek(SeekFrom::End(0))?;
+        // walk backwards in chunks until the last newline is found
+        let mut end = len;
+        let mut chunk = vec![0u8; 4096];
+        while end > 0 {
+            let start = end.saturating_sub(chunk.len() as u64);
+            let buf = &mut chunk[..(end - start) as usize];
+            file.seek(SeekFrom::Start(start))?;
+            file.read_exact(buf)?;
+            if let Some(pos) = buf.iter().rposition(|byte| *byte == b'\n') {
+                let valid = start + pos as u64 + 1;
+                if valid < len {
+                    println!("truncating {} bytes of partial record from {:?}", len - valid, path);
+                    file.set_len(valid)?;
+                }
+                return Ok(valid);
+            }
+            end = start;
+        }
+        if len > 0 {
+            println!("truncating {} bytes of partial record from {:?}", len, path);
+            file.set_len(0)?;
+        }
+        Ok(0)
+    }
+
+    /// where one logged snapshot starts, so it can be read back without keeping every snapshot
+    /// in memory; the offset counts uncompressed bytes for gzipped logs
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct SnapshotRef {
+        pub path: PathBuf,
+        pub offset: u64,
+        pub timestamp: i64,
+    }
+
+    /// the part of a record needed to index it
+    #[derive(Deserialize)]
+    struct Stamp {
+        timestamp: i64,
+    }
+
+    fn index_lines<R: Read>(reader: R, path: &Path, snapshots: &mut Vec<SnapshotRef>) {
+        let mut reader = BufReader::new(reader);
+        let mut line = String::new();
+        let mut offset = 0;
+        for number in 1.. {
+            line.clear();
+            let len = match reader.read_line(&mut line) {
+                Ok(0) => return,
+                Ok(len) => len as u64,
+                Err(err) => {
+                    println!("stopped indexing {:?} at line {}: {}", path, number, err);
+                    return;
+                }
+            };
+            if !line.trim().is_empty() {
+                match serde_json::from_str::<Stamp>(&line) {
+                    Ok(stamp) => snapshots.push(SnapshotRef { path: path.to_owned(), offset, timestamp: stamp.timestamp }),
+                    Err(err) => println!("skipping corrupt record {:?}:{}: {}", path, number, err),
+                }
+            }
+            offset += len;
+        }
+    }
+
+    /// every snapshot still on disk for `target`, oldest first, without reading them in
+    pub fn index_snapshots(config: &LogConfig, target: &str) -> Vec<SnapshotRef> {
+        let mut snapshots = vec![];
+        let mut paths = rotated_logs(config, target).into_iter().map(|a| a.1).collect::<Vec<PathBuf>>();
+        paths.push(log_path(config, target));
+        for path in paths {
+            let file = match File::open(&path) {
+                Ok(file) => file,
+                Err(_) => continue,
+            };
+            if path.extension().map(|ext| ext == "gz").unwrap_or(false) {
+                index_lines(GzDecoder::new(file), &path, &mut snapshots);
+            } else {
+                index_lines(file, &path, &mut snapshots);
+            }
+        }
+        snapshots
+    }
+
+    /// reads the one snapshot `snapshot` points at
+    pub fn read_snapshot(snapshot: &SnapshotRef) -> io::Result<MibObject> {
+        let mut file = File::open(&snapshot.path)?;
+        let mut line = String::new();
+        if snapshot.path.extension().map(|ext| ext == "gz").unwrap_or(false) {
+            let mut decoder = GzDecoder::new(file);
+            io::copy(&mut (&mut decoder).take(snapshot.offset), &mut io::sink())?;
+            BufReader::new(decoder).read_line(&mut line)?;
+        } else {
+            file.seek(SeekFrom::Start(snapshot.offset))?;
+            BufReader::new(file).read_line(&mut line)?;
+        }
+        serde_json::from_str::<MibObject>(&line).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn test_config(name: &str) -> LogConfig {
+            let dir = std::env::temp_dir().join(format!("snmp-monitor-log-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            LogConfig {
+                dir: dir.to_string_lossy().into_owned(),
+                ..LogConfig::default()
+            }
+        }
+
+        fn object(timestamp: i64) -> MibObject {
+            let mut object = MibObject::new();
+            object.target = "192.0.2.1".to_owned();
+            object.timestamp = timestamp;
+            object
+        }
+
+        #[test]
+        fn test_recover_truncates_partial_record() {
+            let config = test_config("recover");
+            let mut log = MibLog::new(config.clone()).unwrap();
+            log.append(&object(100)).unwrap();
+            log.append(&object(130)).unwrap();
+            drop(log);
+
+            let path = log_path(&config, "192.0.2.1");
+            let complete = fs::metadata(&path).unwrap().len();
+            OpenOptions::new().append(true).open(&path).unwrap().write_all(b"{\"oid\":[1,3,6").unwrap();
+            assert_eq!(recover(&path).unwrap(), complete);
+            assert_eq!(fs::metadata(&path).unwrap().len(), complete);
+
+            // a corrupt line in the middle is skipped instead of failing the whole read
+            OpenOptions::new().append(true).open(&path).unwrap().write_all(b"garbage\n").unwrap();
+            let mut log = MibLog::new(config.clone()).unwrap();
+            log.append(&object(160)).unwrap();
+            let timestamps = index_snapshots(&config, "192.0.2.1").iter().map(|a| a.timestamp).collect::<Vec<i64>>();
+            assert_eq!(timestamps, vec![100, 130, 160]);
+            let _ = fs::remove_dir_all(config.dir);
+        }
+
+        #[test]
+        fn test_rotation_and_retention() {
+            let config = LogConfig {
+                max_age_hours: 1,
+                retention_files: 2,
+                ..test_config("rotate")
+            };
+            let mut log = MibLog::new(config.clone()).unwrap();
+            for hour in 0..5 {
+                log.append(&object(hour * 3600)).unwrap();
+            }
+            let rotated = rotated_logs(&config, "192.0.2.1");
+            assert_eq!(rotated.iter().map(|a| a.0).collect::<Vec<i64>>(), vec![3 * 3600, 4 * 3600]);
+            assert!(rotated.iter().all(|a| a.1.extension().unwrap() == "gz"));
+            // the index points at every kept snapshot, in gzipped and active logs alike
+            let snapshots = index_snapshots(&config, "192.0.2.1");
+            assert_eq!(snapshots.iter().map(|a| a.timestamp).collect::<Vec<i64>>(), vec![2 * 3600, 3 * 3600, 4 * 3600]);
+            assert!(snapshots.iter().all(|a| read_snapshot(a).unwrap().timestamp == a.timestamp));
+            let _ = fs::remove_dir_all(config.dir);
+        }
+
+        #[test]
+        fn test_rotations_in_one_second_are_kept() {
+            let config = LogConfig { gzip: false, ..test_config("same-second") };
+            let mut log = MibLog::new(config.clone()).unwrap();
+            for timestamp in [100, 130, 160] {
+                log.append(&object(timestamp)).unwrap();
+                log.rotate("192.0.2.1", 200).unwrap();
+            }
+            let rotated = rotated_logs(&config, "192.0.2.1");
+            assert_eq!(rotated.len(), 3);
+            assert!(rotated[2].1.to_string_lossy().ends_with(".200.2.log"));
+            let timestamps = index_snapshots(&config, "192.0.2.1").iter().map(|a| a.timestamp).collect::<Vec<i64>>();
+            assert_eq!(timestamps, vec![100, 130, 160]);
+            let _ = fs::remove_dir_all(config.dir);
+        }
+    }
+}
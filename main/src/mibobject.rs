@@ -1,21 +1,23 @@
 pub mod MibModule {
     use async_trait::async_trait;
     use chrono::Utc;
-    use csnmp::{ObjectIdentifier, Snmp2cClient};
+    use csnmp::{ObjectIdentifier, ObjectValue, Snmp2cClient, SnmpClientError};
     use egui::{Context, Ui, Window};
     use egui_extras::{Column, TableBuilder};
     use serde::{Deserialize, Serialize};
-    use std::{
-        fs::File,
-        io::{BufRead, BufReader},
-        str::FromStr,
-    };
+    use std::collections::{BTreeMap, HashMap};
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicBool, Ordering};
 
-    use crate::{Plottable, SnmpMonitorApp};
+    use crate::inet::InetModule;
+    use crate::set::SetModule::Edit;
+    use crate::{history_range, Plottable, SnmpMonitorApp};
 
     #[derive(Serialize, Deserialize)]
     pub struct MibObject {
         pub oid: Vec<u16>,
+        #[serde(default)]
+        pub target: String,
         pub timestamp: i64,
         pub system: System,
         pub interfaces: Interfaces,
@@ -27,6 +29,23 @@ pub mod MibModule {
         pub egp: Egp,
         pub transmission: MibValue,
         pub snmp: Snmp,
+        #[serde(default)]
+        pub lldp: Lldp,
+        #[serde(default)]
+        pub cdp: Cdp,
+        #[serde(default)]
+        pub host: HostResources,
+        #[serde(default)]
+        pub entity: Entity,
+        #[serde(default)]
+        pub bridge: Bridge,
+        #[serde(default)]
+        pub ucd: Ucd,
+        #[serde(default)]
+        pub vendor: Vendor,
+        /// leaves pinned from the MIB browser to be polled on this target, whatever its profile
+        #[serde(default)]
+        pub pinned: Vec<MibValue>,
     }
 
     #[derive(Serialize, Deserialize)]
@@ -46,6 +65,8 @@ pub mod MibModule {
         pub oid: Vec<u16>,
         pub ifNumber: MibValue,
         pub ifTable: IfTable,
+        #[serde(default)]
+        pub ifXTable: IfXTable,
     }
 
     #[derive(Serialize, Deserialize)]
@@ -75,6 +96,31 @@ pub mod MibModule {
         pub ifSpecific: MibValue,
     }
 
+    /// IF-MIB ifXTable, the names and 64-bit counters of the interfaces in ifTable
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct IfXTable {
+        pub oid: Vec<u16>,
+        pub ifName: MibValue,
+        pub ifInMulticastPkts: MibValue,
+        pub ifInBroadcastPkts: MibValue,
+        pub ifOutMulticastPkts: MibValue,
+        pub ifOutBroadcastPkts: MibValue,
+        pub ifHCInOctets: MibValue,
+        pub ifHCInUcastPkts: MibValue,
+        pub ifHCInMulticastPkts: MibValue,
+        pub ifHCInBroadcastPkts: MibValue,
+        pub ifHCOutOctets: MibValue,
+        pub ifHCOutUcastPkts: MibValue,
+        pub ifHCOutMulticastPkts: MibValue,
+        pub ifHCOutBroadcastPkts: MibValue,
+        pub ifLinkUpDownTrapEnable: MibValue,
+        pub ifHighSpeed: MibValue,
+        pub ifPromiscuousMode: MibValue,
+        pub ifConnectorPresent: MibValue,
+        pub ifAlias: MibValue,
+        pub ifCounterDiscontinuityTime: MibValue,
+    }
+
     #[derive(Serialize, Deserialize)]
     pub struct At {
         pub oid: Vec<u16>,
@@ -115,6 +161,12 @@ pub mod MibModule {
         pub ipRouteTable: IpRouteTable,
         pub ipNetToMediaTable: IpNetToMediaTable,
         pub ipRoutingDiscards: MibValue,
+        #[serde(default)]
+        pub ipAddressTable: IpAddressTable,
+        #[serde(default)]
+        pub ipNetToPhysicalTable: IpNetToPhysicalTable,
+        #[serde(default)]
+        pub inetCidrRouteTable: InetCidrRouteTable,
     }
 
     #[derive(Serialize, Deserialize)]
@@ -154,6 +206,50 @@ pub mod MibModule {
         pub ipNetToMediaType: MibValue,
     }
 
+    /// IP-MIB ipAddressTable, the IPv4 and IPv6 addresses of the interfaces, indexed by
+    /// InetAddressType and InetAddress
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct IpAddressTable {
+        pub oid: Vec<u16>,
+        pub ipAddressIfIndex: MibValue,
+        pub ipAddressType: MibValue,
+        pub ipAddressPrefix: MibValue,
+        pub ipAddressOrigin: MibValue,
+        pub ipAddressStatus: MibValue,
+        pub ipAddressCreated: MibValue,
+        pub ipAddressLastChanged: MibValue,
+        pub ipAddressRowStatus: MibValue,
+        pub ipAddressStorageType: MibValue,
+    }
+
+    /// IP-MIB ipNetToPhysicalTable, the ARP and IPv6 neighbour caches
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct IpNetToPhysicalTable {
+        pub oid: Vec<u16>,
+        pub ipNetToPhysicalPhysAddress: MibValue,
+        pub ipNetToPhysicalLastUpdated: MibValue,
+        pub ipNetToPhysicalType: MibValue,
+        pub ipNetToPhysicalState: MibValue,
+        pub ipNetToPhysicalRowStatus: MibValue,
+    }
+
+    /// IP-FORWARD-MIB inetCidrRouteTable, the IPv4 and IPv6 routes
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct InetCidrRouteTable {
+        pub oid: Vec<u16>,
+        pub inetCidrRouteIfIndex: MibValue,
+        pub inetCidrRouteType: MibValue,
+        pub inetCidrRouteProto: MibValue,
+        pub inetCidrRouteAge: MibValue,
+        pub inetCidrRouteNextHopAS: MibValue,
+        pub inetCidrRouteMetric1: MibValue,
+        pub inetCidrRouteMetric2: MibValue,
+        pub inetCidrRouteMetric3: MibValue,
+        pub inetCidrRouteMetric4: MibValue,
+        pub inetCidrRouteMetric5: MibValue,
+        pub inetCidrRouteStatus: MibValue,
+    }
+
     #[derive(Serialize, Deserialize)]
     pub struct Icmp {
         pub oid: Vec<u16>,
@@ -203,6 +299,10 @@ pub mod MibModule {
         pub tcpConnTable: TcpConnTable,
         pub tcpInErrs: MibValue,
         pub tcpOutRsts: MibValue,
+        #[serde(default)]
+        pub tcpConnectionTable: TcpConnectionTable,
+        #[serde(default)]
+        pub tcpListenerTable: TcpListenerTable,
     }
 
     #[derive(Serialize, Deserialize)]
@@ -215,6 +315,21 @@ pub mod MibModule {
         pub tcpConnRemPort: MibValue,
     }
 
+    /// TCP-MIB tcpConnectionTable, the IPv4 and IPv6 connections past the LISTEN state
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct TcpConnectionTable {
+        pub oid: Vec<u16>,
+        pub tcpConnectionState: MibValue,
+        pub tcpConnectionProcess: MibValue,
+    }
+
+    /// TCP-MIB tcpListenerTable, the IPv4 and IPv6 listening sockets
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct TcpListenerTable {
+        pub oid: Vec<u16>,
+        pub tcpListenerProcess: MibValue,
+    }
+
     #[derive(Serialize, Deserialize)]
     pub struct Udp {
         pub oid: Vec<u16>,
@@ -223,6 +338,8 @@ pub mod MibModule {
         pub udpInErrors: MibValue,
         pub udpOutDatagrams: MibValue,
         pub udpTable: UdpTable,
+        #[serde(default)]
+        pub udpEndpointTable: UdpEndpointTable,
     }
 
     #[derive(Serialize, Deserialize)]
@@ -232,6 +349,13 @@ pub mod MibModule {
         pub udpLocalPort: MibValue,
     }
 
+    /// UDP-MIB udpEndpointTable, the IPv4 and IPv6 UDP endpoints
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct UdpEndpointTable {
+        pub oid: Vec<u16>,
+        pub udpEndpointProcess: MibValue,
+    }
+
     #[derive(Serialize, Deserialize)]
     pub struct Egp {
         pub oid: Vec<u16>,
@@ -296,11 +420,305 @@ pub mod MibModule {
         pub snmpEnableAuthenTraps: MibValue,
     }
 
+    /// LLDP-MIB lldpLocalSystemData and lldpRemoteSystemsData
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct Lldp {
+        pub oid: Vec<u16>,
+        pub lldpLocChassisIdSubtype: MibValue,
+        pub lldpLocChassisId: MibValue,
+        pub lldpLocSysName: MibValue,
+        pub lldpLocPortTable: LldpLocPortTable,
+        pub lldpRemTable: LldpRemTable,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct LldpLocPortTable {
+        pub oid: Vec<u16>,
+        pub lldpLocPortIdSubtype: MibValue,
+        pub lldpLocPortId: MibValue,
+        pub lldpLocPortDesc: MibValue,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct LldpRemTable {
+        pub oid: Vec<u16>,
+        pub lldpRemChassisIdSubtype: MibValue,
+        pub lldpRemChassisId: MibValue,
+        pub lldpRemPortIdSubtype: MibValue,
+        pub lldpRemPortId: MibValue,
+        pub lldpRemPortDesc: MibValue,
+        pub lldpRemSysName: MibValue,
+        pub lldpRemSysDesc: MibValue,
+    }
+
+    /// HOST-RESOURCES-MIB
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct HostResources {
+        pub oid: Vec<u16>,
+        pub hrSystemUptime: MibValue,
+        pub hrSystemNumUsers: MibValue,
+        pub hrSystemProcesses: MibValue,
+        pub hrSystemMaxProcesses: MibValue,
+        pub hrMemorySize: MibValue,
+        pub hrStorageTable: HrStorageTable,
+        pub hrDeviceTable: HrDeviceTable,
+        pub hrProcessorTable: HrProcessorTable,
+        pub hrSWRunTable: HrSWRunTable,
+        pub hrSWRunPerfTable: HrSWRunPerfTable,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct HrStorageTable {
+        pub oid: Vec<u16>,
+        pub hrStorageIndex: MibValue,
+        pub hrStorageType: MibValue,
+        pub hrStorageDescr: MibValue,
+        pub hrStorageAllocationUnits: MibValue,
+        pub hrStorageSize: MibValue,
+        pub hrStorageUsed: MibValue,
+        pub hrStorageAllocationFailures: MibValue,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct HrDeviceTable {
+        pub oid: Vec<u16>,
+        pub hrDeviceIndex: MibValue,
+        pub hrDeviceType: MibValue,
+        pub hrDeviceDescr: MibValue,
+        pub hrDeviceStatus: MibValue,
+        pub hrDeviceErrors: MibValue,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct HrProcessorTable {
+        pub oid: Vec<u16>,
+        pub hrProcessorLoad: MibValue,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct HrSWRunTable {
+        pub oid: Vec<u16>,
+        pub hrSWRunIndex: MibValue,
+        pub hrSWRunName: MibValue,
+        pub hrSWRunPath: MibValue,
+        pub hrSWRunParameters: MibValue,
+        pub hrSWRunType: MibValue,
+        pub hrSWRunStatus: MibValue,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct HrSWRunPerfTable {
+        pub oid: Vec<u16>,
+        pub hrSWRunPerfCPU: MibValue,
+        pub hrSWRunPerfMem: MibValue,
+    }
+
+    /// ENTITY-MIB entityPhysical
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct Entity {
+        pub oid: Vec<u16>,
+        pub entPhysicalTable: EntPhysicalTable,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct EntPhysicalTable {
+        pub oid: Vec<u16>,
+        pub entPhysicalDescr: MibValue,
+        pub entPhysicalVendorType: MibValue,
+        pub entPhysicalContainedIn: MibValue,
+        pub entPhysicalClass: MibValue,
+        pub entPhysicalParentRelPos: MibValue,
+        pub entPhysicalName: MibValue,
+        pub entPhysicalHardwareRev: MibValue,
+        pub entPhysicalFirmwareRev: MibValue,
+        pub entPhysicalSoftwareRev: MibValue,
+        pub entPhysicalSerialNum: MibValue,
+        pub entPhysicalMfgName: MibValue,
+        pub entPhysicalModelName: MibValue,
+        pub entPhysicalAlias: MibValue,
+        pub entPhysicalAssetID: MibValue,
+        pub entPhysicalIsFRU: MibValue,
+    }
+
+    /// BRIDGE-MIB and Q-BRIDGE-MIB, the forwarding database and VLANs of a switch
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct Bridge {
+        pub oid: Vec<u16>,
+        pub dot1dBasePortTable: Dot1dBasePortTable,
+        pub dot1dTpFdbTable: Dot1dTpFdbTable,
+        pub dot1qTpFdbTable: Dot1qTpFdbTable,
+        pub dot1qVlanStaticTable: Dot1qVlanStaticTable,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct Dot1dBasePortTable {
+        pub oid: Vec<u16>,
+        pub dot1dBasePort: MibValue,
+        pub dot1dBasePortIfIndex: MibValue,
+        pub dot1dBasePortCircuit: MibValue,
+        pub dot1dBasePortDelayExceededDiscards: MibValue,
+        pub dot1dBasePortMtuExceededDiscards: MibValue,
+    }
+
+    /// the MAC addresses learned by a bridge without VLANs, indexed by MAC
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct Dot1dTpFdbTable {
+        pub oid: Vec<u16>,
+        pub dot1dTpFdbAddress: MibValue,
+        pub dot1dTpFdbPort: MibValue,
+        pub dot1dTpFdbStatus: MibValue,
+    }
+
+    /// the MAC addresses learned per filtering database, indexed by dot1qFdbId and MAC
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct Dot1qTpFdbTable {
+        pub oid: Vec<u16>,
+        pub dot1qTpFdbPort: MibValue,
+        pub dot1qTpFdbStatus: MibValue,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct Dot1qVlanStaticTable {
+        pub oid: Vec<u16>,
+        pub dot1qVlanStaticName: MibValue,
+        pub dot1qVlanStaticEgressPorts: MibValue,
+        pub dot1qVlanForbiddenEgressPorts: MibValue,
+        pub dot1qVlanStaticUntaggedPorts: MibValue,
+        pub dot1qVlanStaticRowStatus: MibValue,
+    }
+
+    /// UCD-SNMP-MIB, the system statistics of Net-SNMP agents, and the output of the commands
+    /// their `extend` directives run (NET-SNMP-EXTEND-MIB)
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct Ucd {
+        pub oid: Vec<u16>,
+        pub memTotalSwap: MibValue,
+        pub memAvailSwap: MibValue,
+        pub memTotalReal: MibValue,
+        pub memAvailReal: MibValue,
+        pub memTotalFree: MibValue,
+        pub memShared: MibValue,
+        pub memBuffer: MibValue,
+        pub memCached: MibValue,
+        pub ssCpuRawUser: MibValue,
+        pub ssCpuRawNice: MibValue,
+        pub ssCpuRawSystem: MibValue,
+        pub ssCpuRawIdle: MibValue,
+        pub ssCpuRawWait: MibValue,
+        pub ssCpuRawKernel: MibValue,
+        pub ssCpuRawInterrupt: MibValue,
+        pub ssCpuRawSoftIRQ: MibValue,
+        pub ssCpuRawSteal: MibValue,
+        pub laTable: LaTable,
+        pub dskTable: DskTable,
+        pub nsExtendOutput1Table: NsExtendOutput1Table,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct LaTable {
+        pub oid: Vec<u16>,
+        pub laIndex: MibValue,
+        pub laNames: MibValue,
+        pub laLoad: MibValue,
+        pub laConfig: MibValue,
+        pub laLoadInt: MibValue,
+        pub laLoadFloat: MibValue,
+        pub laErrorFlag: MibValue,
+        pub laErrMessage: MibValue,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct DskTable {
+        pub oid: Vec<u16>,
+        pub dskIndex: MibValue,
+        pub dskPath: MibValue,
+        pub dskDevice: MibValue,
+        pub dskTotal: MibValue,
+        pub dskAvail: MibValue,
+        pub dskUsed: MibValue,
+        pub dskPercent: MibValue,
+        pub dskPercentNode: MibValue,
+        pub dskTotalLow: MibValue,
+        pub dskTotalHigh: MibValue,
+        pub dskAvailLow: MibValue,
+        pub dskAvailHigh: MibValue,
+        pub dskUsedLow: MibValue,
+        pub dskUsedHigh: MibValue,
+        pub dskErrorFlag: MibValue,
+        pub dskErrorMsg: MibValue,
+    }
+
+    /// the output of every extend command, indexed by its nsExtendToken
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct NsExtendOutput1Table {
+        pub oid: Vec<u16>,
+        pub nsExtendOutput1Line: MibValue,
+        pub nsExtendOutputFull: MibValue,
+        pub nsExtendOutNumLines: MibValue,
+        pub nsExtendResult: MibValue,
+    }
+
+    /// the objects and tables a vendor profile adds to targets whose sysObjectID it matches,
+    /// empty for every other target
+    #[derive(Serialize, Deserialize, Clone, Default)]
+    pub struct Vendor {
+        pub profile: String,
+        pub objects: Vec<MibValue>,
+        pub tables: Vec<VendorTable>,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct VendorTable {
+        pub name: String,
+        pub columns: Vec<MibValue>,
+    }
+
+    /// CISCO-CDP-MIB cdpCache
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct Cdp {
+        pub oid: Vec<u16>,
+        pub cdpCacheTable: CdpCacheTable,
+    }
+
+    #[derive(Serialize, Deserialize, Clone)]
+    pub struct CdpCacheTable {
+        pub oid: Vec<u16>,
+        pub cdpCacheAddressType: MibValue,
+        pub cdpCacheAddress: MibValue,
+        pub cdpCacheVersion: MibValue,
+        pub cdpCacheDeviceId: MibValue,
+        pub cdpCacheDevicePort: MibValue,
+        pub cdpCachePlatform: MibValue,
+    }
+
+    /// a walk of one target, which stops sending requests once one of them timed out
+    pub struct Walk<'a> {
+        client: &'a Snmp2cClient,
+        timed_out: AtomicBool,
+    }
+
+    impl Walk<'_> {
+        async fn walk_bulk(&self, oid: ObjectIdentifier, max_repetitions: u32) -> Result<BTreeMap<ObjectIdentifier, ObjectValue>, SnmpClientError> {
+            if self.timed_out.load(Ordering::Relaxed) {
+                return Err(SnmpClientError::TimedOut);
+            }
+            let result = self.client.walk_bulk(oid, max_repetitions).await;
+            if let Err(SnmpClientError::TimedOut) = result {
+                self.timed_out.store(true, Ordering::Relaxed);
+            }
+            result
+        }
+    }
+
     impl MibObject {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
             println!("looking in mibobject for oid {:?}[..6] = {:?} vs {:?}", oid, &oid[..6], &self.ip.oid[..]);
+            if let Some(value) = self.pinned.iter().find_map(|value| value.clone_instance(&oid)) {
+                return Some(value);
+            }
             match &oid[..7] {
                 oid_slice if oid_slice == &self.system.oid[..] => self.system.find_oid(oid),
+                _ if oid.starts_with(&self.interfaces.ifXTable.oid) => self.interfaces.ifXTable.find_oid(oid),
                 oid_slice if oid_slice == &self.interfaces.oid[..] => self.interfaces.find_oid(oid),
                 oid_slice if oid_slice == &self.at.oid[..] => self.at.find_oid(oid),
                 oid_slice if oid_slice == &self.ip.oid[..] => self.ip.find_oid(oid),
@@ -310,11 +728,20 @@ pub mod MibModule {
                 oid_slice if oid_slice == &self.egp.oid[..] => self.egp.find_oid(oid),
                 oid_slice if self.transmission.has_oid(oid_slice) => Some(self.transmission.clone()),
                 oid_slice if oid_slice == &self.snmp.oid[..] => self.snmp.find_oid(oid),
-                _ => None,
+                oid_slice if oid_slice == &self.host.oid[..] => self.host.find_oid(oid),
+                _ if oid.starts_with(&self.lldp.oid) => self.lldp.find_oid(oid),
+                _ if oid.starts_with(&self.cdp.oid) => self.cdp.find_oid(oid),
+                _ if oid.starts_with(&self.entity.oid) => self.entity.find_oid(oid),
+                _ if oid.starts_with(&self.bridge.oid) => self.bridge.find_oid(oid),
+                _ if oid.starts_with(&self.ucd.oid) || oid.starts_with(&self.ucd.nsExtendOutput1Table.oid) => self.ucd.find_oid(oid),
+                _ => self.vendor.find_oid(oid),
             }
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        /// walks every object of the target, giving up on the rest after the first request that
+        /// timed out; false when it did
+        pub async fn walk(&mut self, client: &Snmp2cClient) -> bool {
+            let client = &Walk { client, timed_out: AtomicBool::new(false) };
             self.system.walk(client).await;
             self.interfaces.walk(client).await;
             self.at.walk(client).await;
@@ -325,6 +752,86 @@ pub mod MibModule {
             self.egp.walk(client).await;
             self.transmission.walk(client).await;
             self.snmp.walk(client).await;
+            self.lldp.walk(client).await;
+            self.cdp.walk(client).await;
+            self.host.walk(client).await;
+            self.entity.walk(client).await;
+            self.bridge.walk(client).await;
+            self.ucd.walk(client).await;
+            self.vendor.walk(client).await;
+            for value in self.pinned.iter_mut() {
+                value.walk(client).await;
+            }
+            !client.timed_out.load(Ordering::Relaxed)
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = vec![
+                &self.transmission,
+            ];
+            values.extend(self.system.values());
+            values.extend(self.interfaces.values());
+            values.extend(self.at.values());
+            values.extend(self.ip.values());
+            values.extend(self.icmp.values());
+            values.extend(self.tcp.values());
+            values.extend(self.udp.values());
+            values.extend(self.egp.values());
+            values.extend(self.snmp.values());
+            values.extend(self.lldp.values());
+            values.extend(self.cdp.values());
+            values.extend(self.host.values());
+            values.extend(self.entity.values());
+            values.extend(self.bridge.values());
+            values.extend(self.ucd.values());
+            values.extend(self.vendor.values());
+            values.extend(self.pinned.iter());
+            values
+        }
+
+        pub fn samples(&self) -> Vec<MibSample> {
+            self.values().into_iter().flat_map(|value| value.samples()).collect()
+        }
+
+        /// the columns of every table, keyed by the table's name
+        pub fn tables(&self) -> Vec<(&'static str, Vec<&MibValue>)> {
+            vec![
+                ("ifTable", self.interfaces.ifTable.values()),
+                ("ifXTable", self.interfaces.ifXTable.values()),
+                ("atTable", self.at.atTable.values()),
+                ("ipAddrTable", self.ip.ipAddrTable.values()),
+                ("ipRouteTable", self.ip.ipRouteTable.values()),
+                ("ipNetToMediaTable", self.ip.ipNetToMediaTable.values()),
+                ("ipAddressTable", self.ip.ipAddressTable.values()),
+                ("ipNetToPhysicalTable", self.ip.ipNetToPhysicalTable.values()),
+                ("inetCidrRouteTable", self.ip.inetCidrRouteTable.values()),
+                ("tcpConnTable", self.tcp.tcpConnTable.values()),
+                ("tcpConnectionTable", self.tcp.tcpConnectionTable.values()),
+                ("tcpListenerTable", self.tcp.tcpListenerTable.values()),
+                ("udpTable", self.udp.udpTable.values()),
+                ("udpEndpointTable", self.udp.udpEndpointTable.values()),
+                ("egpNeighTable", self.egp.egpNeighTable.values()),
+                ("lldpLocPortTable", self.lldp.lldpLocPortTable.values()),
+                ("lldpRemTable", self.lldp.lldpRemTable.values()),
+                ("cdpCacheTable", self.cdp.cdpCacheTable.values()),
+                ("hrStorageTable", self.host.hrStorageTable.values()),
+                ("hrDeviceTable", self.host.hrDeviceTable.values()),
+                ("hrProcessorTable", self.host.hrProcessorTable.values()),
+                ("hrSWRunTable", self.host.hrSWRunTable.values()),
+                ("hrSWRunPerfTable", self.host.hrSWRunPerfTable.values()),
+                ("entPhysicalTable", self.entity.entPhysicalTable.values()),
+                ("dot1dBasePortTable", self.bridge.dot1dBasePortTable.values()),
+                ("dot1dTpFdbTable", self.bridge.dot1dTpFdbTable.values()),
+                ("dot1qTpFdbTable", self.bridge.dot1qTpFdbTable.values()),
+                ("dot1qVlanStaticTable", self.bridge.dot1qVlanStaticTable.values()),
+                ("laTable", self.ucd.laTable.values()),
+                ("dskTable", self.ucd.dskTable.values()),
+                ("nsExtendOutput1Table", self.ucd.nsExtendOutput1Table.values()),
+            ]
+        }
+
+        pub fn table(&self, name: &str) -> Option<Vec<&MibValue>> {
+            self.tables().into_iter().find(|a| a.0 == name).map(|a| a.1).or_else(|| self.vendor.table(name))
         }
 
         pub fn egui_show(&self, ctx: &egui::Context, app: &mut SnmpMonitorApp) {
@@ -340,6 +847,20 @@ pub mod MibModule {
                     self.egp.egui_show(app, ctx, ui);
                     self.transmission.egui_show(app, ctx, ui);
                     self.snmp.egui_show(app, ctx, ui);
+                    self.lldp.egui_show(app, ctx, ui);
+                    self.cdp.egui_show(app, ctx, ui);
+                    self.host.egui_show(app, ctx, ui);
+                    self.entity.egui_show(app, ctx, ui);
+                    self.bridge.egui_show(app, ctx, ui);
+                    self.ucd.egui_show(app, ctx, ui);
+                    self.vendor.egui_show(app, ctx, ui);
+                    if !self.pinned.is_empty() {
+                        ui.collapsing("Pinned", |ui| {
+                            for value in &self.pinned {
+                                value.egui_show(app, ctx, ui);
+                            }
+                        });
+                    }
                 });
             });
         }
@@ -359,7 +880,7 @@ pub mod MibModule {
             }
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.sysDesc.walk(client).await;
             self.sysObjectID.walk(client).await;
             self.sysUpTime.walk(client).await;
@@ -369,6 +890,18 @@ pub mod MibModule {
             self.sysServices.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.sysDesc,
+                &self.sysObjectID,
+                &self.sysUpTime,
+                &self.sysContact,
+                &self.sysName,
+                &self.sysLocation,
+                &self.sysServices,
+            ]
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("System", |ui| {
                 self.sysDesc.egui_show(app, ctx, ui);
@@ -391,49 +924,93 @@ pub mod MibModule {
             }
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.ifNumber.walk(client).await;
             self.ifTable.walk(client).await;
+            self.ifXTable.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = vec![
+                &self.ifNumber,
+            ];
+            values.extend(self.ifTable.values());
+            values.extend(self.ifXTable.values());
+            values
         }
 
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("Interfaces", |ui| {
                 self.ifNumber.egui_show(app, ctx, ui);
                 self.ifTable.egui_show(app, ctx, ui);
+                open_table_show(app, ui, "ifXTable");
+                open_table_show(app, ui, "utilisation");
             });
         }
     }
 
+    impl IfXTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.ifName.walk(client).await;
+            self.ifInMulticastPkts.walk(client).await;
+            self.ifInBroadcastPkts.walk(client).await;
+            self.ifOutMulticastPkts.walk(client).await;
+            self.ifOutBroadcastPkts.walk(client).await;
+            self.ifHCInOctets.walk(client).await;
+            self.ifHCInUcastPkts.walk(client).await;
+            self.ifHCInMulticastPkts.walk(client).await;
+            self.ifHCInBroadcastPkts.walk(client).await;
+            self.ifHCOutOctets.walk(client).await;
+            self.ifHCOutUcastPkts.walk(client).await;
+            self.ifHCOutMulticastPkts.walk(client).await;
+            self.ifHCOutBroadcastPkts.walk(client).await;
+            self.ifLinkUpDownTrapEnable.walk(client).await;
+            self.ifHighSpeed.walk(client).await;
+            self.ifPromiscuousMode.walk(client).await;
+            self.ifConnectorPresent.walk(client).await;
+            self.ifAlias.walk(client).await;
+            self.ifCounterDiscontinuityTime.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.ifName,
+                &self.ifInMulticastPkts,
+                &self.ifInBroadcastPkts,
+                &self.ifOutMulticastPkts,
+                &self.ifOutBroadcastPkts,
+                &self.ifHCInOctets,
+                &self.ifHCInUcastPkts,
+                &self.ifHCInMulticastPkts,
+                &self.ifHCInBroadcastPkts,
+                &self.ifHCOutOctets,
+                &self.ifHCOutUcastPkts,
+                &self.ifHCOutMulticastPkts,
+                &self.ifHCOutBroadcastPkts,
+                &self.ifLinkUpDownTrapEnable,
+                &self.ifHighSpeed,
+                &self.ifPromiscuousMode,
+                &self.ifConnectorPresent,
+                &self.ifAlias,
+                &self.ifCounterDiscontinuityTime,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
     impl IfTable {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
-            match &oid[..10] {
-                oid_slice if self.ifIndex.has_oid(oid_slice) => Some(self.ifIndex.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifDescr.has_oid(oid_slice) => Some(self.ifDescr.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifType.has_oid(oid_slice) => Some(self.ifType.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifMtu.has_oid(oid_slice) => Some(self.ifMtu.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifSpeed.has_oid(oid_slice) => Some(self.ifSpeed.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifPhysAddress.has_oid(oid_slice) => Some(self.ifPhysAddress.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifAdminStatus.has_oid(oid_slice) => Some(self.ifAdminStatus.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifOperStatus.has_oid(oid_slice) => Some(self.ifOperStatus.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifLastChange.has_oid(oid_slice) => Some(self.ifLastChange.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifInOctets.has_oid(oid_slice) => Some(self.ifInOctets.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifInUcastPkts.has_oid(oid_slice) => Some(self.ifInUcastPkts.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifInNUcastPkts.has_oid(oid_slice) => Some(self.ifInNUcastPkts.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifInDiscards.has_oid(oid_slice) => Some(self.ifInDiscards.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifInErrors.has_oid(oid_slice) => Some(self.ifInErrors.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifInUnknownProtos.has_oid(oid_slice) => Some(self.ifInUnknownProtos.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifOutOctets.has_oid(oid_slice) => Some(self.ifOutOctets.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifOutUcastPkts.has_oid(oid_slice) => Some(self.ifOutUcastPkts.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifOutNUcastPkts.has_oid(oid_slice) => Some(self.ifOutNUcastPkts.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifOutDiscards.has_oid(oid_slice) => Some(self.ifOutDiscards.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifOutErrors.has_oid(oid_slice) => Some(self.ifOutErrors.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifOutQLen.has_oid(oid_slice) => Some(self.ifOutQLen.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ifSpecific.has_oid(oid_slice) => Some(self.ifSpecific.clone_index(oid.last().unwrap().to_owned() as usize)),
-                _ => None,
-            }
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.ifIndex.walk(client).await;
             self.ifDescr.walk(client).await;
             self.ifType.walk(client).await;
@@ -458,6 +1035,33 @@ pub mod MibModule {
             self.ifSpecific.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.ifIndex,
+                &self.ifDescr,
+                &self.ifType,
+                &self.ifMtu,
+                &self.ifSpeed,
+                &self.ifPhysAddress,
+                &self.ifAdminStatus,
+                &self.ifOperStatus,
+                &self.ifLastChange,
+                &self.ifInOctets,
+                &self.ifInUcastPkts,
+                &self.ifInNUcastPkts,
+                &self.ifInDiscards,
+                &self.ifInErrors,
+                &self.ifInUnknownProtos,
+                &self.ifOutOctets,
+                &self.ifOutUcastPkts,
+                &self.ifOutNUcastPkts,
+                &self.ifOutDiscards,
+                &self.ifOutErrors,
+                &self.ifOutQLen,
+                &self.ifSpecific,
+            ]
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("ifTable", |ui| {
                 if ui.add(egui::Button::new("open table")).clicked() {
@@ -469,85 +1073,9 @@ pub mod MibModule {
             });
         }
 
-        pub fn egui_table_show(&self, ui: &mut Ui) {
-            egui::ScrollArea::horizontal()
-                .auto_shrink(false)
-                .show(ui, |ui| {
-                    TableBuilder::new(ui)
-                        .striped(true)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifIndex
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifDescr
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifType
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifMtu
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifSpeed
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifPhysAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifAdminStatus
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifOperStatus
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifLastChange
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifInOctets
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifInUcastPkts
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifInNUcastPkts
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifInDiscards
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifInErrors
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifInUnknownProtos
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifOutOctets
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifOutUcastPkts
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifOutNUcastPkts
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifOutDiscards
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifOutErrors
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifOutQLen
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // ifSpecific
-                        .header(26.0, |mut header| {
-                            header.col(|ui| { ui.strong(&self.ifIndex.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifDescr.as_mvstring().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifType.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifMtu.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifSpeed.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifPhysAddress.as_mvipv6().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifAdminStatus.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifOperStatus.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifLastChange.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifInOctets.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifInUcastPkts.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifInNUcastPkts.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifInDiscards.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifInErrors.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifInUnknownProtos.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifOutOctets.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifOutUcastPkts.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifOutNUcastPkts.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifOutDiscards.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifOutErrors.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifOutQLen.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ifSpecific.as_mvoid().unwrap().name); });
-                        })
-                        .body(|body| {
-                            body.rows(26.0, self.ifIndex.as_mvinti32().unwrap().value.len(), |index, mut row| {
-                                row.col(|ui| match &self.ifIndex.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifIndex.as_mvinti32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ifDescr.as_mvstring().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifDescr.as_mvstring().unwrap().value.get(index).unwrap());}, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ifType.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifType.as_mvinti32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifMtu.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifMtu.as_mvinti32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifSpeed.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifSpeed.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifPhysAddress.as_mvipv6().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(format!("{:02x?}", self.ifPhysAddress.as_mvipv6().unwrap().value.get(index).unwrap())); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ifAdminStatus.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifAdminStatus.as_mvinti32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifOperStatus.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifOperStatus.as_mvinti32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifLastChange.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifLastChange.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifInOctets.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifInOctets.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifInUcastPkts.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifInUcastPkts.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifInNUcastPkts.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifInNUcastPkts.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifInDiscards.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifInDiscards.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifInErrors.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifInErrors.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifInUnknownProtos.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifInUnknownProtos.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifOutOctets.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifOutOctets.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifOutUcastPkts.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifOutUcastPkts.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifOutNUcastPkts.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifOutNUcastPkts.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifOutDiscards.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifOutDiscards.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifOutErrors.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifOutErrors.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifOutQLen.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifOutQLen.as_mvintu32().unwrap().value.get(index).unwrap().to_string()); }, _ => { ui.spinner(); }});
-                                row.col(|ui| match &self.ifSpecific.as_mvoid().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(self.ifSpecific.as_mvoid().unwrap().value.get(index).unwrap().into_iter().map(|b| b.to_string()).collect::<Vec<String>>().join("."),); }, _ => { ui.spinner(); }});
-                            });
-                        });
-                });
+        /// shows every interface, scrolling to the row at `scroll_to` when given
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu, scroll_to: Option<usize>) {
+            egui_index_rows_show(ui, &["index".to_owned()], &self.values(), &index_rows(&self.values()), menu, scroll_to);
         }
     }
 
@@ -559,10 +1087,14 @@ pub mod MibModule {
             }
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.atTable.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            self.atTable.values()
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("At", |ui| {
                 self.atTable.egui_show(app, ctx, ui);
@@ -572,20 +1104,23 @@ pub mod MibModule {
 
     impl AtTable {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
-            match &oid[..10] {
-                oid_slice if self.atIfIndex.has_oid(oid_slice) => Some(self.atIfIndex.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.atPhysAddress.has_oid(oid_slice) => Some(self.atPhysAddress.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.atNetAddress.has_oid(oid_slice) => Some(self.atNetAddress.clone_index(oid.last().unwrap().to_owned() as usize)),
-                _ => None,
-            }
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.atIfIndex.walk(client).await;
             self.atPhysAddress.walk(client).await;
             self.atNetAddress.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.atIfIndex,
+                &self.atPhysAddress,
+                &self.atNetAddress,
+            ]
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("atTable", |ui| {
                 if ui.add(egui::Button::new("open table")).clicked() {
@@ -597,28 +1132,8 @@ pub mod MibModule {
             });
         }
 
-        pub fn egui_table_show(&self, ui: &mut Ui) {
-            egui::ScrollArea::horizontal()
-                .auto_shrink(false)
-                .show(ui, |ui| {
-                    TableBuilder::new(ui)
-                        .striped(true)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atIfIndex
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atPhysAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .header(26.0, |mut header| {
-                            header.col(|ui| { ui.strong(&self.atIfIndex.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.atPhysAddress.as_mvipv6().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.atNetAddress.as_mvipv4().unwrap().name); });
-                        })
-                        .body(|body| {
-                            body.rows(26.0, self.atIfIndex.as_mvinti32().unwrap().value.len(), |index, mut row| {
-                                row.col(|ui| match &self.atIfIndex.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.atIfIndex.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.atPhysAddress.as_mvipv6().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:02x?}", self.atPhysAddress.as_mvinti32().unwrap().value.clone().get(index).unwrap()));}, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.atNetAddress.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:?}", self.atNetAddress.as_mvinti32().unwrap().value.clone().get(index).unwrap()));}, _ => { ui.spinner(); } });
-                            });
-                        });
-                });
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
         }
     }
 
@@ -626,6 +1141,9 @@ pub mod MibModule {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
             println!("looking in ip values for oid {:?}", oid);
             match &oid[..8] {
+                _ if oid.starts_with(&self.ipAddressTable.oid) => self.ipAddressTable.find_oid(oid),
+                _ if oid.starts_with(&self.ipNetToPhysicalTable.oid) => self.ipNetToPhysicalTable.find_oid(oid),
+                _ if oid.starts_with(&self.inetCidrRouteTable.oid) => self.inetCidrRouteTable.find_oid(oid),
                 oid_slice if self.ipForwarding.has_oid(oid_slice) => Some(self.ipForwarding.clone()),
                 oid_slice if self.ipDefaultTTL.has_oid(oid_slice) => Some(self.ipDefaultTTL.clone()),
                 oid_slice if self.ipInReceives.has_oid(oid_slice) => Some(self.ipInReceives.clone()),
@@ -653,7 +1171,7 @@ pub mod MibModule {
             }
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.ipForwarding.walk(client).await;
             self.ipDefaultTTL.walk(client).await;
             self.ipInReceives.walk(client).await;
@@ -677,6 +1195,41 @@ pub mod MibModule {
             self.ipRouteTable.walk(client).await;
             self.ipNetToMediaTable.walk(client).await;
             self.ipRoutingDiscards.walk(client).await;
+            self.ipAddressTable.walk(client).await;
+            self.ipNetToPhysicalTable.walk(client).await;
+            self.inetCidrRouteTable.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = vec![
+                &self.ipForwarding,
+                &self.ipDefaultTTL,
+                &self.ipInReceives,
+                &self.ipInHdrErrors,
+                &self.ipInAddrErrors,
+                &self.ipForwDatagrams,
+                &self.ipInUnknownProtos,
+                &self.ipInDiscards,
+                &self.ipInDelivers,
+                &self.ipOutRequests,
+                &self.ipOutDiscards,
+                &self.ipOutNoRoutes,
+                &self.ipReasmTimeout,
+                &self.ipReasmReqds,
+                &self.ipReasmOKs,
+                &self.ipReasmFails,
+                &self.ipFragOKs,
+                &self.ipFragFails,
+                &self.ipFragCreates,
+                &self.ipRoutingDiscards,
+            ];
+            values.extend(self.ipAddrTable.values());
+            values.extend(self.ipRouteTable.values());
+            values.extend(self.ipNetToMediaTable.values());
+            values.extend(self.ipAddressTable.values());
+            values.extend(self.ipNetToPhysicalTable.values());
+            values.extend(self.inetCidrRouteTable.values());
+            values
         }
 
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
@@ -704,23 +1257,19 @@ pub mod MibModule {
                 self.ipRouteTable.egui_show(app, ctx, ui);
                 self.ipNetToMediaTable.egui_show(app, ctx, ui);
                 self.ipRoutingDiscards.egui_show(app, ctx, ui);
+                open_table_show(app, ui, "ipAddressTable");
+                open_table_show(app, ui, "ipNetToPhysicalTable");
+                open_table_show(app, ui, "inetCidrRouteTable");
             });
         }
     }
 
     impl IpAddrTable {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
-            match &oid[..10] {
-                oid_slice if self.ipAdEntAddr.has_oid(oid_slice) => Some(self.ipAdEntAddr.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipAdEntIfIndex.has_oid(oid_slice) => Some(self.ipAdEntIfIndex.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipAdEntNetMask.has_oid(oid_slice) => Some(self.ipAdEntNetMask.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipAdEntBcastAddr.has_oid(oid_slice) => Some(self.ipAdEntBcastAddr.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipAdEntReasmMaxSize.has_oid(oid_slice) => Some(self.ipAdEntReasmMaxSize.clone_index(oid.last().unwrap().to_owned() as usize)),
-                _ => None,
-            }
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.ipAdEntAddr.walk(client).await;
             self.ipAdEntIfIndex.walk(client).await;
             self.ipAdEntNetMask.walk(client).await;
@@ -728,6 +1277,16 @@ pub mod MibModule {
             self.ipAdEntReasmMaxSize.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.ipAdEntAddr,
+                &self.ipAdEntIfIndex,
+                &self.ipAdEntNetMask,
+                &self.ipAdEntBcastAddr,
+                &self.ipAdEntReasmMaxSize,
+            ]
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("ipAddrTable", |ui| {
                 if ui.add(egui::Button::new("open table")).clicked() {
@@ -737,58 +1296,17 @@ pub mod MibModule {
             });
         }
 
-        pub fn egui_table_show(&self, ui: &mut Ui) {
-            egui::ScrollArea::horizontal()
-                .auto_shrink(false)
-                .show(ui, |ui| {
-                    TableBuilder::new(ui)
-                        .striped(true)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atIfIndex
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atPhysAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .header(26.0, |mut header| {
-                            header.col(|ui| { ui.strong(&self.ipAdEntAddr.as_mvipv4().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipAdEntIfIndex.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipAdEntNetMask.as_mvipv4().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipAdEntBcastAddr.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipAdEntReasmMaxSize.as_mvinti32().unwrap().name); });
-                        })
-                        .body(|body| {
-                            body.rows(26.0, self.ipAdEntAddr.as_mvipv4().unwrap().value.len(), |index, mut row| {
-                                row.col(|ui| match &self.ipAdEntAddr.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:?}",self.ipAdEntAddr.as_mvipv4().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipAdEntIfIndex.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipAdEntIfIndex.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipAdEntNetMask.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:?}",self.ipAdEntNetMask.as_mvipv4().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipAdEntBcastAddr.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipAdEntBcastAddr.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipAdEntReasmMaxSize.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipAdEntReasmMaxSize.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                            });
-                        });
-                });
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
         }
     }
 
     impl IpRouteTable {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
-            match &oid[..10] {
-                oid_slice if self.ipRouteDest.has_oid(oid_slice) => Some(self.ipRouteDest.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteIfIndex.has_oid(oid_slice) => Some(self.ipRouteIfIndex.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteMetric1.has_oid(oid_slice) => Some(self.ipRouteMetric1.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteMetric2.has_oid(oid_slice) => Some(self.ipRouteMetric2.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteMetric3.has_oid(oid_slice) => Some(self.ipRouteMetric3.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteMetric4.has_oid(oid_slice) => Some(self.ipRouteMetric4.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteNextHop.has_oid(oid_slice) => Some(self.ipRouteNextHop.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteType.has_oid(oid_slice) => Some(self.ipRouteType.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteProto.has_oid(oid_slice) => Some(self.ipRouteProto.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteAge.has_oid(oid_slice) => Some(self.ipRouteAge.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteMask.has_oid(oid_slice) => Some(self.ipRouteMask.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteMetric5.has_oid(oid_slice) => Some(self.ipRouteMetric5.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipRouteInfo.has_oid(oid_slice) => Some(self.ipRouteInfo.clone_index(oid.last().unwrap().to_owned() as usize)),
-                _ => None,
-            }
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.ipRouteDest.walk(client).await;
             self.ipRouteIfIndex.walk(client).await;
             self.ipRouteMetric1.walk(client).await;
@@ -804,6 +1322,24 @@ pub mod MibModule {
             self.ipRouteInfo.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.ipRouteDest,
+                &self.ipRouteIfIndex,
+                &self.ipRouteMetric1,
+                &self.ipRouteMetric2,
+                &self.ipRouteMetric3,
+                &self.ipRouteMetric4,
+                &self.ipRouteNextHop,
+                &self.ipRouteType,
+                &self.ipRouteProto,
+                &self.ipRouteAge,
+                &self.ipRouteMask,
+                &self.ipRouteMetric5,
+                &self.ipRouteInfo,
+            ]
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("ipRouteTable", |ui| {
                 if ui.add(egui::Button::new("open table")).clicked() {
@@ -815,79 +1351,32 @@ pub mod MibModule {
             });
         }
 
-        pub fn egui_table_show(&self, ui: &mut Ui) {
-            egui::ScrollArea::horizontal()
-                .auto_shrink(false)
-                .show(ui, |ui| {
-                    TableBuilder::new(ui)
-                        .striped(true)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atIfIndex
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atPhysAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true)) // atNetAddress
-                        .header(26.0, |mut header| {
-                            header.col(|ui| { ui.strong(&self.ipRouteDest.as_mvipv4().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteIfIndex.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteMetric1.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteMetric2.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteMetric3.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteMetric4.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteNextHop.as_mvipv4().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteType.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteProto.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteAge.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteMask.as_mvipv4().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteMetric5.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipRouteInfo.as_mvoid().unwrap().name); });
-                        })
-                        .body(|body| {
-                            body.rows(26.0, self.ipRouteIfIndex.as_mvinti32().unwrap().value.len(), |index, mut row| {
-                                row.col(|ui| match &self.ipRouteDest.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:?}", self.ipRouteDest.as_mvipv4().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteIfIndex.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipRouteIfIndex.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteMetric1.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipRouteMetric1.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteMetric2.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipRouteMetric2.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteMetric3.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipRouteMetric3.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteMetric4.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipRouteMetric4.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteNextHop.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:?}", self.ipRouteNextHop.as_mvipv4().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteType.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipRouteType.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteProto.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipRouteProto.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteAge.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipRouteAge.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteMask.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:?}", self.ipRouteMask.as_mvipv4().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteMetric5.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipRouteMetric5.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.ipRouteInfo.as_mvoid().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:02x?}", self.ipRouteInfo.as_mvoid().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } });
-                            });
-                        });
-                });
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
         }
     }
 
     impl IpNetToMediaTable {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
-            match &oid[..10] {
-                oid_slice if self.ipNetToMediaIfIndex.has_oid(oid_slice) => Some(self.ipNetToMediaIfIndex.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipNetToMediaPhysAddress.has_oid(oid_slice) => Some(self.ipNetToMediaPhysAddress.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipNetToMediaNetAddress.has_oid(oid_slice) => Some(self.ipNetToMediaNetAddress.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.ipNetToMediaType.has_oid(oid_slice) => Some(self.ipNetToMediaType.clone_index(oid.last().unwrap().to_owned() as usize)),
-                _ => None,
-            }
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.ipNetToMediaIfIndex.walk(client).await;
             self.ipNetToMediaPhysAddress.walk(client).await;
             self.ipNetToMediaNetAddress.walk(client).await;
             self.ipNetToMediaType.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.ipNetToMediaIfIndex,
+                &self.ipNetToMediaPhysAddress,
+                &self.ipNetToMediaNetAddress,
+                &self.ipNetToMediaType,
+            ]
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("ipNetToMediaTable", |ui| {
                 if ui.add(egui::Button::new("open table")).clicked() {
@@ -899,32 +1388,112 @@ pub mod MibModule {
             });
         }
 
-        pub fn egui_table_show(&self, ui: &mut Ui) {
-            egui::ScrollArea::horizontal()
-                .auto_shrink(false)
-                .show(ui, |ui| {
-                    TableBuilder::new(ui)
-                        .striped(true)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atIfIndex
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atPhysAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atNetAddress
-                        .header(26.0, |mut header| {
-                            header.col(|ui| { ui.strong(&self.ipNetToMediaIfIndex.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipNetToMediaPhysAddress.as_mvipv6().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipNetToMediaNetAddress.as_mvipv4().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.ipNetToMediaType.as_mvinti32().unwrap().name); });
-                        })
-                        .body(|body| {
-                            body.rows(26.0, self.ipNetToMediaIfIndex.as_mvinti32().unwrap().value.len(), |index, mut row| {
-                                    row.col(|ui| { match &self.ipNetToMediaIfIndex.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipNetToMediaIfIndex.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } }});
-                                    row.col(|ui| { match &self.ipNetToMediaPhysAddress.as_mvipv6().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(format!("{:02x?}", self.ipNetToMediaPhysAddress.as_mvipv6().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } } });
-                                    row.col(|ui| { match &self.ipNetToMediaNetAddress.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => {ui.label(format!("{:?}", self.ipNetToMediaNetAddress.as_mvipv4().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } } });
-                                    row.col(|ui| { match &self.ipNetToMediaType.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.ipNetToMediaType.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } }});
-                                },
-                            );
-                        });
-                });
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl IpAddressTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.ipAddressIfIndex.walk(client).await;
+            self.ipAddressType.walk(client).await;
+            self.ipAddressPrefix.walk(client).await;
+            self.ipAddressOrigin.walk(client).await;
+            self.ipAddressStatus.walk(client).await;
+            self.ipAddressCreated.walk(client).await;
+            self.ipAddressLastChanged.walk(client).await;
+            self.ipAddressRowStatus.walk(client).await;
+            self.ipAddressStorageType.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.ipAddressIfIndex,
+                &self.ipAddressType,
+                &self.ipAddressPrefix,
+                &self.ipAddressOrigin,
+                &self.ipAddressStatus,
+                &self.ipAddressCreated,
+                &self.ipAddressLastChanged,
+                &self.ipAddressRowStatus,
+                &self.ipAddressStorageType,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_inet_rows_show(ui, &self.oid, &self.values(), menu);
+        }
+    }
+
+    impl IpNetToPhysicalTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.ipNetToPhysicalPhysAddress.walk(client).await;
+            self.ipNetToPhysicalLastUpdated.walk(client).await;
+            self.ipNetToPhysicalType.walk(client).await;
+            self.ipNetToPhysicalState.walk(client).await;
+            self.ipNetToPhysicalRowStatus.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.ipNetToPhysicalPhysAddress,
+                &self.ipNetToPhysicalLastUpdated,
+                &self.ipNetToPhysicalType,
+                &self.ipNetToPhysicalState,
+                &self.ipNetToPhysicalRowStatus,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_inet_rows_show(ui, &self.oid, &self.values(), menu);
+        }
+    }
+
+    impl InetCidrRouteTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.inetCidrRouteIfIndex.walk(client).await;
+            self.inetCidrRouteType.walk(client).await;
+            self.inetCidrRouteProto.walk(client).await;
+            self.inetCidrRouteAge.walk(client).await;
+            self.inetCidrRouteNextHopAS.walk(client).await;
+            self.inetCidrRouteMetric1.walk(client).await;
+            self.inetCidrRouteMetric2.walk(client).await;
+            self.inetCidrRouteMetric3.walk(client).await;
+            self.inetCidrRouteMetric4.walk(client).await;
+            self.inetCidrRouteMetric5.walk(client).await;
+            self.inetCidrRouteStatus.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.inetCidrRouteIfIndex,
+                &self.inetCidrRouteType,
+                &self.inetCidrRouteProto,
+                &self.inetCidrRouteAge,
+                &self.inetCidrRouteNextHopAS,
+                &self.inetCidrRouteMetric1,
+                &self.inetCidrRouteMetric2,
+                &self.inetCidrRouteMetric3,
+                &self.inetCidrRouteMetric4,
+                &self.inetCidrRouteMetric5,
+                &self.inetCidrRouteStatus,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_inet_rows_show(ui, &self.oid, &self.values(), menu);
         }
     }
 
@@ -961,7 +1530,7 @@ pub mod MibModule {
             }
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.icmpInMsgs.walk(client).await;
             self.icmpInErrors.walk(client).await;
             self.icmpInDestUnreachs.walk(client).await;
@@ -990,6 +1559,37 @@ pub mod MibModule {
             self.icmpOutAddrMaskReps.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.icmpInMsgs,
+                &self.icmpInErrors,
+                &self.icmpInDestUnreachs,
+                &self.icmpInTimeExcds,
+                &self.icmpInParmProbs,
+                &self.icmpInSrcQuenchs,
+                &self.icmpInRedirects,
+                &self.icmpInEchos,
+                &self.icmpInEchoReps,
+                &self.icmpInTimestamps,
+                &self.icmpInTimestampReps,
+                &self.icmpInAddrMasks,
+                &self.icmpInAddrMaskReps,
+                &self.icmpOutMsgs,
+                &self.icmpOutErrors,
+                &self.icmpOutDestUnreachs,
+                &self.icmpOutTimeExcds,
+                &self.icmpOutParmProbs,
+                &self.icmpOutSrcQuenchs,
+                &self.icmpOutRedirects,
+                &self.icmpOutEchos,
+                &self.icmpOutEchoReps,
+                &self.icmpOutTimestamps,
+                &self.icmpOutTimestampReps,
+                &self.icmpOutAddrMasks,
+                &self.icmpOutAddrMaskReps,
+            ]
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("Icmp", |ui| {
                 self.icmpInMsgs.egui_show(app, ctx, ui);
@@ -1025,6 +1625,8 @@ pub mod MibModule {
     impl Tcp {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
             match &oid[..8] {
+                _ if oid.starts_with(&self.tcpConnectionTable.oid) => self.tcpConnectionTable.find_oid(oid),
+                _ if oid.starts_with(&self.tcpListenerTable.oid) => self.tcpListenerTable.find_oid(oid),
                 oid_slice if self.tcpRtoAlgorithm.has_oid(oid_slice) => Some(self.tcpRtoAlgorithm.clone()), 
                 oid_slice if self.tcpRtoMin.has_oid(oid_slice) => Some(self.tcpRtoMin.clone()),
                 oid_slice if self.tcpRtoMax.has_oid(oid_slice) => Some(self.tcpRtoMax.clone()),
@@ -1044,7 +1646,7 @@ pub mod MibModule {
             }
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.tcpRtoAlgorithm.walk(client).await;
             self.tcpRtoMin.walk(client).await;
             self.tcpRtoMax.walk(client).await;
@@ -1060,6 +1662,31 @@ pub mod MibModule {
             self.tcpConnTable.walk(client).await;
             self.tcpInErrs.walk(client).await;
             self.tcpOutRsts.walk(client).await;
+            self.tcpConnectionTable.walk(client).await;
+            self.tcpListenerTable.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = vec![
+                &self.tcpRtoAlgorithm,
+                &self.tcpRtoMin,
+                &self.tcpRtoMax,
+                &self.tcpMaxConn,
+                &self.tcpActiveOpens,
+                &self.tcpPassiveOpens,
+                &self.tcpAttemptFails,
+                &self.tcpEstabResets,
+                &self.tcpCurrEstab,
+                &self.tcpInSegs,
+                &self.tcpOutSegs,
+                &self.tcpRetransSegs,
+                &self.tcpInErrs,
+                &self.tcpOutRsts,
+            ];
+            values.extend(self.tcpConnTable.values());
+            values.extend(self.tcpConnectionTable.values());
+            values.extend(self.tcpListenerTable.values());
+            values
         }
 
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
@@ -1079,23 +1706,18 @@ pub mod MibModule {
                 self.tcpConnTable.egui_show(app, ctx, ui);
                 self.tcpInErrs.egui_show(app, ctx, ui);
                 self.tcpOutRsts.egui_show(app, ctx, ui);
+                open_table_show(app, ui, "tcpConnectionTable");
+                open_table_show(app, ui, "tcpListenerTable");
             });
         }
     }
 
     impl TcpConnTable {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
-            match &oid[..10] {
-                oid_slice if self.tcpConnState.has_oid(oid_slice) => Some(self.tcpConnState.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.tcpConnLocalAddress.has_oid(oid_slice) => Some(self.tcpConnLocalAddress.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.tcpConnLocalPort.has_oid(oid_slice) => Some(self.tcpConnLocalPort.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.tcpConnRemAddress.has_oid(oid_slice) => Some(self.tcpConnRemAddress.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.tcpConnRemPort.has_oid(oid_slice) => Some(self.tcpConnRemPort.clone_index(oid.last().unwrap().to_owned() as usize)),
-                _ => None,
-            }
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.tcpConnState.walk(client).await;
             self.tcpConnLocalAddress.walk(client).await;
             self.tcpConnLocalPort.walk(client).await;
@@ -1103,6 +1725,16 @@ pub mod MibModule {
             self.tcpConnRemPort.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.tcpConnState,
+                &self.tcpConnLocalAddress,
+                &self.tcpConnLocalPort,
+                &self.tcpConnRemAddress,
+                &self.tcpConnRemPort,
+            ]
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("tcpConnTable", |ui| {
                 if ui.add(egui::Button::new("open table")).clicked() {
@@ -1114,40 +1746,57 @@ pub mod MibModule {
             });
         }
 
-        pub fn egui_table_show(&self, ui: &mut Ui) {
-            egui::ScrollArea::horizontal()
-                .auto_shrink(false)
-                .show(ui, |ui| {
-                    TableBuilder::new(ui)
-                        .striped(true)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atIfIndex
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atPhysAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atNetAddress
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atNetAddress
-                        .header(26.0, |mut header| {
-                            header.col(|ui| { ui.strong(&self.tcpConnState.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.tcpConnLocalAddress.as_mvipv4().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.tcpConnLocalPort.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.tcpConnRemAddress.as_mvipv4().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.tcpConnRemPort.as_mvinti32().unwrap().name); });
-                        })
-                        .body(|body| {
-                            body.rows(26.0, self.tcpConnState.as_mvinti32().unwrap().value.len(), |index, mut row| {
-                                row.col(|ui| match &self.tcpConnState.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.tcpConnState.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.tcpConnLocalAddress.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:?}", self.tcpConnLocalAddress.as_mvipv4().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.tcpConnLocalPort.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.tcpConnLocalPort.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.tcpConnRemAddress.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:?}", self.tcpConnRemAddress.as_mvipv4().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.tcpConnRemPort.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.tcpConnRemPort.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                            });
-                        });
-                });
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl TcpConnectionTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.tcpConnectionState.walk(client).await;
+            self.tcpConnectionProcess.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.tcpConnectionState,
+                &self.tcpConnectionProcess,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_inet_rows_show(ui, &self.oid, &self.values(), menu);
+        }
+    }
+
+    impl TcpListenerTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.tcpListenerProcess.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.tcpListenerProcess,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_inet_rows_show(ui, &self.oid, &self.values(), menu);
         }
     }
 
     impl Udp {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
             match &oid[..8] {
+                _ if oid.starts_with(&self.udpEndpointTable.oid) => self.udpEndpointTable.find_oid(oid),
                 oid_slice if self.udpInDatagrams.has_oid(oid_slice) => Some(self.udpInDatagrams.clone()), 
                 oid_slice if self.udpNoPorts.has_oid(oid_slice) => Some(self.udpNoPorts.clone()),
                 oid_slice if self.udpInErrors.has_oid(oid_slice) => Some(self.udpInErrors.clone()),
@@ -1157,12 +1806,25 @@ pub mod MibModule {
             }
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.udpInDatagrams.walk(client).await;
             self.udpNoPorts.walk(client).await;
             self.udpInErrors.walk(client).await;
             self.udpOutDatagrams.walk(client).await;
             self.udpTable.walk(client).await;
+            self.udpEndpointTable.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = vec![
+                &self.udpInDatagrams,
+                &self.udpNoPorts,
+                &self.udpInErrors,
+                &self.udpOutDatagrams,
+            ];
+            values.extend(self.udpTable.values());
+            values.extend(self.udpEndpointTable.values());
+            values
         }
 
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
@@ -1172,24 +1834,28 @@ pub mod MibModule {
                 self.udpInErrors.egui_show(app, ctx, ui);
                 self.udpOutDatagrams.egui_show(app, ctx, ui);
                 self.udpTable.egui_show(app, ctx, ui);
+                open_table_show(app, ui, "udpEndpointTable");
             });
         }
     }
 
     impl UdpTable {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
-            match &oid[..10] {
-                oid_slice if self.udpLocalAddress.has_oid(oid_slice) => Some(self.udpLocalAddress.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.udpLocalPort.has_oid(oid_slice) => Some(self.udpLocalPort.clone_index(oid.last().unwrap().to_owned() as usize)),
-                _ => None,
-            }
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.udpLocalAddress.walk(client).await;
             self.udpLocalPort.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.udpLocalAddress,
+                &self.udpLocalPort,
+            ]
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("udpTable", |ui| {
                 if ui.add(egui::Button::new("open table")).clicked() {
@@ -1201,25 +1867,28 @@ pub mod MibModule {
             });
         }
 
-        pub fn egui_table_show(&self, ui: &mut Ui) {
-            egui::ScrollArea::horizontal()
-                .auto_shrink(false)
-                .show(ui, |ui| {
-                    TableBuilder::new(ui)
-                        .striped(true)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atIfIndex
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),) // atPhysAddress
-                        .header(26.0, |mut header| {
-                            header.col(|ui| { ui.strong(&self.udpLocalAddress.as_mvipv4().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.udpLocalPort.as_mvinti32().unwrap().name); });
-                        })
-                        .body(|body| {
-                            body.rows(26.0, self.udpLocalPort.as_mvinti32().unwrap().value.len(), |index, mut row| {
-                                row.col(|ui| match &self.udpLocalAddress.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:?}", self.udpLocalAddress.as_mvipv4().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.udpLocalPort.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.udpLocalPort.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                            });
-                        });
-                });
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl UdpEndpointTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.udpEndpointProcess.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.udpEndpointProcess,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_inet_rows_show(ui, &self.oid, &self.values(), menu);
         }
     }
 
@@ -1236,7 +1905,7 @@ pub mod MibModule {
             }
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.egpInMsgs.walk(client).await;
             self.egpInErrors.walk(client).await;
             self.egpOutMsgs.walk(client).await;
@@ -1245,6 +1914,18 @@ pub mod MibModule {
             self.egpAs.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = vec![
+                &self.egpInMsgs,
+                &self.egpInErrors,
+                &self.egpOutMsgs,
+                &self.egpOutErrors,
+                &self.egpAs,
+            ];
+            values.extend(self.egpNeighTable.values());
+            values
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("Egp", |ui| {
                 self.egpInMsgs.egui_show(app, ctx, ui);
@@ -1259,27 +1940,10 @@ pub mod MibModule {
 
     impl EgpNeighTable {
         pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
-            match &oid[..10] {
-                oid_slice if self.egpNeighState.has_oid(oid_slice) => Some(self.egpNeighState.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighAddr.has_oid(oid_slice) => Some(self.egpNeighAddr.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighAs.has_oid(oid_slice) => Some(self.egpNeighAs.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighInMsgs.has_oid(oid_slice) => Some(self.egpNeighInMsgs.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighInErrs.has_oid(oid_slice) => Some(self.egpNeighInErrs.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighOutMsgs.has_oid(oid_slice) => Some(self.egpNeighOutMsgs.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighOutErrs.has_oid(oid_slice) => Some(self.egpNeighOutErrs.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighInErrMsgs.has_oid(oid_slice) => Some(self.egpNeighInErrMsgs.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighOutErrMsgs.has_oid(oid_slice) => Some(self.egpNeighOutErrMsgs.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighStateUps.has_oid(oid_slice) => Some(self.egpNeighStateUps.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighStateDowns.has_oid(oid_slice) => Some(self.egpNeighStateDowns.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighIntervalHello.has_oid(oid_slice) => Some(self.egpNeighIntervalHello.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighIntervalPoll.has_oid(oid_slice) => Some(self.egpNeighIntervalPoll.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighMode.has_oid(oid_slice) => Some(self.egpNeighMode.clone_index(oid.last().unwrap().to_owned() as usize)),
-                oid_slice if self.egpNeighEventTrigger.has_oid(oid_slice) => Some(self.egpNeighEventTrigger.clone_index(oid.last().unwrap().to_owned() as usize)),
-                _ => None,
-            }
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
             self.egpNeighState.walk(client).await;
             self.egpNeighAddr.walk(client).await;
             self.egpNeighAs.walk(client).await;
@@ -1297,6 +1961,26 @@ pub mod MibModule {
             self.egpNeighEventTrigger.walk(client).await;
         }
 
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.egpNeighState,
+                &self.egpNeighAddr,
+                &self.egpNeighAs,
+                &self.egpNeighInMsgs,
+                &self.egpNeighInErrs,
+                &self.egpNeighOutMsgs,
+                &self.egpNeighOutErrs,
+                &self.egpNeighInErrMsgs,
+                &self.egpNeighOutErrMsgs,
+                &self.egpNeighStateUps,
+                &self.egpNeighStateDowns,
+                &self.egpNeighIntervalHello,
+                &self.egpNeighIntervalPoll,
+                &self.egpNeighMode,
+                &self.egpNeighEventTrigger,
+            ]
+        }
+
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
             ui.collapsing("egpNeighTable", |ui| {
                 if ui.add(egui::Button::new("open table")).clicked() {
@@ -1308,64 +1992,8 @@ pub mod MibModule {
             });
         }
 
-        pub fn egui_table_show(&self, ui: &mut Ui) {
-            egui::ScrollArea::horizontal()
-                .auto_shrink(false)
-                .show(ui, |ui| {
-                    TableBuilder::new(ui)
-                        .striped(true)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true),)
-                        .header(26.0, |mut header| {
-                            header.col(|ui| { ui.strong(&self.egpNeighState.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighAddr.as_mvipv4().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighAs.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighInMsgs.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighInErrs.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighOutMsgs.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighOutErrs.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighInErrMsgs.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighOutErrMsgs.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighStateUps.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighStateDowns.as_mvintu32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighIntervalHello.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighIntervalPoll.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighMode.as_mvinti32().unwrap().name); });
-                            header.col(|ui| { ui.strong(&self.egpNeighEventTrigger.as_mvinti32().unwrap().name); });
-                        })
-                        .body(|body| {
-                            body.rows(26.0, self.egpNeighState.as_mvinti32().unwrap().value.len(), |index, mut row| {
-                                row.col(|ui| match &self.egpNeighState.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighState.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighAddr.as_mvipv4().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(format!("{:?}", self.egpNeighAddr.as_mvipv4().unwrap().value.clone().get(index).unwrap())); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighAs.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighAs.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighInMsgs.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighInMsgs.as_mvintu32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighInErrs.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighInErrs.as_mvintu32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighOutMsgs.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighOutMsgs.as_mvintu32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighOutErrs.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighOutErrs.as_mvintu32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighInErrMsgs.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighInErrMsgs.as_mvintu32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighOutErrMsgs.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighOutErrMsgs.as_mvintu32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighStateUps.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighStateUps.as_mvintu32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighStateDowns.as_mvintu32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighStateDowns.as_mvintu32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighIntervalHello.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighIntervalHello.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighIntervalPoll.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighIntervalPoll.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighMode.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighMode.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                                row.col(|ui| match &self.egpNeighEventTrigger.as_mvinti32().unwrap().value.len().cmp(&0) { std::cmp::Ordering::Greater => { ui.label(self.egpNeighEventTrigger.as_mvinti32().unwrap().value.clone().get(index).unwrap().to_string()); }, _ => { ui.spinner(); } });
-                            });
-                        });
-                });
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
         }
     }
 
@@ -1404,75 +2032,989 @@ pub mod MibModule {
             }
         }
 
-        pub async fn walk(&mut self, client: &Snmp2cClient) {
-            self.snmpInPkts.walk(client).await;
-            self.snmpOutPkts.walk(client).await;
-            self.snmpInBadVersions.walk(client).await;
-            self.snmpInBadCommunityNames.walk(client).await;
-            self.snmpInBadCommunityUses.walk(client).await;
-            self.snmpInASNParseErrs.walk(client).await;
-            self.snmpInTooBigs.walk(client).await;
-            self.snmpInNoSuchNames.walk(client).await;
-            self.snmpInBadValues.walk(client).await;
-            self.snmpInReadOnlys.walk(client).await;
-            self.snmpInGenErrs.walk(client).await;
-            self.snmpInTotalReqVars.walk(client).await;
-            self.snmpInTotalSetVars.walk(client).await;
-            self.snmpInGetRequests.walk(client).await;
-            self.snmpInGetNexts.walk(client).await;
-            self.snmpInSetRequests.walk(client).await;
-            self.snmpInGetResponses.walk(client).await;
-            self.snmpInTraps.walk(client).await;
-            self.snmpOutTooBigs.walk(client).await;
-            self.snmpOutNoSuchNames.walk(client).await;
-            self.snmpOutBadValues.walk(client).await;
-            self.snmpOutGenErrs.walk(client).await;
-            self.snmpOutGetRequests.walk(client).await;
-            self.snmpOutGetNexts.walk(client).await;
-            self.snmpOutSetRequests.walk(client).await;
-            self.snmpOutGetResponses.walk(client).await;
-            self.snmpOutTraps.walk(client).await;
-            self.snmpEnableAuthenTraps.walk(client).await;
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.snmpInPkts.walk(client).await;
+            self.snmpOutPkts.walk(client).await;
+            self.snmpInBadVersions.walk(client).await;
+            self.snmpInBadCommunityNames.walk(client).await;
+            self.snmpInBadCommunityUses.walk(client).await;
+            self.snmpInASNParseErrs.walk(client).await;
+            self.snmpInTooBigs.walk(client).await;
+            self.snmpInNoSuchNames.walk(client).await;
+            self.snmpInBadValues.walk(client).await;
+            self.snmpInReadOnlys.walk(client).await;
+            self.snmpInGenErrs.walk(client).await;
+            self.snmpInTotalReqVars.walk(client).await;
+            self.snmpInTotalSetVars.walk(client).await;
+            self.snmpInGetRequests.walk(client).await;
+            self.snmpInGetNexts.walk(client).await;
+            self.snmpInSetRequests.walk(client).await;
+            self.snmpInGetResponses.walk(client).await;
+            self.snmpInTraps.walk(client).await;
+            self.snmpOutTooBigs.walk(client).await;
+            self.snmpOutNoSuchNames.walk(client).await;
+            self.snmpOutBadValues.walk(client).await;
+            self.snmpOutGenErrs.walk(client).await;
+            self.snmpOutGetRequests.walk(client).await;
+            self.snmpOutGetNexts.walk(client).await;
+            self.snmpOutSetRequests.walk(client).await;
+            self.snmpOutGetResponses.walk(client).await;
+            self.snmpOutTraps.walk(client).await;
+            self.snmpEnableAuthenTraps.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.snmpInPkts,
+                &self.snmpOutPkts,
+                &self.snmpInBadVersions,
+                &self.snmpInBadCommunityNames,
+                &self.snmpInBadCommunityUses,
+                &self.snmpInASNParseErrs,
+                &self.snmpInTooBigs,
+                &self.snmpInNoSuchNames,
+                &self.snmpInBadValues,
+                &self.snmpInReadOnlys,
+                &self.snmpInGenErrs,
+                &self.snmpInTotalReqVars,
+                &self.snmpInTotalSetVars,
+                &self.snmpInGetRequests,
+                &self.snmpInGetNexts,
+                &self.snmpInSetRequests,
+                &self.snmpInGetResponses,
+                &self.snmpInTraps,
+                &self.snmpOutTooBigs,
+                &self.snmpOutNoSuchNames,
+                &self.snmpOutBadValues,
+                &self.snmpOutGenErrs,
+                &self.snmpOutGetRequests,
+                &self.snmpOutGetNexts,
+                &self.snmpOutSetRequests,
+                &self.snmpOutGetResponses,
+                &self.snmpOutTraps,
+                &self.snmpEnableAuthenTraps,
+            ]
+        }
+
+        pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
+            ui.collapsing("Snmp", |ui| {
+                self.snmpInPkts.egui_show(app, ctx, ui);
+                self.snmpOutPkts.egui_show(app, ctx, ui);
+                self.snmpInBadVersions.egui_show(app, ctx, ui);
+                self.snmpInBadCommunityNames.egui_show(app, ctx, ui);
+                self.snmpInBadCommunityUses.egui_show(app, ctx, ui);
+                self.snmpInASNParseErrs.egui_show(app, ctx, ui);
+                self.snmpInTooBigs.egui_show(app, ctx, ui);
+                self.snmpInNoSuchNames.egui_show(app, ctx, ui);
+                self.snmpInBadValues.egui_show(app, ctx, ui);
+                self.snmpInReadOnlys.egui_show(app, ctx, ui);
+                self.snmpInGenErrs.egui_show(app, ctx, ui);
+                self.snmpInTotalReqVars.egui_show(app, ctx, ui);
+                self.snmpInTotalSetVars.egui_show(app, ctx, ui);
+                self.snmpInGetRequests.egui_show(app, ctx, ui);
+                self.snmpInGetNexts.egui_show(app, ctx, ui);
+                self.snmpInSetRequests.egui_show(app, ctx, ui);
+                self.snmpInGetResponses.egui_show(app, ctx, ui);
+                self.snmpInTraps.egui_show(app, ctx, ui);
+                self.snmpOutTooBigs.egui_show(app, ctx, ui);
+                self.snmpOutNoSuchNames.egui_show(app, ctx, ui);
+                self.snmpOutBadValues.egui_show(app, ctx, ui);
+                self.snmpOutGenErrs.egui_show(app, ctx, ui);
+                self.snmpOutGetRequests.egui_show(app, ctx, ui);
+                self.snmpOutGetNexts.egui_show(app, ctx, ui);
+                self.snmpOutSetRequests.egui_show(app, ctx, ui);
+                self.snmpOutGetResponses.egui_show(app, ctx, ui);
+                self.snmpOutTraps.egui_show(app, ctx, ui);
+                self.snmpEnableAuthenTraps.egui_show(app, ctx, ui);
+            });
+        }
+    }
+
+    impl Lldp {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            match &oid[..] {
+                oid_slice if oid_slice.starts_with(&self.lldpLocPortTable.oid) => self.lldpLocPortTable.find_oid(oid),
+                oid_slice if oid_slice.starts_with(&self.lldpRemTable.oid) => self.lldpRemTable.find_oid(oid),
+                oid_slice => [&self.lldpLocChassisIdSubtype, &self.lldpLocChassisId, &self.lldpLocSysName]
+                    .into_iter()
+                    .find(|value| oid_slice.starts_with(&value.get_oid()))
+                    .cloned(),
+            }
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.lldpLocChassisIdSubtype.walk(client).await;
+            self.lldpLocChassisId.walk(client).await;
+            self.lldpLocSysName.walk(client).await;
+            self.lldpLocPortTable.walk(client).await;
+            self.lldpRemTable.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = vec![
+                &self.lldpLocChassisIdSubtype,
+                &self.lldpLocChassisId,
+                &self.lldpLocSysName,
+            ];
+            values.extend(self.lldpLocPortTable.values());
+            values.extend(self.lldpRemTable.values());
+            values
+        }
+
+        pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
+            ui.collapsing("Lldp", |ui| {
+                self.lldpLocChassisIdSubtype.egui_show(app, ctx, ui);
+                self.lldpLocChassisId.egui_show(app, ctx, ui);
+                self.lldpLocSysName.egui_show(app, ctx, ui);
+                open_table_show(app, ui, "lldpLocPortTable");
+                open_table_show(app, ui, "lldpRemTable");
+            });
+        }
+    }
+
+    impl LldpLocPortTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.lldpLocPortIdSubtype.walk(client).await;
+            self.lldpLocPortId.walk(client).await;
+            self.lldpLocPortDesc.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.lldpLocPortIdSubtype,
+                &self.lldpLocPortId,
+                &self.lldpLocPortDesc,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl LldpRemTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.lldpRemChassisIdSubtype.walk(client).await;
+            self.lldpRemChassisId.walk(client).await;
+            self.lldpRemPortIdSubtype.walk(client).await;
+            self.lldpRemPortId.walk(client).await;
+            self.lldpRemPortDesc.walk(client).await;
+            self.lldpRemSysName.walk(client).await;
+            self.lldpRemSysDesc.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.lldpRemChassisIdSubtype,
+                &self.lldpRemChassisId,
+                &self.lldpRemPortIdSubtype,
+                &self.lldpRemPortId,
+                &self.lldpRemPortDesc,
+                &self.lldpRemSysName,
+                &self.lldpRemSysDesc,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl HostResources {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            match &oid[..] {
+                oid_slice if oid_slice.starts_with(&self.hrStorageTable.oid) => self.hrStorageTable.find_oid(oid),
+                oid_slice if oid_slice.starts_with(&self.hrDeviceTable.oid) => self.hrDeviceTable.find_oid(oid),
+                oid_slice if oid_slice.starts_with(&self.hrProcessorTable.oid) => self.hrProcessorTable.find_oid(oid),
+                oid_slice if oid_slice.starts_with(&self.hrSWRunTable.oid) => self.hrSWRunTable.find_oid(oid),
+                oid_slice if oid_slice.starts_with(&self.hrSWRunPerfTable.oid) => self.hrSWRunPerfTable.find_oid(oid),
+                oid_slice => [&self.hrSystemUptime, &self.hrSystemNumUsers, &self.hrSystemProcesses, &self.hrSystemMaxProcesses, &self.hrMemorySize]
+                    .into_iter()
+                    .find(|value| oid_slice.starts_with(&value.get_oid()))
+                    .cloned(),
+            }
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.hrSystemUptime.walk(client).await;
+            self.hrSystemNumUsers.walk(client).await;
+            self.hrSystemProcesses.walk(client).await;
+            self.hrSystemMaxProcesses.walk(client).await;
+            self.hrMemorySize.walk(client).await;
+            self.hrStorageTable.walk(client).await;
+            self.hrDeviceTable.walk(client).await;
+            self.hrProcessorTable.walk(client).await;
+            self.hrSWRunTable.walk(client).await;
+            self.hrSWRunPerfTable.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = vec![
+                &self.hrSystemUptime,
+                &self.hrSystemNumUsers,
+                &self.hrSystemProcesses,
+                &self.hrSystemMaxProcesses,
+                &self.hrMemorySize,
+            ];
+            values.extend(self.hrStorageTable.values());
+            values.extend(self.hrDeviceTable.values());
+            values.extend(self.hrProcessorTable.values());
+            values.extend(self.hrSWRunTable.values());
+            values.extend(self.hrSWRunPerfTable.values());
+            values
+        }
+
+        pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
+            ui.collapsing("HostResources", |ui| {
+                self.hrSystemUptime.egui_show(app, ctx, ui);
+                self.hrSystemNumUsers.egui_show(app, ctx, ui);
+                self.hrSystemProcesses.egui_show(app, ctx, ui);
+                self.hrSystemMaxProcesses.egui_show(app, ctx, ui);
+                self.hrMemorySize.egui_show(app, ctx, ui);
+                open_table_show(app, ui, "hrStorageTable");
+                open_table_show(app, ui, "hrDeviceTable");
+                open_table_show(app, ui, "hrProcessorTable");
+                open_table_show(app, ui, "hrSWRunTable");
+                open_table_show(app, ui, "hrSWRunPerfTable");
+                open_table_show(app, ui, "host");
+            });
+        }
+    }
+
+    impl HrStorageTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.hrStorageIndex.walk(client).await;
+            self.hrStorageType.walk(client).await;
+            self.hrStorageDescr.walk(client).await;
+            self.hrStorageAllocationUnits.walk(client).await;
+            self.hrStorageSize.walk(client).await;
+            self.hrStorageUsed.walk(client).await;
+            self.hrStorageAllocationFailures.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.hrStorageIndex,
+                &self.hrStorageType,
+                &self.hrStorageDescr,
+                &self.hrStorageAllocationUnits,
+                &self.hrStorageSize,
+                &self.hrStorageUsed,
+                &self.hrStorageAllocationFailures,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl HrDeviceTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.hrDeviceIndex.walk(client).await;
+            self.hrDeviceType.walk(client).await;
+            self.hrDeviceDescr.walk(client).await;
+            self.hrDeviceStatus.walk(client).await;
+            self.hrDeviceErrors.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.hrDeviceIndex,
+                &self.hrDeviceType,
+                &self.hrDeviceDescr,
+                &self.hrDeviceStatus,
+                &self.hrDeviceErrors,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl HrProcessorTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.hrProcessorLoad.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.hrProcessorLoad,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl HrSWRunTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.hrSWRunIndex.walk(client).await;
+            self.hrSWRunName.walk(client).await;
+            self.hrSWRunPath.walk(client).await;
+            self.hrSWRunParameters.walk(client).await;
+            self.hrSWRunType.walk(client).await;
+            self.hrSWRunStatus.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.hrSWRunIndex,
+                &self.hrSWRunName,
+                &self.hrSWRunPath,
+                &self.hrSWRunParameters,
+                &self.hrSWRunType,
+                &self.hrSWRunStatus,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl HrSWRunPerfTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.hrSWRunPerfCPU.walk(client).await;
+            self.hrSWRunPerfMem.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.hrSWRunPerfCPU,
+                &self.hrSWRunPerfMem,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl Entity {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.entPhysicalTable.find_oid(oid)
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.entPhysicalTable.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            self.entPhysicalTable.values()
+        }
+
+        pub fn egui_show(&self, app: &mut SnmpMonitorApp, _ctx: &Context, ui: &mut Ui) {
+            ui.collapsing("Entity", |ui| {
+                open_table_show(app, ui, "entPhysicalTable");
+                open_table_show(app, ui, "inventory");
+            });
+        }
+    }
+
+    impl EntPhysicalTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.entPhysicalDescr.walk(client).await;
+            self.entPhysicalVendorType.walk(client).await;
+            self.entPhysicalContainedIn.walk(client).await;
+            self.entPhysicalClass.walk(client).await;
+            self.entPhysicalParentRelPos.walk(client).await;
+            self.entPhysicalName.walk(client).await;
+            self.entPhysicalHardwareRev.walk(client).await;
+            self.entPhysicalFirmwareRev.walk(client).await;
+            self.entPhysicalSoftwareRev.walk(client).await;
+            self.entPhysicalSerialNum.walk(client).await;
+            self.entPhysicalMfgName.walk(client).await;
+            self.entPhysicalModelName.walk(client).await;
+            self.entPhysicalAlias.walk(client).await;
+            self.entPhysicalAssetID.walk(client).await;
+            self.entPhysicalIsFRU.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.entPhysicalDescr,
+                &self.entPhysicalVendorType,
+                &self.entPhysicalContainedIn,
+                &self.entPhysicalClass,
+                &self.entPhysicalParentRelPos,
+                &self.entPhysicalName,
+                &self.entPhysicalHardwareRev,
+                &self.entPhysicalFirmwareRev,
+                &self.entPhysicalSoftwareRev,
+                &self.entPhysicalSerialNum,
+                &self.entPhysicalMfgName,
+                &self.entPhysicalModelName,
+                &self.entPhysicalAlias,
+                &self.entPhysicalAssetID,
+                &self.entPhysicalIsFRU,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl Bridge {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.dot1dBasePortTable.walk(client).await;
+            self.dot1dTpFdbTable.walk(client).await;
+            self.dot1qTpFdbTable.walk(client).await;
+            self.dot1qVlanStaticTable.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = self.dot1dBasePortTable.values();
+            values.extend(self.dot1dTpFdbTable.values());
+            values.extend(self.dot1qTpFdbTable.values());
+            values.extend(self.dot1qVlanStaticTable.values());
+            values
+        }
+
+        pub fn egui_show(&self, app: &mut SnmpMonitorApp, _ctx: &Context, ui: &mut Ui) {
+            ui.collapsing("Bridge", |ui| {
+                open_table_show(app, ui, "dot1dBasePortTable");
+                open_table_show(app, ui, "dot1dTpFdbTable");
+                open_table_show(app, ui, "dot1qTpFdbTable");
+                open_table_show(app, ui, "dot1qVlanStaticTable");
+                open_table_show(app, ui, "bridge");
+            });
+        }
+    }
+
+    impl Dot1dBasePortTable {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.dot1dBasePort.walk(client).await;
+            self.dot1dBasePortIfIndex.walk(client).await;
+            self.dot1dBasePortCircuit.walk(client).await;
+            self.dot1dBasePortDelayExceededDiscards.walk(client).await;
+            self.dot1dBasePortMtuExceededDiscards.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.dot1dBasePort,
+                &self.dot1dBasePortIfIndex,
+                &self.dot1dBasePortCircuit,
+                &self.dot1dBasePortDelayExceededDiscards,
+                &self.dot1dBasePortMtuExceededDiscards,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl Dot1dTpFdbTable {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.dot1dTpFdbAddress.walk(client).await;
+            self.dot1dTpFdbPort.walk(client).await;
+            self.dot1dTpFdbStatus.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.dot1dTpFdbAddress,
+                &self.dot1dTpFdbPort,
+                &self.dot1dTpFdbStatus,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl Dot1qTpFdbTable {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.dot1qTpFdbPort.walk(client).await;
+            self.dot1qTpFdbStatus.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.dot1qTpFdbPort,
+                &self.dot1qTpFdbStatus,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl Dot1qVlanStaticTable {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.dot1qVlanStaticName.walk(client).await;
+            self.dot1qVlanStaticEgressPorts.walk(client).await;
+            self.dot1qVlanForbiddenEgressPorts.walk(client).await;
+            self.dot1qVlanStaticUntaggedPorts.walk(client).await;
+            self.dot1qVlanStaticRowStatus.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.dot1qVlanStaticName,
+                &self.dot1qVlanStaticEgressPorts,
+                &self.dot1qVlanForbiddenEgressPorts,
+                &self.dot1qVlanStaticUntaggedPorts,
+                &self.dot1qVlanStaticRowStatus,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl Ucd {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.memTotalSwap.walk(client).await;
+            self.memAvailSwap.walk(client).await;
+            self.memTotalReal.walk(client).await;
+            self.memAvailReal.walk(client).await;
+            self.memTotalFree.walk(client).await;
+            self.memShared.walk(client).await;
+            self.memBuffer.walk(client).await;
+            self.memCached.walk(client).await;
+            self.ssCpuRawUser.walk(client).await;
+            self.ssCpuRawNice.walk(client).await;
+            self.ssCpuRawSystem.walk(client).await;
+            self.ssCpuRawIdle.walk(client).await;
+            self.ssCpuRawWait.walk(client).await;
+            self.ssCpuRawKernel.walk(client).await;
+            self.ssCpuRawInterrupt.walk(client).await;
+            self.ssCpuRawSoftIRQ.walk(client).await;
+            self.ssCpuRawSteal.walk(client).await;
+            self.laTable.walk(client).await;
+            self.dskTable.walk(client).await;
+            self.nsExtendOutput1Table.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = vec![
+                &self.memTotalSwap,
+                &self.memAvailSwap,
+                &self.memTotalReal,
+                &self.memAvailReal,
+                &self.memTotalFree,
+                &self.memShared,
+                &self.memBuffer,
+                &self.memCached,
+                &self.ssCpuRawUser,
+                &self.ssCpuRawNice,
+                &self.ssCpuRawSystem,
+                &self.ssCpuRawIdle,
+                &self.ssCpuRawWait,
+                &self.ssCpuRawKernel,
+                &self.ssCpuRawInterrupt,
+                &self.ssCpuRawSoftIRQ,
+                &self.ssCpuRawSteal,
+            ];
+            values.extend(self.laTable.values());
+            values.extend(self.dskTable.values());
+            values.extend(self.nsExtendOutput1Table.values());
+            values
+        }
+
+        pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
+            ui.collapsing("Ucd", |ui| {
+                self.memTotalSwap.egui_show(app, ctx, ui);
+                self.memAvailSwap.egui_show(app, ctx, ui);
+                self.memTotalReal.egui_show(app, ctx, ui);
+                self.memAvailReal.egui_show(app, ctx, ui);
+                self.memTotalFree.egui_show(app, ctx, ui);
+                self.memShared.egui_show(app, ctx, ui);
+                self.memBuffer.egui_show(app, ctx, ui);
+                self.memCached.egui_show(app, ctx, ui);
+                self.ssCpuRawUser.egui_show(app, ctx, ui);
+                self.ssCpuRawNice.egui_show(app, ctx, ui);
+                self.ssCpuRawSystem.egui_show(app, ctx, ui);
+                self.ssCpuRawIdle.egui_show(app, ctx, ui);
+                self.ssCpuRawWait.egui_show(app, ctx, ui);
+                self.ssCpuRawKernel.egui_show(app, ctx, ui);
+                self.ssCpuRawInterrupt.egui_show(app, ctx, ui);
+                self.ssCpuRawSoftIRQ.egui_show(app, ctx, ui);
+                self.ssCpuRawSteal.egui_show(app, ctx, ui);
+                open_table_show(app, ui, "laTable");
+                open_table_show(app, ui, "dskTable");
+                open_table_show(app, ui, "nsExtendOutput1Table");
+                open_table_show(app, ui, "ucd");
+            });
+        }
+    }
+
+    impl LaTable {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.laIndex.walk(client).await;
+            self.laNames.walk(client).await;
+            self.laLoad.walk(client).await;
+            self.laConfig.walk(client).await;
+            self.laLoadInt.walk(client).await;
+            self.laLoadFloat.walk(client).await;
+            self.laErrorFlag.walk(client).await;
+            self.laErrMessage.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.laIndex,
+                &self.laNames,
+                &self.laLoad,
+                &self.laConfig,
+                &self.laLoadInt,
+                &self.laLoadFloat,
+                &self.laErrorFlag,
+                &self.laErrMessage,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl DskTable {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.dskIndex.walk(client).await;
+            self.dskPath.walk(client).await;
+            self.dskDevice.walk(client).await;
+            self.dskTotal.walk(client).await;
+            self.dskAvail.walk(client).await;
+            self.dskUsed.walk(client).await;
+            self.dskPercent.walk(client).await;
+            self.dskPercentNode.walk(client).await;
+            self.dskTotalLow.walk(client).await;
+            self.dskTotalHigh.walk(client).await;
+            self.dskAvailLow.walk(client).await;
+            self.dskAvailHigh.walk(client).await;
+            self.dskUsedLow.walk(client).await;
+            self.dskUsedHigh.walk(client).await;
+            self.dskErrorFlag.walk(client).await;
+            self.dskErrorMsg.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.dskIndex,
+                &self.dskPath,
+                &self.dskDevice,
+                &self.dskTotal,
+                &self.dskAvail,
+                &self.dskUsed,
+                &self.dskPercent,
+                &self.dskPercentNode,
+                &self.dskTotalLow,
+                &self.dskTotalHigh,
+                &self.dskAvailLow,
+                &self.dskAvailHigh,
+                &self.dskUsedLow,
+                &self.dskUsedHigh,
+                &self.dskErrorFlag,
+                &self.dskErrorMsg,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl NsExtendOutput1Table {
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.nsExtendOutput1Line.walk(client).await;
+            self.nsExtendOutputFull.walk(client).await;
+            self.nsExtendOutNumLines.walk(client).await;
+            self.nsExtendResult.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.nsExtendOutput1Line,
+                &self.nsExtendOutputFull,
+                &self.nsExtendOutNumLines,
+                &self.nsExtendResult,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    impl Vendor {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            for value in self.objects.iter_mut() {
+                value.walk(client).await;
+            }
+            for table in self.tables.iter_mut() {
+                for column in table.columns.iter_mut() {
+                    column.walk(client).await;
+                }
+            }
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            let mut values = self.objects.iter().collect::<Vec<&MibValue>>();
+            values.extend(self.tables.iter().flat_map(|table| table.columns.iter()));
+            values
+        }
+
+        pub fn table(&self, name: &str) -> Option<Vec<&MibValue>> {
+            self.tables.iter().find(|a| a.name == name).map(|a| a.columns.iter().collect())
         }
 
         pub fn egui_show(&self, app: &mut SnmpMonitorApp, ctx: &Context, ui: &mut Ui) {
-            ui.collapsing("Snmp", |ui| {
-                self.snmpInPkts.egui_show(app, ctx, ui);
-                self.snmpOutPkts.egui_show(app, ctx, ui);
-                self.snmpInBadVersions.egui_show(app, ctx, ui);
-                self.snmpInBadCommunityNames.egui_show(app, ctx, ui);
-                self.snmpInBadCommunityUses.egui_show(app, ctx, ui);
-                self.snmpInASNParseErrs.egui_show(app, ctx, ui);
-                self.snmpInTooBigs.egui_show(app, ctx, ui);
-                self.snmpInNoSuchNames.egui_show(app, ctx, ui);
-                self.snmpInBadValues.egui_show(app, ctx, ui);
-                self.snmpInReadOnlys.egui_show(app, ctx, ui);
-                self.snmpInGenErrs.egui_show(app, ctx, ui);
-                self.snmpInTotalReqVars.egui_show(app, ctx, ui);
-                self.snmpInTotalSetVars.egui_show(app, ctx, ui);
-                self.snmpInGetRequests.egui_show(app, ctx, ui);
-                self.snmpInGetNexts.egui_show(app, ctx, ui);
-                self.snmpInSetRequests.egui_show(app, ctx, ui);
-                self.snmpInGetResponses.egui_show(app, ctx, ui);
-                self.snmpInTraps.egui_show(app, ctx, ui);
-                self.snmpOutTooBigs.egui_show(app, ctx, ui);
-                self.snmpOutNoSuchNames.egui_show(app, ctx, ui);
-                self.snmpOutBadValues.egui_show(app, ctx, ui);
-                self.snmpOutGenErrs.egui_show(app, ctx, ui);
-                self.snmpOutGetRequests.egui_show(app, ctx, ui);
-                self.snmpOutGetNexts.egui_show(app, ctx, ui);
-                self.snmpOutSetRequests.egui_show(app, ctx, ui);
-                self.snmpOutGetResponses.egui_show(app, ctx, ui);
-                self.snmpOutTraps.egui_show(app, ctx, ui);
-                self.snmpEnableAuthenTraps.egui_show(app, ctx, ui);
+            if self.profile.is_empty() {
+                return;
+            }
+            ui.collapsing(&self.profile, |ui| {
+                for value in &self.objects {
+                    value.egui_show(app, ctx, ui);
+                }
+                for table in &self.tables {
+                    open_table_show(app, ui, &table.name);
+                }
+                open_table_show(app, ui, "vendor");
+            });
+        }
+    }
+
+    impl Cdp {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.cdpCacheTable.find_oid(oid)
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.cdpCacheTable.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            self.cdpCacheTable.values()
+        }
+
+        pub fn egui_show(&self, app: &mut SnmpMonitorApp, _ctx: &Context, ui: &mut Ui) {
+            ui.collapsing("Cdp", |ui| {
+                open_table_show(app, ui, "cdpCacheTable");
             });
         }
     }
 
+    impl CdpCacheTable {
+        pub fn find_oid(&self, oid: Vec<u16>) -> Option<MibValue> {
+            self.values().into_iter().find_map(|value| value.clone_instance(&oid))
+        }
+
+        pub async fn walk(&mut self, client: &Walk<'_>) {
+            self.cdpCacheAddressType.walk(client).await;
+            self.cdpCacheAddress.walk(client).await;
+            self.cdpCacheVersion.walk(client).await;
+            self.cdpCacheDeviceId.walk(client).await;
+            self.cdpCacheDevicePort.walk(client).await;
+            self.cdpCachePlatform.walk(client).await;
+        }
+
+        pub fn values(&self) -> Vec<&MibValue> {
+            vec![
+                &self.cdpCacheAddressType,
+                &self.cdpCacheAddress,
+                &self.cdpCacheVersion,
+                &self.cdpCacheDeviceId,
+                &self.cdpCacheDevicePort,
+                &self.cdpCachePlatform,
+            ]
+        }
+
+        pub fn egui_table_show(&self, ui: &mut Ui, menu: &mut CellMenu) {
+            egui_rows_show(ui, &self.values(), menu);
+        }
+    }
+
+    /// the button of a table in the side panel that opens it in a tab
+    fn open_table_show(app: &mut SnmpMonitorApp, ui: &mut Ui, name: &str) {
+        ui.collapsing(name, |ui| {
+            if ui.add(egui::Button::new("open table")).clicked() {
+                app.context.open_tabs.insert(name.to_owned());
+                app.tabs_tree
+                    .main_surface_mut()
+                    .push_to_focused_leaf(name.to_owned());
+            }
+        });
+    }
+
+    /// shows the columns of a table lined up by instance index, one row per index; right clicking
+    /// a numeric cell offers to plot it
+    pub fn egui_rows_show(ui: &mut Ui, columns: &[&MibValue], menu: &mut CellMenu) {
+        egui_index_rows_show(ui, &["index".to_owned()], columns, &index_rows(columns), menu, None);
+    }
+
+    /// the rows of `table_rows` as `(index, shown index, cells)`, the index shown as it is
+    fn index_rows(columns: &[&MibValue]) -> Vec<(String, Vec<String>, Vec<String>)> {
+        table_rows(columns).into_iter().map(|(index, cells)| (index.clone(), vec![index], cells)).collect()
+    }
+
+    /// shows a table indexed by InetAddress with a column per object of its INDEX clause, so
+    /// that IPv4 and IPv6 rows read alike; rows whose index does not decode keep it raw
+    pub fn egui_inet_rows_show(ui: &mut Ui, table_oid: &[u16], columns: &[&MibValue], menu: &mut CellMenu) {
+        let table = table_oid.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".");
+        let parts = InetModule::table_index(&table).unwrap_or_default();
+        let names = match parts.is_empty() {
+            true => vec!["index".to_owned()],
+            false => parts.iter().map(|a| a.0.to_owned()).collect::<Vec<String>>(),
+        };
+        let rows = table_rows(columns)
+            .into_iter()
+            .map(|(index, cells)| {
+                let arcs = index.split('.').map(|a| a.parse::<u32>().ok()).collect::<Option<Vec<u32>>>().unwrap_or_default();
+                let mut decoded = InetModule::decode(parts, &arcs).unwrap_or_else(|| vec![index.clone()]);
+                decoded.resize(names.len(), String::new());
+                (index, decoded, cells)
+            })
+            .collect::<Vec<(String, Vec<String>, Vec<String>)>>();
+        egui_index_rows_show(ui, &names, columns, &rows, menu, None);
+    }
+
+    /// a numeric table cell picked from its context menu, to be plotted in `plot` or in a new
+    /// plot of that name
+    #[derive(Clone, Debug)]
+    pub struct PlotCell {
+        /// the whole column, so the series follows the row by its instance index
+        pub column: MibValue,
+        pub position: usize,
+        /// the column and the row as shown, like `ifInOctets 3`
+        pub name: String,
+        pub plot: String,
+    }
+
+    /// the plots offered in the context menu of table cells, and the cell picked from it
+    #[derive(Default)]
+    pub struct CellMenu {
+        pub plots: Vec<String>,
+        pub picked: Option<PlotCell>,
+    }
+
+    fn egui_index_rows_show(
+        ui: &mut Ui,
+        index_names: &[String],
+        columns: &[&MibValue],
+        rows: &[(String, Vec<String>, Vec<String>)],
+        menu: &mut CellMenu,
+        scroll_to: Option<usize>,
+    ) {
+        egui::ScrollArea::horizontal()
+            .auto_shrink(false)
+            .show(ui, |ui| {
+                let mut table = TableBuilder::new(ui).striped(true);
+                if let Some(row) = scroll_to {
+                    table = table.scroll_to_row(row, Some(egui::Align::Center));
+                }
+                for _ in index_names {
+                    table = table.column(Column::auto().at_least(50.0).resizable(true));
+                }
+                for _ in columns {
+                    table = table.column(Column::auto_with_initial_suggestion(100.0).at_least(50.0).resizable(true));
+                }
+                table
+                    .header(26.0, |mut header| {
+                        for name in index_names {
+                            header.col(|ui| { ui.strong(name); });
+                        }
+                        for column in columns {
+                            header.col(|ui| { ui.strong(column.get_name()); });
+                        }
+                    })
+                    .body(|body| {
+                        // where the cells of each row sit in their columns, for the plot menu
+                        let positions = columns.iter().map(|column| column.positions()).collect::<Vec<HashMap<String, usize>>>();
+                        body.rows(26.0, rows.len(), |position, mut row| {
+                            let (index, shown, cells) = &rows[position];
+                            for part in shown {
+                                row.col(|ui| { ui.label(part); });
+                            }
+                            for ((column, cell), positions) in columns.iter().zip(cells).zip(&positions) {
+                                row.col(|ui| {
+                                    let response = ui.label(cell);
+                                    if let Some(position) = positions.get(index).copied().filter(|_| !cell.is_empty()) {
+                                        response.context_menu(|ui| {
+                                            let name = format!("{} {}", column.get_name(), shown.join(" "));
+                                            let plots = menu.plots.clone();
+                                            let mut pick = |plot: &str| menu.picked = Some(PlotCell { column: (*column).clone(), position, name: name.clone(), plot: plot.to_owned() });
+                                            if !plots.is_empty() {
+                                                ui.menu_button("add to existing plot", |ui| {
+                                                    for plot in plots {
+                                                        if ui.button(&plot).clicked() {
+                                                            pick(&plot);
+                                                            ui.close_menu();
+                                                        }
+                                                    }
+                                                });
+                                            }
+                                            if ui.button("create new plot").clicked() {
+                                                pick(&name);
+                                                ui.close_menu();
+                                            }
+                                        });
+                                    }
+                                });
+                            }
+                        });
+                    });
+            });
+    }
+
     impl Clone for MibObject {
         fn clone(&self) -> Self {
             MibObject {
                 oid: self.oid.to_owned(),
+                target: self.target.clone(),
                 timestamp: self.timestamp.clone(),
                 system: self.system.clone(),
                 interfaces: self.interfaces.clone(),
@@ -1484,6 +3026,14 @@ pub mod MibModule {
                 egp: self.egp.clone(),
                 transmission: self.transmission.clone(),
                 snmp: self.snmp.clone(),
+                lldp: self.lldp.clone(),
+                cdp: self.cdp.clone(),
+                host: self.host.clone(),
+                entity: self.entity.clone(),
+                bridge: self.bridge.clone(),
+                ucd: self.ucd.clone(),
+                vendor: self.vendor.clone(),
+                pinned: self.pinned.clone(),
             }
         }
     }
@@ -1509,6 +3059,7 @@ pub mod MibModule {
                 oid: self.oid.to_owned(),
                 ifNumber: self.ifNumber.clone(),
                 ifTable: self.ifTable.clone(),
+                ifXTable: self.ifXTable.clone(),
             }
         }
     }
@@ -1590,6 +3141,9 @@ pub mod MibModule {
                 ipRouteTable: self.ipRouteTable.clone(),
                 ipNetToMediaTable: self.ipNetToMediaTable.clone(),
                 ipRoutingDiscards: self.ipRoutingDiscards.clone(),
+                ipAddressTable: self.ipAddressTable.clone(),
+                ipNetToPhysicalTable: self.ipNetToPhysicalTable.clone(),
+                inetCidrRouteTable: self.inetCidrRouteTable.clone(),
             }
         }
     }
@@ -1693,6 +3247,8 @@ pub mod MibModule {
                 tcpConnTable: self.tcpConnTable.clone(),
                 tcpInErrs: self.tcpInErrs.clone(),
                 tcpOutRsts: self.tcpOutRsts.clone(),
+                tcpConnectionTable: self.tcpConnectionTable.clone(),
+                tcpListenerTable: self.tcpListenerTable.clone(),
             }
         }
     }
@@ -1719,6 +3275,7 @@ pub mod MibModule {
                 udpInErrors: self.udpInErrors.clone(),
                 udpOutDatagrams: self.udpOutDatagrams.clone(),
                 udpTable: self.udpTable.clone(),
+                udpEndpointTable: self.udpEndpointTable.clone(),
             }
         }
     }
@@ -1810,232 +3367,568 @@ pub mod MibModule {
         pub fn new() -> Self {
             MibObject {
                 oid: vec![1, 3, 6, 1, 2, 1],
+                target: "".to_owned(),
                 timestamp: chrono::Local::now().timestamp(),
                 system: System {
                     oid: vec![1, 3, 6, 1, 2, 1, 1],
-                    sysDesc: MibValue::string(mvstring {name: "sysDesc".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 1],mutable: false,value: vec![]}), // DisplayString
-                    sysObjectID: MibValue::oid(mvoid {name: "sysObjectID".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 2],mutable: false,value: vec![]}), // OBJECT_IDENTIFIER
-                    sysUpTime: MibValue::intu32(mvintu32 {name: "sysUpTime".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 3],mutable: false,value: vec![]}), // TimeTicks
-                    sysContact: MibValue::string(mvstring {name: "sysContact".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 4],mutable: true,value: vec![]}), // DisplayString
-                    sysName: MibValue::string(mvstring {name: "sysName".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 5],mutable: true,value: vec![]}), // DisplayString
-                    sysLocation: MibValue::string(mvstring {name: "sysLocation".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 6],mutable: true,value: vec![]}), // DisplayString
-                    sysServices: MibValue::inti32(mvinti32 {name: "sysServices".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 7],mutable: false,value: vec![]}), // INTEGER
+                    sysDesc: MibValue::string(mvstring {name: "sysDesc".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 1],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    sysObjectID: MibValue::oid(mvoid {name: "sysObjectID".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 2],mutable: false,value: vec![], index: vec![]}), // OBJECT_IDENTIFIER
+                    sysUpTime: MibValue::intu32(mvintu32 {name: "sysUpTime".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 3],mutable: false,value: vec![], index: vec![]}), // TimeTicks
+                    sysContact: MibValue::string(mvstring {name: "sysContact".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 4],mutable: true,value: vec![], index: vec![]}), // DisplayString
+                    sysName: MibValue::string(mvstring {name: "sysName".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 5],mutable: true,value: vec![], index: vec![]}), // DisplayString
+                    sysLocation: MibValue::string(mvstring {name: "sysLocation".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 6],mutable: true,value: vec![], index: vec![]}), // DisplayString
+                    sysServices: MibValue::inti32(mvinti32 {name: "sysServices".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 1, 7],mutable: false,value: vec![], index: vec![]}), // INTEGER
                 },
                 interfaces: Interfaces {
                     oid: vec![1, 3, 6, 1, 2, 1, 2],
-                    ifNumber: MibValue::inti32(mvinti32 {name: "ifNumber".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 1],mutable: false,value: vec![]}), // INTEGER
+                    ifNumber: MibValue::inti32(mvinti32 {name: "ifNumber".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 1],mutable: false,value: vec![], index: vec![]}), // INTEGER
                     ifTable: IfTable {
                         oid: vec![1, 3, 6, 1, 2, 1, 2, 2],
-                        ifIndex: MibValue::inti32(mvinti32 {name: "ifIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 1],mutable: false,value: vec![]}), // INTEGER
-                        ifDescr: MibValue::string(mvstring {name: "ifDescr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 2],mutable: false,value: vec![]}), // DisplayString
-                        ifType: MibValue::inti32(mvinti32 {name: "ifType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 3],mutable: false,value: vec![]}), // INTEGER
-                        ifMtu: MibValue::inti32(mvinti32 {name: "ifMtu".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 4],mutable: false,value: vec![]}), // INTEGER
-                        ifSpeed: MibValue::intu32(mvintu32 {name: "ifSpeed".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 5],mutable: false,value: vec![]}), // Gauge
-                        ifPhysAddress: MibValue::ipv6(mvipv6 {name: "ifPhysAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 6],mutable: false,value: vec![]}), // PhysAddress
-                        ifAdminStatus: MibValue::inti32(mvinti32 {name: "ifAdminStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 7],mutable: true,value: vec![]}), // INTEGER
-                        ifOperStatus: MibValue::inti32(mvinti32 {name: "ifOperStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 8],mutable: false,value: vec![]}), // INTEGER
-                        ifLastChange: MibValue::intu32(mvintu32 {name: "ifLastChange".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 9],mutable: false,value: vec![]}), // TimeTicks
-                        ifInOctets: MibValue::intu32(mvintu32 {name: "ifInOctets".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 10],mutable: false,value: vec![]}), // Counter
-                        ifInUcastPkts: MibValue::intu32(mvintu32 {name: "ifInUcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 11],mutable: false,value: vec![]}), // Counter
-                        ifInNUcastPkts: MibValue::intu32(mvintu32 {name: "ifInNUcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 12],mutable: false,value: vec![]}), // Counter
-                        ifInDiscards: MibValue::intu32(mvintu32 {name: "ifInDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 13],mutable: false,value: vec![]}), // Counter
-                        ifInErrors: MibValue::intu32(mvintu32 {name: "ifInErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 14],mutable: false,value: vec![]}), // Counter
-                        ifInUnknownProtos: MibValue::intu32(mvintu32 {name: "ifInUnknownProtos".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 15],mutable: false,value: vec![]}), // Counter
-                        ifOutOctets: MibValue::intu32(mvintu32 {name: "ifOutOctets".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 16],mutable: false,value: vec![]}), // Counter
-                        ifOutUcastPkts: MibValue::intu32(mvintu32 {name: "ifOutUcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 17],mutable: false,value: vec![]}), // Counter
-                        ifOutNUcastPkts: MibValue::intu32(mvintu32 {name: "ifOutNUcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 18],mutable: false,value: vec![]}), // Counter
-                        ifOutDiscards: MibValue::intu32(mvintu32 {name: "ifOutDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 19],mutable: false,value: vec![]}), // Counter
-                        ifOutErrors: MibValue::intu32(mvintu32 {name: "ifOutErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 20],mutable: false,value: vec![]}), // Counter
-                        ifOutQLen: MibValue::intu32(mvintu32 {name: "ifOutQLen".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 21],mutable: false,value: vec![]}), // Gauge
-                        ifSpecific: MibValue::oid(mvoid {name: "ifSpecific".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 22],mutable: false,value: vec![]}), // OBJECT_IDENTIFIER
+                        ifIndex: MibValue::inti32(mvinti32 {name: "ifIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 1],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        ifDescr: MibValue::string(mvstring {name: "ifDescr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 2],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                        ifType: MibValue::inti32(mvinti32 {name: "ifType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 3],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        ifMtu: MibValue::inti32(mvinti32 {name: "ifMtu".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 4],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        ifSpeed: MibValue::intu32(mvintu32 {name: "ifSpeed".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 5],mutable: false,value: vec![], index: vec![]}), // Gauge
+                        ifPhysAddress: MibValue::ipv6(mvipv6 {name: "ifPhysAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 6],mutable: false,value: vec![], index: vec![]}), // PhysAddress
+                        ifAdminStatus: MibValue::inti32(mvinti32 {name: "ifAdminStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 7],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ifOperStatus: MibValue::inti32(mvinti32 {name: "ifOperStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 8],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        ifLastChange: MibValue::intu32(mvintu32 {name: "ifLastChange".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 9],mutable: false,value: vec![], index: vec![]}), // TimeTicks
+                        ifInOctets: MibValue::intu32(mvintu32 {name: "ifInOctets".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 10],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifInUcastPkts: MibValue::intu32(mvintu32 {name: "ifInUcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 11],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifInNUcastPkts: MibValue::intu32(mvintu32 {name: "ifInNUcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 12],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifInDiscards: MibValue::intu32(mvintu32 {name: "ifInDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 13],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifInErrors: MibValue::intu32(mvintu32 {name: "ifInErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 14],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifInUnknownProtos: MibValue::intu32(mvintu32 {name: "ifInUnknownProtos".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 15],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifOutOctets: MibValue::intu32(mvintu32 {name: "ifOutOctets".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 16],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifOutUcastPkts: MibValue::intu32(mvintu32 {name: "ifOutUcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 17],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifOutNUcastPkts: MibValue::intu32(mvintu32 {name: "ifOutNUcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 18],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifOutDiscards: MibValue::intu32(mvintu32 {name: "ifOutDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 19],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifOutErrors: MibValue::intu32(mvintu32 {name: "ifOutErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 20],mutable: false,value: vec![], index: vec![]}), // Counter
+                        ifOutQLen: MibValue::intu32(mvintu32 {name: "ifOutQLen".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 21],mutable: false,value: vec![], index: vec![]}), // Gauge
+                        ifSpecific: MibValue::oid(mvoid {name: "ifSpecific".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 22],mutable: false,value: vec![], index: vec![]}), // OBJECT_IDENTIFIER
                     },
+                    ifXTable: IfXTable::default(),
                 },
                 at: At {
                     oid: vec![1, 3, 6, 1, 2, 1, 3],
                     atTable: AtTable {
                         oid: vec![1, 3, 6, 1, 2, 1, 3, 1],
-                        atIfIndex: MibValue::inti32(mvinti32 {name: "atIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 3, 1, 1, 1],mutable: true,value: vec![]}), // INTEGER
-                        atPhysAddress: MibValue::ipv6(mvipv6 {name: "atPhysAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 3, 1, 1, 2],mutable: true,value: vec![]}), // PhysAddress
-                        atNetAddress: MibValue::ipv4(mvipv4 {name: "atNetAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 3, 1, 1, 3],mutable: true,value: vec![]}), // IpAddress
+                        atIfIndex: MibValue::inti32(mvinti32 {name: "atIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 3, 1, 1, 1],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        atPhysAddress: MibValue::ipv6(mvipv6 {name: "atPhysAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 3, 1, 1, 2],mutable: true,value: vec![], index: vec![]}), // PhysAddress
+                        atNetAddress: MibValue::ipv4(mvipv4 {name: "atNetAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 3, 1, 1, 3],mutable: true,value: vec![], index: vec![]}), // IpAddress
                     },
                 },
                 ip: Ip {
                     oid: vec![1, 3, 6, 1, 2, 1, 4],
-                    ipForwarding: MibValue::inti32(mvinti32 {name: "ipForwarding".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 1],mutable: true,value: vec![]}), // INTEGER
-                    ipDefaultTTL: MibValue::inti32(mvinti32 {name: "ipDefaultTTL".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 2],mutable: true,value: vec![]}), // INTEGER
-                    ipInReceives: MibValue::intu32(mvintu32 {name: "ipInReceives".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 3],mutable: false,value: vec![]}), // Counter
-                    ipInHdrErrors: MibValue::intu32(mvintu32 {name: "ipInHdrErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 4],mutable: false,value: vec![]}), // Counter
-                    ipInAddrErrors: MibValue::intu32(mvintu32 {name: "ipInAddrErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 5],mutable: false,value: vec![]}), // Counter
-                    ipForwDatagrams: MibValue::intu32(mvintu32 {name: "ipForwDatagrams".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 6],mutable: false,value: vec![]}), // Counter
-                    ipInUnknownProtos: MibValue::intu32(mvintu32 {name: "ipInUnknownProtos".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 7],mutable: false,value: vec![]}), // Counter
-                    ipInDiscards: MibValue::intu32(mvintu32 {name: "ipInDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 8],mutable: false,value: vec![]}), // Counter
-                    ipInDelivers: MibValue::intu32(mvintu32 {name: "ipInDelivers".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 9],mutable: false,value: vec![]}), // Counter
-                    ipOutRequests: MibValue::intu32(mvintu32 {name: "ipOutRequests".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 10],mutable: false,value: vec![]}), // Counter
-                    ipOutDiscards: MibValue::intu32(mvintu32 {name: "ipOutDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 11],mutable: false,value: vec![]}), // Counter
-                    ipOutNoRoutes: MibValue::intu32(mvintu32 {name: "ipOutNoRoutes".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 12],mutable: false,value: vec![]}), // Counter
-                    ipReasmTimeout: MibValue::inti32(mvinti32 {name: "ipReasmTimeout".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 13],mutable: false,value: vec![]}), // INTEGER
-                    ipReasmReqds: MibValue::intu32(mvintu32 {name: "ipReasmReqds".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 14],mutable: false,value: vec![]}), // Counter
-                    ipReasmOKs: MibValue::intu32(mvintu32 {name: "ipReasmOKs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 15],mutable: false,value: vec![]}), // Counter
-                    ipReasmFails: MibValue::intu32(mvintu32 {name: "ipReasmFails".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 16],mutable: false,value: vec![]}), // Counter
-                    ipFragOKs: MibValue::intu32(mvintu32 {name: "ipFragOKs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 17],mutable: false,value: vec![]}), // Counter
-                    ipFragFails: MibValue::intu32(mvintu32 {name: "ipFragFails".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 18],mutable: false,value: vec![]}), // Counter
-                    ipFragCreates: MibValue::intu32(mvintu32 {name: "ipFragCreates".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 19],mutable: false,value: vec![]}), // Counter
+                    ipForwarding: MibValue::inti32(mvinti32 {name: "ipForwarding".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 1],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                    ipDefaultTTL: MibValue::inti32(mvinti32 {name: "ipDefaultTTL".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 2],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                    ipInReceives: MibValue::intu32(mvintu32 {name: "ipInReceives".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 3],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipInHdrErrors: MibValue::intu32(mvintu32 {name: "ipInHdrErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 4],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipInAddrErrors: MibValue::intu32(mvintu32 {name: "ipInAddrErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 5],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipForwDatagrams: MibValue::intu32(mvintu32 {name: "ipForwDatagrams".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 6],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipInUnknownProtos: MibValue::intu32(mvintu32 {name: "ipInUnknownProtos".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 7],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipInDiscards: MibValue::intu32(mvintu32 {name: "ipInDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 8],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipInDelivers: MibValue::intu32(mvintu32 {name: "ipInDelivers".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 9],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipOutRequests: MibValue::intu32(mvintu32 {name: "ipOutRequests".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 10],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipOutDiscards: MibValue::intu32(mvintu32 {name: "ipOutDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 11],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipOutNoRoutes: MibValue::intu32(mvintu32 {name: "ipOutNoRoutes".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 12],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipReasmTimeout: MibValue::inti32(mvinti32 {name: "ipReasmTimeout".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 13],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                    ipReasmReqds: MibValue::intu32(mvintu32 {name: "ipReasmReqds".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 14],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipReasmOKs: MibValue::intu32(mvintu32 {name: "ipReasmOKs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 15],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipReasmFails: MibValue::intu32(mvintu32 {name: "ipReasmFails".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 16],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipFragOKs: MibValue::intu32(mvintu32 {name: "ipFragOKs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 17],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipFragFails: MibValue::intu32(mvintu32 {name: "ipFragFails".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 18],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipFragCreates: MibValue::intu32(mvintu32 {name: "ipFragCreates".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 19],mutable: false,value: vec![], index: vec![]}), // Counter
                     ipAddrTable: IpAddrTable {
                         oid: vec![1, 3, 6, 1, 2, 1, 4, 20],
-                        ipAdEntAddr: MibValue::ipv4(mvipv4 {name: "ipAdEntAddr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 20, 1, 1],mutable: false,value: vec![]}), // IpAddress
-                        ipAdEntIfIndex: MibValue::inti32(mvinti32 {name: "ipAdEntIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 20, 1, 2],mutable: false,value: vec![]}), // INTEGER
-                        ipAdEntNetMask: MibValue::ipv4(mvipv4 {name: "ipAdEntNetMask".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 20, 1, 3],mutable: false,value: vec![]}), // IpAddress
-                        ipAdEntBcastAddr: MibValue::inti32(mvinti32 {name: "ipAdEntBcastAddr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 20, 1, 4],mutable: false,value: vec![]}), // INTEGER
-                        ipAdEntReasmMaxSize: MibValue::inti32(mvinti32 {name: "ipAdEntReasmMaxSize".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 20, 1, 5],mutable: false,value: vec![]}), // INTEGER
+                        ipAdEntAddr: MibValue::ipv4(mvipv4 {name: "ipAdEntAddr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 20, 1, 1],mutable: false,value: vec![], index: vec![]}), // IpAddress
+                        ipAdEntIfIndex: MibValue::inti32(mvinti32 {name: "ipAdEntIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 20, 1, 2],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        ipAdEntNetMask: MibValue::ipv4(mvipv4 {name: "ipAdEntNetMask".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 20, 1, 3],mutable: false,value: vec![], index: vec![]}), // IpAddress
+                        ipAdEntBcastAddr: MibValue::inti32(mvinti32 {name: "ipAdEntBcastAddr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 20, 1, 4],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        ipAdEntReasmMaxSize: MibValue::inti32(mvinti32 {name: "ipAdEntReasmMaxSize".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 20, 1, 5],mutable: false,value: vec![], index: vec![]}), // INTEGER
                     },
                     ipRouteTable: IpRouteTable {
                         oid: vec![1, 3, 6, 1, 2, 1, 4, 21],
-                        ipRouteDest: MibValue::ipv4(mvipv4 {name: "ipRouteDest".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 1],mutable: true,value: vec![]}), // IpAddress
-                        ipRouteIfIndex: MibValue::inti32(mvinti32 {name: "ipRouteIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 2],mutable: true,value: vec![]}), // INTEGER
-                        ipRouteMetric1: MibValue::inti32(mvinti32 {name: "ipRouteMetric1".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 3],mutable: true,value: vec![]}), // INTEGER
-                        ipRouteMetric2: MibValue::inti32(mvinti32 {name: "ipRouteMetric2".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 4],mutable: true,value: vec![]}), // INTEGER
-                        ipRouteMetric3: MibValue::inti32(mvinti32 {name: "ipRouteMetric3".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 5],mutable: true,value: vec![]}), // INTEGER
-                        ipRouteMetric4: MibValue::inti32(mvinti32 {name: "ipRouteMetric4".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 6],mutable: true,value: vec![]}), // INTEGER
-                        ipRouteNextHop: MibValue::ipv4(mvipv4 {name: "ipRouteNextHop".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 7],mutable: true,value: vec![]}), // IpAddress
-                        ipRouteType: MibValue::inti32(mvinti32 {name: "ipRouteType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 8],mutable: true,value: vec![]}), // INTEGER
-                        ipRouteProto: MibValue::inti32(mvinti32 {name: "ipRouteProto".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 9],mutable: true,value: vec![]}), // INTEGER
-                        ipRouteAge: MibValue::inti32(mvinti32 {name: "ipRouteAge".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 10],mutable: true,value: vec![]}), // INTEGER
-                        ipRouteMask: MibValue::ipv4(mvipv4 {name: "ipRouteMask".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 11],mutable: true,value: vec![]}), // IpAddress
-                        ipRouteMetric5: MibValue::inti32(mvinti32 {name: "ipRouteMetric5".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 12],mutable: true,value: vec![]}), // INTEGER
-                        ipRouteInfo: MibValue::oid(mvoid {name: "ipRouteInfo".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 13],mutable: true,value: vec![]}), // OBJECT_IDENTIFIER
+                        ipRouteDest: MibValue::ipv4(mvipv4 {name: "ipRouteDest".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 1],mutable: true,value: vec![], index: vec![]}), // IpAddress
+                        ipRouteIfIndex: MibValue::inti32(mvinti32 {name: "ipRouteIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 2],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ipRouteMetric1: MibValue::inti32(mvinti32 {name: "ipRouteMetric1".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 3],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ipRouteMetric2: MibValue::inti32(mvinti32 {name: "ipRouteMetric2".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 4],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ipRouteMetric3: MibValue::inti32(mvinti32 {name: "ipRouteMetric3".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 5],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ipRouteMetric4: MibValue::inti32(mvinti32 {name: "ipRouteMetric4".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 6],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ipRouteNextHop: MibValue::ipv4(mvipv4 {name: "ipRouteNextHop".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 7],mutable: true,value: vec![], index: vec![]}), // IpAddress
+                        ipRouteType: MibValue::inti32(mvinti32 {name: "ipRouteType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 8],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ipRouteProto: MibValue::inti32(mvinti32 {name: "ipRouteProto".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 9],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ipRouteAge: MibValue::inti32(mvinti32 {name: "ipRouteAge".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 10],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ipRouteMask: MibValue::ipv4(mvipv4 {name: "ipRouteMask".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 11],mutable: true,value: vec![], index: vec![]}), // IpAddress
+                        ipRouteMetric5: MibValue::inti32(mvinti32 {name: "ipRouteMetric5".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 12],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ipRouteInfo: MibValue::oid(mvoid {name: "ipRouteInfo".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 21, 1, 13],mutable: true,value: vec![], index: vec![]}), // OBJECT_IDENTIFIER
                     },
                     ipNetToMediaTable: IpNetToMediaTable {
                         oid: vec![1, 3, 6, 1, 2, 1, 4, 22],
-                        ipNetToMediaIfIndex: MibValue::inti32(mvinti32 {name: "ipNetToMediaIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 22, 1, 1],mutable: true,value: vec![]}), // INTEGER
-                        ipNetToMediaPhysAddress: MibValue::ipv6(mvipv6 {name: "ipNetToMediaPhysAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 22, 1, 2],mutable: true,value: vec![]}), // PhysAddress
-                        ipNetToMediaNetAddress: MibValue::ipv4(mvipv4 {name: "ipNetToMediaNetAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 22, 1, 3],mutable: true,value: vec![]}), // IpAddress
-                        ipNetToMediaType: MibValue::inti32(mvinti32 {name: "ipNetToMediaType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 22, 1, 4],mutable: true,value: vec![]}), // INTEGER
+                        ipNetToMediaIfIndex: MibValue::inti32(mvinti32 {name: "ipNetToMediaIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 22, 1, 1],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        ipNetToMediaPhysAddress: MibValue::ipv6(mvipv6 {name: "ipNetToMediaPhysAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 22, 1, 2],mutable: true,value: vec![], index: vec![]}), // PhysAddress
+                        ipNetToMediaNetAddress: MibValue::ipv4(mvipv4 {name: "ipNetToMediaNetAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 22, 1, 3],mutable: true,value: vec![], index: vec![]}), // IpAddress
+                        ipNetToMediaType: MibValue::inti32(mvinti32 {name: "ipNetToMediaType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 22, 1, 4],mutable: true,value: vec![], index: vec![]}), // INTEGER
                     },
-                    ipRoutingDiscards: MibValue::intu32(mvintu32 {name: "ipRoutingDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 23],mutable: false,value: vec![]}), // Counter
+                    ipRoutingDiscards: MibValue::intu32(mvintu32 {name: "ipRoutingDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 23],mutable: false,value: vec![], index: vec![]}), // Counter
+                    ipAddressTable: IpAddressTable::default(),
+                    ipNetToPhysicalTable: IpNetToPhysicalTable::default(),
+                    inetCidrRouteTable: InetCidrRouteTable::default(),
                 },
                 icmp: Icmp {
                     oid: vec![1, 3, 6, 1, 2, 1, 5],
-                    icmpInMsgs: MibValue::intu32(mvintu32 {name: "icmpInMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 1],mutable: false,value: vec![]}), // Counter
-                    icmpInErrors: MibValue::intu32(mvintu32 {name: "icmpInErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 2],mutable: false,value: vec![]}), // Counter
-                    icmpInDestUnreachs: MibValue::intu32(mvintu32 {name: "icmpInDestUnreachs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 3],mutable: false,value: vec![]}), // Counter
-                    icmpInTimeExcds: MibValue::intu32(mvintu32 {name: "icmpInTimeExcds".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 4],mutable: false,value: vec![]}), // Counter
-                    icmpInParmProbs: MibValue::intu32(mvintu32 {name: "icmpInParmProbs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 5],mutable: false,value: vec![]}), // Counter
-                    icmpInSrcQuenchs: MibValue::intu32(mvintu32 {name: "icmpInSrcQuenchs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 6],mutable: false,value: vec![]}), // Counter
-                    icmpInRedirects: MibValue::intu32(mvintu32 {name: "icmpInRedirects".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 7],mutable: false,value: vec![]}), // Counter
-                    icmpInEchos: MibValue::intu32(mvintu32 {name: "icmpInEchos".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 8],mutable: false,value: vec![]}), // Counter
-                    icmpInEchoReps: MibValue::intu32(mvintu32 {name: "icmpInEchoReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 9],mutable: false,value: vec![]}), // Counter
-                    icmpInTimestamps: MibValue::intu32(mvintu32 {name: "icmpInTimestamps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 10],mutable: false,value: vec![]}), // Counter
-                    icmpInTimestampReps: MibValue::intu32(mvintu32 {name: "icmpInTimestampReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 11],mutable: false,value: vec![]}), // counter
-                    icmpInAddrMasks: MibValue::intu32(mvintu32 {name: "icmpInAddrMasks".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 12],mutable: false,value: vec![]}), // Counter
-                    icmpInAddrMaskReps: MibValue::intu32(mvintu32 {name: "icmpInAddrMaskReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 13],mutable: false,value: vec![]}), // counter
-                    icmpOutMsgs: MibValue::intu32(mvintu32 {name: "icmpOutMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 14],mutable: false,value: vec![]}), // Counter
-                    icmpOutErrors: MibValue::intu32(mvintu32 {name: "icmpOutErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 15],mutable: false,value: vec![]}), // Counter
-                    icmpOutDestUnreachs: MibValue::intu32(mvintu32 {name: "icmpOutDestUnreachs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 16],mutable: false,value: vec![]}), // counter
-                    icmpOutTimeExcds: MibValue::intu32(mvintu32 {name: "icmpOutTimeExcds".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 17],mutable: false,value: vec![]}), // Counter
-                    icmpOutParmProbs: MibValue::intu32(mvintu32 {name: "icmpOutParmProbs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 18],mutable: false,value: vec![]}), // Counter
-                    icmpOutSrcQuenchs: MibValue::intu32(mvintu32 {name: "icmpOutSrcQuenchs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 19],mutable: false,value: vec![]}), // Counter
-                    icmpOutRedirects: MibValue::intu32(mvintu32 {name: "icmpOutRedirects".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 20],mutable: false,value: vec![]}), // Counter
-                    icmpOutEchos: MibValue::intu32(mvintu32 {name: "icmpOutEchos".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 21],mutable: false,value: vec![]}), // Counter
-                    icmpOutEchoReps: MibValue::intu32(mvintu32 {name: "icmpOutEchoReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 22],mutable: false,value: vec![]}), // Counter
-                    icmpOutTimestamps: MibValue::intu32(mvintu32 {name: "icmpOutTimestamps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 23],mutable: false,value: vec![]}), // Counter
-                    icmpOutTimestampReps: MibValue::intu32(mvintu32 {name: "icmpOutTimestampReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 24],mutable: false,value: vec![]}), // counter
-                    icmpOutAddrMasks: MibValue::intu32(mvintu32 {name: "icmpOutAddrMasks".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 25],mutable: false,value: vec![]}), // Counter
-                    icmpOutAddrMaskReps: MibValue::intu32(mvintu32 {name: "icmpOutAddrMaskReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 26],mutable: false,value: vec![]}), // counter
+                    icmpInMsgs: MibValue::intu32(mvintu32 {name: "icmpInMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 1],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInErrors: MibValue::intu32(mvintu32 {name: "icmpInErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 2],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInDestUnreachs: MibValue::intu32(mvintu32 {name: "icmpInDestUnreachs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 3],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInTimeExcds: MibValue::intu32(mvintu32 {name: "icmpInTimeExcds".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 4],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInParmProbs: MibValue::intu32(mvintu32 {name: "icmpInParmProbs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 5],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInSrcQuenchs: MibValue::intu32(mvintu32 {name: "icmpInSrcQuenchs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 6],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInRedirects: MibValue::intu32(mvintu32 {name: "icmpInRedirects".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 7],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInEchos: MibValue::intu32(mvintu32 {name: "icmpInEchos".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 8],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInEchoReps: MibValue::intu32(mvintu32 {name: "icmpInEchoReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 9],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInTimestamps: MibValue::intu32(mvintu32 {name: "icmpInTimestamps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 10],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInTimestampReps: MibValue::intu32(mvintu32 {name: "icmpInTimestampReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 11],mutable: false,value: vec![], index: vec![]}), // counter
+                    icmpInAddrMasks: MibValue::intu32(mvintu32 {name: "icmpInAddrMasks".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 12],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpInAddrMaskReps: MibValue::intu32(mvintu32 {name: "icmpInAddrMaskReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 13],mutable: false,value: vec![], index: vec![]}), // counter
+                    icmpOutMsgs: MibValue::intu32(mvintu32 {name: "icmpOutMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 14],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpOutErrors: MibValue::intu32(mvintu32 {name: "icmpOutErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 15],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpOutDestUnreachs: MibValue::intu32(mvintu32 {name: "icmpOutDestUnreachs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 16],mutable: false,value: vec![], index: vec![]}), // counter
+                    icmpOutTimeExcds: MibValue::intu32(mvintu32 {name: "icmpOutTimeExcds".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 17],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpOutParmProbs: MibValue::intu32(mvintu32 {name: "icmpOutParmProbs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 18],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpOutSrcQuenchs: MibValue::intu32(mvintu32 {name: "icmpOutSrcQuenchs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 19],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpOutRedirects: MibValue::intu32(mvintu32 {name: "icmpOutRedirects".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 20],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpOutEchos: MibValue::intu32(mvintu32 {name: "icmpOutEchos".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 21],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpOutEchoReps: MibValue::intu32(mvintu32 {name: "icmpOutEchoReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 22],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpOutTimestamps: MibValue::intu32(mvintu32 {name: "icmpOutTimestamps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 23],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpOutTimestampReps: MibValue::intu32(mvintu32 {name: "icmpOutTimestampReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 24],mutable: false,value: vec![], index: vec![]}), // counter
+                    icmpOutAddrMasks: MibValue::intu32(mvintu32 {name: "icmpOutAddrMasks".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 25],mutable: false,value: vec![], index: vec![]}), // Counter
+                    icmpOutAddrMaskReps: MibValue::intu32(mvintu32 {name: "icmpOutAddrMaskReps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 5, 26],mutable: false,value: vec![], index: vec![]}), // counter
                 },
                 tcp: Tcp {
                     oid: vec![1, 3, 6, 1, 2, 1, 6],
-                    tcpRtoAlgorithm: MibValue::inti32(mvinti32 {name: "tcpRtoAlgorithm".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 1],mutable: false,value: vec![]}), // INTEGER
-                    tcpRtoMin: MibValue::inti32(mvinti32 {name: "tcpRtoMin".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 2],mutable: false,value: vec![]}), // INTEGER
-                    tcpRtoMax: MibValue::inti32(mvinti32 {name: "tcpRtoMax".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 3],mutable: false,value: vec![]}), // INTEGER
-                    tcpMaxConn: MibValue::inti32(mvinti32 {name: "tcpMaxConn".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 4],mutable: false,value: vec![]}), // INTEGER
-                    tcpActiveOpens: MibValue::intu32(mvintu32 {name: "tcpActiveOpens".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 5],mutable: false,value: vec![]}), // Counter
-                    tcpPassiveOpens: MibValue::intu32(mvintu32 {name: "tcpPassiveOpens".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 6],mutable: false,value: vec![]}), // Counter
-                    tcpAttemptFails: MibValue::intu32(mvintu32 {name: "tcpAttemptFails".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 7],mutable: false,value: vec![]}), // Counter
-                    tcpEstabResets: MibValue::intu32(mvintu32 {name: "tcpEstabResets".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 8],mutable: false,value: vec![]}), // Counter
-                    tcpCurrEstab: MibValue::intu32(mvintu32 {name: "tcpCurrEstab".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 9],mutable: false,value: vec![]}), // Gauge
-                    tcpInSegs: MibValue::intu32(mvintu32 {name: "tcpInSegs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 10],mutable: false,value: vec![]}), // Counter
-                    tcpOutSegs: MibValue::intu32(mvintu32 {name: "tcpOutSegs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 11],mutable: false,value: vec![]}), // Counter
-                    tcpRetransSegs: MibValue::intu32(mvintu32 {name: "tcpRetransSegs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 12],mutable: false,value: vec![]}), // Counter
+                    tcpRtoAlgorithm: MibValue::inti32(mvinti32 {name: "tcpRtoAlgorithm".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 1],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                    tcpRtoMin: MibValue::inti32(mvinti32 {name: "tcpRtoMin".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 2],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                    tcpRtoMax: MibValue::inti32(mvinti32 {name: "tcpRtoMax".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 3],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                    tcpMaxConn: MibValue::inti32(mvinti32 {name: "tcpMaxConn".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 4],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                    tcpActiveOpens: MibValue::intu32(mvintu32 {name: "tcpActiveOpens".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 5],mutable: false,value: vec![], index: vec![]}), // Counter
+                    tcpPassiveOpens: MibValue::intu32(mvintu32 {name: "tcpPassiveOpens".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 6],mutable: false,value: vec![], index: vec![]}), // Counter
+                    tcpAttemptFails: MibValue::intu32(mvintu32 {name: "tcpAttemptFails".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 7],mutable: false,value: vec![], index: vec![]}), // Counter
+                    tcpEstabResets: MibValue::intu32(mvintu32 {name: "tcpEstabResets".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 8],mutable: false,value: vec![], index: vec![]}), // Counter
+                    tcpCurrEstab: MibValue::intu32(mvintu32 {name: "tcpCurrEstab".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 9],mutable: false,value: vec![], index: vec![]}), // Gauge
+                    tcpInSegs: MibValue::intu32(mvintu32 {name: "tcpInSegs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 10],mutable: false,value: vec![], index: vec![]}), // Counter
+                    tcpOutSegs: MibValue::intu32(mvintu32 {name: "tcpOutSegs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 11],mutable: false,value: vec![], index: vec![]}), // Counter
+                    tcpRetransSegs: MibValue::intu32(mvintu32 {name: "tcpRetransSegs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 12],mutable: false,value: vec![], index: vec![]}), // Counter
                     tcpConnTable: TcpConnTable {
                         oid: vec![1, 3, 6, 1, 2, 1, 6, 13],
-                        tcpConnState: MibValue::inti32(mvinti32 {name: "tcpConnState".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 13, 1, 1],mutable: true,value: vec![]}), // INTEGER
-                        tcpConnLocalAddress: MibValue::ipv4(mvipv4 {name: "tcpConnLocalAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 13, 1, 2],mutable: false,value: vec![]}), // IpAddress
-                        tcpConnLocalPort: MibValue::inti32(mvinti32 {name: "tcpConnLocalPort".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 13, 1, 3],mutable: false,value: vec![]}), // INTEGER
-                        tcpConnRemAddress: MibValue::ipv4(mvipv4 {name: "tcpConnRemAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 13, 1, 4],mutable: false,value: vec![]}), // IpAddress
-                        tcpConnRemPort: MibValue::inti32(mvinti32 {name: "tcpConnRemPort".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 13, 1, 5],mutable: false,value: vec![]}), // INTEGER
+                        tcpConnState: MibValue::inti32(mvinti32 {name: "tcpConnState".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 13, 1, 1],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                        tcpConnLocalAddress: MibValue::ipv4(mvipv4 {name: "tcpConnLocalAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 13, 1, 2],mutable: false,value: vec![], index: vec![]}), // IpAddress
+                        tcpConnLocalPort: MibValue::inti32(mvinti32 {name: "tcpConnLocalPort".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 13, 1, 3],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        tcpConnRemAddress: MibValue::ipv4(mvipv4 {name: "tcpConnRemAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 13, 1, 4],mutable: false,value: vec![], index: vec![]}), // IpAddress
+                        tcpConnRemPort: MibValue::inti32(mvinti32 {name: "tcpConnRemPort".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 13, 1, 5],mutable: false,value: vec![], index: vec![]}), // INTEGER
                     },
-                    tcpInErrs: MibValue::intu32(mvintu32 {name: "tcpInErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 14],mutable: false,value: vec![]}), // Counter
-                    tcpOutRsts: MibValue::intu32(mvintu32 {name: "tcpOutRsts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 15],mutable: false,value: vec![]}), // Counter
+                    tcpInErrs: MibValue::intu32(mvintu32 {name: "tcpInErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 14],mutable: false,value: vec![], index: vec![]}), // Counter
+                    tcpOutRsts: MibValue::intu32(mvintu32 {name: "tcpOutRsts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 15],mutable: false,value: vec![], index: vec![]}), // Counter
+                    tcpConnectionTable: TcpConnectionTable::default(),
+                    tcpListenerTable: TcpListenerTable::default(),
                 },
                 udp: Udp {
                     oid: vec![1, 3, 6, 1, 2, 1, 7],
-                    udpInDatagrams: MibValue::intu32(mvintu32 {name: "udpInDatagrams".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 1],mutable: false,value: vec![]}), // Counter
-                    udpNoPorts: MibValue::intu32(mvintu32 {name: "udpNoPorts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 2],mutable: false,value: vec![]}), // Counter
-                    udpInErrors: MibValue::intu32(mvintu32 {name: "udpInErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 3],mutable: false,value: vec![]}), // Counter
-                    udpOutDatagrams: MibValue::intu32(mvintu32 {name: "udpOutDatagrams".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 4],mutable: false,value: vec![]}), // Counter
+                    udpInDatagrams: MibValue::intu32(mvintu32 {name: "udpInDatagrams".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 1],mutable: false,value: vec![], index: vec![]}), // Counter
+                    udpNoPorts: MibValue::intu32(mvintu32 {name: "udpNoPorts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 2],mutable: false,value: vec![], index: vec![]}), // Counter
+                    udpInErrors: MibValue::intu32(mvintu32 {name: "udpInErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 3],mutable: false,value: vec![], index: vec![]}), // Counter
+                    udpOutDatagrams: MibValue::intu32(mvintu32 {name: "udpOutDatagrams".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 4],mutable: false,value: vec![], index: vec![]}), // Counter
                     udpTable: UdpTable {
                         oid: vec![1, 3, 6, 1, 2, 1, 7, 5],
-                        udpLocalAddress: MibValue::ipv4(mvipv4 {name: "udpLocalAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 5, 1, 1],mutable: false,value: vec![]}), // IpAddress
-                        udpLocalPort: MibValue::inti32(mvinti32 {name: "udpLocalPort".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 5, 1, 2],mutable: false,value: vec![]}), // INTEGER
+                        udpLocalAddress: MibValue::ipv4(mvipv4 {name: "udpLocalAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 5, 1, 1],mutable: false,value: vec![], index: vec![]}), // IpAddress
+                        udpLocalPort: MibValue::inti32(mvinti32 {name: "udpLocalPort".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 5, 1, 2],mutable: false,value: vec![], index: vec![]}), // INTEGER
                     },
+                    udpEndpointTable: UdpEndpointTable::default(),
                 },
                 egp: Egp {
                     oid: vec![1, 3, 6, 1, 2, 1, 8],
-                    egpInMsgs: MibValue::intu32(mvintu32 {name: "egpInMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 1],mutable: false,value: vec![]}), // Counter
-                    egpInErrors: MibValue::intu32(mvintu32 {name: "egpInErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 2],mutable: false,value: vec![]}), // Counter
-                    egpOutMsgs: MibValue::intu32(mvintu32 {name: "egpOutMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 3],mutable: false,value: vec![]}), // Counter
-                    egpOutErrors: MibValue::intu32(mvintu32 {name: "egpOutErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 4],mutable: false,value: vec![]}), // Counter
+                    egpInMsgs: MibValue::intu32(mvintu32 {name: "egpInMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 1],mutable: false,value: vec![], index: vec![]}), // Counter
+                    egpInErrors: MibValue::intu32(mvintu32 {name: "egpInErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 2],mutable: false,value: vec![], index: vec![]}), // Counter
+                    egpOutMsgs: MibValue::intu32(mvintu32 {name: "egpOutMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 3],mutable: false,value: vec![], index: vec![]}), // Counter
+                    egpOutErrors: MibValue::intu32(mvintu32 {name: "egpOutErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 4],mutable: false,value: vec![], index: vec![]}), // Counter
                     egpNeighTable: EgpNeighTable {
                         oid: vec![1, 3, 6, 1, 2, 1, 8, 5],
-                        egpNeighState: MibValue::inti32(mvinti32 {name: "egpNeighState".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 1],mutable: false,value: vec![]}), // INTEGER
-                        egpNeighAddr: MibValue::ipv4(mvipv4 {name: "egpNeighAddr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 2],mutable: false,value: vec![]}), // IpAddress
-                        egpNeighAs: MibValue::inti32(mvinti32 {name: "egpNeighAs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 3],mutable: false,value: vec![]}), // INTEGER
-                        egpNeighInMsgs: MibValue::intu32(mvintu32 {name: "egpNeighInMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 4],mutable: false,value: vec![]}), // Counter
-                        egpNeighInErrs: MibValue::intu32(mvintu32 {name: "egpNeighInErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 5],mutable: false,value: vec![]}), // Counter
-                        egpNeighOutMsgs: MibValue::intu32(mvintu32 {name: "egpNeighOutMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 6],mutable: false,value: vec![]}), // Counter
-                        egpNeighOutErrs: MibValue::intu32(mvintu32 {name: "egpNeighOutErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 7],mutable: false,value: vec![]}), // Counter
-                        egpNeighInErrMsgs: MibValue::intu32(mvintu32 {name: "egpNeighInErrMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 8],mutable: false,value: vec![]}), // Counter
-                        egpNeighOutErrMsgs: MibValue::intu32(mvintu32 {name: "egpNeighOutErrMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 9],mutable: false,value: vec![]}), // Counter
-                        egpNeighStateUps: MibValue::intu32(mvintu32 {name: "egpNeighStateUps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 10],mutable: false,value: vec![]}), // Counter
-                        egpNeighStateDowns: MibValue::intu32(mvintu32 {name: "egpNeighStateDowns".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 11],mutable: false,value: vec![]}), // Counter
-                        egpNeighIntervalHello: MibValue::inti32(mvinti32 {name: "egpNeighIntervalHello".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 12],mutable: false,value: vec![]}), // INTEGER
-                        egpNeighIntervalPoll: MibValue::inti32(mvinti32 {name: "egpNeighIntervalPoll".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 13],mutable: false,value: vec![]}), // INTEGER
-                        egpNeighMode: MibValue::inti32(mvinti32 {name: "egpNeighMode".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 14],mutable: false,value: vec![]}), // INTEGER
-                        egpNeighEventTrigger: MibValue::inti32(mvinti32 {name: "egpNeighEventTrigger".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 15],mutable: true,value: vec![]}), // INTEGER
+                        egpNeighState: MibValue::inti32(mvinti32 {name: "egpNeighState".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 1],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        egpNeighAddr: MibValue::ipv4(mvipv4 {name: "egpNeighAddr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 2],mutable: false,value: vec![], index: vec![]}), // IpAddress
+                        egpNeighAs: MibValue::inti32(mvinti32 {name: "egpNeighAs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 3],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        egpNeighInMsgs: MibValue::intu32(mvintu32 {name: "egpNeighInMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 4],mutable: false,value: vec![], index: vec![]}), // Counter
+                        egpNeighInErrs: MibValue::intu32(mvintu32 {name: "egpNeighInErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 5],mutable: false,value: vec![], index: vec![]}), // Counter
+                        egpNeighOutMsgs: MibValue::intu32(mvintu32 {name: "egpNeighOutMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 6],mutable: false,value: vec![], index: vec![]}), // Counter
+                        egpNeighOutErrs: MibValue::intu32(mvintu32 {name: "egpNeighOutErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 7],mutable: false,value: vec![], index: vec![]}), // Counter
+                        egpNeighInErrMsgs: MibValue::intu32(mvintu32 {name: "egpNeighInErrMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 8],mutable: false,value: vec![], index: vec![]}), // Counter
+                        egpNeighOutErrMsgs: MibValue::intu32(mvintu32 {name: "egpNeighOutErrMsgs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 9],mutable: false,value: vec![], index: vec![]}), // Counter
+                        egpNeighStateUps: MibValue::intu32(mvintu32 {name: "egpNeighStateUps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 10],mutable: false,value: vec![], index: vec![]}), // Counter
+                        egpNeighStateDowns: MibValue::intu32(mvintu32 {name: "egpNeighStateDowns".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 11],mutable: false,value: vec![], index: vec![]}), // Counter
+                        egpNeighIntervalHello: MibValue::inti32(mvinti32 {name: "egpNeighIntervalHello".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 12],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        egpNeighIntervalPoll: MibValue::inti32(mvinti32 {name: "egpNeighIntervalPoll".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 13],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        egpNeighMode: MibValue::inti32(mvinti32 {name: "egpNeighMode".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 14],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                        egpNeighEventTrigger: MibValue::inti32(mvinti32 {name: "egpNeighEventTrigger".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 5, 1, 15],mutable: true,value: vec![], index: vec![]}), // INTEGER
                     },
-                    egpAs: MibValue::inti32(mvinti32 {name: "egpAs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 6],mutable: false,value: vec![]}), // INTEGER
+                    egpAs: MibValue::inti32(mvinti32 {name: "egpAs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 8, 6],mutable: false,value: vec![], index: vec![]}), // INTEGER
                 },
-                transmission: MibValue::oid(mvoid {name: "transmission".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 10],mutable: false,value: vec![]}), // objectidentity
+                transmission: MibValue::oid(mvoid {name: "transmission".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 10],mutable: false,value: vec![], index: vec![]}), // objectidentity
                 snmp: Snmp {
                     oid: vec![1, 3, 6, 1, 2, 1, 11],
-                    snmpInPkts: MibValue::intu32(mvintu32 {name: "snmpInPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 1],mutable: false,value: vec![]}), // Counter
-                    snmpOutPkts: MibValue::intu32(mvintu32 {name: "snmpOutPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 2],mutable: false,value: vec![]}), // Counter
-                    snmpInBadVersions: MibValue::intu32(mvintu32 {name: "snmpInBadVersions".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 3],mutable: false,value: vec![]}), // Counter
-                    snmpInBadCommunityNames: MibValue::intu32(mvintu32 {name: "snmpInBadCommunityNames".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 4],mutable: false,value: vec![]}), // Counter
-                    snmpInBadCommunityUses: MibValue::intu32(mvintu32 {name: "snmpInBadCommunityUses".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 5],mutable: false,value: vec![]}), // Counter
-                    snmpInASNParseErrs: MibValue::intu32(mvintu32 {name: "snmpInASNParseErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 6],mutable: false,value: vec![]}), // Counter
-                    snmpInTooBigs: MibValue::intu32(mvintu32 {name: "snmpInTooBigs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 8],mutable: false,value: vec![]}), // Counter
-                    snmpInNoSuchNames: MibValue::intu32(mvintu32 {name: "snmpInNoSuchNames".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 9],mutable: false,value: vec![]}), // Counter
-                    snmpInBadValues: MibValue::intu32(mvintu32 {name: "snmpInBadValues".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 10],mutable: false,value: vec![]}), // Counter
-                    snmpInReadOnlys: MibValue::intu32(mvintu32 {name: "snmpInReadOnlys".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 11],mutable: false,value: vec![]}), // Counter
-                    snmpInGenErrs: MibValue::intu32(mvintu32 {name: "snmpInGenErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 12],mutable: false,value: vec![]}), // Counter
-                    snmpInTotalReqVars: MibValue::intu32(mvintu32 {name: "snmpInTotalReqVars".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 13],mutable: false,value: vec![]}), // Counter
-                    snmpInTotalSetVars: MibValue::intu32(mvintu32 {name: "snmpInTotalSetVars".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 14],mutable: false,value: vec![]}), // Counter
-                    snmpInGetRequests: MibValue::intu32(mvintu32 {name: "snmpInGetRequests".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 15],mutable: false,value: vec![]}), // Counter
-                    snmpInGetNexts: MibValue::intu32(mvintu32 {name: "snmpInGetNexts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 16],mutable: false,value: vec![]}), // Counter
-                    snmpInSetRequests: MibValue::intu32(mvintu32 {name: "snmpInSetRequests".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 17],mutable: false,value: vec![]}), // Counter
-                    snmpInGetResponses: MibValue::intu32(mvintu32 {name: "snmpInGetResponses".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 18],mutable: false,value: vec![]}), // Counter
-                    snmpInTraps: MibValue::intu32(mvintu32 {name: "snmpInTraps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 19],mutable: false,value: vec![]}), // Counter
-                    snmpOutTooBigs: MibValue::intu32(mvintu32 {name: "snmpOutTooBigs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 20],mutable: false,value: vec![]}), // Counter
-                    snmpOutNoSuchNames: MibValue::intu32(mvintu32 {name: "snmpOutNoSuchNames".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 21],mutable: false,value: vec![]}), // Counter
-                    snmpOutBadValues: MibValue::intu32(mvintu32 {name: "snmpOutBadValues".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 22],mutable: false,value: vec![]}), // Counter
-                    snmpOutGenErrs: MibValue::intu32(mvintu32 {name: "snmpOutGenErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 24],mutable: false,value: vec![]}), // Counter
-                    snmpOutGetRequests: MibValue::intu32(mvintu32 {name: "snmpOutGetRequests".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 25],mutable: false,value: vec![]}), // Counter
-                    snmpOutGetNexts: MibValue::intu32(mvintu32 {name: "snmpOutGetNexts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 26],mutable: false,value: vec![]}), // Counter
-                    snmpOutSetRequests: MibValue::intu32(mvintu32 {name: "snmpOutSetRequests".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 27],mutable: false,value: vec![]}), // Counter
-                    snmpOutGetResponses: MibValue::intu32(mvintu32 {name: "snmpOutGetResponses".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 28],mutable: false,value: vec![]}), // Counter
-                    snmpOutTraps: MibValue::intu32(mvintu32 {name: "snmpOutTraps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 29],mutable: false,value: vec![]}), // Counter
-                    snmpEnableAuthenTraps: MibValue::inti32(mvinti32 {name: "snmpEnableAuthenTraps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 30],mutable: true,value: vec![]}), // INTEGER
+                    snmpInPkts: MibValue::intu32(mvintu32 {name: "snmpInPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 1],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpOutPkts: MibValue::intu32(mvintu32 {name: "snmpOutPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 2],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInBadVersions: MibValue::intu32(mvintu32 {name: "snmpInBadVersions".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 3],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInBadCommunityNames: MibValue::intu32(mvintu32 {name: "snmpInBadCommunityNames".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 4],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInBadCommunityUses: MibValue::intu32(mvintu32 {name: "snmpInBadCommunityUses".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 5],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInASNParseErrs: MibValue::intu32(mvintu32 {name: "snmpInASNParseErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 6],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInTooBigs: MibValue::intu32(mvintu32 {name: "snmpInTooBigs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 8],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInNoSuchNames: MibValue::intu32(mvintu32 {name: "snmpInNoSuchNames".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 9],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInBadValues: MibValue::intu32(mvintu32 {name: "snmpInBadValues".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 10],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInReadOnlys: MibValue::intu32(mvintu32 {name: "snmpInReadOnlys".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 11],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInGenErrs: MibValue::intu32(mvintu32 {name: "snmpInGenErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 12],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInTotalReqVars: MibValue::intu32(mvintu32 {name: "snmpInTotalReqVars".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 13],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInTotalSetVars: MibValue::intu32(mvintu32 {name: "snmpInTotalSetVars".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 14],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInGetRequests: MibValue::intu32(mvintu32 {name: "snmpInGetRequests".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 15],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInGetNexts: MibValue::intu32(mvintu32 {name: "snmpInGetNexts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 16],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInSetRequests: MibValue::intu32(mvintu32 {name: "snmpInSetRequests".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 17],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInGetResponses: MibValue::intu32(mvintu32 {name: "snmpInGetResponses".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 18],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpInTraps: MibValue::intu32(mvintu32 {name: "snmpInTraps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 19],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpOutTooBigs: MibValue::intu32(mvintu32 {name: "snmpOutTooBigs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 20],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpOutNoSuchNames: MibValue::intu32(mvintu32 {name: "snmpOutNoSuchNames".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 21],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpOutBadValues: MibValue::intu32(mvintu32 {name: "snmpOutBadValues".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 22],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpOutGenErrs: MibValue::intu32(mvintu32 {name: "snmpOutGenErrs".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 24],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpOutGetRequests: MibValue::intu32(mvintu32 {name: "snmpOutGetRequests".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 25],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpOutGetNexts: MibValue::intu32(mvintu32 {name: "snmpOutGetNexts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 26],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpOutSetRequests: MibValue::intu32(mvintu32 {name: "snmpOutSetRequests".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 27],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpOutGetResponses: MibValue::intu32(mvintu32 {name: "snmpOutGetResponses".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 28],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpOutTraps: MibValue::intu32(mvintu32 {name: "snmpOutTraps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 29],mutable: false,value: vec![], index: vec![]}), // Counter
+                    snmpEnableAuthenTraps: MibValue::inti32(mvinti32 {name: "snmpEnableAuthenTraps".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 11, 30],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                },
+                lldp: Lldp::default(),
+                cdp: Cdp::default(),
+                host: HostResources::default(),
+                entity: Entity::default(),
+                bridge: Bridge::default(),
+                ucd: Ucd::default(),
+                vendor: Vendor::default(),
+                pinned: vec![],
+            }
+        }
+    }
+
+    impl Default for IfXTable {
+        fn default() -> Self {
+            IfXTable {
+                oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1],
+                ifName: MibValue::string(mvstring {name: "ifName".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 1],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                ifInMulticastPkts: MibValue::intu32(mvintu32 {name: "ifInMulticastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 2],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ifInBroadcastPkts: MibValue::intu32(mvintu32 {name: "ifInBroadcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 3],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ifOutMulticastPkts: MibValue::intu32(mvintu32 {name: "ifOutMulticastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 4],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ifOutBroadcastPkts: MibValue::intu32(mvintu32 {name: "ifOutBroadcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 5],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ifHCInOctets: MibValue::intu64(mvintu64 {name: "ifHCInOctets".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 6],mutable: false,value: vec![], index: vec![]}), // Counter64
+                ifHCInUcastPkts: MibValue::intu64(mvintu64 {name: "ifHCInUcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 7],mutable: false,value: vec![], index: vec![]}), // Counter64
+                ifHCInMulticastPkts: MibValue::intu64(mvintu64 {name: "ifHCInMulticastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 8],mutable: false,value: vec![], index: vec![]}), // Counter64
+                ifHCInBroadcastPkts: MibValue::intu64(mvintu64 {name: "ifHCInBroadcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 9],mutable: false,value: vec![], index: vec![]}), // Counter64
+                ifHCOutOctets: MibValue::intu64(mvintu64 {name: "ifHCOutOctets".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 10],mutable: false,value: vec![], index: vec![]}), // Counter64
+                ifHCOutUcastPkts: MibValue::intu64(mvintu64 {name: "ifHCOutUcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 11],mutable: false,value: vec![], index: vec![]}), // Counter64
+                ifHCOutMulticastPkts: MibValue::intu64(mvintu64 {name: "ifHCOutMulticastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 12],mutable: false,value: vec![], index: vec![]}), // Counter64
+                ifHCOutBroadcastPkts: MibValue::intu64(mvintu64 {name: "ifHCOutBroadcastPkts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 13],mutable: false,value: vec![], index: vec![]}), // Counter64
+                ifLinkUpDownTrapEnable: MibValue::inti32(mvinti32 {name: "ifLinkUpDownTrapEnable".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 14],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                ifHighSpeed: MibValue::intu32(mvintu32 {name: "ifHighSpeed".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 15],mutable: false,value: vec![], index: vec![]}), // Gauge32
+                ifPromiscuousMode: MibValue::inti32(mvinti32 {name: "ifPromiscuousMode".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 16],mutable: true,value: vec![], index: vec![]}), // TruthValue
+                ifConnectorPresent: MibValue::inti32(mvinti32 {name: "ifConnectorPresent".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 17],mutable: false,value: vec![], index: vec![]}), // TruthValue
+                ifAlias: MibValue::string(mvstring {name: "ifAlias".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 18],mutable: true,value: vec![], index: vec![]}), // DisplayString
+                ifCounterDiscontinuityTime: MibValue::intu32(mvintu32 {name: "ifCounterDiscontinuityTime".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 19],mutable: false,value: vec![], index: vec![]}), // TimeStamp
+            }
+        }
+    }
+
+    impl Default for IpAddressTable {
+        fn default() -> Self {
+            IpAddressTable {
+                oid: vec![1, 3, 6, 1, 2, 1, 4, 34],
+                ipAddressIfIndex: MibValue::inti32(mvinti32 {name: "ipAddressIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 34, 1, 3],mutable: true,value: vec![], index: vec![]}), // InterfaceIndex
+                ipAddressType: MibValue::inti32(mvinti32 {name: "ipAddressType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 34, 1, 4],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                ipAddressPrefix: MibValue::oid(mvoid {name: "ipAddressPrefix".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 34, 1, 5],mutable: false,value: vec![], index: vec![]}), // RowPointer
+                ipAddressOrigin: MibValue::inti32(mvinti32 {name: "ipAddressOrigin".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 34, 1, 6],mutable: false,value: vec![], index: vec![]}), // IpAddressOriginTC
+                ipAddressStatus: MibValue::inti32(mvinti32 {name: "ipAddressStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 34, 1, 7],mutable: true,value: vec![], index: vec![]}), // IpAddressStatusTC
+                ipAddressCreated: MibValue::intu32(mvintu32 {name: "ipAddressCreated".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 34, 1, 8],mutable: false,value: vec![], index: vec![]}), // TimeStamp
+                ipAddressLastChanged: MibValue::intu32(mvintu32 {name: "ipAddressLastChanged".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 34, 1, 9],mutable: false,value: vec![], index: vec![]}), // TimeStamp
+                ipAddressRowStatus: MibValue::inti32(mvinti32 {name: "ipAddressRowStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 34, 1, 10],mutable: true,value: vec![], index: vec![]}), // RowStatus
+                ipAddressStorageType: MibValue::inti32(mvinti32 {name: "ipAddressStorageType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 34, 1, 11],mutable: true,value: vec![], index: vec![]}), // StorageType
+            }
+        }
+    }
+
+    impl Default for IpNetToPhysicalTable {
+        fn default() -> Self {
+            IpNetToPhysicalTable {
+                oid: vec![1, 3, 6, 1, 2, 1, 4, 35],
+                ipNetToPhysicalPhysAddress: MibValue::string(mvstring {name: "ipNetToPhysicalPhysAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 35, 1, 4],mutable: true,value: vec![], index: vec![]}), // PhysAddress
+                ipNetToPhysicalLastUpdated: MibValue::intu32(mvintu32 {name: "ipNetToPhysicalLastUpdated".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 35, 1, 5],mutable: false,value: vec![], index: vec![]}), // TimeStamp
+                ipNetToPhysicalType: MibValue::inti32(mvinti32 {name: "ipNetToPhysicalType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 35, 1, 6],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                ipNetToPhysicalState: MibValue::inti32(mvinti32 {name: "ipNetToPhysicalState".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 35, 1, 7],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                ipNetToPhysicalRowStatus: MibValue::inti32(mvinti32 {name: "ipNetToPhysicalRowStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 35, 1, 8],mutable: true,value: vec![], index: vec![]}), // RowStatus
+            }
+        }
+    }
+
+    impl Default for InetCidrRouteTable {
+        fn default() -> Self {
+            InetCidrRouteTable {
+                oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7],
+                inetCidrRouteIfIndex: MibValue::inti32(mvinti32 {name: "inetCidrRouteIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 7],mutable: true,value: vec![], index: vec![]}), // InterfaceIndexOrZero
+                inetCidrRouteType: MibValue::inti32(mvinti32 {name: "inetCidrRouteType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 8],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                inetCidrRouteProto: MibValue::inti32(mvinti32 {name: "inetCidrRouteProto".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 9],mutable: false,value: vec![], index: vec![]}), // IANAipRouteProtocol
+                inetCidrRouteAge: MibValue::intu32(mvintu32 {name: "inetCidrRouteAge".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 10],mutable: false,value: vec![], index: vec![]}), // Gauge32
+                inetCidrRouteNextHopAS: MibValue::intu32(mvintu32 {name: "inetCidrRouteNextHopAS".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 11],mutable: true,value: vec![], index: vec![]}), // InetAutonomousSystemNumber
+                inetCidrRouteMetric1: MibValue::inti32(mvinti32 {name: "inetCidrRouteMetric1".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 12],mutable: true,value: vec![], index: vec![]}), // Integer32
+                inetCidrRouteMetric2: MibValue::inti32(mvinti32 {name: "inetCidrRouteMetric2".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 13],mutable: true,value: vec![], index: vec![]}), // Integer32
+                inetCidrRouteMetric3: MibValue::inti32(mvinti32 {name: "inetCidrRouteMetric3".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 14],mutable: true,value: vec![], index: vec![]}), // Integer32
+                inetCidrRouteMetric4: MibValue::inti32(mvinti32 {name: "inetCidrRouteMetric4".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 15],mutable: true,value: vec![], index: vec![]}), // Integer32
+                inetCidrRouteMetric5: MibValue::inti32(mvinti32 {name: "inetCidrRouteMetric5".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 16],mutable: true,value: vec![], index: vec![]}), // Integer32
+                inetCidrRouteStatus: MibValue::inti32(mvinti32 {name: "inetCidrRouteStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 4, 24, 7, 1, 17],mutable: true,value: vec![], index: vec![]}), // RowStatus
+            }
+        }
+    }
+
+    impl Default for TcpConnectionTable {
+        fn default() -> Self {
+            TcpConnectionTable {
+                oid: vec![1, 3, 6, 1, 2, 1, 6, 19],
+                tcpConnectionState: MibValue::inti32(mvinti32 {name: "tcpConnectionState".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 19, 1, 7],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                tcpConnectionProcess: MibValue::intu32(mvintu32 {name: "tcpConnectionProcess".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 19, 1, 8],mutable: false,value: vec![], index: vec![]}), // Unsigned32
+            }
+        }
+    }
+
+    impl Default for TcpListenerTable {
+        fn default() -> Self {
+            TcpListenerTable {
+                oid: vec![1, 3, 6, 1, 2, 1, 6, 20],
+                tcpListenerProcess: MibValue::intu32(mvintu32 {name: "tcpListenerProcess".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 6, 20, 1, 4],mutable: false,value: vec![], index: vec![]}), // Unsigned32
+            }
+        }
+    }
+
+    impl Default for UdpEndpointTable {
+        fn default() -> Self {
+            UdpEndpointTable {
+                oid: vec![1, 3, 6, 1, 2, 1, 7, 7],
+                udpEndpointProcess: MibValue::intu32(mvintu32 {name: "udpEndpointProcess".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 7, 7, 1, 8],mutable: false,value: vec![], index: vec![]}), // Unsigned32
+            }
+        }
+    }
+
+    impl Default for Lldp {
+        fn default() -> Self {
+            Lldp {
+                oid: vec![1, 0, 8802, 1, 1, 2, 1],
+                lldpLocChassisIdSubtype: MibValue::inti32(mvinti32 {name: "lldpLocChassisIdSubtype".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 3, 1],mutable: false,value: vec![], index: vec![]}), // LldpChassisIdSubtype
+                lldpLocChassisId: MibValue::string(mvstring {name: "lldpLocChassisId".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 3, 2],mutable: false,value: vec![], index: vec![]}), // LldpChassisId
+                lldpLocSysName: MibValue::string(mvstring {name: "lldpLocSysName".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 3, 3],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                lldpLocPortTable: LldpLocPortTable {
+                    oid: vec![1, 0, 8802, 1, 1, 2, 1, 3, 7],
+                    lldpLocPortIdSubtype: MibValue::inti32(mvinti32 {name: "lldpLocPortIdSubtype".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 3, 7, 1, 2],mutable: false,value: vec![], index: vec![]}), // LldpPortIdSubtype
+                    lldpLocPortId: MibValue::string(mvstring {name: "lldpLocPortId".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 3, 7, 1, 3],mutable: false,value: vec![], index: vec![]}), // LldpPortId
+                    lldpLocPortDesc: MibValue::string(mvstring {name: "lldpLocPortDesc".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 3, 7, 1, 4],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                },
+                lldpRemTable: LldpRemTable {
+                    oid: vec![1, 0, 8802, 1, 1, 2, 1, 4, 1],
+                    lldpRemChassisIdSubtype: MibValue::inti32(mvinti32 {name: "lldpRemChassisIdSubtype".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 4],mutable: false,value: vec![], index: vec![]}), // LldpChassisIdSubtype
+                    lldpRemChassisId: MibValue::string(mvstring {name: "lldpRemChassisId".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 5],mutable: false,value: vec![], index: vec![]}), // LldpChassisId
+                    lldpRemPortIdSubtype: MibValue::inti32(mvinti32 {name: "lldpRemPortIdSubtype".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 6],mutable: false,value: vec![], index: vec![]}), // LldpPortIdSubtype
+                    lldpRemPortId: MibValue::string(mvstring {name: "lldpRemPortId".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 7],mutable: false,value: vec![], index: vec![]}), // LldpPortId
+                    lldpRemPortDesc: MibValue::string(mvstring {name: "lldpRemPortDesc".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 8],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                    lldpRemSysName: MibValue::string(mvstring {name: "lldpRemSysName".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 9],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                    lldpRemSysDesc: MibValue::string(mvstring {name: "lldpRemSysDesc".to_owned(), oid: vec![1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 10],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                },
+            }
+        }
+    }
+
+    impl Default for HostResources {
+        fn default() -> Self {
+            HostResources {
+                oid: vec![1, 3, 6, 1, 2, 1, 25],
+                hrSystemUptime: MibValue::intu32(mvintu32 {name: "hrSystemUptime".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 1, 1],mutable: false,value: vec![], index: vec![]}), // TimeTicks
+                hrSystemNumUsers: MibValue::intu32(mvintu32 {name: "hrSystemNumUsers".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 1, 5],mutable: false,value: vec![], index: vec![]}), // Gauge32
+                hrSystemProcesses: MibValue::intu32(mvintu32 {name: "hrSystemProcesses".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 1, 6],mutable: false,value: vec![], index: vec![]}), // Gauge32
+                hrSystemMaxProcesses: MibValue::inti32(mvinti32 {name: "hrSystemMaxProcesses".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 1, 7],mutable: false,value: vec![], index: vec![]}), // Integer32
+                hrMemorySize: MibValue::inti32(mvinti32 {name: "hrMemorySize".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 2, 2],mutable: false,value: vec![], index: vec![]}), // KBytes
+                hrStorageTable: HrStorageTable {
+                    oid: vec![1, 3, 6, 1, 2, 1, 25, 2, 3],
+                    hrStorageIndex: MibValue::inti32(mvinti32 {name: "hrStorageIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 2, 3, 1, 1],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    hrStorageType: MibValue::oid(mvoid {name: "hrStorageType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 2, 3, 1, 2],mutable: false,value: vec![], index: vec![]}), // AutonomousType
+                    hrStorageDescr: MibValue::string(mvstring {name: "hrStorageDescr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 2, 3, 1, 3],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    hrStorageAllocationUnits: MibValue::inti32(mvinti32 {name: "hrStorageAllocationUnits".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 2, 3, 1, 4],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    hrStorageSize: MibValue::inti32(mvinti32 {name: "hrStorageSize".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 2, 3, 1, 5],mutable: true,value: vec![], index: vec![]}), // Integer32
+                    hrStorageUsed: MibValue::inti32(mvinti32 {name: "hrStorageUsed".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 2, 3, 1, 6],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    hrStorageAllocationFailures: MibValue::intu32(mvintu32 {name: "hrStorageAllocationFailures".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 2, 3, 1, 7],mutable: false,value: vec![], index: vec![]}), // Counter32
+                },
+                hrDeviceTable: HrDeviceTable {
+                    oid: vec![1, 3, 6, 1, 2, 1, 25, 3, 2],
+                    hrDeviceIndex: MibValue::inti32(mvinti32 {name: "hrDeviceIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 3, 2, 1, 1],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    hrDeviceType: MibValue::oid(mvoid {name: "hrDeviceType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 3, 2, 1, 2],mutable: false,value: vec![], index: vec![]}), // AutonomousType
+                    hrDeviceDescr: MibValue::string(mvstring {name: "hrDeviceDescr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 3, 2, 1, 3],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    hrDeviceStatus: MibValue::inti32(mvinti32 {name: "hrDeviceStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 3, 2, 1, 5],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                    hrDeviceErrors: MibValue::intu32(mvintu32 {name: "hrDeviceErrors".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 3, 2, 1, 6],mutable: false,value: vec![], index: vec![]}), // Counter32
+                },
+                hrProcessorTable: HrProcessorTable {
+                    oid: vec![1, 3, 6, 1, 2, 1, 25, 3, 3],
+                    hrProcessorLoad: MibValue::inti32(mvinti32 {name: "hrProcessorLoad".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 3, 3, 1, 2],mutable: false,value: vec![], index: vec![]}), // Integer32
+                },
+                hrSWRunTable: HrSWRunTable {
+                    oid: vec![1, 3, 6, 1, 2, 1, 25, 4, 2],
+                    hrSWRunIndex: MibValue::inti32(mvinti32 {name: "hrSWRunIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 4, 2, 1, 1],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    hrSWRunName: MibValue::string(mvstring {name: "hrSWRunName".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 4, 2, 1, 2],mutable: false,value: vec![], index: vec![]}), // InternationalDisplayString
+                    hrSWRunPath: MibValue::string(mvstring {name: "hrSWRunPath".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 4, 2, 1, 4],mutable: false,value: vec![], index: vec![]}), // InternationalDisplayString
+                    hrSWRunParameters: MibValue::string(mvstring {name: "hrSWRunParameters".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 4, 2, 1, 5],mutable: false,value: vec![], index: vec![]}), // InternationalDisplayString
+                    hrSWRunType: MibValue::inti32(mvinti32 {name: "hrSWRunType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 4, 2, 1, 6],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                    hrSWRunStatus: MibValue::inti32(mvinti32 {name: "hrSWRunStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 4, 2, 1, 7],mutable: true,value: vec![], index: vec![]}), // INTEGER
+                },
+                hrSWRunPerfTable: HrSWRunPerfTable {
+                    oid: vec![1, 3, 6, 1, 2, 1, 25, 5, 1],
+                    hrSWRunPerfCPU: MibValue::inti32(mvinti32 {name: "hrSWRunPerfCPU".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 5, 1, 1, 1],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    hrSWRunPerfMem: MibValue::inti32(mvinti32 {name: "hrSWRunPerfMem".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 25, 5, 1, 1, 2],mutable: false,value: vec![], index: vec![]}), // KBytes
+                },
+            }
+        }
+    }
+
+    impl Default for Entity {
+        fn default() -> Self {
+            Entity {
+                oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1],
+                entPhysicalTable: EntPhysicalTable {
+                    oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1],
+                    entPhysicalDescr: MibValue::string(mvstring {name: "entPhysicalDescr".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 2],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                    entPhysicalVendorType: MibValue::oid(mvoid {name: "entPhysicalVendorType".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 3],mutable: false,value: vec![], index: vec![]}), // AutonomousType
+                    entPhysicalContainedIn: MibValue::inti32(mvinti32 {name: "entPhysicalContainedIn".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 4],mutable: false,value: vec![], index: vec![]}), // PhysicalIndexOrZero
+                    entPhysicalClass: MibValue::inti32(mvinti32 {name: "entPhysicalClass".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 5],mutable: false,value: vec![], index: vec![]}), // PhysicalClass
+                    entPhysicalParentRelPos: MibValue::inti32(mvinti32 {name: "entPhysicalParentRelPos".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 6],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    entPhysicalName: MibValue::string(mvstring {name: "entPhysicalName".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 7],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                    entPhysicalHardwareRev: MibValue::string(mvstring {name: "entPhysicalHardwareRev".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 8],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                    entPhysicalFirmwareRev: MibValue::string(mvstring {name: "entPhysicalFirmwareRev".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 9],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                    entPhysicalSoftwareRev: MibValue::string(mvstring {name: "entPhysicalSoftwareRev".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 10],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                    entPhysicalSerialNum: MibValue::string(mvstring {name: "entPhysicalSerialNum".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 11],mutable: true,value: vec![], index: vec![]}), // SnmpAdminString
+                    entPhysicalMfgName: MibValue::string(mvstring {name: "entPhysicalMfgName".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 12],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                    entPhysicalModelName: MibValue::string(mvstring {name: "entPhysicalModelName".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 13],mutable: false,value: vec![], index: vec![]}), // SnmpAdminString
+                    entPhysicalAlias: MibValue::string(mvstring {name: "entPhysicalAlias".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 14],mutable: true,value: vec![], index: vec![]}), // SnmpAdminString
+                    entPhysicalAssetID: MibValue::string(mvstring {name: "entPhysicalAssetID".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 15],mutable: true,value: vec![], index: vec![]}), // SnmpAdminString
+                    entPhysicalIsFRU: MibValue::inti32(mvinti32 {name: "entPhysicalIsFRU".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 16],mutable: false,value: vec![], index: vec![]}), // TruthValue
+                },
+            }
+        }
+    }
+
+    impl Default for Bridge {
+        fn default() -> Self {
+            Bridge {
+                oid: vec![1, 3, 6, 1, 2, 1, 17],
+                dot1dBasePortTable: Dot1dBasePortTable {
+                    oid: vec![1, 3, 6, 1, 2, 1, 17, 1, 4],
+                    dot1dBasePort: MibValue::inti32(mvinti32 {name: "dot1dBasePort".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 1, 4, 1, 1],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                    dot1dBasePortIfIndex: MibValue::inti32(mvinti32 {name: "dot1dBasePortIfIndex".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 1, 4, 1, 2],mutable: false,value: vec![], index: vec![]}), // InterfaceIndex
+                    dot1dBasePortCircuit: MibValue::oid(mvoid {name: "dot1dBasePortCircuit".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 1, 4, 1, 3],mutable: false,value: vec![], index: vec![]}), // OBJECT IDENTIFIER
+                    dot1dBasePortDelayExceededDiscards: MibValue::intu32(mvintu32 {name: "dot1dBasePortDelayExceededDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 1, 4, 1, 4],mutable: false,value: vec![], index: vec![]}), // Counter32
+                    dot1dBasePortMtuExceededDiscards: MibValue::intu32(mvintu32 {name: "dot1dBasePortMtuExceededDiscards".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 1, 4, 1, 5],mutable: false,value: vec![], index: vec![]}), // Counter32
+                },
+                dot1dTpFdbTable: Dot1dTpFdbTable {
+                    oid: vec![1, 3, 6, 1, 2, 1, 17, 4, 3],
+                    dot1dTpFdbAddress: MibValue::string(mvstring {name: "dot1dTpFdbAddress".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 4, 3, 1, 1],mutable: false,value: vec![], index: vec![]}), // MacAddress
+                    dot1dTpFdbPort: MibValue::inti32(mvinti32 {name: "dot1dTpFdbPort".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 4, 3, 1, 2],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                    dot1dTpFdbStatus: MibValue::inti32(mvinti32 {name: "dot1dTpFdbStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 4, 3, 1, 3],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                },
+                dot1qTpFdbTable: Dot1qTpFdbTable {
+                    oid: vec![1, 3, 6, 1, 2, 1, 17, 7, 1, 2, 2],
+                    dot1qTpFdbPort: MibValue::inti32(mvinti32 {name: "dot1qTpFdbPort".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 7, 1, 2, 2, 1, 2],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                    dot1qTpFdbStatus: MibValue::inti32(mvinti32 {name: "dot1qTpFdbStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 7, 1, 2, 2, 1, 3],mutable: false,value: vec![], index: vec![]}), // INTEGER
+                },
+                dot1qVlanStaticTable: Dot1qVlanStaticTable {
+                    oid: vec![1, 3, 6, 1, 2, 1, 17, 7, 1, 4, 3],
+                    dot1qVlanStaticName: MibValue::string(mvstring {name: "dot1qVlanStaticName".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 7, 1, 4, 3, 1, 1],mutable: true,value: vec![], index: vec![]}), // SnmpAdminString
+                    dot1qVlanStaticEgressPorts: MibValue::string(mvstring {name: "dot1qVlanStaticEgressPorts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 7, 1, 4, 3, 1, 2],mutable: true,value: vec![], index: vec![]}), // PortList
+                    dot1qVlanForbiddenEgressPorts: MibValue::string(mvstring {name: "dot1qVlanForbiddenEgressPorts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 7, 1, 4, 3, 1, 3],mutable: true,value: vec![], index: vec![]}), // PortList
+                    dot1qVlanStaticUntaggedPorts: MibValue::string(mvstring {name: "dot1qVlanStaticUntaggedPorts".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 7, 1, 4, 3, 1, 4],mutable: true,value: vec![], index: vec![]}), // PortList
+                    dot1qVlanStaticRowStatus: MibValue::inti32(mvinti32 {name: "dot1qVlanStaticRowStatus".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 17, 7, 1, 4, 3, 1, 5],mutable: true,value: vec![], index: vec![]}), // RowStatus
+                },
+            }
+        }
+    }
+
+    impl Default for Ucd {
+        fn default() -> Self {
+            Ucd {
+                oid: vec![1, 3, 6, 1, 4, 1, 2021],
+                memTotalSwap: MibValue::inti32(mvinti32 {name: "memTotalSwap".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 4, 3],mutable: false,value: vec![], index: vec![]}), // Integer32
+                memAvailSwap: MibValue::inti32(mvinti32 {name: "memAvailSwap".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 4, 4],mutable: false,value: vec![], index: vec![]}), // Integer32
+                memTotalReal: MibValue::inti32(mvinti32 {name: "memTotalReal".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 4, 5],mutable: false,value: vec![], index: vec![]}), // Integer32
+                memAvailReal: MibValue::inti32(mvinti32 {name: "memAvailReal".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 4, 6],mutable: false,value: vec![], index: vec![]}), // Integer32
+                memTotalFree: MibValue::inti32(mvinti32 {name: "memTotalFree".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 4, 11],mutable: false,value: vec![], index: vec![]}), // Integer32
+                memShared: MibValue::inti32(mvinti32 {name: "memShared".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 4, 13],mutable: false,value: vec![], index: vec![]}), // Integer32
+                memBuffer: MibValue::inti32(mvinti32 {name: "memBuffer".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 4, 14],mutable: false,value: vec![], index: vec![]}), // Integer32
+                memCached: MibValue::inti32(mvinti32 {name: "memCached".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 4, 15],mutable: false,value: vec![], index: vec![]}), // Integer32
+                ssCpuRawUser: MibValue::intu32(mvintu32 {name: "ssCpuRawUser".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 11, 50],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ssCpuRawNice: MibValue::intu32(mvintu32 {name: "ssCpuRawNice".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 11, 51],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ssCpuRawSystem: MibValue::intu32(mvintu32 {name: "ssCpuRawSystem".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 11, 52],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ssCpuRawIdle: MibValue::intu32(mvintu32 {name: "ssCpuRawIdle".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 11, 53],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ssCpuRawWait: MibValue::intu32(mvintu32 {name: "ssCpuRawWait".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 11, 54],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ssCpuRawKernel: MibValue::intu32(mvintu32 {name: "ssCpuRawKernel".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 11, 55],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ssCpuRawInterrupt: MibValue::intu32(mvintu32 {name: "ssCpuRawInterrupt".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 11, 56],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ssCpuRawSoftIRQ: MibValue::intu32(mvintu32 {name: "ssCpuRawSoftIRQ".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 11, 61],mutable: false,value: vec![], index: vec![]}), // Counter32
+                ssCpuRawSteal: MibValue::intu32(mvintu32 {name: "ssCpuRawSteal".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 11, 64],mutable: false,value: vec![], index: vec![]}), // Counter32
+                laTable: LaTable {
+                    oid: vec![1, 3, 6, 1, 4, 1, 2021, 10],
+                    laIndex: MibValue::inti32(mvinti32 {name: "laIndex".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 10, 1, 1],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    laNames: MibValue::string(mvstring {name: "laNames".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 10, 1, 2],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    laLoad: MibValue::string(mvstring {name: "laLoad".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 10, 1, 3],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    laConfig: MibValue::string(mvstring {name: "laConfig".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 10, 1, 4],mutable: true,value: vec![], index: vec![]}), // DisplayString
+                    laLoadInt: MibValue::inti32(mvinti32 {name: "laLoadInt".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 10, 1, 5],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    laLoadFloat: MibValue::string(mvstring {name: "laLoadFloat".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 10, 1, 6],mutable: false,value: vec![], index: vec![]}), // Float
+                    laErrorFlag: MibValue::inti32(mvinti32 {name: "laErrorFlag".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 10, 1, 100],mutable: false,value: vec![], index: vec![]}), // UCDErrorFlag
+                    laErrMessage: MibValue::string(mvstring {name: "laErrMessage".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 10, 1, 101],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                },
+                dskTable: DskTable {
+                    oid: vec![1, 3, 6, 1, 4, 1, 2021, 9],
+                    dskIndex: MibValue::inti32(mvinti32 {name: "dskIndex".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 1],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    dskPath: MibValue::string(mvstring {name: "dskPath".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 2],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    dskDevice: MibValue::string(mvstring {name: "dskDevice".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 3],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    dskTotal: MibValue::inti32(mvinti32 {name: "dskTotal".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 6],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    dskAvail: MibValue::inti32(mvinti32 {name: "dskAvail".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 7],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    dskUsed: MibValue::inti32(mvinti32 {name: "dskUsed".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 8],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    dskPercent: MibValue::inti32(mvinti32 {name: "dskPercent".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 9],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    dskPercentNode: MibValue::inti32(mvinti32 {name: "dskPercentNode".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 10],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    dskTotalLow: MibValue::intu32(mvintu32 {name: "dskTotalLow".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 11],mutable: false,value: vec![], index: vec![]}), // Unsigned32
+                    dskTotalHigh: MibValue::intu32(mvintu32 {name: "dskTotalHigh".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 12],mutable: false,value: vec![], index: vec![]}), // Unsigned32
+                    dskAvailLow: MibValue::intu32(mvintu32 {name: "dskAvailLow".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 13],mutable: false,value: vec![], index: vec![]}), // Unsigned32
+                    dskAvailHigh: MibValue::intu32(mvintu32 {name: "dskAvailHigh".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 14],mutable: false,value: vec![], index: vec![]}), // Unsigned32
+                    dskUsedLow: MibValue::intu32(mvintu32 {name: "dskUsedLow".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 15],mutable: false,value: vec![], index: vec![]}), // Unsigned32
+                    dskUsedHigh: MibValue::intu32(mvintu32 {name: "dskUsedHigh".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 16],mutable: false,value: vec![], index: vec![]}), // Unsigned32
+                    dskErrorFlag: MibValue::inti32(mvinti32 {name: "dskErrorFlag".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 100],mutable: false,value: vec![], index: vec![]}), // UCDErrorFlag
+                    dskErrorMsg: MibValue::string(mvstring {name: "dskErrorMsg".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 2021, 9, 1, 101],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                },
+                nsExtendOutput1Table: NsExtendOutput1Table {
+                    oid: vec![1, 3, 6, 1, 4, 1, 8072, 1, 3, 2, 3],
+                    nsExtendOutput1Line: MibValue::string(mvstring {name: "nsExtendOutput1Line".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 8072, 1, 3, 2, 3, 1, 1],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    nsExtendOutputFull: MibValue::string(mvstring {name: "nsExtendOutputFull".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 8072, 1, 3, 2, 3, 1, 2],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    nsExtendOutNumLines: MibValue::inti32(mvinti32 {name: "nsExtendOutNumLines".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 8072, 1, 3, 2, 3, 1, 3],mutable: false,value: vec![], index: vec![]}), // Integer32
+                    nsExtendResult: MibValue::inti32(mvinti32 {name: "nsExtendResult".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 8072, 1, 3, 2, 3, 1, 4],mutable: false,value: vec![], index: vec![]}), // Integer32
+                },
+            }
+        }
+    }
+
+    impl Default for Cdp {
+        fn default() -> Self {
+            Cdp {
+                oid: vec![1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2],
+                cdpCacheTable: CdpCacheTable {
+                    oid: vec![1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1],
+                    cdpCacheAddressType: MibValue::inti32(mvinti32 {name: "cdpCacheAddressType".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1, 1, 3],mutable: false,value: vec![], index: vec![]}), // CiscoNetworkProtocol
+                    cdpCacheAddress: MibValue::string(mvstring {name: "cdpCacheAddress".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1, 1, 4],mutable: false,value: vec![], index: vec![]}), // CiscoNetworkAddress
+                    cdpCacheVersion: MibValue::string(mvstring {name: "cdpCacheVersion".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1, 1, 5],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    cdpCacheDeviceId: MibValue::string(mvstring {name: "cdpCacheDeviceId".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1, 1, 6],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    cdpCacheDevicePort: MibValue::string(mvstring {name: "cdpCacheDevicePort".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1, 1, 7],mutable: false,value: vec![], index: vec![]}), // DisplayString
+                    cdpCachePlatform: MibValue::string(mvstring {name: "cdpCachePlatform".to_owned(), oid: vec![1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1, 1, 8],mutable: false,value: vec![], index: vec![]}), // DisplayString
                 },
             }
         }
@@ -2048,6 +3941,8 @@ pub mod MibModule {
         pub oid: Vec<u16>,
         pub mutable: bool,
         pub value: Vec<String>,
+        #[serde(default)]
+        pub index: Vec<Vec<u32>>,
     }
     
     #[derive(Serialize, Deserialize, PartialEq, Debug)]
@@ -2056,6 +3951,8 @@ pub mod MibModule {
         pub oid: Vec<u16>,
         pub mutable: bool,
         pub value: Vec<i32>,
+        #[serde(default)]
+        pub index: Vec<Vec<u32>>,
     }
     
     #[derive(Serialize, Deserialize, PartialEq, Debug)]
@@ -2064,6 +3961,8 @@ pub mod MibModule {
         pub oid: Vec<u16>,
         pub mutable: bool,
         pub value: Vec<u32>,
+        #[serde(default)]
+        pub index: Vec<Vec<u32>>,
     }
     
     #[derive(Serialize, Deserialize, PartialEq, Debug)]
@@ -2072,6 +3971,8 @@ pub mod MibModule {
         pub oid: Vec<u16>,
         pub mutable: bool,
         pub value: Vec<u64>,
+        #[serde(default)]
+        pub index: Vec<Vec<u32>>,
     }
     
     #[derive(Serialize, Deserialize, PartialEq, Debug)]
@@ -2080,6 +3981,8 @@ pub mod MibModule {
         pub oid: Vec<u16>,
         pub mutable: bool,
         pub value: Vec<Vec<u8>>,
+        #[serde(default)]
+        pub index: Vec<Vec<u32>>,
     }
     
     #[derive(Serialize, Deserialize, PartialEq, Debug)]
@@ -2088,6 +3991,8 @@ pub mod MibModule {
         pub oid: Vec<u16>,
         pub mutable: bool,
         pub value: Vec<(u8, u8, u8, u8)>,
+        #[serde(default)]
+        pub index: Vec<Vec<u32>>,
     }
     
     #[derive(Serialize, Deserialize, PartialEq, Debug)]
@@ -2096,6 +4001,8 @@ pub mod MibModule {
         pub oid: Vec<u16>,
         pub mutable: bool,
         pub value: Vec<(u16, u16, u16, u16, u16, u16)>,
+        #[serde(default)]
+        pub index: Vec<Vec<u32>>,
     }
     
     #[derive(Serialize, Deserialize, PartialEq, Debug)]
@@ -2104,8 +4011,66 @@ pub mod MibModule {
             pub oid: Vec<u16>,
             pub mutable: bool,
             pub value: Vec<(u16, u16, u16, u16, u16, u16, u16, u16)>,
+            #[serde(default)]
+            pub index: Vec<Vec<u32>>,
         }
     
+    /// a single numeric reading of one object instance, as written to the time-series store
+    #[derive(Clone, PartialEq, Debug)]
+    pub struct MibSample {
+        pub key: String,
+        pub name: String,
+        pub value: i64,
+    }
+
+    /// the text of an OCTET STRING, or its bytes in hex like `00:1a:2b` when it is binary, as
+    /// chassis IDs and network addresses are
+    pub fn octet_string(bytes: &[u8]) -> String {
+        match std::str::from_utf8(bytes) {
+            Ok(text) if !text.chars().any(|a| a.is_control() && !a.is_whitespace()) => text.to_owned(),
+            _ => bytes.iter().map(|a| format!("{:02x}", a)).collect::<Vec<String>>().join(":"),
+        }
+    }
+
+    /// the text of a float or double wrapped in an Opaque the way Net-SNMP agents send them, as
+    /// an ASN.1 tag of the opaque-special class followed by the IEEE 754 bytes
+    pub fn opaque_float(bytes: &[u8]) -> Option<String> {
+        match bytes {
+            [0x9f, 0x78, 4, rest @ ..] => Some(f32::from_be_bytes(<[u8; 4]>::try_from(rest).ok()?).to_string()),
+            [0x9f, 0x79, 8, rest @ ..] => Some(f64::from_be_bytes(<[u8; 8]>::try_from(rest).ok()?).to_string()),
+            _ => None,
+        }
+    }
+
+    /// lines the columns of a table up by instance index, as `(index, cells)` rows in the order
+    /// the rows first appear; cells of columns missing a row are empty
+    pub fn table_rows(columns: &[&MibValue]) -> Vec<(String, Vec<String>)> {
+        let mut rows: Vec<(String, Vec<String>)> = vec![];
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for (column, value) in columns.iter().enumerate() {
+            for (position, text) in value.display_values().into_iter().enumerate() {
+                let index = value.instance_index(position).iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".");
+                let row = *positions.entry(index.clone()).or_insert_with(|| {
+                    rows.push((index, vec![String::new(); columns.len()]));
+                    rows.len() - 1
+                });
+                rows[row].1[column] = text;
+            }
+        }
+        rows
+    }
+
+    /// the rows of a table as `(index, cells)` with the cells mapped from column name to text
+    pub fn table_maps(columns: &[&MibValue]) -> Vec<(String, HashMap<String, String>)> {
+        let names = columns.iter().map(|a| a.get_name()).collect::<Vec<String>>();
+        table_rows(columns).into_iter().map(|(index, cells)| (index, names.iter().cloned().zip(cells).collect())).collect()
+    }
+
+    /// a cell of a `table_maps` row as a number, 0 when it is missing or not one
+    pub fn row_number(row: &HashMap<String, String>, column: &str) -> i64 {
+        row.get(column).and_then(|a| a.parse::<i64>().ok()).unwrap_or_default()
+    }
+
     #[derive(Serialize, Deserialize, PartialEq, Debug)]
     pub enum MibValue {
         string(mvstring),
@@ -2126,94 +4091,116 @@ pub mod MibModule {
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => MibValue::string(mvstring {
                     name: name.clone(),
                     oid: oid.to_owned(),
                     mutable: mutable.clone(),
                     value: value.to_owned(),
+                    index: index.to_owned(),
                 }),
                 MibValue::inti32(mvinti32 {
                     name,
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => MibValue::inti32(mvinti32 {
                     name: name.clone(),
                     oid: oid.to_owned(),
                     mutable: mutable.clone(),
                     value: value.to_owned(),
+                    index: index.to_owned(),
                 }),
                 MibValue::intu32(mvintu32 {
                     name,
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => MibValue::intu32(mvintu32 {
                     name: name.clone(),
                     oid: oid.to_owned(),
                     mutable: mutable.clone(),
                     value: value.to_owned(),
+                    index: index.to_owned(),
                 }),
                 MibValue::intu64(mvintu64 {
                     name,
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => MibValue::intu64(mvintu64 {
                     name: name.clone(),
                     oid: oid.to_owned(),
                     mutable: mutable.clone(),
                     value: value.to_owned(),
+                    index: index.to_owned(),
                 }),
                 MibValue::oid(mvoid {
                     name,
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => MibValue::oid(mvoid {
                     name: name.clone(),
                     oid: oid.to_owned(),
                     mutable: mutable.clone(),
                     value: value.to_owned(),
+                    index: index.to_owned(),
                 }),
                 MibValue::ipv4(mvipv4 {
                     name,
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => MibValue::ipv4(mvipv4 {
                     name: name.clone(),
                     oid: oid.to_owned(),
                     mutable: mutable.clone(),
                     value: value.to_owned(),
+                    index: index.to_owned(),
                 }),
                 MibValue::mac(mvmac {
                     name,
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => MibValue::mac(mvmac {
                     name: name.clone(),
                     oid: oid.to_owned(),
                     mutable: mutable.clone(),
                     value: value.to_owned(),
+                    index: index.to_owned(),
                 }),
                 MibValue::ipv6(mvipv6 {
                     name,
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => MibValue::ipv6(mvipv6 {
                     name: name.clone(),
                     oid: oid.to_owned(),
                     mutable: mutable.clone(),
                     value: value.to_owned(),
+                    index: index.to_owned(),
                 }),
             }
         }
     }
 
     impl MibValue {
+        /// the instance of a table column named by its full instance oid
+        fn clone_instance(&self, oid: &[u16]) -> Option<Self> {
+            let suffix = oid.strip_prefix(&self.get_oid()[..])?.iter().map(|a| *a as u32).collect::<Vec<u32>>();
+            let position = self.index().iter().position(|a| *a == suffix)?;
+            Some(self.clone_index(position))
+        }
         fn clone_index(&self, index: usize) -> Self {
             match self {
                 MibValue::string(mvstring) => MibValue::string(mvstring {
@@ -2221,48 +4208,56 @@ pub mod MibModule {
                     oid: mvstring.oid.to_owned(),
                     mutable: mvstring.mutable.clone(),
                     value: vec![mvstring.value.get(index).unwrap().to_owned()],
+                    index: mvstring.index.get(index).cloned().into_iter().collect(),
                 }),
                 MibValue::inti32(mvinti32) => MibValue::inti32(mvinti32 {
                     name: mvinti32.name.clone(),
                     oid: mvinti32.oid.to_owned(),
                     mutable: mvinti32.mutable.clone(),
                     value: vec![mvinti32.value.get(index).unwrap().to_owned()],
+                    index: mvinti32.index.get(index).cloned().into_iter().collect(),
                 }),
                 MibValue::intu32(mvintu32) => MibValue::intu32(mvintu32 {
                     name: mvintu32.name.clone(),
                     oid: mvintu32.oid.to_owned(),
                     mutable: mvintu32.mutable.clone(),
                     value: vec![mvintu32.value.get(index).unwrap().to_owned()],
+                    index: mvintu32.index.get(index).cloned().into_iter().collect(),
                 }),
                 MibValue::intu64(mvintu64) => MibValue::intu64(mvintu64 {
                     name: mvintu64.name.clone(),
                     oid: mvintu64.oid.to_owned(),
                     mutable: mvintu64.mutable.clone(),
                     value: vec![mvintu64.value.get(index).unwrap().to_owned()],
+                    index: mvintu64.index.get(index).cloned().into_iter().collect(),
                 }),
                 MibValue::oid(mvoid) => MibValue::oid(mvoid {
                     name: mvoid.name.clone(),
                     oid: mvoid.oid.to_owned(),
                     mutable: mvoid.mutable.clone(),
                     value: vec![mvoid.value.get(index).unwrap().to_owned()],
+                    index: mvoid.index.get(index).cloned().into_iter().collect(),
                 }),
                 MibValue::ipv4(mvipv4) => MibValue::ipv4(mvipv4 {
                     name: mvipv4.name.clone(),
                     oid: mvipv4.oid.to_owned(),
                     mutable: mvipv4.mutable.clone(),
                     value: vec![mvipv4.value.get(index).unwrap().to_owned()],
+                    index: mvipv4.index.get(index).cloned().into_iter().collect(),
                 }),
                 MibValue::mac(mvmac) => MibValue::mac(mvmac {
                     name: mvmac.name.clone(),
                     oid: mvmac.oid.to_owned(),
                     mutable: mvmac.mutable.clone(),
                     value: vec![mvmac.value.get(index).unwrap().to_owned()],
+                    index: mvmac.index.get(index).cloned().into_iter().collect(),
                 }),
                 MibValue::ipv6(mvipv6) => MibValue::ipv6(mvipv6 {
                     name: mvipv6.name.clone(),
                     oid: mvipv6.oid.to_owned(),
                     mutable: mvipv6.mutable.clone(),
                     value: vec![mvipv6.value.get(index).unwrap().to_owned()],
+                    index: mvipv6.index.get(index).cloned().into_iter().collect(),
                 }),
             }
         }
@@ -2280,6 +4275,99 @@ pub mod MibModule {
                 _ => false,
             }
         }
+        pub fn get_name(&self) -> String {
+            match self {
+                MibValue::string(mvstring {name,..}) => name.clone(),
+                MibValue::inti32(mvinti32 {name,..}) => name.clone(),
+                MibValue::intu32(mvintu32 {name,..}) => name.clone(),
+                MibValue::intu64(mvintu64 {name,..}) => name.clone(),
+                MibValue::oid(mvoid {name,..}) => name.clone(),
+                MibValue::ipv4(mvipv4 {name,..}) => name.clone(),
+                MibValue::mac(mvmac {name,..}) => name.clone(),
+                MibValue::ipv6(mvipv6 {name,..}) => name.clone(),
+            }
+        }
+        pub fn is_mutable(&self) -> bool {
+            match self {
+                MibValue::string(mvstring {mutable,..}) => *mutable,
+                MibValue::inti32(mvinti32 {mutable,..}) => *mutable,
+                MibValue::intu32(mvintu32 {mutable,..}) => *mutable,
+                MibValue::intu64(mvintu64 {mutable,..}) => *mutable,
+                MibValue::oid(mvoid {mutable,..}) => *mutable,
+                MibValue::ipv4(mvipv4 {mutable,..}) => *mutable,
+                MibValue::mac(mvmac {mutable,..}) => *mutable,
+                MibValue::ipv6(mvipv6 {mutable,..}) => *mutable,
+            }
+        }
+        pub fn index(&self) -> &[Vec<u32>] {
+            match self {
+                MibValue::string(mvstring {index,..}) => index,
+                MibValue::inti32(mvinti32 {index,..}) => index,
+                MibValue::intu32(mvintu32 {index,..}) => index,
+                MibValue::intu64(mvintu64 {index,..}) => index,
+                MibValue::oid(mvoid {index,..}) => index,
+                MibValue::ipv4(mvipv4 {index,..}) => index,
+                MibValue::mac(mvmac {index,..}) => index,
+                MibValue::ipv6(mvipv6 {index,..}) => index,
+            }
+        }
+        /// returns the instance index of the value at `position`, falling back to `.0` for scalars and
+        /// the 1-based row position for tables when the object was logged before indexes were recorded
+        pub fn instance_index(&self, position: usize) -> Vec<u32> {
+            let scalar = match self {
+                MibValue::inti32(mvinti32) => mvinti32.value.len() == 1,
+                MibValue::intu32(mvintu32) => mvintu32.value.len() == 1,
+                MibValue::intu64(mvintu64) => mvintu64.value.len() == 1,
+                _ => false,
+            };
+            match self.index().get(position) {
+                Some(index) => index.clone(),
+                None if scalar => vec![0],
+                None => vec![position as u32 + 1],
+            }
+        }
+        /// the position of every numeric instance by its dotted index, wherever the agent put it
+        pub fn positions(&self) -> HashMap<String, usize> {
+            let count = match self {
+                MibValue::inti32(mvinti32) => mvinti32.value.len(),
+                MibValue::intu32(mvintu32) => mvintu32.value.len(),
+                MibValue::intu64(mvintu64) => mvintu64.value.len(),
+                _ => 0,
+            };
+            (0..count).map(|position| (self.instance_index(position).iter().map(|a| a.to_string()).collect::<Vec<String>>().join("."), position)).collect()
+        }
+        /// flattens every numeric value into one sample per instance, keyed by the full instance oid
+        pub fn samples(&self) -> Vec<MibSample> {
+            match self.val_as_mvinti64() {
+                Some(values) => values
+                    .into_iter()
+                    .enumerate()
+                    .map(|(position, value)| {
+                        let index = self.instance_index(position);
+                        let suffix = index.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".");
+                        MibSample {
+                            key: [self.oid(), suffix.clone()].join("."),
+                            name: [self.get_name(), suffix].join("."),
+                            value,
+                        }
+                    })
+                    .collect(),
+                None => vec![],
+            }
+        }
+        /// renders every value as text, one entry per instance
+        pub fn display_values(&self) -> Vec<String> {
+            match self {
+                MibValue::string(mvstring) => mvstring.value.clone(),
+                MibValue::inti32(mvinti32) => mvinti32.value.iter().map(|a| a.to_string()).collect(),
+                MibValue::intu32(mvintu32) => mvintu32.value.iter().map(|a| a.to_string()).collect(),
+                MibValue::intu64(mvintu64) => mvintu64.value.iter().map(|a| a.to_string()).collect(),
+                MibValue::oid(mvoid) => mvoid.value.iter().map(|a| a.iter().map(|b| b.to_string()).collect::<Vec<String>>().join(".")).collect(),
+                MibValue::ipv4(mvipv4) => mvipv4.value.iter().map(|a| format!("{}.{}.{}.{}", a.0, a.1, a.2, a.3)).collect(),
+                MibValue::mac(mvmac) => mvmac.value.iter().map(|a| format!("{:02x?}", a)).collect(),
+                MibValue::ipv6(mvipv6) => mvipv6.value.iter().map(|a| format!("{:02x?}", a)).collect(),
+            }
+        }
         pub fn get_oid(&self) -> Vec<u16> {
             match self {
                 MibValue::string(mvstring {oid,..}) => oid.clone(),
@@ -2308,7 +4396,7 @@ pub mod MibModule {
             match self {
                 MibValue::string(mvstring) => {
                     ui.collapsing(mvstring.name.clone(), |ui| match mvstring.value.len() {
-                        1 => ui.label(mvstring.value.first().unwrap().to_string()),
+                        1 => ui.label(mvstring.value.first().unwrap().to_string()).context_menu(|ui| self.add_edit_menu(app, ui)),
                         _ => ui.spinner(),
                     });
                 }
@@ -2316,7 +4404,10 @@ pub mod MibModule {
                     ui.collapsing(mvinti32.name.clone(), |ui| match mvinti32.value.len() {
                         1 => ui
                             .label(mvinti32.value.first().unwrap().to_string())
-                            .context_menu(|ui| self.add_plot_menu(app, ctx, ui, mvinti32.oid.clone())),
+                            .context_menu(|ui| {
+                                self.add_edit_menu(app, ui);
+                                self.add_plot_menu(app, ctx, ui, mvinti32.oid.clone())
+                            }),
                         _ => ui.spinner(),
                     });
                 }
@@ -2324,7 +4415,10 @@ pub mod MibModule {
                     ui.collapsing(mvintu32.name.clone(), |ui| match mvintu32.value.len() {
                         1 => ui
                             .label(mvintu32.value.first().unwrap().to_string())
-                            .context_menu(|ui| self.add_plot_menu(app, ctx, ui, mvintu32.oid.clone())),
+                            .context_menu(|ui| {
+                                self.add_edit_menu(app, ui);
+                                self.add_plot_menu(app, ctx, ui, mvintu32.oid.clone())
+                            }),
                         _ => ui.spinner(),
                     });
                 }
@@ -2338,13 +4432,13 @@ pub mod MibModule {
                 }
                 MibValue::oid(mvoid) => {
                     ui.collapsing(mvoid.name.clone(), |ui| match mvoid.value.len() {
-                        1 => ui.label(format!("{:02x?}", mvoid.value.first().unwrap())),
+                        1 => ui.label(format!("{:02x?}", mvoid.value.first().unwrap())).context_menu(|ui| self.add_edit_menu(app, ui)),
                         _ => ui.spinner(),
                     });
                 }
                 MibValue::ipv4(mvipv4) => {
                     ui.collapsing(mvipv4.name.clone(), |ui| match mvipv4.value.len() {
-                        1 => ui.label(format!("{:?}", mvipv4.value.first().unwrap())),
+                        1 => ui.label(format!("{:?}", mvipv4.value.first().unwrap())).context_menu(|ui| self.add_edit_menu(app, ui)),
                         _ => ui.spinner(),
                     });
                 }
@@ -2362,6 +4456,14 @@ pub mod MibModule {
                 }
             }
         }
+        /// hands a writable scalar to the set tab
+        fn add_edit_menu(&self, app: &mut SnmpMonitorApp, ui: &mut Ui) {
+            if self.is_mutable() && ui.button("edit").clicked() {
+                app.context.edits.push(Edit::new(self.clone(), 0));
+                app.context.to_open.push("set".to_owned());
+                ui.close_menu();
+            }
+        }
         fn add_plot_menu(
             &self,
             app: &mut SnmpMonitorApp,
@@ -2379,25 +4481,15 @@ pub mod MibModule {
                                     println!("{} button clicked", plot.0);
                                     app.new_plot_window_manager.value_to_add = Some(self.clone());
 
-                                    let snmp_log: Vec<MibObject> = BufReader::new(File::open(format!("MIB-log.log")).unwrap())
-                                                                        .lines()
-                                                                        .map(|line| line.unwrap())
-                                                                        .filter(|line| line != "")
-                                                                        .map(|line| serde_json::from_str::<MibObject>(&line).unwrap())
-                                                                        .collect::<Vec<MibObject>>();
-            
-                                    
-                                    app.context
-                                        .plots
-                                        .get_mut(&plot.0.clone())
-                                        .unwrap()
-                                        .plottables
-                                        .push(Plottable::new(self.clone()).unwrap());
-
-                                    snmp_log.into_iter().for_each(|obj| {
-                                        println!("{:?}", app.new_plot_window_manager.value_to_add.clone().expect("couldnt clone oid").get_oid());
-                                        app.context.plots.get_mut(&plot.0.clone()).unwrap().plottables.last_mut().unwrap().add((obj.timestamp, obj.find_oid(app.new_plot_window_manager.value_to_add.clone().expect("couldnt clone oid").get_oid()).expect("couldnt find object by oid").val_as_mvinti64().expect("couldnt convert value to mvinti64").first().expect("no first value in vector").clone() ));
-                                    });
+                                    let target = app.context.shown_target.clone();
+                                    if let Some(plottable) = Plottable::load(self.clone(), &app.tsdb, &target, history_range(app.context.plots.get(&plot.0))) {
+                                        app.context
+                                            .plots
+                                            .get_mut(&plot.0.clone())
+                                            .unwrap()
+                                            .plottables
+                                            .push(plottable);
+                                    }
 
                                     ui.close_menu();
                                 };
@@ -2421,25 +4513,15 @@ pub mod MibModule {
                                     println!("{} button clicked", plot.0);
                                     app.new_plot_window_manager.value_to_add = Some(self.clone());
 
-                                    let snmp_log: Vec<MibObject> = BufReader::new(File::open(format!("MIB-log.log")).unwrap())
-                                                                        .lines()
-                                                                        .map(|line| line.unwrap())
-                                                                        .filter(|line| line != "")
-                                                                        .map(|line| serde_json::from_str::<MibObject>(&line).unwrap())
-                                                                        .collect::<Vec<MibObject>>();
-            
-                                    
-                                    app.context
-                                        .plots
-                                        .get_mut(&plot.0.clone())
-                                        .unwrap()
-                                        .plottables
-                                        .push(Plottable::new(self.clone()).unwrap());
-
-                                    snmp_log.into_iter().for_each(|obj| {
-                                        println!("{:?}", app.new_plot_window_manager.value_to_add.clone().expect("couldnt clone oid").get_oid());
-                                        app.context.plots.get_mut(&plot.0.clone()).unwrap().plottables.last_mut().unwrap().add((obj.timestamp, obj.find_oid(app.new_plot_window_manager.value_to_add.clone().expect("couldnt clone oid").get_oid()).expect("couldnt find object by oid").val_as_mvinti64().expect("couldnt convert value to mvinti64").first().expect("no first value in vector").clone() ));
-                                    });
+                                    let target = app.context.shown_target.clone();
+                                    if let Some(plottable) = Plottable::load(self.clone(), &app.tsdb, &target, history_range(app.context.plots.get(&plot.0))) {
+                                        app.context
+                                            .plots
+                                            .get_mut(&plot.0.clone())
+                                            .unwrap()
+                                            .plottables
+                                            .push(plottable);
+                                    }
 
                                     ui.close_menu();
                                 };
@@ -2463,25 +4545,15 @@ pub mod MibModule {
                                     println!("{} button clicked", plot.0);
                                     app.new_plot_window_manager.value_to_add = Some(self.clone());
 
-                                    let snmp_log: Vec<MibObject> = BufReader::new(File::open(format!("MIB-log.log")).unwrap())
-                                                                        .lines()
-                                                                        .map(|line| line.unwrap())
-                                                                        .filter(|line| line != "")
-                                                                        .map(|line| serde_json::from_str::<MibObject>(&line).unwrap())
-                                                                        .collect::<Vec<MibObject>>();
-            
-                                    
-                                    app.context
-                                        .plots
-                                        .get_mut(&plot.0.clone())
-                                        .unwrap()
-                                        .plottables
-                                        .push(Plottable::new(self.clone()).unwrap());
-
-                                    snmp_log.into_iter().for_each(|obj| {
-                                        println!("{:?}", app.new_plot_window_manager.value_to_add.clone().expect("couldnt clone oid").get_oid());
-                                        app.context.plots.get_mut(&plot.0.clone()).unwrap().plottables.last_mut().unwrap().add((obj.timestamp, obj.find_oid(app.new_plot_window_manager.value_to_add.clone().expect("couldnt clone oid").get_oid()).expect("couldnt find object by oid").val_as_mvinti64().expect("couldnt convert value to mvinti64").first().expect("no first value in vector").clone() ));
-                                    });
+                                    let target = app.context.shown_target.clone();
+                                    if let Some(plottable) = Plottable::load(self.clone(), &app.tsdb, &target, history_range(app.context.plots.get(&plot.0))) {
+                                        app.context
+                                            .plots
+                                            .get_mut(&plot.0.clone())
+                                            .unwrap()
+                                            .plottables
+                                            .push(plottable);
+                                    }
 
                                     ui.close_menu();
                                 };
@@ -2499,18 +4571,17 @@ pub mod MibModule {
                 _ => {},
             }
         }
-        async fn walk(&mut self, client: &Snmp2cClient) -> Option<String> {
+        async fn walk(&mut self, client: &Walk<'_>) -> Option<String> {
             match self {
-                MibValue::string(mvstring {name, oid, mutable, value }) => {
+                MibValue::string(mvstring {name, oid, mutable, value, index }) => {
                     match ObjectIdentifier::from_str(&oid.clone().into_iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".").to_owned()) {
                         Ok(res) => {
                             match client.walk_bulk(res, 100).await {
                                 Ok(res) => {
+                                    *index = res.keys().map(|key| key.as_slice().iter().skip(oid.len()).copied().collect()).collect();
                                     *value = res.into_iter().map(|a| match a.1.as_bytes() {
-                                            Some(res) => match String::from_utf8(res.to_owned()) {
-                                                Ok(res) => res,
-                                                Err(_) => "err".to_owned(),
-                                            },
+                                            Some(res) if a.1.is_opaque() => opaque_float(res).unwrap_or_else(|| octet_string(res)),
+                                            Some(res) => octet_string(res),
                                             None => "err".to_owned(),
                                         }).collect::<Vec<String>>();
                                     return None;
@@ -2526,11 +4597,13 @@ pub mod MibModule {
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => {
                     match ObjectIdentifier::from_str(&oid.clone().into_iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".").to_owned()) {
                         Ok(res) => {
                             match client.walk_bulk(res, 100).await {
                                 Ok(res) => {
+                                    *index = res.keys().map(|key| key.as_slice().iter().skip(oid.len()).copied().collect()).collect();
                                     *value = res
                                         .into_iter()
                                         .map(|a| match a.1.as_i32() {
@@ -2555,11 +4628,13 @@ pub mod MibModule {
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => {
                     match ObjectIdentifier::from_str(&oid.clone().into_iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".").to_owned()) {
                         Ok(res) => {
                             match client.walk_bulk(res, 100).await {
                                 Ok(res) => {
+                                    *index = res.keys().map(|key| key.as_slice().iter().skip(oid.len()).copied().collect()).collect();
                                     *value = res
                                         .into_iter()
                                         .map(|a| match a.1.as_u32() {
@@ -2584,11 +4659,13 @@ pub mod MibModule {
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => {
                     match ObjectIdentifier::from_str(&oid.clone().into_iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".").to_owned()) {
                         Ok(res) => {
                             match client.walk_bulk(res, 100).await {
                                 Ok(res) => {
+                                    *index = res.keys().map(|key| key.as_slice().iter().skip(oid.len()).copied().collect()).collect();
                                     *value = res
                                         .into_iter()
                                         .map(|a| match a.1.as_u64() {
@@ -2613,11 +4690,13 @@ pub mod MibModule {
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => {
                     match ObjectIdentifier::from_str(&oid.clone().into_iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".").to_owned()) {
                         Ok(res) => {
                             match client.walk_bulk(res, 100).await {
                                 Ok(res) => {
+                                    *index = res.keys().map(|key| key.as_slice().iter().skip(oid.len()).copied().collect()).collect();
                                     *value = res
                                         .into_iter()
                                         .map(|a| match a.1.as_oid() {
@@ -2647,11 +4726,13 @@ pub mod MibModule {
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => {
                     match ObjectIdentifier::from_str(&oid.clone().into_iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".").to_owned()) {
                         Ok(res) => {
                             match client.walk_bulk(res, 100).await {
                                 Ok(res) => {
+                                    *index = res.keys().map(|key| key.as_slice().iter().skip(oid.len()).copied().collect()).collect();
                                     *value = res
                                         .into_iter()
                                         .map(|a| {
@@ -2686,11 +4767,13 @@ pub mod MibModule {
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => {
                     match ObjectIdentifier::from_str(&oid.clone().into_iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".").to_owned()) {
                         Ok(res) => {
                             match client.walk_bulk(res, 100).await {
                                 Ok(res) => {
+                                    *index = res.keys().map(|key| key.as_slice().iter().skip(oid.len()).copied().collect()).collect();
                                     *value = res
                                         .into_iter()
                                         .map(|a| match a.1.as_bytes() {
@@ -2733,11 +4816,13 @@ pub mod MibModule {
                     oid,
                     mutable,
                     value,
+                    index,
                 }) => {
                     match ObjectIdentifier::from_str(&oid.clone().into_iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".").to_owned()) {
                         Ok(res) => {
                             match client.walk_bulk(res, 100).await {
                                 Ok(res) => {
+                                    *index = res.keys().map(|key| key.as_slice().iter().skip(oid.len()).copied().collect()).collect();
                                     *value = res
                                         .into_iter()
                                         .map(|a| match a.1.as_bytes() {
@@ -2777,18 +4862,6 @@ pub mod MibModule {
                 }
             }
         }
-        pub fn as_mvstring(&self) -> Option<mvstring> {
-            match self {
-                MibValue::string(mvstring) => Some(mvstring { name: mvstring.name.clone(), oid: mvstring.oid.clone(), mutable: mvstring.mutable.clone(), value: mvstring.value.clone() }),
-                _ => None,
-            }
-        }
-        pub fn as_mvinti32(&self) -> Option<mvinti32> {
-            match self {
-                MibValue::inti32(mvinti32) => Some(mvinti32 { name: mvinti32.name.clone(), oid: mvinti32.oid.clone(), mutable: mvinti32.mutable.clone(), value: mvinti32.value.clone() }),
-                _ => None,
-            }
-        }
         pub fn val_as_mvinti64(&self) -> Option<Vec<i64>> {
             match self {
                 MibValue::inti32(mvinti32) => Some(mvinti32.value.clone().into_iter().map(|int| int as i64).collect::<Vec<i64>>()),
@@ -2797,41 +4870,62 @@ pub mod MibModule {
                 _ => None,
             }
         }
-        pub fn as_mvintu32(&self) -> Option<mvintu32> {
-            match self {
-                MibValue::intu32(mvintu32) => Some(mvintu32 { name: mvintu32.name.clone(), oid: mvintu32.oid.clone(), mutable: mvintu32.mutable.clone(), value: mvintu32.value.clone() }),
-                _ => None,
+    }
+
+    /// fills the columns of test objects with values at the given instances
+    #[cfg(test)]
+    pub(crate) mod testing {
+        use super::*;
+
+        pub(crate) fn set_int(value: &mut MibValue, values: Vec<i32>, index: Vec<Vec<u32>>) {
+            if let MibValue::inti32(mvinti32 { value, index: old, .. }) = value {
+                (*value, *old) = (values, index);
             }
         }
-        pub fn as_mvintu64(&self) -> Option<mvintu64> {
-            match self {
-                MibValue::intu64(mvintu64) => Some(mvintu64 { name: mvintu64.name.clone(), oid: mvintu64.oid.clone(), mutable: mvintu64.mutable.clone(), value: mvintu64.value.clone() }),
-                _ => None,
+
+        pub(crate) fn set_unsigned(value: &mut MibValue, values: Vec<u32>, index: Vec<Vec<u32>>) {
+            if let MibValue::intu32(mvintu32 { value, index: old, .. }) = value {
+                (*value, *old) = (values, index);
             }
         }
-        pub fn as_mvoid(&self) -> Option<mvoid> {
-            match self {
-                MibValue::oid(mvoid) => Some(mvoid { name: mvoid.name.clone(), oid: mvoid.oid.clone(), mutable: mvoid.mutable.clone(), value: mvoid.value.clone() }),
-                _ => None,
+
+        pub(crate) fn set_counter64(value: &mut MibValue, values: Vec<u64>, index: Vec<Vec<u32>>) {
+            if let MibValue::intu64(mvintu64 { value, index: old, .. }) = value {
+                (*value, *old) = (values, index);
             }
         }
-        pub fn as_mvipv4(&self) -> Option<mvipv4> {
-            match self {
-                MibValue::ipv4(mvipv4) => Some(mvipv4 { name: mvipv4.name.clone(), oid: mvipv4.oid.clone(), mutable: mvipv4.mutable.clone(), value: mvipv4.value.clone() }),
-                _ => None,
+
+        pub(crate) fn set_string(value: &mut MibValue, values: Vec<&str>, index: Vec<Vec<u32>>) {
+            if let MibValue::string(mvstring { value, index: old, .. }) = value {
+                (*value, *old) = (values.into_iter().map(|a| a.to_owned()).collect(), index);
             }
         }
-        pub fn as_mvmac(&self) -> Option<mvmac> {
-            match self {
-                MibValue::mac(mvmac) => Some(mvmac { name: mvmac.name.clone(), oid: mvmac.oid.clone(), mutable: mvmac.mutable.clone(), value: mvmac.value.clone() }),
-                _ => None,
+
+        pub(crate) fn set_ipv4(value: &mut MibValue, values: Vec<(u8, u8, u8, u8)>, index: Vec<Vec<u32>>) {
+            if let MibValue::ipv4(mvipv4 { value, index: old, .. }) = value {
+                (*value, *old) = (values, index);
             }
         }
-        pub fn as_mvipv6(&self) -> Option<mvipv6> {
-            match self {
-                MibValue::ipv6(mvipv6) => Some(mvipv6 { name: mvipv6.name.clone(), oid: mvipv6.oid.clone(), mutable: mvipv6.mutable.clone(), value: mvipv6.value.clone() }),
-                _ => None,
-            }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn column(values: Vec<u32>, index: Vec<Vec<u32>>) -> MibValue {
+            MibValue::intu32(mvintu32 { name: "ifInOctets".to_owned(), oid: vec![1, 3, 6, 1, 2, 1, 2, 2, 1, 10], mutable: false, value: values, index })
+        }
+
+        #[test]
+        fn test_instances_follow_their_index() {
+            let before = column(vec![100, 300], vec![vec![1], vec![3]]);
+            let after = column(vec![350, 200, 150], vec![vec![3], vec![2], vec![1]]);
+            assert_eq!(before.positions().get("3"), Some(&1));
+            assert_eq!(after.positions().get("3"), Some(&0));
+            assert_eq!(after.positions().get("4"), None);
+            let instance = after.clone_instance(&[1, 3, 6, 1, 2, 1, 2, 2, 1, 10, 3]).unwrap();
+            assert_eq!(instance.val_as_mvinti64().unwrap(), vec![350]);
+            assert!(after.clone_instance(&[1, 3, 6, 1, 2, 1, 2, 2, 1, 10, 4]).is_none());
         }
     }
 }
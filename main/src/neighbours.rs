@@ -0,0 +1,356 @@
+pub mod NeighboursModule {
+    use std::collections::{BTreeSet, HashMap, VecDeque};
+    use std::fmt;
+    use std::net::Ipv4Addr;
+    use std::sync::{Arc, Mutex};
+
+    use crate::mibobject::MibModule::{table_maps, MibObject, MibValue};
+    use crate::topology::TopologyModule::{Graph, NodeKind, Status};
+
+    /// how many link changes are kept for the neighbours tab
+    const RECENT: usize = 200;
+    /// lldpRemPortIdSubtype of ports identified by their MAC address, which say less than the
+    /// port description
+    const PORT_ID_MAC: &str = "3";
+    /// cdpCacheAddressType of IPv4 addresses
+    const CDP_IP: &str = "1";
+
+    /// one device seen on a port by LLDP or CDP
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Neighbour {
+        pub protocol: &'static str,
+        /// the local port as the neighbour table numbers it, lldpRemLocalPortNum or cdpCacheIfIndex
+        pub local_port: String,
+        /// the local port's name
+        pub port: String,
+        /// the ifIndex of the local port, when ifTable has it
+        pub if_index: Option<String>,
+        /// the neighbour's system name, or its chassis ID when it does not tell its name
+        pub remote: String,
+        pub chassis_id: String,
+        pub remote_port: String,
+        pub address: Option<String>,
+        /// the neighbour's system description or platform
+        pub description: String,
+    }
+
+    /// a physical link between the ports of two devices, devices being polled targets or the
+    /// names neighbours go by
+    #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct Link {
+        pub from: String,
+        pub from_port: String,
+        pub to: String,
+        pub to_port: String,
+    }
+
+    impl fmt::Display for Link {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} {} <-> {} {}", self.from, self.from_port, self.to, self.to_port)
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct LinkChange {
+        pub timestamp: i64,
+        pub added: bool,
+        pub link: Link,
+    }
+
+    impl fmt::Display for LinkChange {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "link {} {}", if self.added { "up" } else { "gone" }, self.link)
+        }
+    }
+
+    fn first(value: &MibValue) -> String {
+        value.display_values().first().cloned().unwrap_or_default()
+    }
+
+    /// the first of `texts` that is not empty
+    fn either(texts: &[Option<&String>]) -> String {
+        texts.iter().flatten().find(|a| !a.is_empty()).map(|a| a.to_string()).unwrap_or_default()
+    }
+
+    /// the IPv4 address in a cdpCacheAddress, which is shown as text or as hex octets
+    fn cdp_address(text: &str) -> Option<String> {
+        let octets = match text.split(':').map(|a| u8::from_str_radix(a, 16)).collect::<Result<Vec<u8>, _>>() {
+            Ok(octets) if text.len() == 11 => octets,
+            _ => text.bytes().collect(),
+        };
+        <[u8; 4]>::try_from(octets).ok().map(|a| Ipv4Addr::from(a).to_string())
+    }
+
+    /// every LLDP and CDP neighbour of `object`, with the local port matched to its ifTable row
+    pub fn neighbours(object: &MibObject) -> Vec<Neighbour> {
+        let interfaces = table_maps(&object.interfaces.ifTable.values())
+            .into_iter()
+            .filter_map(|(_, row)| Some((row.get("ifIndex")?.clone(), row.get("ifDescr").cloned().unwrap_or_default())))
+            .collect::<Vec<(String, String)>>();
+        let descr = |if_index: &str| interfaces.iter().find(|a| a.0 == if_index).map(|a| a.1.clone());
+        let mut neighbours = vec![];
+
+        let local_ports = table_maps(&object.lldp.lldpLocPortTable.values()).into_iter().collect::<HashMap<String, HashMap<String, String>>>();
+        for (index, row) in table_maps(&object.lldp.lldpRemTable.values()) {
+            // lldpRemTimeMark.lldpRemLocalPortNum.lldpRemIndex
+            let local_port = match index.split('.').nth(1) {
+                Some(local_port) => local_port.to_owned(),
+                None => continue,
+            };
+            let local = local_ports.get(&local_port);
+            let (port_id, port_desc) = (local.and_then(|a| a.get("lldpLocPortId")), local.and_then(|a| a.get("lldpLocPortDesc")));
+            // the port number is usually the ifIndex, but the port's name says for sure
+            let if_index = interfaces
+                .iter()
+                .find(|(_, descr)| !descr.is_empty() && [port_id, port_desc].contains(&Some(descr)))
+                .map(|a| a.0.clone())
+                .or(descr(&local_port).map(|_| local_port.clone()));
+            let chassis_id = row.get("lldpRemChassisId").cloned().unwrap_or_default();
+            let remote_port = match row.get("lldpRemPortIdSubtype").map(|a| a.as_str()) {
+                Some(PORT_ID_MAC) => either(&[row.get("lldpRemPortDesc"), row.get("lldpRemPortId")]),
+                _ => either(&[row.get("lldpRemPortId"), row.get("lldpRemPortDesc")]),
+            };
+            neighbours.push(Neighbour {
+                protocol: "lldp",
+                port: either(&[port_id, if_index.as_ref().and_then(|a| descr(a)).as_ref(), Some(&local_port)]),
+                local_port,
+                if_index,
+                remote: either(&[row.get("lldpRemSysName"), Some(&chassis_id)]),
+                chassis_id,
+                remote_port,
+                address: None,
+                description: row.get("lldpRemSysDesc").cloned().unwrap_or_default(),
+            });
+        }
+
+        for (index, row) in table_maps(&object.cdp.cdpCacheTable.values()) {
+            // cdpCacheIfIndex.cdpCacheDeviceIndex
+            let if_index = match index.split('.').next() {
+                Some(if_index) => if_index.to_owned(),
+                None => continue,
+            };
+            let address = match row.get("cdpCacheAddressType").map(|a| a.as_str()) {
+                Some(CDP_IP) => row.get("cdpCacheAddress").and_then(|a| cdp_address(a)),
+                _ => None,
+            };
+            let device_id = row.get("cdpCacheDeviceId").cloned().unwrap_or_default();
+            neighbours.push(Neighbour {
+                protocol: "cdp",
+                port: descr(&if_index).unwrap_or(if_index.clone()),
+                local_port: if_index.clone(),
+                if_index: descr(&if_index).map(|_| if_index),
+                remote: device_id.clone(),
+                chassis_id: device_id,
+                remote_port: row.get("cdpCacheDevicePort").cloned().unwrap_or_default(),
+                address,
+                description: row.get("cdpCachePlatform").cloned().unwrap_or_default(),
+            });
+        }
+        neighbours
+    }
+
+    /// the name a device goes by in neighbour tables, without the domain CDP adds
+    fn short_name(name: &str) -> String {
+        name.split('.').next().unwrap_or_default().to_lowercase()
+    }
+
+    /// the polled target a neighbour is, by chassis ID, system name or address
+    fn resolve(neighbour: &Neighbour, objects: &[&MibObject]) -> Option<String> {
+        objects
+            .iter()
+            .find(|object| {
+                let chassis_id = first(&object.lldp.lldpLocChassisId);
+                let sys_name = either(&[Some(&first(&object.lldp.lldpLocSysName)), Some(&first(&object.system.sysName))]);
+                (!chassis_id.is_empty() && chassis_id == neighbour.chassis_id)
+                    || (!sys_name.is_empty() && short_name(&sys_name) == short_name(&neighbour.remote))
+                    || neighbour.address.as_ref() == Some(&object.target)
+            })
+            .map(|object| object.target.clone())
+    }
+
+    /// the links a device reports, to polled targets where the neighbour is one
+    fn device_links(object: &MibObject, objects: &[&MibObject]) -> Vec<Link> {
+        neighbours(object)
+            .into_iter()
+            .map(|neighbour| Link {
+                from: object.target.clone(),
+                from_port: neighbour.port.clone(),
+                to: resolve(&neighbour, objects).unwrap_or(neighbour.remote.clone()),
+                to_port: neighbour.remote_port,
+            })
+            .collect()
+    }
+
+    /// every physical link between `objects` and their neighbours, a link seen from both of its
+    /// ends counted once
+    pub fn links(objects: &[&MibObject]) -> Vec<Link> {
+        let mut links = BTreeSet::new();
+        for object in objects {
+            for link in device_links(object, objects) {
+                let reverse = Link { from: link.to.clone(), from_port: link.to_port.clone(), to: link.from.clone(), to_port: link.from_port.clone() };
+                if !links.contains(&reverse) {
+                    links.insert(link);
+                }
+            }
+        }
+        links.into_iter().collect()
+    }
+
+    /// the physical link graph: polled devices and the neighbours they see, linked port to port
+    pub fn graph(objects: &[&MibObject]) -> Graph {
+        let mut graph = Graph::default();
+        let targets = objects.iter().map(|a| (a.target.clone(), *a)).collect::<HashMap<String, &MibObject>>();
+        for object in objects {
+            let sys_name = first(&object.system.sysName);
+            let label = if sys_name.is_empty() { object.target.clone() } else { sys_name };
+            graph.node(&["device:", object.target.as_str()].concat(), NodeKind::Device, &label).details.push(format!("polled at {}", object.target));
+        }
+        // the link is up when the local port is, so the status of every port with a neighbour is
+        // looked up once per device rather than once per link
+        let oper_statuses = objects
+            .iter()
+            .map(|object| {
+                let statuses = table_maps(&object.interfaces.ifTable.values())
+                    .into_iter()
+                    .filter_map(|(_, row)| Some((row.get("ifIndex")?.clone(), row.get("ifOperStatus")?.clone())))
+                    .collect::<HashMap<String, String>>();
+                let ports = neighbours(object)
+                    .into_iter()
+                    .filter_map(|neighbour| Some((neighbour.port, statuses.get(neighbour.if_index.as_ref()?)?.clone())))
+                    .collect::<HashMap<String, String>>();
+                (object.target.clone(), ports)
+            })
+            .collect::<HashMap<String, HashMap<String, String>>>();
+        for link in links(objects) {
+            let from = ["device:", link.from.as_str()].concat();
+            let to = match targets.contains_key(&link.to) {
+                true => ["device:", link.to.as_str()].concat(),
+                false => {
+                    let id = ["neighbour:", link.to.as_str()].concat();
+                    graph.node(&id, NodeKind::Host, &link.to);
+                    id
+                }
+            };
+            let oper_status = oper_statuses.get(&link.from).and_then(|ports| ports.get(&link.from_port));
+            graph.node(&from, NodeKind::Device, &link.from).details.push(format!("{} to {} {}", link.from_port, link.to, link.to_port));
+            graph.edge(&from, &to, format!("{} - {}", link.from_port, link.to_port), Status::from_oper_status(oper_status));
+        }
+        graph
+    }
+
+    pub type Links = Arc<Mutex<LinkTracker>>;
+
+    /// remembers the links every target reported in its last poll to tell which came and went
+    #[derive(Default)]
+    pub struct LinkTracker {
+        links: HashMap<String, BTreeSet<Link>>,
+        recent: VecDeque<LinkChange>,
+    }
+
+    impl LinkTracker {
+        /// the links of `object` that appeared or disappeared since its previous poll, nothing on
+        /// its first
+        pub fn update(&mut self, object: &MibObject, objects: &[&MibObject]) -> Vec<LinkChange> {
+            let current = device_links(object, objects).into_iter().collect::<BTreeSet<Link>>();
+            let previous = match self.links.insert(object.target.clone(), current.clone()) {
+                Some(previous) => previous,
+                None => return vec![],
+            };
+            let change = |added: bool| move |link: &Link| LinkChange { timestamp: object.timestamp, added, link: link.clone() };
+            let changes = current
+                .difference(&previous)
+                .map(change(true))
+                .chain(previous.difference(&current).map(change(false)))
+                .collect::<Vec<LinkChange>>();
+            for change in &changes {
+                self.recent.push_front(change.clone());
+            }
+            self.recent.truncate(RECENT);
+            changes
+        }
+
+        /// the latest link changes, newest first
+        pub fn recent(&self) -> Vec<LinkChange> {
+            self.recent.iter().cloned().collect()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::testing::{set_int, set_string};
+
+        /// a switch called `name` with the ports Gi0/1 and Gi0/2 as ifIndex 1 and 2
+        fn switch(target: &str, name: &str, chassis_id: &str) -> MibObject {
+            let mut object = MibObject::new();
+            object.target = target.to_owned();
+            set_string(&mut object.system.sysName, vec![name], vec![vec![0]]);
+            set_string(&mut object.lldp.lldpLocChassisId, vec![chassis_id], vec![vec![0]]);
+            let interfaces = &mut object.interfaces.ifTable;
+            set_int(&mut interfaces.ifIndex, vec![1, 2], vec![vec![1], vec![2]]);
+            set_string(&mut interfaces.ifDescr, vec!["Gi0/1", "Gi0/2"], vec![vec![1], vec![2]]);
+            set_int(&mut interfaces.ifOperStatus, vec![1, 2], vec![vec![1], vec![2]]);
+            let ports = &mut object.lldp.lldpLocPortTable;
+            set_string(&mut ports.lldpLocPortId, vec!["Gi0/1", "Gi0/2"], vec![vec![1], vec![2]]);
+            object
+        }
+
+        /// `object` sees `remotes` as `(local port, chassis ID, name, remote port)` over LLDP
+        fn see(object: &mut MibObject, remotes: Vec<(u32, &str, &str, &str)>) {
+            let index = remotes.iter().enumerate().map(|(a, remote)| vec![0, remote.0, a as u32 + 1]).collect::<Vec<Vec<u32>>>();
+            let table = &mut object.lldp.lldpRemTable;
+            set_string(&mut table.lldpRemChassisId, remotes.iter().map(|a| a.1).collect(), index.clone());
+            set_string(&mut table.lldpRemSysName, remotes.iter().map(|a| a.2).collect(), index.clone());
+            set_int(&mut table.lldpRemPortIdSubtype, remotes.iter().map(|_| 5).collect(), index.clone());
+            set_string(&mut table.lldpRemPortId, remotes.iter().map(|a| a.3).collect(), index);
+        }
+
+        #[test]
+        fn test_neighbours() {
+            let mut core = switch("10.0.0.1", "core", "00:00:5e:00:53:01");
+            see(&mut core, vec![(1, "00:00:5e:00:53:02", "access", "Gi0/2"), (2, "00:00:5e:00:53:09", "", "eth0")]);
+            let table = &mut core.cdp.cdpCacheTable;
+            set_int(&mut table.cdpCacheAddressType, vec![1], vec![vec![2, 1]]);
+            set_string(&mut table.cdpCacheAddress, vec!["0a:00:00:02"], vec![vec![2, 1]]);
+            set_string(&mut table.cdpCacheDeviceId, vec!["access.example.com"], vec![vec![2, 1]]);
+            set_string(&mut table.cdpCacheDevicePort, vec!["GigabitEthernet0/1"], vec![vec![2, 1]]);
+
+            let neighbours = neighbours(&core);
+            assert_eq!(neighbours.len(), 3);
+            assert_eq!((neighbours[0].port.as_str(), neighbours[0].if_index.as_deref()), ("Gi0/1", Some("1")));
+            assert_eq!(neighbours[0].remote, "access");
+            // a neighbour without a name goes by its chassis ID
+            assert_eq!(neighbours[1].remote, "00:00:5e:00:53:09");
+            assert_eq!((neighbours[2].protocol, neighbours[2].port.as_str()), ("cdp", "Gi0/2"));
+            assert_eq!(neighbours[2].address.as_deref(), Some("10.0.0.2"));
+        }
+
+        #[test]
+        fn test_links() {
+            let mut core = switch("10.0.0.1", "core", "00:00:5e:00:53:01");
+            let mut access = switch("10.0.0.2", "access", "00:00:5e:00:53:02");
+            see(&mut core, vec![(1, "00:00:5e:00:53:02", "access", "Gi0/2"), (2, "00:00:5e:00:53:09", "", "eth0")]);
+            see(&mut access, vec![(2, "00:00:5e:00:53:01", "core", "Gi0/1")]);
+
+            // the core to access link is seen from both ends but counted once
+            let links = links(&[&core, &access]);
+            assert_eq!(links.len(), 2);
+            assert!(links.contains(&Link { from: "10.0.0.1".to_owned(), from_port: "Gi0/1".to_owned(), to: "10.0.0.2".to_owned(), to_port: "Gi0/2".to_owned() }));
+            let graph = graph(&[&core, &access]);
+            assert_eq!(graph.edges.len(), 2);
+            assert_eq!(graph.get("neighbour:00:00:5e:00:53:09").map(|a| a.kind), Some(NodeKind::Host));
+            // the link on Gi0/2 is down
+            assert!(graph.edges.iter().any(|a| a.to == "neighbour:00:00:5e:00:53:09" && a.status == Status::Down));
+
+            let mut tracker = LinkTracker::default();
+            assert!(tracker.update(&core, &[&core, &access]).is_empty());
+            see(&mut core, vec![(1, "00:00:5e:00:53:02", "access", "Gi0/2")]);
+            core.timestamp += 30;
+            let changes = tracker.update(&core, &[&core, &access]);
+            assert_eq!(changes.len(), 1);
+            assert!(!changes[0].added);
+            assert_eq!(changes[0].link.to, "00:00:5e:00:53:09");
+            assert_eq!(tracker.recent(), changes);
+        }
+    }
+}
@@ -0,0 +1,527 @@
+pub mod NotifyModule {
+    use chrono::{SecondsFormat, TimeZone};
+    use std::collections::{BTreeMap, HashMap, VecDeque};
+    use std::io;
+    use std::time::Duration;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{TcpStream, UdpSocket};
+    use tokio::process::Command;
+    use tokio::task::JoinHandle;
+    use tokio::time::timeout;
+
+    use crate::alert::AlertModule::{AlertEvent, AlertStatus};
+    use crate::config::ConfigModule::{AlertRule, NotifyConfig, Severity, SinkConfig, SinkKind, SyslogProtocol};
+    use crate::http::HttpModule;
+
+    /// how long a single delivery may take before it is abandoned
+    const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// routes alert events to the configured sinks
+    pub struct Notifier {
+        sinks: Vec<SinkConfig>,
+        default_sinks: Vec<String>,
+        routes: HashMap<String, Vec<String>>,
+        sent: HashMap<String, VecDeque<i64>>,
+    }
+
+    impl Notifier {
+        pub fn new(config: NotifyConfig, rules: &[AlertRule]) -> Self {
+            for name in rules.iter().flat_map(|rule| rule.notify.iter()).chain(config.default_sinks.iter()) {
+                if !config.sinks.iter().any(|sink| &sink.name == name) {
+                    println!("alert notifications reference unknown sink '{}'", name);
+                }
+            }
+            Notifier {
+                sinks: config.sinks,
+                default_sinks: config.default_sinks,
+                routes: rules.iter().map(|rule| (rule.name.clone(), rule.notify.clone())).collect(),
+                sent: HashMap::new(),
+            }
+        }
+
+        /// the sinks `event` is routed to, before rate limiting
+        fn route(&self, event: &AlertEvent) -> Vec<&SinkConfig> {
+            if event.status == AlertStatus::Pending {
+                return vec![];
+            }
+            let names = match self.routes.get(&event.rule) {
+                Some(names) if !names.is_empty() => names,
+                _ => &self.default_sinks,
+            };
+            self.sinks
+                .iter()
+                .filter(|sink| names.contains(&sink.name) && event.severity >= sink.min_severity)
+                .collect()
+        }
+
+        /// whether `sink` may send another notification at `now`, recording it if so
+        fn allow(&mut self, sink: &SinkConfig, now: i64) -> bool {
+            if sink.rate_limit == 0 {
+                return true;
+            }
+            let sent = self.sent.entry(sink.name.clone()).or_default();
+            while sent.front().map(|a| now - a >= sink.rate_window_secs).unwrap_or(false) {
+                sent.pop_front();
+            }
+            match sent.len() < sink.rate_limit {
+                true => {
+                    sent.push_back(now);
+                    true
+                }
+                false => false,
+            }
+        }
+
+        /// starts delivering every routed event in the background, one task per sink and event
+        pub fn dispatch(&mut self, events: &[AlertEvent]) -> Vec<JoinHandle<io::Result<()>>> {
+            let mut handles = vec![];
+            for event in events {
+                let sinks = self.route(event).into_iter().cloned().collect::<Vec<SinkConfig>>();
+                for sink in sinks {
+                    if !self.allow(&sink, event.timestamp) {
+                        println!("rate limit of sink '{}' reached, dropping notification for {}", sink.name, event.rule);
+                        continue;
+                    }
+                    let event = event.clone();
+                    handles.push(tokio::spawn(async move {
+                        let result = match timeout(SEND_TIMEOUT, send(&sink, &event)).await {
+                            Ok(result) => result,
+                            Err(_) => Err(io::Error::new(io::ErrorKind::TimedOut, "timed out")),
+                        };
+                        if let Err(err) = &result {
+                            println!("could not notify sink '{}' of {}: {}", sink.name, event.rule, err);
+                        }
+                        result
+                    }));
+                }
+            }
+            handles
+        }
+    }
+
+    /// replaces every `{{field}}` in `template` with the matching field of `event`
+    pub fn render(template: &str, event: &AlertEvent) -> String {
+        let time = match chrono::Utc.timestamp_opt(event.timestamp, 0).single() {
+            Some(time) => time.to_rfc3339_opts(SecondsFormat::Secs, true),
+            None => event.timestamp.to_string(),
+        };
+        [
+            ("rule", event.rule.clone()),
+            ("target", event.target.clone()),
+            ("instance", event.instance.clone()),
+            ("severity", severity_name(event.severity).to_owned()),
+            ("status", status_name(event.status).to_owned()),
+            ("value", event.value.clone()),
+            ("timestamp", event.timestamp.to_string()),
+            ("time", time),
+            ("message", event.message.clone()),
+        ]
+        .iter()
+        .fold(template.to_owned(), |text, (field, value)| text.replace(&["{{", field, "}}"].concat(), value))
+    }
+
+    fn severity_name(severity: Severity) -> &'static str {
+        match severity {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        }
+    }
+
+    fn status_name(status: AlertStatus) -> &'static str {
+        match status {
+            AlertStatus::Pending => "pending",
+            AlertStatus::Firing => "firing",
+            AlertStatus::Resolved => "resolved",
+        }
+    }
+
+    async fn send(sink: &SinkConfig, event: &AlertEvent) -> io::Result<()> {
+        let text = render(&sink.template, event);
+        match &sink.kind {
+            SinkKind::Webhook { url, headers } => send_webhook(url, headers, event, &text).await,
+            SinkKind::Smtp { server, from, to, username, password } => {
+                let credentials = username.as_deref().zip(password.as_deref());
+                send_mail(server, from, to, credentials, &render(&sink.subject, event), &text).await
+            }
+            SinkKind::Syslog { server, protocol, facility } => send_syslog(server, *protocol, *facility, event, &text).await,
+            SinkKind::Command { program, args } => run_command(program, args, event, &text).await,
+        }
+    }
+
+    async fn send_webhook(url: &str, headers: &BTreeMap<String, String>, event: &AlertEvent, text: &str) -> io::Result<()> {
+        let mut payload = serde_json::to_value(event)?;
+        payload["text"] = serde_json::Value::String(text.to_owned());
+        HttpModule::send("POST", url, headers, "application/json", &serde_json::to_vec(&payload)?).await
+    }
+
+    /// reads one possibly multi-line SMTP reply and checks its code
+    async fn smtp_reply(reader: &mut BufReader<TcpStream>, expected: u16) -> io::Result<()> {
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).await? == 0 {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "mail server closed the connection"));
+            }
+            if line.len() < 4 || line.as_bytes()[3] != b'-' {
+                return match line.get(..3).and_then(|code| code.parse::<u16>().ok()) {
+                    Some(code) if code == expected => Ok(()),
+                    _ => Err(io::Error::other(format!("mail server answered '{}'", line.trim()))),
+                };
+            }
+        }
+    }
+
+    async fn smtp_command(reader: &mut BufReader<TcpStream>, command: &str, expected: u16) -> io::Result<()> {
+        reader.get_mut().write_all([command, "\r\n"].concat().as_bytes()).await?;
+        smtp_reply(reader, expected).await
+    }
+
+    async fn send_mail(
+        server: &str,
+        from: &str,
+        to: &[String],
+        credentials: Option<(&str, &str)>,
+        subject: &str,
+        text: &str,
+    ) -> io::Result<()> {
+        let mut reader = BufReader::new(TcpStream::connect(server).await?);
+        smtp_reply(&mut reader, 220).await?;
+        smtp_command(&mut reader, "EHLO snmp-monitor", 250).await?;
+        if let Some((username, password)) = credentials {
+            let token = base64(&[b"\0", username.as_bytes(), b"\0", password.as_bytes()].concat());
+            smtp_command(&mut reader, &["AUTH PLAIN ", &token].concat(), 235).await?;
+        }
+        smtp_command(&mut reader, &format!("MAIL FROM:<{}>", from), 250).await?;
+        for recipient in to {
+            smtp_command(&mut reader, &format!("RCPT TO:<{}>", recipient), 250).await?;
+        }
+        smtp_command(&mut reader, "DATA", 354).await?;
+        let mut message = format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n",
+            from,
+            to.join(", "),
+            header_value(subject),
+            chrono::Utc::now().to_rfc2822()
+        );
+        for line in text.lines() {
+            // lines starting with a dot are escaped so they do not end the message early
+            if line.starts_with('.') {
+                message.push('.');
+            }
+            message.push_str(line);
+            message.push_str("\r\n");
+        }
+        message.push('.');
+        smtp_command(&mut reader, &message, 250).await?;
+        smtp_command(&mut reader, "QUIT", 221).await
+    }
+
+    /// folds line breaks out of a header so a rule or target name cannot add headers of its own
+    fn header_value(value: &str) -> String {
+        value.split(['\r', '\n']).filter(|part| !part.is_empty()).collect::<Vec<&str>>().join(" ")
+    }
+
+    fn base64(data: &[u8]) -> String {
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        data.chunks(3)
+            .flat_map(|chunk| {
+                let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+                let bits = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+                (0..4).map(move |position| match position <= chunk.len() {
+                    true => ALPHABET[(bits >> (18 - position * 6) & 0x3f) as usize] as char,
+                    false => '=',
+                })
+            })
+            .collect()
+    }
+
+    /// formats `event` as an RFC 5424 message
+    pub fn syslog_message(facility: u8, event: &AlertEvent, text: &str) -> String {
+        let severity = match (event.status, event.severity) {
+            (AlertStatus::Resolved, _) => 5,
+            (_, Severity::Critical) => 2,
+            (_, Severity::Warning) => 4,
+            (_, Severity::Info) => 6,
+        };
+        let time = match chrono::Utc.timestamp_opt(event.timestamp, 0).single() {
+            Some(time) => time.to_rfc3339_opts(SecondsFormat::Secs, true),
+            None => "-".to_owned(),
+        };
+        let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"").replace(']', "\\]");
+        format!(
+            "<{}>1 {} - snmp_monitor {} alert [alert@32473 rule=\"{}\" target=\"{}\" instance=\"{}\" status=\"{}\"] {}",
+            facility as u16 * 8 + severity,
+            time,
+            std::process::id(),
+            escape(&event.rule),
+            escape(&event.target),
+            escape(&event.instance),
+            status_name(event.status),
+            text
+        )
+    }
+
+    async fn send_syslog(server: &str, protocol: SyslogProtocol, facility: u8, event: &AlertEvent, text: &str) -> io::Result<()> {
+        let message = syslog_message(facility, event, text);
+        match protocol {
+            SyslogProtocol::Udp => {
+                let socket = UdpSocket::bind("0.0.0.0:0").await?;
+                socket.send_to(message.as_bytes(), server).await?;
+            }
+            SyslogProtocol::Tcp => {
+                // octet counting framing from RFC 6587
+                let mut stream = TcpStream::connect(server).await?;
+                stream.write_all(format!("{} {}", message.len(), message).as_bytes()).await?;
+                stream.shutdown().await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn run_command(program: &str, args: &[String], event: &AlertEvent, text: &str) -> io::Result<()> {
+        let output = Command::new(program)
+            .args(args)
+            .env("SNMP_ALERT_RULE", &event.rule)
+            .env("SNMP_ALERT_TARGET", &event.target)
+            .env("SNMP_ALERT_INSTANCE", &event.instance)
+            .env("SNMP_ALERT_SEVERITY", severity_name(event.severity))
+            .env("SNMP_ALERT_STATUS", status_name(event.status))
+            .env("SNMP_ALERT_VALUE", &event.value)
+            .env("SNMP_ALERT_TIMESTAMP", event.timestamp.to_string())
+            .env("SNMP_ALERT_TEXT", text)
+            .kill_on_drop(true)
+            .output()
+            .await?;
+        match output.status.success() {
+            true => Ok(()),
+            false => Err(io::Error::other(format!(
+                "{} exited with {}: {}",
+                program,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use tokio::io::AsyncReadExt;
+        use tokio::net::TcpListener;
+
+        fn event(status: AlertStatus, severity: Severity) -> AlertEvent {
+            AlertEvent {
+                timestamp: 1700000000,
+                target: "192.0.2.1".to_owned(),
+                rule: "link down".to_owned(),
+                instance: "ifOperStatus.2".to_owned(),
+                severity,
+                status,
+                value: "2".to_owned(),
+                message: "link down".to_owned(),
+            }
+        }
+
+        fn sink(name: &str, kind: SinkKind) -> SinkConfig {
+            serde_json::from_value(serde_json::json!({ "name": name, "type": "command", "program": "true" }))
+                .map(|sink: SinkConfig| SinkConfig { kind, ..sink })
+                .unwrap()
+        }
+
+        fn notifier_for(sinks: Vec<SinkConfig>) -> Notifier {
+            let names = sinks.iter().map(|sink| sink.name.clone()).collect();
+            Notifier::new(NotifyConfig { sinks, default_sinks: names }, &[])
+        }
+
+        async fn deliver(notifier: &mut Notifier, event: AlertEvent) -> Vec<io::Result<()>> {
+            let mut results = vec![];
+            for handle in notifier.dispatch(&[event]) {
+                results.push(handle.await.unwrap());
+            }
+            results
+        }
+
+        #[test]
+        fn test_render_and_syslog_format() {
+            let event = event(AlertStatus::Firing, Severity::Critical);
+            assert_eq!(render("{{rule}} {{status}} at {{time}}", &event), "link down firing at 2023-11-14T22:13:20Z");
+            assert_eq!(base64(b"\0user\0pass"), "AHVzZXIAcGFzcw==");
+            assert_eq!(header_value("link down on 192.0.2.1\r\nBcc: someone@example.com"), "link down on 192.0.2.1 Bcc: someone@example.com");
+            let message = syslog_message(1, &event, "text");
+            assert!(message.starts_with("<10>1 2023-11-14T22:13:20Z - snmp_monitor "));
+            assert!(message.ends_with(" alert [alert@32473 rule=\"link down\" target=\"192.0.2.1\" instance=\"ifOperStatus.2\" status=\"firing\"] text"));
+        }
+
+        #[test]
+        fn test_routing_and_rate_limit() {
+            let mut limited = sink("pager", SinkKind::Command { program: "true".to_owned(), args: vec![] });
+            limited.rate_limit = 2;
+            limited.min_severity = Severity::Warning;
+            let other = sink("other", SinkKind::Command { program: "true".to_owned(), args: vec![] });
+            let rules = vec![AlertRule { name: "link down".to_owned(), notify: vec!["pager".to_owned()], ..AlertRule::default() }];
+            let mut notifier = Notifier::new(NotifyConfig { sinks: vec![limited.clone(), other], default_sinks: vec!["other".to_owned()] }, &rules);
+
+            let firing = event(AlertStatus::Firing, Severity::Critical);
+            assert_eq!(notifier.route(&firing).iter().map(|a| a.name.as_str()).collect::<Vec<_>>(), vec!["pager"]);
+            assert!(notifier.route(&event(AlertStatus::Pending, Severity::Critical)).is_empty());
+            assert!(notifier.route(&event(AlertStatus::Firing, Severity::Info)).is_empty());
+            let unrouted = AlertEvent { rule: "other rule".to_owned(), ..firing.clone() };
+            assert_eq!(notifier.route(&unrouted).iter().map(|a| a.name.as_str()).collect::<Vec<_>>(), vec!["other"]);
+
+            assert!(notifier.allow(&limited, 0));
+            assert!(notifier.allow(&limited, 10));
+            assert!(!notifier.allow(&limited, 59));
+            assert!(notifier.allow(&limited, 60));
+        }
+
+        #[tokio::test]
+        async fn test_webhook() {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}/hook", listener.local_addr().unwrap());
+            let server = tokio::spawn(async move {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut head = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).await.unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    head.push_str(&line);
+                }
+                let length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .unwrap()
+                    .parse::<usize>()
+                    .unwrap();
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).await.unwrap();
+                reader.get_mut().write_all(b"HTTP/1.1 204 No Content\r\n\r\n").await.unwrap();
+                (head, serde_json::from_slice::<serde_json::Value>(&body).unwrap())
+            });
+
+            let headers = [("X-Token".to_owned(), "secret".to_owned())].into_iter().collect();
+            let mut notifier = notifier_for(vec![sink("hook", SinkKind::Webhook { url, headers })]);
+            let results = deliver(&mut notifier, event(AlertStatus::Firing, Severity::Critical)).await;
+            assert!(results.iter().all(|a| a.is_ok()));
+
+            let (head, body) = server.await.unwrap();
+            assert!(head.starts_with("POST /hook HTTP/1.1\r\n"));
+            assert!(head.contains("X-Token: secret\r\n"));
+            assert_eq!(body["rule"], "link down");
+            assert_eq!(body["status"], "firing");
+            assert!(body["text"].as_str().unwrap().starts_with("critical alert link down is firing"));
+
+            let mut notifier = notifier_for(vec![sink("tls", SinkKind::Webhook { url: "https://example.com".to_owned(), headers: Default::default() })]);
+            assert!(deliver(&mut notifier, event(AlertStatus::Firing, Severity::Critical)).await[0].is_err());
+        }
+
+        #[tokio::test]
+        async fn test_smtp() {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let server = listener.local_addr().unwrap().to_string();
+            let stand_in = tokio::spawn(async move {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut commands = vec![];
+                let mut data = String::new();
+                reader.get_mut().write_all(b"220 stand-in ready\r\n").await.unwrap();
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).await.unwrap() == 0 {
+                        break;
+                    }
+                    let command = line.trim_end().to_owned();
+                    let reply: &[u8] = match command.as_str() {
+                        "DATA" => b"354 go ahead\r\n",
+                        "QUIT" => b"221 bye\r\n",
+                        command if command.starts_with("EHLO") => b"250-stand-in\r\n250 AUTH PLAIN\r\n",
+                        command if command.starts_with("AUTH") => b"235 ok\r\n",
+                        _ => b"250 ok\r\n",
+                    };
+                    if command == "DATA" {
+                        reader.get_mut().write_all(reply).await.unwrap();
+                        loop {
+                            let mut line = String::new();
+                            reader.read_line(&mut line).await.unwrap();
+                            if line == ".\r\n" {
+                                break;
+                            }
+                            data.push_str(&line);
+                        }
+                        reader.get_mut().write_all(b"250 queued\r\n").await.unwrap();
+                        commands.push(command);
+                        continue;
+                    }
+                    reader.get_mut().write_all(reply).await.unwrap();
+                    commands.push(command);
+                }
+                (commands, data)
+            });
+
+            let mut notifier = notifier_for(vec![sink("mail", SinkKind::Smtp {
+                server,
+                from: "monitor@example.com".to_owned(),
+                to: vec!["oncall@example.com".to_owned()],
+                username: Some("user".to_owned()),
+                password: Some("pass".to_owned()),
+            })]);
+            let results = deliver(&mut notifier, event(AlertStatus::Resolved, Severity::Warning)).await;
+            assert!(results.iter().all(|a| a.is_ok()));
+
+            let (commands, data) = stand_in.await.unwrap();
+            assert_eq!(commands, vec![
+                "EHLO snmp-monitor",
+                "AUTH PLAIN AHVzZXIAcGFzcw==",
+                "MAIL FROM:<monitor@example.com>",
+                "RCPT TO:<oncall@example.com>",
+                "DATA",
+                "QUIT",
+            ]);
+            assert!(data.contains("Subject: [warning] link down resolved on 192.0.2.1\r\n"));
+            assert!(data.contains("warning alert link down is resolved on 192.0.2.1"));
+        }
+
+        #[tokio::test]
+        async fn test_syslog() {
+            let udp = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+            let tcp = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let mut notifier = notifier_for(vec![
+                sink("udp", SinkKind::Syslog { server: udp.local_addr().unwrap().to_string(), protocol: SyslogProtocol::Udp, facility: 4 }),
+                sink("tcp", SinkKind::Syslog { server: tcp.local_addr().unwrap().to_string(), protocol: SyslogProtocol::Tcp, facility: 4 }),
+            ]);
+            let results = deliver(&mut notifier, event(AlertStatus::Firing, Severity::Warning)).await;
+            assert!(results.iter().all(|a| a.is_ok()));
+
+            let mut datagram = vec![0; 2048];
+            let length = udp.recv(&mut datagram).await.unwrap();
+            let datagram = String::from_utf8(datagram[..length].to_vec()).unwrap();
+            assert!(datagram.starts_with("<36>1 "));
+
+            let (mut stream, _) = tcp.accept().await.unwrap();
+            let mut framed = String::new();
+            stream.read_to_string(&mut framed).await.unwrap();
+            let (length, message) = framed.split_once(' ').unwrap();
+            assert_eq!(length.parse::<usize>().unwrap(), message.len());
+            assert_eq!(message, datagram);
+        }
+
+        #[cfg(unix)]
+        #[tokio::test]
+        async fn test_command() {
+            let output = std::env::temp_dir().join(format!("snmp-monitor-notify-{}", std::process::id()));
+            let script = format!("printf '%s %s' \"$SNMP_ALERT_RULE\" \"$SNMP_ALERT_STATUS\" > {}", output.display());
+            let mut notifier = notifier_for(vec![
+                sink("script", SinkKind::Command { program: "sh".to_owned(), args: vec!["-c".to_owned(), script] }),
+                sink("failing", SinkKind::Command { program: "false".to_owned(), args: vec![] }),
+            ]);
+            let results = deliver(&mut notifier, event(AlertStatus::Firing, Severity::Critical)).await;
+            assert!(results[0].is_ok());
+            assert!(results[1].is_err());
+            assert_eq!(std::fs::read_to_string(&output).unwrap(), "link down firing");
+            let _ = std::fs::remove_file(output);
+        }
+    }
+}
@@ -0,0 +1,260 @@
+pub mod ReplayModule {
+    use std::collections::BTreeMap;
+
+    use crate::config::ConfigModule::LogConfig;
+    use crate::miblog::MibLogModule::{self, SnapshotRef};
+    use crate::mibobject::MibModule::{MibObject, MibValue};
+
+    /// snapshots of one target indexed in its log, and the one currently being viewed
+    pub struct Replay {
+        pub target: String,
+        pub snapshots: Vec<SnapshotRef>,
+        pub position: usize,
+        /// the snapshot at `position`, read from the log when the position changes
+        current: Option<(usize, MibObject)>,
+        /// the snapshot before it, for the views that compare two polls
+        previous: Option<(usize, MibObject)>,
+    }
+
+    impl Replay {
+        /// indexes every snapshot of `target` and starts at the most recent one
+        pub fn load(config: &LogConfig, target: &str) -> Self {
+            let snapshots = MibLogModule::index_snapshots(config, target);
+            let position = snapshots.len().saturating_sub(1);
+            let mut replay = Replay { target: target.to_owned(), snapshots, position, current: None, previous: None };
+            replay.seek();
+            replay
+        }
+
+        /// reads the snapshots at and before `position` unless they are the ones already read
+        pub fn seek(&mut self) {
+            let before = self.position.checked_sub(1);
+            if self.previous.as_ref().map(|a| a.0) != before {
+                // stepping forward by one makes the snapshot read last the one before
+                self.previous = match self.current.take() {
+                    Some(current) if Some(current.0) == before => Some(current),
+                    current => {
+                        self.current = current;
+                        before.and_then(|position| self.read(position))
+                    }
+                };
+            }
+            if !self.current.as_ref().is_some_and(|a| a.0 == self.position) {
+                self.current = self.read(self.position);
+            }
+        }
+
+        fn read(&self, position: usize) -> Option<(usize, MibObject)> {
+            let snapshot = self.snapshots.get(position)?;
+            match MibLogModule::read_snapshot(snapshot) {
+                Ok(object) => Some((position, object)),
+                Err(err) => {
+                    println!("could not read snapshot {:?}@{}: {}", snapshot.path, snapshot.offset, err);
+                    None
+                }
+            }
+        }
+
+        pub fn current(&self) -> Option<&MibObject> {
+            self.current.as_ref().map(|a| &a.1)
+        }
+
+        pub fn previous(&self) -> Option<&MibObject> {
+            self.previous.as_ref().map(|a| &a.1)
+        }
+
+        /// moves the current snapshot out until `put` hands it back
+        pub fn take(&mut self) -> Option<MibObject> {
+            self.current.take().map(|a| a.1)
+        }
+
+        pub fn put(&mut self, snapshot: MibObject) {
+            self.current = Some((self.position, snapshot));
+        }
+    }
+
+    /// a value that differs between the replayed snapshot and the live object, empty on the
+    /// side where it does not exist
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Change {
+        pub name: String,
+        pub then: String,
+        pub now: String,
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct MibDiff {
+        pub values: Vec<Change>,
+        pub interfaces_added: Vec<String>,
+        pub interfaces_removed: Vec<String>,
+        pub routes: Vec<Change>,
+    }
+
+    /// compares the snapshot `then` against the live object `now`
+    pub fn diff(then: &MibObject, now: &MibObject) -> MibDiff {
+        let interfaces_then = interfaces(then);
+        let interfaces_now = interfaces(now);
+        MibDiff {
+            values: changes(instances(then), instances(now)),
+            interfaces_added: interfaces_now
+                .iter()
+                .filter(|a| !interfaces_then.contains_key(a.0))
+                .map(|a| a.1.clone())
+                .collect(),
+            interfaces_removed: interfaces_then
+                .iter()
+                .filter(|a| !interfaces_now.contains_key(a.0))
+                .map(|a| a.1.clone())
+                .collect(),
+            routes: changes(routes(then), routes(now)),
+        }
+    }
+
+    fn changes(then: BTreeMap<String, String>, now: BTreeMap<String, String>) -> Vec<Change> {
+        let mut names = then.keys().chain(now.keys()).cloned().collect::<Vec<String>>();
+        names.sort();
+        names.dedup();
+        names
+            .into_iter()
+            .filter_map(|name| {
+                let old = then.get(&name).cloned().unwrap_or_default();
+                let new = now.get(&name).cloned().unwrap_or_default();
+                match old == new {
+                    true => None,
+                    false => Some(Change { name, then: old, now: new }),
+                }
+            })
+            .collect()
+    }
+
+    fn suffix(value: &MibValue, position: usize) -> String {
+        value.instance_index(position).iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".")
+    }
+
+    /// every value instance of `object` as `name.index -> text`
+    fn instances(object: &MibObject) -> BTreeMap<String, String> {
+        object
+            .values()
+            .into_iter()
+            .flat_map(|value| {
+                value
+                    .display_values()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(position, text)| ([value.get_name(), suffix(value, position)].join("."), text))
+                    .collect::<Vec<(String, String)>>()
+            })
+            .collect()
+    }
+
+    /// interfaces of `object` as `ifIndex -> "ifIndex (ifDescr)"`
+    fn interfaces(object: &MibObject) -> BTreeMap<i64, String> {
+        let table = &object.interfaces.ifTable;
+        let descrs = table.ifDescr.display_values();
+        table
+            .ifIndex
+            .val_as_mvinti64()
+            .unwrap_or_default()
+            .into_iter()
+            .enumerate()
+            .map(|(position, index)| match descrs.get(position) {
+                Some(descr) => (index, format!("{} ({})", index, descr)),
+                None => (index, index.to_string()),
+            })
+            .collect()
+    }
+
+    /// routes of `object` as `dest/mask -> "via nexthop if ifIndex"`
+    fn routes(object: &MibObject) -> BTreeMap<String, String> {
+        let table = &object.ip.ipRouteTable;
+        let masks = table.ipRouteMask.display_values();
+        let next_hops = table.ipRouteNextHop.display_values();
+        let if_indexes = table.ipRouteIfIndex.display_values();
+        table
+            .ipRouteDest
+            .display_values()
+            .into_iter()
+            .enumerate()
+            .map(|(position, dest)| {
+                let mask = masks.get(position).cloned().unwrap_or_default();
+                let next_hop = next_hops.get(position).cloned().unwrap_or_default();
+                let if_index = if_indexes.get(position).cloned().unwrap_or_default();
+                (format!("{}/{}", dest, mask), format!("via {} if {}", next_hop, if_index))
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::{mvinti32, mvipv4, mvstring};
+
+        type Ipv4 = (u8, u8, u8, u8);
+
+        fn object(interfaces: Vec<(i32, &str)>, routes: Vec<(Ipv4, Ipv4)>) -> MibObject {
+            let mut object = MibObject::new();
+            let table = &mut object.interfaces.ifTable;
+            if let MibValue::inti32(mvinti32 { value, index, .. }) = &mut table.ifIndex {
+                *value = interfaces.iter().map(|a| a.0).collect();
+                *index = interfaces.iter().map(|a| vec![a.0 as u32]).collect();
+            }
+            if let MibValue::string(mvstring { value, index, .. }) = &mut table.ifDescr {
+                *value = interfaces.iter().map(|a| a.1.to_owned()).collect();
+                *index = interfaces.iter().map(|a| vec![a.0 as u32]).collect();
+            }
+            let table = &mut object.ip.ipRouteTable;
+            if let MibValue::ipv4(mvipv4 { value, .. }) = &mut table.ipRouteDest {
+                *value = routes.iter().map(|a| a.0).collect();
+            }
+            if let MibValue::ipv4(mvipv4 { value, .. }) = &mut table.ipRouteNextHop {
+                *value = routes.iter().map(|a| a.1).collect();
+            }
+            object
+        }
+
+        #[test]
+        fn test_diff_interfaces_and_routes() {
+            let then = object(vec![(1, "lo"), (2, "eth0")], vec![((0, 0, 0, 0), (10, 0, 0, 1)), ((10, 1, 0, 0), (10, 0, 0, 2))]);
+            let now = object(vec![(1, "lo"), (3, "eth1")], vec![((0, 0, 0, 0), (10, 0, 0, 9)), ((10, 2, 0, 0), (10, 0, 0, 2))]);
+            let diff = diff(&then, &now);
+
+            assert_eq!(diff.interfaces_added, vec!["3 (eth1)".to_owned()]);
+            assert_eq!(diff.interfaces_removed, vec!["2 (eth0)".to_owned()]);
+            assert_eq!(diff.routes.len(), 3);
+            assert_eq!(diff.routes[0], Change {
+                name: "0.0.0.0/".to_owned(),
+                then: "via 10.0.0.1 if ".to_owned(),
+                now: "via 10.0.0.9 if ".to_owned(),
+            });
+            assert!(diff.routes[1].now.is_empty());
+            assert!(diff.routes[2].then.is_empty());
+            assert!(diff.values.contains(&Change { name: "ifDescr.2".to_owned(), then: "eth0".to_owned(), now: String::new() }));
+            assert!(!diff.values.iter().any(|a| a.name == "ifDescr.1"));
+            assert_eq!(super::diff(&now, &now), MibDiff::default());
+        }
+
+        #[test]
+        fn test_seek_reads_the_poll_before() {
+            let dir = std::env::temp_dir().join(format!("snmp-monitor-replay-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            let config = LogConfig { dir: dir.to_string_lossy().into_owned(), ..LogConfig::default() };
+            let mut log = MibLogModule::MibLog::new(config.clone()).unwrap();
+            for timestamp in [100, 130, 160] {
+                let mut object = MibObject::new();
+                object.target = "192.0.2.1".to_owned();
+                object.timestamp = timestamp;
+                log.append(&object).unwrap();
+            }
+            let timestamps = |replay: &Replay| (replay.previous().map(|a| a.timestamp), replay.current().map(|a| a.timestamp));
+            let mut replay = Replay::load(&config, "192.0.2.1");
+            assert_eq!(timestamps(&replay), (Some(130), Some(160)));
+            replay.position = 0;
+            replay.seek();
+            assert_eq!(timestamps(&replay), (None, Some(100)));
+            replay.position = 1;
+            replay.seek();
+            assert_eq!(timestamps(&replay), (Some(100), Some(130)));
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
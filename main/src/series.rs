@@ -0,0 +1,560 @@
+pub mod SeriesModule {
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::sync::Mutex;
+
+    use crate::mibobject::MibModule::MibSample;
+    use crate::tsdb::TsdbModule::Tsdb;
+
+    /// the Y axis a series is read against
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Side {
+        #[default]
+        Left,
+        Right,
+    }
+
+    impl Side {
+        pub const ALL: [Side; 2] = [Side::Left, Side::Right];
+
+        pub fn label(&self) -> &'static str {
+            match self {
+                Side::Left => "left",
+                Side::Right => "right",
+            }
+        }
+    }
+
+    /// how the series of a plot are drawn
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Style {
+        #[default]
+        Lines,
+        /// every series filled down to zero
+        Area,
+        /// the series of each axis filled on top of each other, in the order they were added
+        Stacked,
+    }
+
+    impl Style {
+        pub const ALL: [Style; 3] = [Style::Lines, Style::Area, Style::Stacked];
+
+        pub fn label(&self) -> &'static str {
+            match self {
+                Style::Lines => "lines",
+                Style::Area => "area",
+                Style::Stacked => "stacked",
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub enum Op {
+        Add,
+        Sub,
+        Mul,
+        Div,
+    }
+
+    impl Op {
+        fn apply(&self, a: f64, b: f64) -> Option<f64> {
+            let value = match self {
+                Op::Add => a + b,
+                Op::Sub => a - b,
+                Op::Mul => a * b,
+                Op::Div => a / b,
+            };
+            value.is_finite().then_some(value)
+        }
+    }
+
+    /// a parsed series expression such as `rate(ifInOctets[3]+ifOutOctets[3])*8/ifSpeed[3]*100`
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Expr {
+        Number(f64),
+        /// a polled object with the index of one instance, like `ifInOctets[3]`; without one it
+        /// is the `.0` instance of a scalar, or every instance inside `sum`
+        Ref(String, Option<String>),
+        Neg(Box<Expr>),
+        Binary(Op, Box<Expr>, Box<Expr>),
+        /// the change per second between two polls; a drop is taken as a counter wrap or reset
+        /// and skipped
+        Rate(Box<Expr>),
+        /// the expression added up over every instance of the objects named without an index
+        Sum(Box<Expr>),
+    }
+
+    /// the value of an expression, the same at every poll or one per poll
+    enum Values {
+        Constant(f64),
+        Series(BTreeMap<i64, f64>),
+    }
+
+    struct Parser<'a> {
+        text: &'a str,
+        position: usize,
+    }
+
+    impl Parser<'_> {
+        fn peek(&mut self) -> Option<char> {
+            let rest = &self.text[self.position..];
+            self.position += rest.len() - rest.trim_start().len();
+            self.text[self.position..].chars().next()
+        }
+
+        fn eat(&mut self, token: char) -> bool {
+            let found = self.peek() == Some(token);
+            if found {
+                self.position += token.len_utf8();
+            }
+            found
+        }
+
+        /// the longest run of characters from here that `accept` takes
+        fn take(&mut self, accept: impl Fn(char) -> bool) -> &str {
+            let start = self.position;
+            let length = self.text[start..].find(|a| !accept(a)).unwrap_or(self.text.len() - start);
+            self.position += length;
+            &self.text[start..start + length]
+        }
+
+        fn expr(&mut self) -> Result<Expr, String> {
+            let mut left = self.term()?;
+            loop {
+                let op = match self.peek() {
+                    Some('+') => Op::Add,
+                    Some('-') => Op::Sub,
+                    _ => return Ok(left),
+                };
+                self.position += 1;
+                left = Expr::Binary(op, Box::new(left), Box::new(self.term()?));
+            }
+        }
+
+        fn term(&mut self) -> Result<Expr, String> {
+            let mut left = self.factor()?;
+            loop {
+                let op = match self.peek() {
+                    Some('*') => Op::Mul,
+                    Some('/') => Op::Div,
+                    _ => return Ok(left),
+                };
+                self.position += 1;
+                left = Expr::Binary(op, Box::new(left), Box::new(self.factor()?));
+            }
+        }
+
+        fn factor(&mut self) -> Result<Expr, String> {
+            match self.peek() {
+                Some('-') => {
+                    self.position += 1;
+                    Ok(Expr::Neg(Box::new(self.factor()?)))
+                }
+                Some('(') => {
+                    self.position += 1;
+                    let expr = self.expr()?;
+                    self.close()?;
+                    Ok(expr)
+                }
+                Some(a) if a.is_ascii_digit() || a == '.' => {
+                    let number = self.take(|a| a.is_ascii_digit() || a == '.');
+                    number.parse::<f64>().map(Expr::Number).map_err(|_| format!("'{}' is not a number", number))
+                }
+                Some(a) if a.is_ascii_alphabetic() => {
+                    let name = self.take(|a| a.is_ascii_alphanumeric() || a == '_').to_owned();
+                    match name.as_str() {
+                        "rate" | "sum" if self.eat('(') => {
+                            let expr = Box::new(self.expr()?);
+                            self.close()?;
+                            Ok(if name == "rate" { Expr::Rate(expr) } else { Expr::Sum(expr) })
+                        }
+                        _ => Ok(Expr::Ref(name.clone(), self.index(&name)?)),
+                    }
+                }
+                Some(a) => Err(format!("unexpected '{}' at {}", a, self.position + 1)),
+                None => Err("the expression ends too early".to_owned()),
+            }
+        }
+
+        /// the instance index after a name, written `[10.0.0.1]` or `.3`
+        fn index(&mut self, name: &str) -> Result<Option<String>, String> {
+            let index = match self.text[self.position..].chars().next() {
+                Some('[') => {
+                    self.position += 1;
+                    let index = self.take(|a| a != ']').trim().to_owned();
+                    if !self.eat(']') {
+                        return Err(format!("missing ']' after {}[{}", name, index));
+                    }
+                    index
+                }
+                Some('.') if self.text[self.position + 1..].starts_with(|a: char| a.is_ascii_digit()) => {
+                    self.position += 1;
+                    self.take(|a| a.is_ascii_digit() || a == '.').to_owned()
+                }
+                _ => return Ok(None),
+            };
+            match !index.is_empty() && index.split('.').all(|a| !a.is_empty() && a.chars().all(|a| a.is_ascii_digit())) {
+                true => Ok(Some(index)),
+                false => Err(format!("'{}' is not an instance index of {}", index, name)),
+            }
+        }
+
+        fn close(&mut self) -> Result<(), String> {
+            match self.eat(')') {
+                true => Ok(()),
+                false => Err(format!("missing ')' at {}", self.position + 1)),
+            }
+        }
+    }
+
+    impl Expr {
+        pub fn parse(text: &str) -> Result<Self, String> {
+            let mut parser = Parser { text, position: 0 };
+            let expr = parser.expr()?;
+            match parser.peek() {
+                None => Ok(expr),
+                Some(a) => Err(format!("unexpected '{}' at {}", a, parser.position + 1)),
+            }
+        }
+
+        /// the objects named and the index they are named with, and whether they are summed
+        fn references(&self, summed: bool, found: &mut Vec<(String, Option<String>, bool)>) {
+            match self {
+                Expr::Number(_) => {}
+                Expr::Ref(name, index) => found.push((name.clone(), index.clone(), summed)),
+                Expr::Neg(expr) | Expr::Rate(expr) => expr.references(summed, found),
+                Expr::Sum(expr) => expr.references(true, found),
+                Expr::Binary(_, left, right) => {
+                    left.references(summed, found);
+                    right.references(summed, found);
+                }
+            }
+        }
+
+        /// whether the polled instance `name`, like `ifInOctets.3`, is read by the expression
+        pub fn reads(&self, name: &str) -> bool {
+            let mut found = vec![];
+            self.references(false, &mut found);
+            found.iter().any(|(object, index, summed)| match (index, summed) {
+                (Some(index), _) => name == [object, ".", index].concat(),
+                (None, true) => name.strip_prefix(object.as_str()).is_some_and(|a| a.starts_with('.')),
+                (None, false) => name == [object, ".0"].concat(),
+            })
+        }
+
+        /// fails naming the first object the instances `names` do not have
+        pub fn check(&self, names: &[String]) -> Result<(), String> {
+            let mut found = vec![];
+            self.references(false, &mut found);
+            if found.is_empty() {
+                return Err("the expression reads no polled object".to_owned());
+            }
+            for (object, index, summed) in found {
+                let expr = Expr::Ref(object.clone(), index.clone());
+                let expr = if summed { Expr::Sum(Box::new(expr)) } else { expr };
+                if names.iter().any(|name| expr.reads(name)) {
+                    continue;
+                }
+                let prefix = [object.as_str(), "."].concat();
+                return Err(match index {
+                    None if names.iter().any(|name| name.starts_with(&prefix)) => {
+                        format!("{} has several instances, name one like {}[1] or add them up with sum({})", object, object, object)
+                    }
+                    None => format!("{} is not polled", object),
+                    Some(index) => format!("{} has no instance {}", object, index),
+                });
+            }
+            Ok(())
+        }
+
+        /// how many polls before the latest the expression reads, one for every nested rate
+        fn depth(&self) -> usize {
+            match self {
+                Expr::Number(_) | Expr::Ref(_, _) => 0,
+                Expr::Neg(expr) | Expr::Sum(expr) => expr.depth(),
+                Expr::Rate(expr) => expr.depth() + 1,
+                Expr::Binary(_, left, right) => left.depth().max(right.depth()),
+            }
+        }
+
+        /// the instance indexes that the objects named without an index have in `inputs`
+        fn instances(&self, inputs: &BTreeMap<String, Vec<(i64, i64)>>) -> BTreeSet<String> {
+            let mut found = vec![];
+            self.references(false, &mut found);
+            found
+                .iter()
+                .filter(|a| a.1.is_none())
+                .flat_map(|(object, _, _)| inputs.keys().filter_map(move |name| name.strip_prefix(object.as_str())?.strip_prefix('.').map(|a| a.to_owned())))
+                .collect()
+        }
+
+        /// the expression at every poll of `inputs`, with the objects named without an index
+        /// read at `instance`
+        fn evaluate(&self, inputs: &BTreeMap<String, Vec<(i64, i64)>>, instance: &str) -> Values {
+            match self {
+                Expr::Number(number) => Values::Constant(*number),
+                Expr::Ref(object, index) => {
+                    let name = [object, ".", index.as_deref().unwrap_or(instance)].concat();
+                    Values::Series(inputs.get(&name).into_iter().flatten().map(|(timestamp, value)| (*timestamp, *value as f64)).collect())
+                }
+                Expr::Neg(expr) => Expr::Binary(Op::Sub, Box::new(Expr::Number(0.0)), expr.clone()).evaluate(inputs, instance),
+                Expr::Binary(op, left, right) => match (left.evaluate(inputs, instance), right.evaluate(inputs, instance)) {
+                    (Values::Constant(a), Values::Constant(b)) => Values::Constant(op.apply(a, b).unwrap_or(f64::NAN)),
+                    (Values::Series(a), Values::Constant(b)) => Values::Series(a.into_iter().filter_map(|(t, a)| Some((t, op.apply(a, b)?))).collect()),
+                    (Values::Constant(a), Values::Series(b)) => Values::Series(b.into_iter().filter_map(|(t, b)| Some((t, op.apply(a, b)?))).collect()),
+                    (Values::Series(a), Values::Series(b)) => Values::Series(a.into_iter().filter_map(|(t, a)| Some((t, op.apply(a, *b.get(&t)?)?))).collect()),
+                },
+                Expr::Rate(expr) => match expr.evaluate(inputs, instance) {
+                    Values::Constant(_) => Values::Constant(0.0),
+                    Values::Series(series) => {
+                        let points = series.into_iter().collect::<Vec<(i64, f64)>>();
+                        Values::Series(
+                            points
+                                .windows(2)
+                                .filter(|a| a[1].0 > a[0].0 && a[1].1 >= a[0].1)
+                                .map(|a| (a[1].0, (a[1].1 - a[0].1) / (a[1].0 - a[0].0) as f64))
+                                .collect(),
+                        )
+                    }
+                },
+                Expr::Sum(expr) => {
+                    let instances = expr.instances(inputs);
+                    if instances.is_empty() {
+                        return expr.evaluate(inputs, instance);
+                    }
+                    let mut total = BTreeMap::new();
+                    for instance in instances {
+                        if let Values::Series(series) = expr.evaluate(inputs, &instance) {
+                            series.into_iter().for_each(|(t, value)| *total.entry(t).or_insert(0.0) += value);
+                        }
+                    }
+                    Values::Series(total)
+                }
+            }
+        }
+    }
+
+    /// a series computed from polled values, like the utilisation of an interface from its
+    /// octet counters and speed
+    #[derive(Clone)]
+    pub struct Derived {
+        pub name: String,
+        /// the expression as typed
+        pub text: String,
+        expr: Expr,
+        pub unit: String,
+        pub side: Side,
+        /// the latest points of every instance the expression reads, by instance name, as many
+        /// as the next poll needs
+        inputs: BTreeMap<String, Vec<(i64, i64)>>,
+        pub points: Vec<(i64, f64)>,
+    }
+
+    impl Derived {
+        /// a series without points, named like its expression when `name` is empty
+        pub fn new(name: &str, text: &str, unit: &str, side: Side) -> Result<Self, String> {
+            let expr = Expr::parse(text)?;
+            let name = if name.trim().is_empty() { text.trim() } else { name.trim() };
+            Ok(Derived { name: name.to_owned(), text: text.trim().to_owned(), expr, unit: unit.trim().to_owned(), side, inputs: BTreeMap::new(), points: vec![] })
+        }
+
+        /// fails when an object of the expression is not among the polled instances `names`
+        pub fn check(&self, names: &[String]) -> Result<(), String> {
+            self.expr.check(names)
+        }
+
+        /// fills the series from the history stored for `target` between `from` and `to`
+        pub fn history(mut self, tsdb: &Mutex<Tsdb>, target: &str, (from, to): (i64, i64), max_points: usize) -> Self {
+            let mut tsdb = tsdb.lock().unwrap();
+            let series = tsdb.series(target).ok().flatten().unwrap_or_default();
+            for (key, name) in series.into_iter().filter(|(_, name)| self.expr.reads(name)) {
+                match tsdb.query_span(target, &key, from, to, max_points) {
+                    Ok(points) => self.inputs.entry(name).or_default().extend(points),
+                    Err(err) => println!("could not load history of {} for {}: {}", key, target, err),
+                }
+            }
+            self.inputs.values_mut().for_each(|points| points.sort());
+            self.points = match self.expr.evaluate(&self.inputs, "0") {
+                Values::Series(series) => series.into_iter().collect(),
+                Values::Constant(_) => vec![],
+            };
+            self.trim();
+            self
+        }
+
+        /// adds the samples of one poll the expression reads and evaluates it at that poll only
+        pub fn add(&mut self, timestamp: i64, samples: &[MibSample]) {
+            let mut read = false;
+            for sample in samples.iter().filter(|sample| self.expr.reads(&sample.name)) {
+                self.inputs.entry(sample.name.clone()).or_default().push((timestamp, sample.value));
+                read = true;
+            }
+            if !read {
+                return;
+            }
+            if let Values::Series(series) = self.expr.evaluate(&self.inputs, "0") {
+                match series.get(&timestamp) {
+                    Some(value) if self.points.last().is_none_or(|a| a.0 < timestamp) => self.points.push((timestamp, *value)),
+                    _ => {}
+                }
+            }
+            self.trim();
+        }
+
+        /// drops the inputs older than the polls the next evaluation reads
+        fn trim(&mut self) {
+            let keep = self.expr.depth() + 1;
+            for points in self.inputs.values_mut() {
+                points.drain(..points.len().saturating_sub(keep));
+            }
+        }
+    }
+
+    /// the series added on top of each other, each point raised by the points of the series
+    /// before it at the same time
+    pub fn stack(series: &[Vec<(i64, f64)>]) -> Vec<Vec<(i64, f64)>> {
+        let mut total: BTreeMap<i64, f64> = BTreeMap::new();
+        series
+            .iter()
+            .map(|points| {
+                points
+                    .iter()
+                    .map(|(t, value)| {
+                        let stacked = total.get(t).copied().unwrap_or(0.0) + value;
+                        total.insert(*t, stacked);
+                        (*t, stacked)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// maps the values of the right axis onto the range of the left one, so both share the
+    /// plot; the ranges include zero so filled areas of both sides start at the same line
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Scale {
+        left: (f64, f64),
+        right: (f64, f64),
+    }
+
+    impl Scale {
+        /// the identity when either side has no values
+        pub fn new(left: &[f64], right: &[f64]) -> Self {
+            let range = |values: &[f64]| {
+                let (min, max) = values.iter().fold((0.0f64, 0.0f64), |(min, max), a| (min.min(*a), max.max(*a)));
+                (min, if max > min { max } else { min + 1.0 })
+            };
+            match left.is_empty() || right.is_empty() {
+                true => Scale { left: (0.0, 1.0), right: (0.0, 1.0) },
+                false => Scale { left: range(left), right: range(right) },
+            }
+        }
+
+        /// where the right axis value `value` is drawn
+        pub fn apply(&self, value: f64) -> f64 {
+            self.left.0 + (value - self.right.0) * (self.left.1 - self.left.0) / (self.right.1 - self.right.0)
+        }
+
+        /// the right axis value drawn at `y`
+        pub fn invert(&self, y: f64) -> f64 {
+            self.right.0 + (y - self.left.0) * (self.right.1 - self.right.0) / (self.left.1 - self.left.0)
+        }
+    }
+
+    /// `value` with an SI prefix once it reaches a thousand, followed by `unit`
+    pub fn format_value(value: f64, unit: &str) -> String {
+        let prefixes = ["", "k", "M", "G", "T", "P"];
+        let exponent = ((value.abs().max(1.0).log10() / 3.0) as usize).min(prefixes.len() - 1);
+        let scaled = value / 1000f64.powi(exponent as i32);
+        let number = match exponent == 0 && scaled.fract() == 0.0 {
+            true => format!("{}", scaled),
+            false => format!("{:.2}", scaled).trim_end_matches('0').trim_end_matches('.').to_owned(),
+        };
+        match (prefixes[exponent], unit) {
+            ("", "") => number,
+            (prefix, unit) => format!("{} {}{}", number, prefix, unit),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn sample(name: &str, value: i64) -> MibSample {
+            MibSample { key: name.to_owned(), name: name.to_owned(), value }
+        }
+
+        #[test]
+        fn test_parse() {
+            let expr = Expr::parse("(ifInOctets[3] + ifOutOctets.3) * 8").unwrap();
+            assert_eq!(
+                expr,
+                Expr::Binary(
+                    Op::Mul,
+                    Box::new(Expr::Binary(Op::Add, Box::new(Expr::Ref("ifInOctets".to_owned(), Some("3".to_owned()))), Box::new(Expr::Ref("ifOutOctets".to_owned(), Some("3".to_owned()))))),
+                    Box::new(Expr::Number(8.0)),
+                )
+            );
+            assert_eq!(Expr::parse("ipRouteMetric1[10.0.0.0]").unwrap(), Expr::Ref("ipRouteMetric1".to_owned(), Some("10.0.0.0".to_owned())));
+            assert_eq!(Expr::parse("-sum(rate(ifInOctets))").unwrap(), Expr::Neg(Box::new(Expr::Sum(Box::new(Expr::Rate(Box::new(Expr::Ref("ifInOctets".to_owned(), None))))))));
+            assert!(Expr::parse("ifInOctets[3").is_err());
+            assert!(Expr::parse("ifInOctets[x]").is_err());
+            assert!(Expr::parse("(tcpOutSegs").is_err());
+            assert!(Expr::parse("tcpOutSegs tcpRetransSegs").is_err());
+            assert!(Expr::parse("tcpOutSegs /").is_err());
+        }
+
+        #[test]
+        fn test_check() {
+            let names = ["tcpOutSegs.0", "ifInOctets.1", "ifInOctets.3"].map(|a| a.to_owned());
+            assert!(Expr::parse("tcpOutSegs / 2").unwrap().check(&names).is_ok());
+            assert!(Expr::parse("ifInOctets[3] + sum(ifInOctets)").unwrap().check(&names).is_ok());
+            assert_eq!(Expr::parse("ifInOctets[7]").unwrap().check(&names), Err("ifInOctets has no instance 7".to_owned()));
+            assert!(Expr::parse("ifInOctets").unwrap().check(&names).unwrap_err().contains("sum(ifInOctets)"));
+            assert_eq!(Expr::parse("tcpRetransSegs").unwrap().check(&names), Err("tcpRetransSegs is not polled".to_owned()));
+            assert!(Expr::parse("8 * 100").unwrap().check(&names).is_err());
+        }
+
+        #[test]
+        fn test_derived() {
+            let mut utilisation = Derived::new("", "rate(ifInOctets[3]+ifOutOctets[3])*8/ifSpeed[3]*100", "%", Side::Right).unwrap();
+            let mut total = Derived::new("in", "sum(rate(ifInOctets))", "B/s", Side::Left).unwrap();
+            let mut retransmits = Derived::new("retransmits", "tcpRetransSegs/tcpOutSegs", "", Side::Left).unwrap();
+            let polls = [
+                (0, vec![sample("ifInOctets.1", 0), sample("ifInOctets.3", 1000), sample("ifOutOctets.3", 0), sample("ifSpeed.3", 8000), sample("tcpOutSegs.0", 0), sample("tcpRetransSegs.0", 0)]),
+                (10, vec![sample("ifInOctets.1", 500), sample("ifInOctets.3", 3000), sample("ifOutOctets.3", 3000), sample("ifSpeed.3", 8000), sample("tcpOutSegs.0", 200), sample("tcpRetransSegs.0", 5)]),
+                // ifInOctets.3 was reset, so this poll has no rate for it
+                (20, vec![sample("ifInOctets.1", 1500), sample("ifInOctets.3", 10), sample("ifOutOctets.3", 3000), sample("ifSpeed.3", 8000), sample("tcpOutSegs.0", 400), sample("tcpRetransSegs.0", 5)]),
+            ];
+            for (timestamp, samples) in polls {
+                [&mut utilisation, &mut total, &mut retransmits].into_iter().for_each(|a| a.add(timestamp, &samples));
+            }
+            assert_eq!(utilisation.name, "rate(ifInOctets[3]+ifOutOctets[3])*8/ifSpeed[3]*100");
+            assert_eq!(utilisation.points, vec![(10, 50.0)]);
+            assert_eq!(total.points, vec![(10, 250.0), (20, 100.0)]);
+            // the first poll divides by zero
+            assert_eq!(retransmits.points, vec![(10, 0.025), (20, 0.0125)]);
+            // only the polls the next rate is computed from are kept
+            assert!(total.inputs.values().all(|points| points.len() == 2));
+            assert!(retransmits.inputs.values().all(|points| points.len() == 1));
+        }
+
+        #[test]
+        fn test_stack_and_scale() {
+            let stacked = stack(&[vec![(0, 1.0), (10, 2.0)], vec![(0, 3.0), (20, 4.0)], vec![(10, 1.0)]]);
+            assert_eq!(stacked, vec![vec![(0, 1.0), (10, 2.0)], vec![(0, 4.0), (20, 4.0)], vec![(10, 3.0)]]);
+
+            let scale = Scale::new(&[0.0, 50.0, 100.0], &[2e9, 4e9]);
+            assert_eq!(scale.apply(4e9), 100.0);
+            assert_eq!(scale.apply(0.0), 0.0);
+            assert_eq!(scale.invert(25.0), 1e9);
+            assert_eq!(Scale::new(&[], &[5.0]).apply(5.0), 5.0);
+
+            assert_eq!(format_value(12.0, ""), "12");
+            assert_eq!(format_value(0.126, "%"), "0.13 %");
+            assert_eq!(format_value(2_500_000.0, "bit/s"), "2.5 Mbit/s");
+        }
+    }
+}
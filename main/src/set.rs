@@ -0,0 +1,232 @@
+pub mod SetModule {
+    use chrono::Utc;
+    use csnmp::{ObjectIdentifier, ObjectValue, Snmp2cClient, SnmpClientError};
+    use serde::{Deserialize, Serialize};
+    use std::fs::{self, OpenOptions};
+    use std::io::{self, Write};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use crate::enums::EnumModule;
+    use crate::mibobject::MibModule::{MibObject, MibValue};
+
+    /// one instance of a writable object and the text typed for its new value
+    #[derive(Clone, Debug)]
+    pub struct Edit {
+        /// the whole column, so the value keeps its type and name
+        pub value: MibValue,
+        pub position: usize,
+        pub input: String,
+    }
+
+    impl Edit {
+        /// starts editing from the current value
+        pub fn new(value: MibValue, position: usize) -> Self {
+            let input = value.display_values().get(position).cloned().unwrap_or_default();
+            Edit { value, position, input }
+        }
+
+        /// finds the writable instance named like `ifAdminStatus.3` or `sysName.0`
+        pub fn find(object: &MibObject, instance: &str) -> Result<Self, String> {
+            let (name, index) = instance.trim().split_once('.').ok_or(format!("'{}' names no instance, e.g. sysName.0", instance.trim()))?;
+            let value = object.values().into_iter().find(|a| a.get_name() == name).ok_or(format!("{} is not polled", name))?;
+            if !value.is_mutable() {
+                return Err(format!("{} is read-only", name));
+            }
+            let position = (0..value.display_values().len())
+                .find(|position| suffix(&value.instance_index(*position)) == index)
+                .ok_or(format!("{} has no instance {}", name, index))?;
+            Ok(Edit::new(value.clone(), position))
+        }
+
+        pub fn name(&self) -> String {
+            [self.value.get_name(), suffix(&self.value.instance_index(self.position))].join(".")
+        }
+
+        pub fn oid(&self) -> String {
+            let oid = self.value.get_oid().iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".");
+            [oid, suffix(&self.value.instance_index(self.position))].join(".")
+        }
+
+        pub fn old(&self) -> String {
+            self.value.display_values().get(self.position).cloned().unwrap_or_default()
+        }
+
+        /// the input as a value of the object's type
+        pub fn parse(&self) -> Result<ObjectValue, String> {
+            parse(&self.value, &self.old(), &self.input)
+        }
+    }
+
+    fn suffix(index: &[u32]) -> String {
+        index.iter().map(|a| a.to_string()).collect::<Vec<String>>().join(".")
+    }
+
+    /// the bytes of text written as hex octets like `00:1a:2b`, the way binary OCTET STRINGs are shown
+    fn hex(text: &str) -> Option<Vec<u8>> {
+        text.contains(':').then_some(())?;
+        text.split(':').map(|a| Some(a).filter(|a| a.len() == 2).and_then(|a| u8::from_str_radix(a, 16).ok())).collect()
+    }
+
+    /// `text` as a value of the type of `value`; INTEGERs also take the labels of their enum and
+    /// strings shown as hex octets take hex octets
+    pub fn parse(value: &MibValue, old: &str, text: &str) -> Result<ObjectValue, String> {
+        let text = text.trim();
+        match value {
+            MibValue::string(_) => match hex(old) {
+                Some(_) if text.is_empty() => Ok(ObjectValue::String(vec![])),
+                Some(_) => hex(text).map(ObjectValue::String).ok_or("expected hex octets like 00:1a:2b".to_owned()),
+                None => Ok(ObjectValue::String(text.as_bytes().to_vec())),
+            },
+            MibValue::inti32(_) => match text.parse::<i32>() {
+                Ok(number) => Ok(ObjectValue::Integer(number)),
+                Err(_) => EnumModule::value(&value.get_name(), text)
+                    .map(|a| ObjectValue::Integer(a as i32))
+                    .ok_or(format!("expected an integer or a label of {}", value.get_name())),
+            },
+            MibValue::intu32(_) => text.parse::<u32>().map(ObjectValue::Unsigned32).map_err(|_| "expected an integer from 0 to 4294967295".to_owned()),
+            MibValue::intu64(_) => Err("Counter64 objects cannot be written".to_owned()),
+            MibValue::oid(_) => ObjectIdentifier::from_str(text.trim_start_matches('.')).map(ObjectValue::ObjectId).map_err(|_| "expected an OID like 1.3.6.1.4.1".to_owned()),
+            MibValue::ipv4(_) => text.parse::<Ipv4Addr>().map(ObjectValue::IpAddress).map_err(|_| "expected an IPv4 address".to_owned()),
+            MibValue::mac(_) => hex(text).filter(|a| a.len() == 6).map(ObjectValue::String).ok_or("expected a MAC address like 00:1a:2b:3c:4d:5e".to_owned()),
+            // atPhysAddress is kept as ipv6 too, so hex octets are taken as well
+            MibValue::ipv6(_) => match text.parse::<Ipv6Addr>() {
+                Ok(address) => Ok(ObjectValue::String(address.octets().to_vec())),
+                Err(_) => hex(text).map(ObjectValue::String).ok_or("expected an IPv6 address or hex octets".to_owned()),
+            },
+        }
+    }
+
+    /// one object instance of a SET, as recorded in the audit log
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Change {
+        pub name: String,
+        pub oid: String,
+        pub old: String,
+        pub new: String,
+    }
+
+    /// the changes sent to one target in a single SetRequest, which the agent applies all or none of
+    #[derive(Clone, Debug)]
+    pub struct SetRequest {
+        pub target: String,
+        pub changes: Vec<Change>,
+        bindings: Vec<(ObjectIdentifier, ObjectValue)>,
+    }
+
+    impl SetRequest {
+        /// fails with the first edit whose input does not parse
+        pub fn new(target: &str, edits: &[Edit]) -> Result<Self, String> {
+            let mut request = SetRequest { target: target.to_owned(), changes: vec![], bindings: vec![] };
+            for edit in edits {
+                let value = edit.parse().map_err(|err| format!("{}: {}", edit.name(), err))?;
+                let oid = ObjectIdentifier::from_str(&edit.oid()).map_err(|_| format!("{}: bad OID {}", edit.name(), edit.oid()))?;
+                request.changes.push(Change { name: edit.name(), oid: edit.oid(), old: edit.old(), new: edit.input.trim().to_owned() });
+                request.bindings.push((oid, value));
+            }
+            Ok(request)
+        }
+    }
+
+    /// the answer to a `SetRequest`, one line of the audit log
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct SetOutcome {
+        pub timestamp: i64,
+        pub target: String,
+        pub changes: Vec<Change>,
+        /// `None` when the agent applied every change
+        pub error: Option<String>,
+    }
+
+    impl SetOutcome {
+        pub fn new(request: &SetRequest, error: Option<String>) -> Self {
+            SetOutcome { timestamp: Utc::now().timestamp(), target: request.target.clone(), changes: request.changes.clone(), error }
+        }
+
+        pub fn summary(&self) -> String {
+            let names = self.changes.iter().map(|a| a.name.as_str()).collect::<Vec<&str>>().join(", ");
+            match &self.error {
+                None => format!("set {} on {}", names, self.target),
+                Some(err) => format!("setting {} on {} failed: {}", names, self.target, err),
+            }
+        }
+    }
+
+    /// the error status of a refused request, naming the change the agent blamed
+    fn describe(changes: &[Change], err: &SnmpClientError) -> String {
+        match err {
+            SnmpClientError::ErrorResponse { error_status, error_index } => match (*error_index as usize).checked_sub(1).and_then(|a| changes.get(a)) {
+                Some(change) => format!("{} for {}", error_status, change.name),
+                None => error_status.to_string(),
+            },
+            err => err.to_string(),
+        }
+    }
+
+    /// sends every change of `request` in one SetRequest PDU
+    pub async fn apply(client: &Snmp2cClient, request: &SetRequest) -> SetOutcome {
+        let error = client.set_multiple(request.bindings.clone()).await.err().map(|err| describe(&request.changes, &err));
+        SetOutcome::new(request, error)
+    }
+
+    pub fn audit(path: &str, outcome: &SetOutcome) -> io::Result<()> {
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut log = OpenOptions::new().append(true).create(true).open(path)?;
+        log.write_all([serde_json::to_string(outcome)?, "\n".to_owned()].concat().as_bytes())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::testing::{set_int, set_string};
+        use csnmp::message::ErrorStatus;
+
+        #[test]
+        fn test_parse() {
+            let object = MibObject::new();
+            let status = &object.interfaces.ifTable.ifAdminStatus;
+            assert_eq!(parse(status, "1", "2"), Ok(ObjectValue::Integer(2)));
+            assert_eq!(parse(status, "1", "down"), Ok(ObjectValue::Integer(2)));
+            assert!(parse(status, "1", "sideways").is_err());
+            // only the labels of the object's own enum
+            assert!(parse(status, "1", "true").is_err());
+            assert!(parse(&object.ip.ipDefaultTTL, "64", "true").is_err());
+            let name = &object.system.sysName;
+            assert_eq!(parse(name, "core-1", "core-2"), Ok(ObjectValue::String(b"core-2".to_vec())));
+            // a string shown as hex octets is written back as bytes
+            assert_eq!(parse(name, "00:1a", "0a:ff"), Ok(ObjectValue::String(vec![0x0a, 0xff])));
+            assert!(parse(name, "00:1a", "core-2").is_err());
+            let address = &object.at.atTable.atNetAddress;
+            assert_eq!(parse(address, "", "10.0.0.1"), Ok(ObjectValue::IpAddress(Ipv4Addr::new(10, 0, 0, 1))));
+            assert!(parse(address, "", "10.0.0").is_err());
+        }
+
+        #[test]
+        fn test_request() {
+            let mut object = MibObject::new();
+            set_int(&mut object.interfaces.ifTable.ifAdminStatus, vec![1, 1], vec![vec![1], vec![3]]);
+            set_string(&mut object.system.sysLocation, vec!["rack 4"], vec![vec![0]]);
+            assert!(Edit::find(&object, "ifOperStatus.3").is_err());
+            assert!(Edit::find(&object, "ifAdminStatus.7").is_err());
+
+            let mut status = Edit::find(&object, "ifAdminStatus.3").unwrap();
+            status.input = "down".to_owned();
+            let mut location = Edit::find(&object, "sysLocation.0").unwrap();
+            location.input = "rack 5".to_owned();
+            let request = SetRequest::new("10.0.0.1", &[status.clone(), location]).unwrap();
+            assert_eq!(request.changes[0], Change { name: "ifAdminStatus.3".to_owned(), oid: "1.3.6.1.2.1.2.2.1.7.3".to_owned(), old: "1".to_owned(), new: "down".to_owned() });
+            assert_eq!(request.bindings[1].1, ObjectValue::String(b"rack 5".to_vec()));
+
+            let refused = SnmpClientError::ErrorResponse { error_status: ErrorStatus::WrongValue, error_index: 2 };
+            assert_eq!(describe(&request.changes, &refused), "wrongValue for sysLocation.0");
+            let refused = SnmpClientError::ErrorResponse { error_status: ErrorStatus::GenErr, error_index: 0 };
+            assert_eq!(describe(&request.changes, &refused), "genErr");
+
+            status.input = "sideways".to_owned();
+            assert!(SetRequest::new("10.0.0.1", &[status]).unwrap_err().starts_with("ifAdminStatus.3"));
+        }
+    }
+}
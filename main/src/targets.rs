@@ -0,0 +1,146 @@
+pub mod TargetsModule {
+    use std::collections::HashMap;
+    use std::fs;
+    use std::io;
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+
+    use crate::config::ConfigModule::{Config, PollProfile, TargetConfig};
+
+    /// poll interval of targets whose profile is not configured
+    const DEFAULT_INTERVAL_SECS: u64 = 30;
+
+    pub type Targets = Arc<Mutex<TargetList>>;
+
+    /// every monitored device and when it is due to be polled next
+    pub struct TargetList {
+        targets_file: String,
+        profiles: Vec<PollProfile>,
+        targets: Vec<TargetConfig>,
+        /// how many of `targets` come from the config rather than the targets file
+        configured: usize,
+        next_poll: HashMap<String, i64>,
+        /// the outcome of the last discovery sweep
+        pub discovery_status: String,
+    }
+
+    impl TargetList {
+        /// the configured targets followed by those added earlier, or the local agent when there
+        /// are none
+        pub fn load(config: &Config) -> Self {
+            let targets_file = config.discovery.targets_file.clone();
+            let discovered = match fs::read_to_string(&targets_file) {
+                Ok(contents) => serde_json::from_str::<Vec<TargetConfig>>(&contents).unwrap_or_else(|err| {
+                    println!("could not parse {}: {}", targets_file, err);
+                    vec![]
+                }),
+                Err(_) => vec![],
+            };
+            let mut list = TargetList {
+                targets_file,
+                profiles: config.profiles.clone(),
+                targets: config.targets.clone(),
+                configured: config.targets.len(),
+                next_poll: HashMap::new(),
+                discovery_status: String::new(),
+            };
+            discovered.into_iter().for_each(|target| {
+                list.insert(target);
+            });
+            if list.targets.is_empty() {
+                list.insert(TargetConfig::new("127.0.0.1", "public"));
+                list.configured = 1;
+            }
+            list
+        }
+
+        pub fn targets(&self) -> &[TargetConfig] {
+            &self.targets
+        }
+
+        fn insert(&mut self, target: TargetConfig) -> bool {
+            if self.targets.iter().any(|a| a.address == target.address) {
+                return false;
+            }
+            self.targets.push(target);
+            true
+        }
+
+        /// starts monitoring `target` unless its address already is, remembering it across
+        /// restarts
+        pub fn add(&mut self, target: TargetConfig) -> bool {
+            if !self.insert(target) {
+                return false;
+            }
+            if let Err(err) = self.save() {
+                println!("could not save targets to {}: {}", self.targets_file, err);
+            }
+            true
+        }
+
+        fn save(&self) -> io::Result<()> {
+            if let Some(dir) = Path::new(&self.targets_file).parent() {
+                fs::create_dir_all(dir)?;
+            }
+            let tmp = [self.targets_file.as_str(), ".tmp"].concat();
+            fs::write(&tmp, serde_json::to_string_pretty(&self.targets[self.configured..])?)?;
+            fs::rename(tmp, &self.targets_file)
+        }
+
+        pub fn interval(&self, target: &TargetConfig) -> u64 {
+            self.profiles
+                .iter()
+                .find(|profile| profile.name == target.profile)
+                .map(|profile| profile.interval_secs.max(1))
+                .unwrap_or(DEFAULT_INTERVAL_SECS)
+        }
+
+        /// the targets due for a poll at `now`, scheduling their next one
+        pub fn due(&mut self, now: i64) -> Vec<TargetConfig> {
+            let due = self
+                .targets
+                .iter()
+                .filter(|target| self.next_poll.get(&target.address).is_none_or(|next| *next <= now))
+                .cloned()
+                .collect::<Vec<TargetConfig>>();
+            for target in &due {
+                let interval = self.interval(target) as i64;
+                self.next_poll.insert(target.address.clone(), now + interval);
+            }
+            due
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_schedule() {
+            let file = std::env::temp_dir().join(format!("snmp-monitor-targets-{}.json", std::process::id()));
+            let _ = fs::remove_file(&file);
+            let mut config = Config::default();
+            config.discovery.targets_file = file.to_string_lossy().into_owned();
+            config.profiles = vec![PollProfile { name: "slow".to_owned(), interval_secs: 300 }];
+            let mut targets = TargetList::load(&config);
+            assert_eq!(targets.targets()[0].address, "127.0.0.1");
+
+            let mut slow = TargetConfig::new("192.0.2.1", "private");
+            slow.profile = "slow".to_owned();
+            assert!(targets.add(slow.clone()));
+            assert!(!targets.add(slow));
+            assert_eq!(targets.due(1000).len(), 2);
+            assert!(targets.due(1029).is_empty());
+            assert_eq!(targets.due(1030)[0].address, "127.0.0.1");
+            assert_eq!(targets.due(1299)[0].address, "127.0.0.1");
+            let due = targets.due(1300);
+            assert_eq!((due.len(), due[0].address.as_str()), (1, "192.0.2.1"));
+
+            // discovered targets come back after a restart
+            let reloaded = TargetList::load(&config);
+            assert_eq!(reloaded.targets().len(), 1);
+            assert_eq!(reloaded.targets()[0].community, "private");
+            let _ = fs::remove_file(&file);
+        }
+    }
+}
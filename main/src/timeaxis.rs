@@ -0,0 +1,181 @@
+pub mod TimeAxisModule {
+    use chrono::{Local, NaiveDateTime, Offset, TimeZone};
+    use egui_plot::{GridInput, GridMark};
+    use serde::{Deserialize, Serialize};
+
+    /// grid steps in seconds that fall on round clock times
+    const STEPS: &[f64] = &[
+        1.0, 5.0, 10.0, 30.0, 60.0, 300.0, 600.0, 1800.0, 3600.0, 3.0 * 3600.0, 6.0 * 3600.0, 12.0 * 3600.0, 86400.0,
+        7.0 * 86400.0, 28.0 * 86400.0, 364.0 * 86400.0,
+    ];
+
+    /// how many typical poll intervals may pass between two points before the line is broken
+    const GAP_INTERVALS: i64 = 3;
+
+    /// the span of time a plot shows
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Window {
+        #[default]
+        Hour,
+        SixHours,
+        Day,
+        Week,
+        /// between two times typed by the user
+        Custom,
+    }
+
+    impl Window {
+        pub const ALL: [Window; 5] = [Window::Hour, Window::SixHours, Window::Day, Window::Week, Window::Custom];
+
+        pub fn label(&self) -> &'static str {
+            match self {
+                Window::Hour => "1h",
+                Window::SixHours => "6h",
+                Window::Day => "24h",
+                Window::Week => "7d",
+                Window::Custom => "custom",
+            }
+        }
+
+        /// the length of the window, `None` for a custom one
+        pub fn secs(&self) -> Option<i64> {
+            match self {
+                Window::Hour => Some(3600),
+                Window::SixHours => Some(6 * 3600),
+                Window::Day => Some(86400),
+                Window::Week => Some(7 * 86400),
+                Window::Custom => None,
+            }
+        }
+    }
+
+    /// the usual time between two points, the median so that a few missed polls do not count
+    pub fn interval<T>(points: &[(i64, T)]) -> Option<i64> {
+        let mut steps = points.windows(2).map(|a| a[1].0 - a[0].0).filter(|a| *a > 0).collect::<Vec<i64>>();
+        steps.sort();
+        steps.get(steps.len() / 2).copied()
+    }
+
+    /// the points in time order between `from` and `to`, with the one before and the one after
+    /// so that lines still run to the edges of the window
+    pub fn clip<T>(points: &[(i64, T)], from: i64, to: i64) -> &[(i64, T)] {
+        let start = points.partition_point(|a| a.0 < from).saturating_sub(1);
+        let end = (points.partition_point(|a| a.0 <= to) + 1).min(points.len());
+        &points[start..end.max(start)]
+    }
+
+    /// the points as runs of `[time, value]`, split wherever polls are missing
+    pub fn segments(points: &[(i64, f64)]) -> Vec<Vec<[f64; 2]>> {
+        let gap = interval(points).map(|a| a * GAP_INTERVALS);
+        let mut segments: Vec<Vec<[f64; 2]>> = vec![];
+        for (position, (timestamp, value)) in points.iter().enumerate() {
+            let broken = match (position.checked_sub(1).map(|a| points[a].0), gap) {
+                (Some(previous), Some(gap)) => timestamp - previous > gap,
+                (Some(_), None) => false,
+                (None, _) => true,
+            };
+            if broken {
+                segments.push(vec![]);
+            }
+            segments.last_mut().unwrap().push([*timestamp as f64, *value]);
+        }
+        segments
+    }
+
+    fn offset() -> f64 {
+        Local::now().offset().fix().local_minus_utc() as f64
+    }
+
+    /// grid lines on round local times, with the thicker lines on the larger of three steps
+    pub fn grid(input: GridInput) -> Vec<GridMark> {
+        let step = |at_least: f64| STEPS.iter().copied().find(|a| *a >= at_least).unwrap_or(at_least);
+        let smallest = step(input.base_step_size);
+        let steps = [smallest, step(smallest * 4.0), step(step(smallest * 4.0) * 4.0)];
+        let offset = offset();
+        let (min, max) = input.bounds;
+        let mut marks = vec![];
+        for step_size in steps {
+            let first = ((min + offset) / step_size).ceil() as i64;
+            let last = ((max + offset) / step_size).floor() as i64;
+            marks.extend((first..=last).map(|i| GridMark { value: i as f64 * step_size - offset, step_size }));
+        }
+        marks
+    }
+
+    /// the local time at `x`, with the date once the plot spans more than a day and the seconds
+    /// when it spans less than ten minutes
+    pub fn label(x: f64, span: f64) -> String {
+        let format = match span {
+            span if span < 600.0 => "%H:%M:%S",
+            span if span <= 86400.0 => "%H:%M",
+            span if span <= 60.0 * 86400.0 => "%m-%d %H:%M",
+            _ => "%Y-%m-%d",
+        };
+        match Local.timestamp_opt(x.round() as i64, 0).single() {
+            Some(time) => time.format(format).to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// a local time typed as `2024-01-31 14:00`, seconds optional
+    pub fn parse_local(text: &str) -> Option<i64> {
+        let text = text.trim();
+        let time = NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S").or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M")).ok()?;
+        Local.from_local_datetime(&time).earliest().map(|a| a.timestamp())
+    }
+
+    pub fn format_local(timestamp: i64) -> String {
+        match Local.timestamp_opt(timestamp, 0).single() {
+            Some(time) => time.format("%Y-%m-%d %H:%M").to_string(),
+            None => timestamp.to_string(),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_segments() {
+            // polled every 30 s, with the polls between 90 and 240 lost and a slower poll after
+            let points = [(0, 1.0), (30, 2.0), (60, 3.0), (90, 4.0), (240, 5.0), (270, 6.0), (330, 7.0)];
+            assert_eq!(interval(&points), Some(30));
+            let segments = segments(&points);
+            assert_eq!(segments.len(), 2);
+            assert_eq!(segments[0].last(), Some(&[90.0, 4.0]));
+            assert_eq!(segments[1], vec![[240.0, 5.0], [270.0, 6.0], [330.0, 7.0]]);
+            assert_eq!(super::segments(&[(5, 1.0)]), vec![vec![[5.0, 1.0]]]);
+            assert!(super::segments(&[]).is_empty());
+        }
+
+        #[test]
+        fn test_clip() {
+            let points = [(0, 1), (30, 2), (60, 3), (90, 4), (120, 5)];
+            assert_eq!(clip(&points, 40, 80), &[(30, 2), (60, 3), (90, 4)]);
+            assert_eq!(clip(&points, 30, 60), &[(0, 1), (30, 2), (60, 3), (90, 4)]);
+            assert_eq!(clip(&points, 200, 300), &[(120, 5)]);
+            assert_eq!(clip(&points, -100, -50), &[(0, 1)]);
+            assert!(clip::<i32>(&[], 0, 10).is_empty());
+        }
+
+        #[test]
+        fn test_grid() {
+            let marks = grid(GridInput { bounds: (0.0, 7200.0), base_step_size: 200.0 });
+            // the thinnest lines every 5 minutes of local time
+            assert!(marks.iter().filter(|a| a.step_size == 300.0).all(|a| ((a.value + offset()) % 300.0).abs() < 1e-6));
+            assert_eq!(marks.iter().filter(|a| a.step_size == 300.0).count(), 25);
+            assert!(marks.iter().any(|a| a.step_size == 1800.0));
+        }
+
+        #[test]
+        fn test_parse_local() {
+            let timestamp = parse_local("2024-01-31 14:05").unwrap();
+            assert_eq!(format_local(timestamp), "2024-01-31 14:05");
+            assert_eq!(parse_local("2024-01-31 14:05:30"), Some(timestamp + 30));
+            assert_eq!(parse_local("yesterday"), None);
+            assert_eq!(label(timestamp as f64, 3600.0), "14:05");
+            assert_eq!(label(timestamp as f64, 3.0 * 86400.0), "01-31 14:05");
+        }
+    }
+}
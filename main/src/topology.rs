@@ -0,0 +1,510 @@
+pub mod TopologyModule {
+    use egui::{Align2, Color32, FontId, Pos2, Rect, Sense, Stroke, Ui, Vec2};
+    use std::collections::{HashMap, HashSet};
+    use std::net::Ipv4Addr;
+    use std::sync::{Arc, Mutex};
+
+    use crate::mibobject::MibModule::{table_maps, MibObject};
+
+    /// ipNetToMediaType and ipRouteType value of deleted entries
+    const INVALID: &str = "2";
+    /// ipRouteType of routes to directly connected networks
+    const DIRECT: &str = "3";
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum NodeKind {
+        /// a monitored device that forwards packets, or any next hop of a route
+        Router,
+        /// a monitored device that does not forward
+        Device,
+        Subnet,
+        /// an address only known from an ARP table
+        Host,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Status {
+        Up,
+        /// some of the interfaces behind the node are up
+        Partial,
+        Down,
+        Unknown,
+    }
+
+    impl Status {
+        /// the status of an interface from its ifOperStatus
+        pub(crate) fn from_oper_status(oper_status: Option<&String>) -> Self {
+            match oper_status.map(|a| a.as_str()) {
+                Some("1") => Status::Up,
+                Some("2") | Some("6") | Some("7") => Status::Down,
+                Some(_) => Status::Partial,
+                None => Status::Unknown,
+            }
+        }
+
+        /// the status of a node whose interfaces have the statuses `statuses`
+        fn combine(statuses: impl Iterator<Item = Status>) -> Self {
+            let statuses = statuses.filter(|a| *a != Status::Unknown).collect::<Vec<Status>>();
+            if statuses.is_empty() {
+                Status::Unknown
+            } else if statuses.iter().all(|a| *a == Status::Up) {
+                Status::Up
+            } else if statuses.iter().all(|a| *a == Status::Down) {
+                Status::Down
+            } else {
+                Status::Partial
+            }
+        }
+
+        pub fn color(&self) -> Color32 {
+            match self {
+                Status::Up => Color32::from_rgb(60, 180, 75),
+                Status::Partial => Color32::from_rgb(230, 180, 30),
+                Status::Down => Color32::from_rgb(220, 50, 50),
+                Status::Unknown => Color32::GRAY,
+            }
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct Node {
+        pub id: String,
+        pub kind: NodeKind,
+        pub label: String,
+        pub details: Vec<String>,
+        pub status: Status,
+        /// statuses of the interfaces attaching the node, combined into `status`
+        links: Vec<Status>,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Edge {
+        pub from: String,
+        pub to: String,
+        pub label: String,
+        pub status: Status,
+    }
+
+    /// routers, subnets and hosts of every monitored device and how they connect
+    #[derive(Default)]
+    pub struct Graph {
+        pub nodes: Vec<Node>,
+        pub edges: Vec<Edge>,
+        positions: HashMap<String, usize>,
+        /// the ends of every edge, so that adding one does not look through all of them
+        ends: HashSet<(String, String)>,
+    }
+
+    /// the graph of the latest polls of every target, rebuilt after each poll rather than for
+    /// every frame it is drawn in
+    pub type Topology = Arc<Mutex<Arc<Graph>>>;
+
+    impl Graph {
+        pub(crate) fn node(&mut self, id: &str, kind: NodeKind, label: &str) -> &mut Node {
+            let position = *self.positions.entry(id.to_owned()).or_insert_with(|| {
+                self.nodes.push(Node { id: id.to_owned(), kind, label: label.to_owned(), details: vec![], status: Status::Unknown, links: vec![] });
+                self.nodes.len() - 1
+            });
+            &mut self.nodes[position]
+        }
+
+        pub fn get(&self, id: &str) -> Option<&Node> {
+            self.positions.get(id).map(|position| &self.nodes[*position])
+        }
+
+        pub(crate) fn edge(&mut self, from: &str, to: &str, label: String, status: Status) {
+            if from == to || !self.ends.insert((from.to_owned(), to.to_owned())) {
+                return;
+            }
+            self.edges.push(Edge { from: from.to_owned(), to: to.to_owned(), label, status });
+            for id in [from, to] {
+                if let Some(position) = self.positions.get(id) {
+                    self.nodes[*position].links.push(status);
+                }
+            }
+        }
+    }
+
+    fn parse_ipv4(text: &str) -> Option<u32> {
+        text.parse::<Ipv4Addr>().ok().map(u32::from)
+    }
+
+    fn subnet_id(network: u32, prefix: u32) -> String {
+        format!("subnet:{}/{}", Ipv4Addr::from(network), prefix)
+    }
+
+    /// the ifOperStatus and ifDescr of every ifIndex of `object`
+    fn interfaces(object: &MibObject) -> HashMap<String, (String, String)> {
+        table_maps(&object.interfaces.ifTable.values())
+            .into_iter()
+            .filter_map(|(_, row)| Some((row.get("ifIndex")?.clone(), (row.get("ifOperStatus")?.clone(), row.get("ifDescr").cloned().unwrap_or_default()))))
+            .collect()
+    }
+
+    /// correlates the address, route and ARP tables of `objects` into one graph: every device is
+    /// attached to the subnets of its addresses, next hops become routers attached to the
+    /// subnets they are in and to the networks routed through them, and ARP entries become hosts
+    pub fn build(objects: &[&MibObject]) -> Graph {
+        let mut graph = Graph::default();
+        // every address of a monitored device, and every subnet they are in
+        let mut owners: HashMap<u32, String> = HashMap::new();
+        let mut subnets: HashSet<(u32, u32)> = HashSet::new();
+        let device_id = |object: &MibObject| ["device:", object.target.as_str()].concat();
+
+        for object in objects {
+            let id = device_id(object);
+            let sys_name = object.system.sysName.display_values().first().cloned().unwrap_or_default();
+            let kind = match object.ip.ipForwarding.display_values().first().map(|a| a.as_str()) {
+                Some("1") => NodeKind::Router,
+                _ => NodeKind::Device,
+            };
+            let label = if sys_name.is_empty() { object.target.clone() } else { sys_name };
+            graph.node(&id, kind, &label).details.push(format!("polled at {}", object.target));
+            let interfaces = interfaces(object);
+            for (_, row) in table_maps(&object.ip.ipAddrTable.values()) {
+                let (address, mask) = match (row.get("ipAdEntAddr").and_then(|a| parse_ipv4(a)), row.get("ipAdEntNetMask").and_then(|a| parse_ipv4(a))) {
+                    (Some(address), Some(mask)) if address != 0 && address >> 24 != 127 => (address, mask),
+                    _ => continue,
+                };
+                let prefix = mask.leading_ones();
+                let network = address & mask;
+                owners.insert(address, id.clone());
+                subnets.insert((network, prefix));
+                let if_index = row.get("ipAdEntIfIndex").cloned().unwrap_or_default();
+                let (oper_status, descr) = interfaces.get(&if_index).cloned().unwrap_or_default();
+                let subnet = subnet_id(network, prefix);
+                graph.node(&subnet, NodeKind::Subnet, &format!("{}/{}", Ipv4Addr::from(network), prefix));
+                graph.node(&id, kind, &label).details.push(format!("{} on {}", Ipv4Addr::from(address), descr));
+                let status = Status::from_oper_status((!oper_status.is_empty()).then_some(&oper_status));
+                graph.edge(&id, &subnet, format!("{} {}", Ipv4Addr::from(address), descr).trim().to_owned(), status);
+            }
+        }
+
+        // the longest known subnet an address lies in
+        let subnet_of = |address: u32| {
+            (1..=32u32)
+                .rev()
+                .map(|prefix| (address & (u32::MAX << (32 - prefix)), prefix))
+                .find(|subnet| subnets.contains(subnet))
+                .map(|(network, prefix)| subnet_id(network, prefix))
+        };
+
+        for object in objects {
+            let id = device_id(object);
+            let interfaces = interfaces(object);
+            for (_, row) in table_maps(&object.ip.ipRouteTable.values()) {
+                let route_type = row.get("ipRouteType").map(|a| a.as_str()).unwrap_or_default();
+                let (dest, mask, next_hop) = match (
+                    row.get("ipRouteDest").and_then(|a| parse_ipv4(a)),
+                    row.get("ipRouteMask").and_then(|a| parse_ipv4(a)),
+                    row.get("ipRouteNextHop").and_then(|a| parse_ipv4(a)),
+                ) {
+                    (Some(dest), Some(mask), Some(next_hop)) if route_type != INVALID && route_type != DIRECT && next_hop != 0 => (dest, mask, next_hop),
+                    _ => continue,
+                };
+                if owners.get(&next_hop) == Some(&id) {
+                    continue;
+                }
+                let status = Status::from_oper_status(row.get("ipRouteIfIndex").and_then(|a| interfaces.get(a)).map(|a| &a.0));
+                let router = match owners.get(&next_hop) {
+                    Some(owner) => {
+                        if let Some(position) = graph.positions.get(owner) {
+                            graph.nodes[*position].kind = NodeKind::Router;
+                        }
+                        owner.clone()
+                    }
+                    None => {
+                        let router = format!("router:{}", Ipv4Addr::from(next_hop));
+                        graph.node(&router, NodeKind::Router, &Ipv4Addr::from(next_hop).to_string()).details.push("next hop, not monitored".to_owned());
+                        if let Some(subnet) = subnet_of(next_hop) {
+                            graph.edge(&router, &subnet, Ipv4Addr::from(next_hop).to_string(), status);
+                        }
+                        router
+                    }
+                };
+                let prefix = mask.leading_ones();
+                let network = subnet_id(dest & mask, prefix);
+                let label = match prefix {
+                    0 => "default".to_owned(),
+                    _ => format!("{}/{}", Ipv4Addr::from(dest & mask), prefix),
+                };
+                if graph.get(&network).is_none() {
+                    graph.node(&network, NodeKind::Subnet, &label).details.push("routed, not attached to a monitored device".to_owned());
+                }
+                graph.edge(&router, &network, format!("via {}", Ipv4Addr::from(next_hop)), status);
+            }
+        }
+
+        for object in objects {
+            let interfaces = interfaces(object);
+            for (_, row) in table_maps(&object.ip.ipNetToMediaTable.values()) {
+                let address = match row.get("ipNetToMediaNetAddress").and_then(|a| parse_ipv4(a)) {
+                    Some(address) if row.get("ipNetToMediaType").map(|a| a.as_str()) != Some(INVALID) && !owners.contains_key(&address) => address,
+                    _ => continue,
+                };
+                let subnet = match subnet_of(address) {
+                    Some(subnet) => subnet,
+                    None => continue,
+                };
+                let status = Status::from_oper_status(row.get("ipNetToMediaIfIndex").and_then(|a| interfaces.get(a)).map(|a| &a.0));
+                let router = format!("router:{}", Ipv4Addr::from(address));
+                let id = match graph.get(&router) {
+                    Some(_) => router,
+                    None => format!("host:{}", Ipv4Addr::from(address)),
+                };
+                let node = graph.node(&id, NodeKind::Host, &Ipv4Addr::from(address).to_string());
+                let mac = format!("mac {}", row.get("ipNetToMediaPhysAddress").cloned().unwrap_or_default());
+                if !node.details.contains(&mac) {
+                    node.details.push(mac);
+                }
+                graph.edge(&id, &subnet, String::new(), status);
+            }
+        }
+
+        for node in graph.nodes.iter_mut() {
+            node.status = Status::combine(node.links.iter().copied());
+        }
+        graph
+    }
+
+    /// node positions settled by a force-directed layout, in layout units around the origin
+    #[derive(Default)]
+    pub struct Layout {
+        pub positions: HashMap<String, Vec2>,
+        /// how far a node may still move in one step, cooling down as the layout settles
+        temperature: f32,
+    }
+
+    impl Layout {
+        /// preferred edge length
+        const DISTANCE: f32 = 80.0;
+
+        /// whether the layout is still moving
+        pub fn settling(&self) -> bool {
+            self.temperature > 0.5
+        }
+
+        /// moves every node except `pinned` one step towards the balance of repulsion between
+        /// all nodes and attraction along edges
+        pub fn step(&mut self, graph: &Graph, pinned: Option<&str>) {
+            let count = graph.nodes.len();
+            for (position, node) in graph.nodes.iter().enumerate() {
+                if !self.positions.contains_key(&node.id) {
+                    // new nodes start on a spiral so that no two share a spot
+                    let angle = position as f32 * 2.4;
+                    let radius = Self::DISTANCE * (1.0 + position as f32).sqrt();
+                    self.positions.insert(node.id.clone(), Vec2::angled(angle) * radius);
+                    self.temperature = self.temperature.max(Self::DISTANCE);
+                }
+            }
+            self.positions.retain(|id, _| graph.positions.contains_key(id));
+
+            let at = |id: &str| self.positions[id];
+            let mut forces = vec![Vec2::ZERO; count];
+            for a in 0..count {
+                for b in a + 1..count {
+                    let delta = at(&graph.nodes[a].id) - at(&graph.nodes[b].id);
+                    let distance = delta.length().max(1.0);
+                    let force = delta / distance * (Self::DISTANCE * Self::DISTANCE / distance);
+                    forces[a] += force;
+                    forces[b] -= force;
+                }
+            }
+            for edge in &graph.edges {
+                let (from, to) = (graph.positions[&edge.from], graph.positions[&edge.to]);
+                let delta = at(&edge.to) - at(&edge.from);
+                let force = delta * (delta.length() / Self::DISTANCE);
+                forces[from] += force;
+                forces[to] -= force;
+            }
+            for (node, force) in graph.nodes.iter().zip(forces) {
+                if pinned == Some(node.id.as_str()) {
+                    continue;
+                }
+                let position = self.positions.get_mut(&node.id).unwrap();
+                // a weak pull to the centre keeps unconnected parts from drifting away
+                let force = force - *position * 0.05;
+                let length = force.length();
+                if length > 0.0 {
+                    *position += force / length * length.min(self.temperature);
+                }
+            }
+            self.temperature *= 0.95;
+        }
+    }
+
+    /// the topology tab: drag nodes to move them, drag the background to pan, scroll to zoom
+    /// and click a node for its details
+    pub struct TopologyView {
+        layout: Layout,
+        offset: Vec2,
+        zoom: f32,
+        dragged: Option<String>,
+        selected: Option<String>,
+    }
+
+    impl Default for TopologyView {
+        fn default() -> Self {
+            TopologyView { layout: Layout::default(), offset: Vec2::ZERO, zoom: 1.0, dragged: None, selected: None }
+        }
+    }
+
+    impl TopologyView {
+        fn to_screen(&self, rect: Rect, position: Vec2) -> Pos2 {
+            rect.center() + self.offset + position * self.zoom
+        }
+
+        /// the node drawn under `pointer`
+        fn node_at(&self, graph: &Graph, rect: Rect, pointer: Pos2) -> Option<String> {
+            graph
+                .nodes
+                .iter()
+                .find(|node| self.layout.positions.get(&node.id).is_some_and(|a| (self.to_screen(rect, *a) - pointer).length() < 12.0))
+                .map(|node| node.id.clone())
+        }
+
+        pub fn show(&mut self, ui: &mut Ui, graph: &Graph) {
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "{} routers, {} subnets, {} hosts",
+                    graph.nodes.iter().filter(|a| a.kind == NodeKind::Router || a.kind == NodeKind::Device).count(),
+                    graph.nodes.iter().filter(|a| a.kind == NodeKind::Subnet).count(),
+                    graph.nodes.iter().filter(|a| a.kind == NodeKind::Host).count(),
+                ));
+                for status in [Status::Up, Status::Partial, Status::Down, Status::Unknown] {
+                    ui.colored_label(status.color(), format!("{:?}", status).to_lowercase());
+                }
+                if ui.button("re-layout").clicked() {
+                    self.layout = Layout::default();
+                }
+            });
+            if let Some(node) = self.selected.as_ref().and_then(|id| graph.get(id)) {
+                ui.label(format!("{} ({:?}, {:?}): {}", node.label, node.kind, node.status, node.details.join(", ")).to_lowercase());
+            }
+
+            let (response, painter) = ui.allocate_painter(ui.available_size(), Sense::click_and_drag());
+            let rect = response.rect;
+            self.layout.step(graph, self.dragged.as_deref());
+            if response.hovered() {
+                let (scroll, zoom) = ui.input(|i| (i.scroll_delta.y, i.zoom_delta()));
+                self.zoom = (self.zoom * zoom * (1.0 + scroll * 0.002)).clamp(0.1, 5.0);
+            }
+            if response.drag_started() {
+                self.dragged = response.interact_pointer_pos().and_then(|pointer| self.node_at(graph, rect, pointer));
+            }
+            if response.dragged() {
+                let delta = response.drag_delta();
+                match self.dragged.as_ref().and_then(|id| self.layout.positions.get_mut(id)) {
+                    Some(position) => *position += delta / self.zoom,
+                    None => self.offset += delta,
+                }
+            }
+            if response.drag_released() {
+                self.dragged = None;
+            }
+            if response.clicked() {
+                self.selected = response.interact_pointer_pos().and_then(|pointer| self.node_at(graph, rect, pointer));
+            }
+
+            let to_screen = |position: Vec2| self.to_screen(rect, position);
+            for edge in &graph.edges {
+                let (from, to) = (to_screen(self.layout.positions[&edge.from]), to_screen(self.layout.positions[&edge.to]));
+                painter.line_segment([from, to], Stroke::new(1.5, edge.status.color()));
+                if self.zoom > 0.8 && !edge.label.is_empty() {
+                    painter.text(from + (to - from) * 0.5, Align2::CENTER_CENTER, &edge.label, FontId::proportional(10.0), Color32::DARK_GRAY);
+                }
+            }
+            for node in &graph.nodes {
+                let center = to_screen(self.layout.positions[&node.id]);
+                let color = node.status.color();
+                match node.kind {
+                    NodeKind::Router => painter.rect_filled(Rect::from_center_size(center, Vec2::splat(18.0)), 3.0, color),
+                    NodeKind::Device => painter.rect_filled(Rect::from_center_size(center, Vec2::splat(16.0)), 8.0, color),
+                    NodeKind::Subnet => painter.rect_filled(Rect::from_center_size(center, Vec2::new(28.0, 12.0)), 6.0, color),
+                    NodeKind::Host => painter.circle_filled(center, 6.0, color),
+                }
+                if self.selected.as_ref() == Some(&node.id) {
+                    painter.circle_stroke(center, 14.0, Stroke::new(2.0, ui.visuals().strong_text_color()));
+                }
+                painter.text(center + Vec2::new(0.0, 12.0), Align2::CENTER_TOP, &node.label, FontId::proportional(12.0), ui.visuals().text_color());
+            }
+            if self.layout.settling() || self.dragged.is_some() {
+                ui.ctx().request_repaint();
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::testing::{set_int, set_ipv4};
+        use crate::mibobject::MibModule::{mvstring, MibValue};
+
+        /// a router on 10.0.0.1/24 (up) and 10.0.1.1/24 (down), routing 10.9.0.0/16 via
+        /// 10.0.0.254 and knowing the host 10.0.0.7
+        fn router() -> MibObject {
+            let mut object = MibObject::new();
+            object.target = "10.0.0.1".to_owned();
+            if let MibValue::string(mvstring { value, .. }) = &mut object.system.sysName {
+                *value = vec!["core".to_owned()];
+            }
+            set_int(&mut object.ip.ipForwarding, vec![1], vec![]);
+            let interfaces = &mut object.interfaces.ifTable;
+            set_int(&mut interfaces.ifIndex, vec![1, 2], vec![vec![1], vec![2]]);
+            set_int(&mut interfaces.ifOperStatus, vec![1, 2], vec![vec![1], vec![2]]);
+            let addresses = &mut object.ip.ipAddrTable;
+            let index = vec![vec![10, 0, 0, 1], vec![10, 0, 1, 1], vec![127, 0, 0, 1]];
+            set_ipv4(&mut addresses.ipAdEntAddr, vec![(10, 0, 0, 1), (10, 0, 1, 1), (127, 0, 0, 1)], index.clone());
+            set_ipv4(&mut addresses.ipAdEntNetMask, vec![(255, 255, 255, 0), (255, 255, 255, 0), (255, 0, 0, 0)], index.clone());
+            set_int(&mut addresses.ipAdEntIfIndex, vec![1, 2, 3], index);
+            let routes = &mut object.ip.ipRouteTable;
+            let index = vec![vec![10, 9, 0, 0], vec![10, 0, 0, 0]];
+            set_ipv4(&mut routes.ipRouteDest, vec![(10, 9, 0, 0), (10, 0, 0, 0)], index.clone());
+            set_ipv4(&mut routes.ipRouteMask, vec![(255, 255, 0, 0), (255, 255, 255, 0)], index.clone());
+            set_ipv4(&mut routes.ipRouteNextHop, vec![(10, 0, 0, 254), (10, 0, 0, 1)], index.clone());
+            set_int(&mut routes.ipRouteType, vec![4, 3], index.clone());
+            set_int(&mut routes.ipRouteIfIndex, vec![1, 1], index);
+            let arp = &mut object.ip.ipNetToMediaTable;
+            let index = vec![vec![1, 10, 0, 0, 7], vec![1, 10, 0, 0, 254]];
+            set_ipv4(&mut arp.ipNetToMediaNetAddress, vec![(10, 0, 0, 7), (10, 0, 0, 254)], index.clone());
+            set_int(&mut arp.ipNetToMediaIfIndex, vec![1, 1], index.clone());
+            set_int(&mut arp.ipNetToMediaType, vec![3, 3], index);
+            object
+        }
+
+        #[test]
+        fn test_build() {
+            let object = router();
+            let graph = build(&[&object]);
+            let node = |id: &str| graph.get(id).unwrap_or_else(|| panic!("no node {}", id));
+            assert_eq!((node("device:10.0.0.1").kind, node("device:10.0.0.1").status), (NodeKind::Router, Status::Partial));
+            assert_eq!(node("device:10.0.0.1").label, "core");
+            assert_eq!(node("subnet:10.0.0.0/24").status, Status::Up);
+            assert_eq!(node("subnet:10.0.1.0/24").status, Status::Down);
+            assert!(graph.get("subnet:127.0.0.0/8").is_none());
+            // the next hop is a router rather than a host, and the routed network hangs off it
+            assert_eq!(node("router:10.0.0.254").kind, NodeKind::Router);
+            assert!(graph.get("host:10.0.0.254").is_none());
+            assert!(graph.edges.iter().any(|a| a.from == "router:10.0.0.254" && a.to == "subnet:10.9.0.0/16"));
+            assert_eq!(node("host:10.0.0.7").kind, NodeKind::Host);
+            assert!(graph.edges.iter().any(|a| a.from == "host:10.0.0.7" && a.to == "subnet:10.0.0.0/24"));
+        }
+
+        #[test]
+        fn test_layout_settles() {
+            let object = router();
+            let graph = build(&[&object]);
+            let mut layout = Layout::default();
+            (0..300).for_each(|_| layout.step(&graph, None));
+            assert!(!layout.settling());
+            assert_eq!(layout.positions.len(), graph.nodes.len());
+            // no two nodes end up on top of each other
+            let positions = layout.positions.values().collect::<Vec<&Vec2>>();
+            for (a, first) in positions.iter().enumerate() {
+                for second in &positions[a + 1..] {
+                    assert!((**first - **second).length() > 10.0);
+                }
+            }
+        }
+    }
+}
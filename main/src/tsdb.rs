@@ -0,0 +1,692 @@
+pub mod TsdbModule {
+    use serde::{Deserialize, Serialize};
+    use std::collections::{BTreeMap, HashMap};
+    use std::fs::{self, File, OpenOptions};
+    use std::io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use crate::config::ConfigModule::StorageConfig;
+    use crate::mibobject::MibModule::MibSample;
+
+    const DAY: i64 = 86400;
+    const INDEX_FILE: &str = "index.json";
+    const WAL_FILE: &str = "head.wal";
+
+    /// the granularity a series is stored at; raw samples are rolled up into minute and hour buckets
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum Resolution {
+        Raw,
+        Minute,
+        Hour,
+    }
+
+    impl Resolution {
+        pub fn bucket(&self) -> i64 {
+            match self {
+                Resolution::Raw => 1,
+                Resolution::Minute => 60,
+                Resolution::Hour => 3600,
+            }
+        }
+
+        /// `raw`, `1m` or `1h`
+        pub fn parse(name: &str) -> Option<Self> {
+            [Resolution::Raw, Resolution::Minute, Resolution::Hour].into_iter().find(|a| a.prefix() == name)
+        }
+
+        fn prefix(&self) -> &'static str {
+            match self {
+                Resolution::Raw => "raw",
+                Resolution::Minute => "1m",
+                Resolution::Hour => "1h",
+            }
+        }
+
+        /// picks the finest resolution that keeps a query over `span` seconds below `max_points`,
+        /// assuming raw samples arrive every `poll_interval` seconds
+        pub fn for_span(span: i64, poll_interval: i64, max_points: usize) -> Self {
+            if span / poll_interval.max(1) <= max_points as i64 {
+                Resolution::Raw
+            } else if span / Resolution::Minute.bucket() <= max_points as i64 {
+                Resolution::Minute
+            } else {
+                Resolution::Hour
+            }
+        }
+    }
+
+    /// min/max/sum/count of all raw samples that fell into one rollup bucket
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct Aggregate {
+        pub timestamp: i64,
+        pub min: i64,
+        pub max: i64,
+        pub sum: i64,
+        pub count: u64,
+    }
+
+    impl Aggregate {
+        pub fn mean(&self) -> f64 {
+            if self.count == 0 {
+                return 0.0;
+            }
+            self.sum as f64 / self.count as f64
+        }
+
+        fn merge(&mut self, other: &Aggregate) {
+            self.min = self.min.min(other.min);
+            self.max = self.max.max(other.max);
+            self.sum = self.sum.saturating_add(other.sum);
+            self.count += other.count;
+        }
+    }
+
+    /// location of one encoded block inside a segment file
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct BlockMeta {
+        pub segment: String,
+        pub offset: u64,
+        pub len: u64,
+        pub min_ts: i64,
+        pub max_ts: i64,
+        pub count: u32,
+    }
+
+    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+    pub struct SeriesIndex {
+        pub name: String,
+        #[serde(default)]
+        pub raw: Vec<BlockMeta>,
+        #[serde(default)]
+        pub minute: Vec<BlockMeta>,
+        #[serde(default)]
+        pub hour: Vec<BlockMeta>,
+    }
+
+    impl SeriesIndex {
+        fn blocks(&self, resolution: Resolution) -> &Vec<BlockMeta> {
+            match resolution {
+                Resolution::Raw => &self.raw,
+                Resolution::Minute => &self.minute,
+                Resolution::Hour => &self.hour,
+            }
+        }
+
+        fn blocks_mut(&mut self, resolution: Resolution) -> &mut Vec<BlockMeta> {
+            match resolution {
+                Resolution::Raw => &mut self.raw,
+                Resolution::Minute => &mut self.minute,
+                Resolution::Hour => &mut self.hour,
+            }
+        }
+    }
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct IndexFile {
+        target: String,
+        series: BTreeMap<String, SeriesIndex>,
+    }
+
+    /// the on-disk store of a single target: an index, day-sized segment files per resolution and a
+    /// write-ahead log holding the samples that have not been packed into a block yet
+    struct TargetStore {
+        dir: PathBuf,
+        index: IndexFile,
+        heads: BTreeMap<String, Vec<(i64, i64)>>,
+        head_polls: usize,
+    }
+
+    impl TargetStore {
+        fn open(dir: PathBuf, target: &str) -> io::Result<Self> {
+            fs::create_dir_all(&dir)?;
+            let mut index = match fs::read_to_string(dir.join(INDEX_FILE)) {
+                Ok(contents) => serde_json::from_str::<IndexFile>(&contents).unwrap_or_else(|err| {
+                    println!("could not parse time-series index in {:?}: {}", dir, err);
+                    IndexFile::default()
+                }),
+                Err(_) => IndexFile::default(),
+            };
+            index.target = target.to_owned();
+
+            let mut store = TargetStore { dir, index, heads: BTreeMap::new(), head_polls: 0 };
+            store.replay_wal()?;
+            Ok(store)
+        }
+
+        fn replay_wal(&mut self) -> io::Result<()> {
+            let wal = match File::open(self.dir.join(WAL_FILE)) {
+                Ok(wal) => wal,
+                Err(_) => return Ok(()),
+            };
+            let mut last_ts = None;
+            for line in BufReader::new(wal).lines() {
+                let line = line?;
+                let fields = line.splitn(4, '\t').collect::<Vec<&str>>();
+                // a torn trailing line from a crash is simply dropped
+                if fields.len() != 4 {
+                    continue;
+                }
+                let (ts, value) = match (fields[0].parse::<i64>(), fields[3].parse::<i64>()) {
+                    (Ok(ts), Ok(value)) => (ts, value),
+                    _ => continue,
+                };
+                self.index.series.entry(fields[1].to_owned()).or_default().name = fields[2].to_owned();
+                self.heads.entry(fields[1].to_owned()).or_default().push((ts, value));
+                if last_ts != Some(ts) {
+                    self.head_polls += 1;
+                    last_ts = Some(ts);
+                }
+            }
+            Ok(())
+        }
+
+        fn append(&mut self, timestamp: i64, samples: &[MibSample]) -> io::Result<()> {
+            let wal = OpenOptions::new().append(true).create(true).open(self.dir.join(WAL_FILE))?;
+            let mut wal_writer = BufWriter::new(wal);
+            for sample in samples {
+                writeln!(wal_writer, "{}\t{}\t{}\t{}", timestamp, sample.key, sample.name, sample.value)?;
+                self.index.series.entry(sample.key.clone()).or_default().name = sample.name.clone();
+                self.heads.entry(sample.key.clone()).or_default().push((timestamp, sample.value));
+            }
+            wal_writer.flush()?;
+            self.head_polls += 1;
+            Ok(())
+        }
+
+        /// packs every head into raw, minute and hour blocks, then commits the index and empties the log
+        fn flush(&mut self) -> io::Result<()> {
+            let heads = std::mem::take(&mut self.heads);
+            for (key, points) in heads.into_iter() {
+                if points.is_empty() {
+                    continue;
+                }
+                let raw = self.write_block(Resolution::Raw, &points, encode_raw(&points))?;
+                let minute_aggregates = aggregate(&points, Resolution::Minute.bucket());
+                let minute = self.write_block(Resolution::Minute, &bucket_timestamps(&minute_aggregates), encode_rollup(&minute_aggregates))?;
+                let hour_aggregates = aggregate(&points, Resolution::Hour.bucket());
+                let hour = self.write_block(Resolution::Hour, &bucket_timestamps(&hour_aggregates), encode_rollup(&hour_aggregates))?;
+
+                let series = self.index.series.entry(key).or_default();
+                series.raw.push(raw);
+                series.minute.push(minute);
+                series.hour.push(hour);
+            }
+            self.save_index()?;
+            File::create(self.dir.join(WAL_FILE))?;
+            self.head_polls = 0;
+            Ok(())
+        }
+
+        fn write_block(&self, resolution: Resolution, points: &[(i64, i64)], bytes: Vec<u8>) -> io::Result<BlockMeta> {
+            let min_ts = points.first().map(|a| a.0).unwrap_or_default();
+            let max_ts = points.last().map(|a| a.0).unwrap_or_default();
+            let segment = format!("{}-{}.dat", resolution.prefix(), min_ts.div_euclid(DAY));
+            let mut file = OpenOptions::new().append(true).create(true).open(self.dir.join(&segment))?;
+            let offset = file.seek(SeekFrom::End(0))?;
+            file.write_all(&bytes)?;
+            Ok(BlockMeta { segment, offset, len: bytes.len() as u64, min_ts, max_ts, count: points.len() as u32 })
+        }
+
+        fn read_block(&self, meta: &BlockMeta) -> io::Result<Vec<u8>> {
+            let mut file = File::open(self.dir.join(&meta.segment))?;
+            file.seek(SeekFrom::Start(meta.offset))?;
+            let mut bytes = vec![0; meta.len as usize];
+            file.read_exact(&mut bytes)?;
+            Ok(bytes)
+        }
+
+        /// writes the index next to the old one and renames it over, so a crash never leaves half an index
+        fn save_index(&self) -> io::Result<()> {
+            let tmp = self.dir.join(format!("{}.tmp", INDEX_FILE));
+            fs::write(&tmp, serde_json::to_string(&self.index)?)?;
+            fs::rename(tmp, self.dir.join(INDEX_FILE))
+        }
+
+        /// the usual seconds between two raw samples of `key`, from the head or its newest block
+        fn interval(&self, key: &str) -> Option<i64> {
+            match self.heads.get(key).filter(|head| head.len() > 1) {
+                Some(head) => Some((head[head.len() - 1].0 - head[0].0) / (head.len() as i64 - 1)),
+                None => {
+                    let meta = self.index.series.get(key)?.raw.last().filter(|meta| meta.count > 1)?;
+                    Some((meta.max_ts - meta.min_ts) / (meta.count as i64 - 1))
+                }
+            }
+        }
+
+        fn query(&self, key: &str, from: i64, to: i64) -> io::Result<Vec<(i64, i64)>> {
+            let mut points = vec![];
+            if let Some(series) = self.index.series.get(key) {
+                for meta in series.raw.iter().filter(|meta| meta.max_ts >= from && meta.min_ts <= to) {
+                    points.extend(decode_raw(&self.read_block(meta)?).ok_or_else(corrupt_block)?);
+                }
+            }
+            if let Some(head) = self.heads.get(key) {
+                points.extend(head.iter().copied());
+            }
+            points.retain(|point| point.0 >= from && point.0 <= to);
+            points.sort_by_key(|point| point.0);
+            Ok(points)
+        }
+
+        fn query_rollup(&self, key: &str, resolution: Resolution, from: i64, to: i64) -> io::Result<Vec<Aggregate>> {
+            let mut buckets: BTreeMap<i64, Aggregate> = BTreeMap::new();
+            let mut aggregates = vec![];
+            if let Some(series) = self.index.series.get(key) {
+                for meta in series.blocks(resolution).iter().filter(|meta| meta.max_ts >= from && meta.min_ts <= to) {
+                    aggregates.extend(decode_rollup(&self.read_block(meta)?).ok_or_else(corrupt_block)?);
+                }
+            }
+            if let Some(head) = self.heads.get(key) {
+                aggregates.extend(aggregate(head, resolution.bucket()));
+            }
+            // buckets cut in half by a block boundary are stitched back together here
+            for agg in aggregates.into_iter().filter(|agg| agg.timestamp >= from && agg.timestamp <= to) {
+                match buckets.get_mut(&agg.timestamp) {
+                    Some(existing) => existing.merge(&agg),
+                    None => {
+                        buckets.insert(agg.timestamp, agg);
+                    }
+                }
+            }
+            Ok(buckets.into_values().collect())
+        }
+
+        /// drops every segment file whose newest block is older than the retention of its resolution
+        fn enforce_retention(&mut self, now: i64, config: &StorageConfig) -> io::Result<()> {
+            let cutoffs = [
+                (Resolution::Raw, now - config.raw_retention_days * DAY),
+                (Resolution::Minute, now - config.minute_retention_days * DAY),
+                (Resolution::Hour, now - config.hour_retention_days * DAY),
+            ];
+            let mut changed = false;
+            for (resolution, cutoff) in cutoffs {
+                let mut newest: HashMap<String, i64> = HashMap::new();
+                for series in self.index.series.values() {
+                    for meta in series.blocks(resolution) {
+                        let entry = newest.entry(meta.segment.clone()).or_insert(i64::MIN);
+                        *entry = (*entry).max(meta.max_ts);
+                    }
+                }
+                let expired = newest
+                    .into_iter()
+                    .filter(|(_, max_ts)| *max_ts < cutoff)
+                    .map(|(segment, _)| segment)
+                    .collect::<Vec<String>>();
+                if expired.is_empty() {
+                    continue;
+                }
+                for series in self.index.series.values_mut() {
+                    series.blocks_mut(resolution).retain(|meta| !expired.contains(&meta.segment));
+                }
+                for segment in expired.iter() {
+                    match fs::remove_file(self.dir.join(segment)) {
+                        Ok(_) => println!("removed expired segment {:?}", self.dir.join(segment)),
+                        Err(err) => println!("could not remove expired segment {}: {}", segment, err),
+                    }
+                }
+                changed = true;
+            }
+            if changed {
+                self.index.series.retain(|key, series| {
+                    !series.raw.is_empty() || !series.minute.is_empty() || !series.hour.is_empty() || self.heads.contains_key(key)
+                });
+                self.save_index()?;
+            }
+            Ok(())
+        }
+    }
+
+    /// embedded time-series store holding one compressed series per object instance and target
+    pub struct Tsdb {
+        root: PathBuf,
+        config: StorageConfig,
+        stores: HashMap<String, TargetStore>,
+    }
+
+    impl Tsdb {
+        pub fn open(config: StorageConfig) -> io::Result<Self> {
+            let root = PathBuf::from(&config.data_dir);
+            fs::create_dir_all(&root)?;
+            Ok(Tsdb { root, config, stores: HashMap::new() })
+        }
+
+        fn store(&mut self, target: &str) -> io::Result<&mut TargetStore> {
+            if !self.stores.contains_key(target) {
+                let dir = self.root.join(target.replace(['.', ':'], "-"));
+                self.stores.insert(target.to_owned(), TargetStore::open(dir, target)?);
+            }
+            Ok(self.stores.get_mut(target).unwrap())
+        }
+
+        /// the store of `target` if it has already written an index or a log, for lookups that must
+        /// not create a directory for every name they are asked about
+        fn existing(&mut self, target: &str) -> io::Result<Option<&mut TargetStore>> {
+            if !self.stores.contains_key(target) {
+                let dir = self.root.join(target.replace(['.', ':'], "-"));
+                if !dir.join(INDEX_FILE).exists() && !dir.join(WAL_FILE).exists() {
+                    return Ok(None);
+                }
+                self.stores.insert(target.to_owned(), TargetStore::open(dir, target)?);
+            }
+            Ok(self.stores.get_mut(target))
+        }
+
+        /// records one poll worth of samples, packing blocks once enough polls have accumulated
+        pub fn append(&mut self, target: &str, timestamp: i64, samples: &[MibSample]) -> io::Result<()> {
+            let block_samples = self.config.block_samples.max(1);
+            let config = self.config.clone();
+            let store = self.store(target)?;
+            store.append(timestamp, samples)?;
+            if store.head_polls >= block_samples {
+                store.flush()?;
+                store.enforce_retention(timestamp, &config)?;
+            }
+            Ok(())
+        }
+
+        /// raw samples of `key` between `from` and `to` (inclusive), oldest first
+        pub fn query(&mut self, target: &str, key: &str, from: i64, to: i64) -> io::Result<Vec<(i64, i64)>> {
+            match self.existing(target)? {
+                Some(store) => store.query(key, from, to),
+                None => Ok(vec![]),
+            }
+        }
+
+        pub fn query_rollup(&mut self, target: &str, key: &str, resolution: Resolution, from: i64, to: i64) -> io::Result<Vec<Aggregate>> {
+            match resolution {
+                Resolution::Raw => Ok(self
+                    .query(target, key, from, to)?
+                    .into_iter()
+                    .map(|(timestamp, value)| Aggregate { timestamp, min: value, max: value, sum: value, count: 1 })
+                    .collect()),
+                _ => match self.existing(target)? {
+                    Some(store) => store.query_rollup(key, resolution, from, to),
+                    None => Ok(vec![]),
+                },
+            }
+        }
+
+        /// the points of `key` between `from` and `to` at the finest resolution that keeps them below
+        /// `max_points` and still holds data that old, rollups read as the mean of their bucket
+        pub fn query_span(&mut self, target: &str, key: &str, from: i64, to: i64, max_points: usize) -> io::Result<Vec<(i64, i64)>> {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).map(|a| a.as_secs() as i64).unwrap_or_default();
+            let config = self.config.clone();
+            let interval = self.existing(target)?.and_then(|store| store.interval(key)).unwrap_or(Resolution::Minute.bucket());
+            let resolution = match Resolution::for_span(to.saturating_sub(from), interval, max_points) {
+                Resolution::Raw if from < now - config.raw_retention_days * DAY => Resolution::Minute,
+                resolution => resolution,
+            };
+            let resolution = match resolution {
+                Resolution::Minute if from < now - config.minute_retention_days * DAY => Resolution::Hour,
+                resolution => resolution,
+            };
+            Ok(self
+                .query_rollup(target, key, resolution, from - from.rem_euclid(resolution.bucket()), to)?
+                .into_iter()
+                .map(|agg| (agg.timestamp, agg.mean().round() as i64))
+                .collect())
+        }
+
+        /// every series stored for `target` as `(key, name)` pairs, `None` when nothing was ever
+        /// stored for it
+        pub fn series(&mut self, target: &str) -> io::Result<Option<Vec<(String, String)>>> {
+            Ok(self
+                .existing(target)?
+                .map(|store| store.index.series.iter().map(|(key, series)| (key.clone(), series.name.clone())).collect()))
+        }
+
+        /// every target that has data on disk
+        pub fn targets(&self) -> Vec<String> {
+            let mut targets = match fs::read_dir(&self.root) {
+                Ok(dir) => dir
+                    .filter_map(|entry| entry.ok())
+                    .filter_map(|entry| fs::read_to_string(entry.path().join(INDEX_FILE)).ok())
+                    .filter_map(|contents| serde_json::from_str::<IndexFile>(&contents).ok())
+                    .map(|index| index.target)
+                    .filter(|target| !target.is_empty())
+                    .collect::<Vec<String>>(),
+                Err(_) => vec![],
+            };
+            for target in self.stores.keys() {
+                if !targets.contains(target) {
+                    targets.push(target.clone());
+                }
+            }
+            targets.sort();
+            targets
+        }
+    }
+
+    fn corrupt_block() -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, "corrupt time-series block")
+    }
+
+    fn aggregate(points: &[(i64, i64)], bucket: i64) -> Vec<Aggregate> {
+        let mut buckets: BTreeMap<i64, Aggregate> = BTreeMap::new();
+        for (ts, value) in points {
+            let start = ts - ts.rem_euclid(bucket);
+            let agg = Aggregate { timestamp: start, min: *value, max: *value, sum: *value, count: 1 };
+            match buckets.get_mut(&start) {
+                Some(existing) => existing.merge(&agg),
+                None => {
+                    buckets.insert(start, agg);
+                }
+            }
+        }
+        buckets.into_values().collect()
+    }
+
+    fn bucket_timestamps(aggregates: &[Aggregate]) -> Vec<(i64, i64)> {
+        aggregates.iter().map(|agg| (agg.timestamp, 0)).collect()
+    }
+
+    fn zigzag(value: i64) -> u64 {
+        ((value << 1) ^ (value >> 63)) as u64
+    }
+
+    fn unzigzag(value: u64) -> i64 {
+        ((value >> 1) as i64) ^ -((value & 1) as i64)
+    }
+
+    fn put_varint(buf: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        buf.push(value as u8);
+    }
+
+    fn get_varint(buf: &[u8], pos: &mut usize) -> Option<u64> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = *buf.get(*pos)?;
+            *pos += 1;
+            if shift >= 64 {
+                return None;
+            }
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+            shift += 7;
+        }
+    }
+
+    /// delta-of-delta encoding: regular poll intervals and steadily growing counters both collapse to
+    /// runs of single zero bytes
+    fn encode_dod(buf: &mut Vec<u8>, values: &[i64]) {
+        let mut prev = 0i64;
+        let mut prev_delta = 0i64;
+        for value in values {
+            let delta = value.wrapping_sub(prev);
+            put_varint(buf, zigzag(delta.wrapping_sub(prev_delta)));
+            prev = *value;
+            prev_delta = delta;
+        }
+    }
+
+    fn decode_dod(buf: &[u8], pos: &mut usize, count: usize) -> Option<Vec<i64>> {
+        let mut values = Vec::with_capacity(count);
+        let mut prev = 0i64;
+        let mut prev_delta = 0i64;
+        for _ in 0..count {
+            let delta = prev_delta.wrapping_add(unzigzag(get_varint(buf, pos)?));
+            let value = prev.wrapping_add(delta);
+            values.push(value);
+            prev = value;
+            prev_delta = delta;
+        }
+        Some(values)
+    }
+
+    fn encode_raw(points: &[(i64, i64)]) -> Vec<u8> {
+        let mut buf = vec![];
+        put_varint(&mut buf, points.len() as u64);
+        encode_dod(&mut buf, &points.iter().map(|a| a.0).collect::<Vec<i64>>());
+        encode_dod(&mut buf, &points.iter().map(|a| a.1).collect::<Vec<i64>>());
+        buf
+    }
+
+    fn decode_raw(buf: &[u8]) -> Option<Vec<(i64, i64)>> {
+        let mut pos = 0;
+        let count = get_varint(buf, &mut pos)? as usize;
+        let timestamps = decode_dod(buf, &mut pos, count)?;
+        let values = decode_dod(buf, &mut pos, count)?;
+        Some(timestamps.into_iter().zip(values).collect())
+    }
+
+    fn encode_rollup(aggregates: &[Aggregate]) -> Vec<u8> {
+        let mut buf = vec![];
+        put_varint(&mut buf, aggregates.len() as u64);
+        encode_dod(&mut buf, &aggregates.iter().map(|a| a.timestamp).collect::<Vec<i64>>());
+        encode_dod(&mut buf, &aggregates.iter().map(|a| a.min).collect::<Vec<i64>>());
+        encode_dod(&mut buf, &aggregates.iter().map(|a| a.max).collect::<Vec<i64>>());
+        encode_dod(&mut buf, &aggregates.iter().map(|a| a.sum).collect::<Vec<i64>>());
+        aggregates.iter().for_each(|a| put_varint(&mut buf, a.count));
+        buf
+    }
+
+    fn decode_rollup(buf: &[u8]) -> Option<Vec<Aggregate>> {
+        let mut pos = 0;
+        let count = get_varint(buf, &mut pos)? as usize;
+        let timestamps = decode_dod(buf, &mut pos, count)?;
+        let mins = decode_dod(buf, &mut pos, count)?;
+        let maxs = decode_dod(buf, &mut pos, count)?;
+        let sums = decode_dod(buf, &mut pos, count)?;
+        let mut aggregates = Vec::with_capacity(count);
+        for i in 0..count {
+            aggregates.push(Aggregate {
+                timestamp: timestamps[i],
+                min: mins[i],
+                max: maxs[i],
+                sum: sums[i],
+                count: get_varint(buf, &mut pos)?,
+            });
+        }
+        Some(aggregates)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn test_config(name: &str) -> StorageConfig {
+            let dir = std::env::temp_dir().join(format!("snmp-monitor-tsdb-{}-{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            StorageConfig {
+                data_dir: dir.to_string_lossy().into_owned(),
+                block_samples: 4,
+                ..StorageConfig::default()
+            }
+        }
+
+        fn sample(key: &str, value: i64) -> MibSample {
+            MibSample { key: key.to_owned(), name: format!("name-{}", key), value }
+        }
+
+        #[test]
+        fn test_dod_roundtrip() {
+            let values = vec![0, 1, i64::MAX, i64::MIN, -5, 30, 60, 90, 4_294_967_295];
+            let mut buf = vec![];
+            encode_dod(&mut buf, &values);
+            let mut pos = 0;
+            assert_eq!(decode_dod(&buf, &mut pos, values.len()).unwrap(), values);
+            assert_eq!(pos, buf.len());
+        }
+
+        #[test]
+        fn test_regular_series_compresses() {
+            let points = (0..120).map(|i| (1_700_000_000 + i * 30, 1000 + i * 250)).collect::<Vec<(i64, i64)>>();
+            let bytes = encode_raw(&points);
+            assert!(bytes.len() < 260);
+            assert_eq!(decode_raw(&bytes).unwrap(), points);
+        }
+
+        #[test]
+        fn test_append_flush_query() {
+            let config = test_config("query");
+            let mut tsdb = Tsdb::open(config.clone()).unwrap();
+            for i in 0..10 {
+                tsdb.append("10.0.0.1", 1_700_000_000 + i * 30, &[sample("1.3.6.1.2.1.1.3.0", i * 100)]).unwrap();
+            }
+            let points = tsdb.query("10.0.0.1", "1.3.6.1.2.1.1.3.0", i64::MIN, i64::MAX).unwrap();
+            assert_eq!(points.len(), 10);
+            assert_eq!(points[9], (1_700_000_270, 900));
+
+            // heads survive a restart through the write-ahead log
+            drop(tsdb);
+            let mut tsdb = Tsdb::open(config.clone()).unwrap();
+            let points = tsdb.query("10.0.0.1", "1.3.6.1.2.1.1.3.0", 1_700_000_060, 1_700_000_120).unwrap();
+            assert_eq!(points, vec![(1_700_000_060, 200), (1_700_000_090, 300), (1_700_000_120, 400)]);
+            assert_eq!(tsdb.targets(), vec!["10.0.0.1".to_owned()]);
+
+            let minutes = tsdb.query_rollup("10.0.0.1", "1.3.6.1.2.1.1.3.0", Resolution::Minute, i64::MIN, i64::MAX).unwrap();
+            assert_eq!(minutes.iter().map(|a| a.count).sum::<u64>(), 10);
+            assert!(minutes.windows(2).all(|a| a[0].timestamp < a[1].timestamp));
+            let _ = fs::remove_dir_all(config.data_dir);
+        }
+
+        #[test]
+        fn test_query_span_picks_resolution() {
+            let config = StorageConfig { raw_retention_days: 100_000, minute_retention_days: 100_000, ..test_config("span") };
+            let mut tsdb = Tsdb::open(config.clone()).unwrap();
+            for i in 0..10 {
+                tsdb.append("10.0.0.3", 1_700_000_000 + i * 30, &[sample("1.3.6.1.2.1.2.2.1.10.1", i * 100)]).unwrap();
+            }
+            let raw = tsdb.query_span("10.0.0.3", "1.3.6.1.2.1.2.2.1.10.1", 1_700_000_000, 1_700_000_270, 20).unwrap();
+            assert_eq!(raw.len(), 10);
+            let minutes = tsdb.query_span("10.0.0.3", "1.3.6.1.2.1.2.2.1.10.1", 1_700_000_000, 1_700_000_270, 5).unwrap();
+            assert!(minutes.len() > 1 && minutes.len() < 10);
+            assert_eq!(minutes[0], (1_699_999_980, 50));
+            let _ = fs::remove_dir_all(config.data_dir);
+
+            // raw samples past their retention are read from the hour rollups
+            let config = test_config("span-old");
+            let mut tsdb = Tsdb::open(config.clone()).unwrap();
+            for i in 0..10 {
+                tsdb.append("10.0.0.3", 1_700_000_000 + i * 30, &[sample("1.3.6.1.2.1.2.2.1.10.1", i * 100)]).unwrap();
+            }
+            let hours = tsdb.query_span("10.0.0.3", "1.3.6.1.2.1.2.2.1.10.1", 1_700_000_000, 1_700_000_270, 1000).unwrap();
+            assert_eq!(hours, vec![(1_699_999_200, 450)]);
+            let _ = fs::remove_dir_all(config.data_dir);
+        }
+
+        #[test]
+        fn test_retention_drops_old_segments() {
+            let config = test_config("retention");
+            let mut tsdb = Tsdb::open(config.clone()).unwrap();
+            for i in 0..4 {
+                tsdb.append("10.0.0.2", 1_000 + i, &[sample("1.3.6.1.2.1.4.3.0", i)]).unwrap();
+            }
+            for i in 0..4 {
+                tsdb.append("10.0.0.2", 100 * DAY + i, &[sample("1.3.6.1.2.1.4.3.0", i)]).unwrap();
+            }
+            let points = tsdb.query("10.0.0.2", "1.3.6.1.2.1.4.3.0", i64::MIN, i64::MAX).unwrap();
+            assert_eq!(points.len(), 4);
+            assert!(points.iter().all(|a| a.0 >= 100 * DAY));
+            let _ = fs::remove_dir_all(config.data_dir);
+        }
+    }
+}
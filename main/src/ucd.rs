@@ -0,0 +1,241 @@
+pub mod UcdModule {
+    use crate::mibobject::MibModule::{row_number, table_maps, MibObject, MibValue};
+
+    /// the ssCpuRaw* counters a processor's time is split into; on Linux ssCpuRawSystem already
+    /// counts the kernel, interrupt and soft interrupt time, so those are left out of the sum
+    const CPU_STATES: &[&str] = &["user", "nice", "system", "idle", "wait", "steal"];
+
+    /// one laTable entry
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Load {
+        pub index: String,
+        /// `Load-1`, `Load-5` or `Load-15`
+        pub name: String,
+        pub load: f64,
+        /// the load above which the agent flags an error
+        pub config: String,
+        pub error: String,
+    }
+
+    /// the `memory` group in bytes
+    #[derive(Clone, Debug, Default, PartialEq)]
+    pub struct Memory {
+        pub total: u64,
+        pub available: u64,
+        pub buffer: u64,
+        pub cached: u64,
+        pub shared: u64,
+        pub swap_total: u64,
+        pub swap_available: u64,
+    }
+
+    impl Memory {
+        /// real memory in use by programs, not counting buffers and the page cache
+        pub fn used(&self) -> u64 {
+            self.total.saturating_sub(self.available).saturating_sub(self.buffer).saturating_sub(self.cached)
+        }
+    }
+
+    /// one dskTable entry in bytes
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Disk {
+        pub index: String,
+        pub path: String,
+        pub device: String,
+        pub total: u64,
+        pub available: u64,
+        pub used: u64,
+        pub percent: i64,
+        pub inode_percent: i64,
+        pub error: String,
+    }
+
+    /// the output of one extend command
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Extend {
+        pub token: String,
+        pub output: String,
+        pub lines: i64,
+        /// the exit code of the command
+        pub result: i64,
+    }
+
+    /// a scalar in KB as bytes; Integer32 sizes of large machines wrap negative on some agents
+    fn kilobytes(value: &MibValue) -> u64 {
+        value.val_as_mvinti64().unwrap_or_default().first().map(|a| *a as u32 as u64 * 1024).unwrap_or_default()
+    }
+
+    /// the 1, 5 and 15 minute load averages, from laLoadFloat when the agent sends the Opaque
+    /// float and from the text or the hundredths otherwise
+    pub fn loads(object: &MibObject) -> Vec<Load> {
+        table_maps(&object.ucd.laTable.values())
+            .into_iter()
+            .map(|(index, row)| {
+                let text = |column: &str| row.get(column).and_then(|a| a.trim().parse::<f64>().ok());
+                Load {
+                    name: row.get("laNames").cloned().unwrap_or_default(),
+                    load: text("laLoadFloat").or(text("laLoad")).unwrap_or(row_number(&row, "laLoadInt") as f64 / 100.0),
+                    config: row.get("laConfig").cloned().unwrap_or_default(),
+                    error: row.get("laErrMessage").cloned().unwrap_or_default(),
+                    index,
+                }
+            })
+            .collect()
+    }
+
+    pub fn memory(object: &MibObject) -> Memory {
+        let ucd = &object.ucd;
+        Memory {
+            total: kilobytes(&ucd.memTotalReal),
+            available: kilobytes(&ucd.memAvailReal),
+            buffer: kilobytes(&ucd.memBuffer),
+            cached: kilobytes(&ucd.memCached),
+            shared: kilobytes(&ucd.memShared),
+            swap_total: kilobytes(&ucd.memTotalSwap),
+            swap_available: kilobytes(&ucd.memAvailSwap),
+        }
+    }
+
+    /// every disk the agent watches, sizes from the 64-bit Low and High halves when the agent
+    /// has them, since dskTotal stops at 2 TB
+    pub fn disks(object: &MibObject) -> Vec<Disk> {
+        table_maps(&object.ucd.dskTable.values())
+            .into_iter()
+            .map(|(index, row)| {
+                let kilobytes = |column: &str| match row.get(&format!("{}Low", column)).filter(|a| !a.is_empty()) {
+                    Some(_) => (row_number(&row, &format!("{}High", column)) as u64) << 32 | row_number(&row, &format!("{}Low", column)) as u64,
+                    None => row_number(&row, column) as u32 as u64,
+                };
+                Disk {
+                    path: row.get("dskPath").cloned().unwrap_or_default(),
+                    device: row.get("dskDevice").cloned().unwrap_or_default(),
+                    total: kilobytes("dskTotal") * 1024,
+                    available: kilobytes("dskAvail") * 1024,
+                    used: kilobytes("dskUsed") * 1024,
+                    percent: row_number(&row, "dskPercent"),
+                    inode_percent: row_number(&row, "dskPercentNode"),
+                    error: row.get("dskErrorMsg").cloned().unwrap_or_default(),
+                    index,
+                }
+            })
+            .collect()
+    }
+
+    /// the share of processor time spent in each state between two polls, in percent; `None`
+    /// without a previous poll or when no time was counted between them
+    pub fn cpu(previous: &MibObject, current: &MibObject) -> Option<Vec<(&'static str, f64)>> {
+        let counters = |object: &MibObject| {
+            let ucd = &object.ucd;
+            [&ucd.ssCpuRawUser, &ucd.ssCpuRawNice, &ucd.ssCpuRawSystem, &ucd.ssCpuRawIdle, &ucd.ssCpuRawWait, &ucd.ssCpuRawSteal]
+                .map(|value| value.val_as_mvinti64().unwrap_or_default().first().copied())
+        };
+        let (then, now) = (counters(previous), counters(current));
+        // Counter32 ticks wrap after a few months on busy machines
+        let ticks = then
+            .iter()
+            .zip(now.iter())
+            .map(|(then, now)| match (then, now) {
+                (Some(then), Some(now)) => (*now as u32).wrapping_sub(*then as u32) as f64,
+                _ => 0.0,
+            })
+            .collect::<Vec<f64>>();
+        let total = ticks.iter().sum::<f64>();
+        (total > 0.0).then(|| CPU_STATES.iter().copied().zip(ticks.iter().map(|a| a * 100.0 / total)).collect())
+    }
+
+    /// the nsExtendToken of a row, which its index carries as a length followed by one
+    /// sub-identifier per character
+    pub fn token(index: &str) -> String {
+        let arcs = index.split('.').filter_map(|a| a.parse::<u8>().ok()).collect::<Vec<u8>>();
+        match arcs.split_first() {
+            Some((len, rest)) if *len as usize == rest.len() => String::from_utf8_lossy(rest).into_owned(),
+            _ => index.to_owned(),
+        }
+    }
+
+    pub fn extends(object: &MibObject) -> Vec<Extend> {
+        table_maps(&object.ucd.nsExtendOutput1Table.values())
+            .into_iter()
+            .map(|(index, row)| Extend {
+                token: token(&index),
+                output: row.get("nsExtendOutputFull").cloned().unwrap_or_default(),
+                lines: row_number(&row, "nsExtendOutNumLines"),
+                result: row_number(&row, "nsExtendResult"),
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::testing::{set_int, set_unsigned, set_string};
+        use crate::mibobject::MibModule::opaque_float;
+
+        #[test]
+        fn test_opaque_float() {
+            assert_eq!(opaque_float(&[0x9f, 0x78, 0x04, 0x3e, 0x19, 0x99, 0x9a]), Some("0.15".to_owned()));
+            assert_eq!(opaque_float(&[0x9f, 0x79, 0x08, 0x40, 0x09, 0x21, 0xfb, 0x54, 0x44, 0x2d, 0x18]), Some(std::f64::consts::PI.to_string()));
+            assert_eq!(opaque_float(&[0x9f, 0x78, 0x04, 0x3e]), None);
+            assert_eq!(opaque_float(b"0.15"), None);
+        }
+
+        #[test]
+        fn test_loads_and_disks() {
+            let mut object = MibObject::new();
+            let index = vec![vec![1], vec![2], vec![3]];
+            let table = &mut object.ucd.laTable;
+            set_string(&mut table.laNames, vec!["Load-1", "Load-5", "Load-15"], index.clone());
+            set_string(&mut table.laLoad, vec!["0.52", "0.40", "0.31"], index.clone());
+            set_string(&mut table.laLoadFloat, vec!["0.52", "", "0.31"], index.clone());
+            set_int(&mut table.laLoadInt, vec![52, 40, 31], index);
+            let loads = loads(&object);
+            assert_eq!(loads.iter().map(|a| a.load).collect::<Vec<f64>>(), vec![0.52, 0.40, 0.31]);
+
+            let index = vec![vec![1], vec![2]];
+            let table = &mut object.ucd.dskTable;
+            set_string(&mut table.dskPath, vec!["/", "/srv"], index.clone());
+            set_int(&mut table.dskTotal, vec![10_000_000, i32::MAX], index.clone());
+            set_int(&mut table.dskPercent, vec![40, 75], index.clone());
+            // only /srv is reported through the 64-bit halves, 5 TiB of it
+            let halves = vec![vec![2]];
+            set_unsigned(&mut table.dskTotalLow, vec![1_073_741_824], halves.clone());
+            set_unsigned(&mut table.dskTotalHigh, vec![1], halves);
+            let disks = disks(&object);
+            assert_eq!((disks[0].path.as_str(), disks[0].total), ("/", 10_240_000_000));
+            assert_eq!((disks[1].path.as_str(), disks[1].total, disks[1].percent), ("/srv", 5 << 40, 75));
+        }
+
+        #[test]
+        fn test_cpu() {
+            let poll = |user: u32, system: u32, idle: u32| {
+                let mut object = MibObject::new();
+                let ucd = &mut object.ucd;
+                set_unsigned(&mut ucd.ssCpuRawUser, vec![user], vec![vec![0]]);
+                set_unsigned(&mut ucd.ssCpuRawSystem, vec![system], vec![vec![0]]);
+                set_unsigned(&mut ucd.ssCpuRawIdle, vec![idle], vec![vec![0]]);
+                object
+            };
+            // user wraps around 2^32 between the polls
+            let cpu = cpu(&poll(u32::MAX - 99, 1000, 5000), &poll(200, 1100, 5600)).unwrap();
+            assert_eq!(cpu[0], ("user", 30.0));
+            assert_eq!(cpu[2], ("system", 10.0));
+            assert_eq!(cpu[3], ("idle", 60.0));
+            assert_eq!(super::cpu(&poll(1, 1, 1), &poll(1, 1, 1)), None);
+        }
+
+        #[test]
+        fn test_extends() {
+            let mut object = MibObject::new();
+            // "apt" and "raid"
+            let index = vec![vec![3, 97, 112, 116], vec![4, 114, 97, 105, 100]];
+            let table = &mut object.ucd.nsExtendOutput1Table;
+            set_string(&mut table.nsExtendOutputFull, vec!["12 updates", "md0 clean\nmd1 degraded"], index.clone());
+            set_int(&mut table.nsExtendOutNumLines, vec![1, 2], index.clone());
+            set_int(&mut table.nsExtendResult, vec![0, 2], index);
+            let extends = extends(&object);
+            assert_eq!((extends[0].token.as_str(), extends[0].output.as_str()), ("apt", "12 updates"));
+            assert_eq!((extends[1].token.as_str(), extends[1].lines, extends[1].result), ("raid", 2, 2));
+            assert_eq!(token("3.97"), "3.97");
+        }
+    }
+}
@@ -0,0 +1,159 @@
+pub mod UtilisationModule {
+    use std::collections::HashMap;
+
+    use crate::mibobject::MibModule::{table_rows, MibObject, MibValue};
+
+    /// ifSpeed of interfaces faster than it can tell, whose speed only ifHighSpeed has
+    const SPEED_SATURATED: u64 = u32::MAX as u64;
+
+    /// the traffic of one interface between two polls
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Utilisation {
+        pub if_index: String,
+        /// ifName, or ifDescr when the agent has no ifXTable
+        pub name: String,
+        pub alias: String,
+        /// bit/s
+        pub speed: Option<u64>,
+        /// bit/s, `None` when a counter was reset between the polls
+        pub in_rate: Option<f64>,
+        pub out_rate: Option<f64>,
+        /// percent of `speed`
+        pub in_percent: Option<f64>,
+        pub out_percent: Option<f64>,
+        /// whether the rates come from the 64-bit ifHC counters
+        pub high_capacity: bool,
+    }
+
+    /// the value of every instance of `value`, keyed by instance index
+    fn by_index(value: &MibValue) -> HashMap<String, String> {
+        table_rows(&[value]).into_iter().map(|(index, mut cells)| (index, cells.remove(0))).collect()
+    }
+
+    fn number(values: &HashMap<String, String>, index: &str) -> Option<u64> {
+        values.get(index).and_then(|a| a.parse::<u64>().ok())
+    }
+
+    /// how far a counter moved, allowing one wrap of a 32-bit counter; 64-bit counters do not
+    /// wrap in practice so going backwards means the counter was reset
+    fn delta(previous: u64, current: u64, high_capacity: bool) -> Option<u64> {
+        match (current >= previous, high_capacity) {
+            (true, _) => Some(current - previous),
+            (false, false) if previous <= SPEED_SATURATED => Some(current + (SPEED_SATURATED + 1) - previous),
+            (false, _) => None,
+        }
+    }
+
+    /// the speed of interface `index` in bit/s, from ifHighSpeed when the agent has it
+    fn speed(object: &MibObject, index: &str) -> Option<u64> {
+        let high_speed = number(&by_index(&object.interfaces.ifXTable.ifHighSpeed), index).filter(|a| *a > 0);
+        let speed = number(&by_index(&object.interfaces.ifTable.ifSpeed), index).filter(|a| *a > 0);
+        match (high_speed, speed) {
+            (Some(high_speed), _) => Some(high_speed * 1_000_000),
+            (None, Some(SPEED_SATURATED)) => None,
+            (None, speed) => speed,
+        }
+    }
+
+    /// the in and out rate and utilisation of every interface of `current`, from the octets
+    /// counted since `previous`; the 64-bit counters are used on every interface that has them
+    pub fn utilisation(previous: &MibObject, current: &MibObject) -> Vec<Utilisation> {
+        let seconds = (current.timestamp - previous.timestamp) as f64;
+        let counters = |object: &MibObject| {
+            [
+                by_index(&object.interfaces.ifXTable.ifHCInOctets),
+                by_index(&object.interfaces.ifXTable.ifHCOutOctets),
+                by_index(&object.interfaces.ifTable.ifInOctets),
+                by_index(&object.interfaces.ifTable.ifOutOctets),
+            ]
+        };
+        let (then, now) = (counters(previous), counters(current));
+        let names = by_index(&current.interfaces.ifXTable.ifName);
+        let aliases = by_index(&current.interfaces.ifXTable.ifAlias);
+        let descrs = by_index(&current.interfaces.ifTable.ifDescr);
+
+        table_rows(&[&current.interfaces.ifTable.ifIndex])
+            .into_iter()
+            .map(|(index, _)| {
+                let high_capacity = [&then[0], &now[0]].iter().all(|a| a.contains_key(&index));
+                let offset = if high_capacity { 0 } else { 2 };
+                let rate = |column: usize| {
+                    let moved = delta(number(&then[offset + column], &index)?, number(&now[offset + column], &index)?, high_capacity)?;
+                    (seconds > 0.0).then(|| moved as f64 * 8.0 / seconds)
+                };
+                let speed = speed(current, &index);
+                let percent = |rate: Option<f64>| Some(rate? * 100.0 / speed? as f64);
+                let (in_rate, out_rate) = (rate(0), rate(1));
+                Utilisation {
+                    name: names.get(&index).filter(|a| !a.is_empty()).or(descrs.get(&index)).cloned().unwrap_or_default(),
+                    alias: aliases.get(&index).cloned().unwrap_or_default(),
+                    if_index: index,
+                    speed,
+                    in_rate,
+                    out_rate,
+                    in_percent: percent(in_rate),
+                    out_percent: percent(out_rate),
+                    high_capacity,
+                }
+            })
+            .collect()
+    }
+
+    /// `bits` per second with an SI prefix
+    pub fn format_rate(bits: f64) -> String {
+        let prefixes = ["", "k", "M", "G", "T"];
+        let exponent = ((bits.max(1.0).log10() / 3.0) as usize).min(prefixes.len() - 1);
+        format!("{:.1} {}bit/s", bits / 1000f64.powi(exponent as i32), prefixes[exponent])
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::mibobject::MibModule::testing::{set_counter64, set_int, set_string, set_unsigned};
+
+        /// an object polled at `timestamp` with the 32-bit octet counters `octets` of ifIndex 1
+        /// and 2, and the 64-bit ones `hc_octets` of ifIndex 2 only
+        fn object(timestamp: i64, octets: [u32; 2], hc_octets: u64) -> MibObject {
+            let mut object = MibObject::new();
+            object.timestamp = timestamp;
+            let index = vec![vec![1], vec![2]];
+            let table = &mut object.interfaces.ifTable;
+            set_int(&mut table.ifIndex, vec![1, 2], index.clone());
+            set_string(&mut table.ifDescr, vec!["eth0", "eth1"], index.clone());
+            for column in [&mut table.ifInOctets, &mut table.ifOutOctets] {
+                set_unsigned(column, octets.to_vec(), index.clone());
+            }
+            // eth0 is a 100 Mbit/s port, eth1 a 40 Gbit/s one that saturates ifSpeed
+            set_unsigned(&mut table.ifSpeed, vec![100_000_000, u32::MAX], index);
+            let table = &mut object.interfaces.ifXTable;
+            set_unsigned(&mut table.ifHighSpeed, vec![40_000], vec![vec![2]]);
+            for column in [&mut table.ifHCInOctets, &mut table.ifHCOutOctets] {
+                set_counter64(column, vec![hc_octets], vec![vec![2]]);
+            }
+            object
+        }
+
+        #[test]
+        fn test_utilisation() {
+            // eth0's counter wraps, eth1 moves 50 GB in 10 s, far more than 32 bits can count
+            let previous = object(1000, [u32::MAX - 999, 0], 1_000_000_000_000);
+            let current = object(1010, [125_000 - 1000, 0], 1_050_000_000_000);
+            let rows = utilisation(&previous, &current);
+
+            assert_eq!((rows[0].name.as_str(), rows[0].high_capacity), ("eth0", false));
+            assert_eq!(rows[0].speed, Some(100_000_000));
+            assert_eq!(rows[0].in_rate, Some(100_000.0));
+            assert_eq!(rows[0].in_percent, Some(0.1));
+
+            assert!(rows[1].high_capacity);
+            assert_eq!(rows[1].speed, Some(40_000_000_000));
+            assert_eq!(rows[1].in_rate, Some(40_000_000_000.0));
+            assert_eq!(rows[1].out_percent, Some(100.0));
+
+            // a 64-bit counter going backwards was reset rather than wrapped
+            let reset = object(1020, [125_000, 0], 10);
+            assert_eq!(utilisation(&current, &reset)[1].in_rate, None);
+            assert_eq!(format_rate(40_000_000_000.0), "40.0 Gbit/s");
+        }
+    }
+}
@@ -0,0 +1,201 @@
+pub mod VendorModule {
+    use csnmp::{ObjectIdentifier, Snmp2cClient};
+    use serde::{Deserialize, Serialize};
+    use std::fs;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use crate::config::ConfigModule::AlertRule;
+    use crate::mibobject::MibModule::{mvinti32, mvintu32, mvintu64, mvoid, mvstring, MibValue, Vendor, VendorTable};
+
+    const SYS_OBJECT_ID: &str = "1.3.6.1.2.1.1.2.0";
+
+    /// how the values of an object are read, and so which `MibValue` they are kept in
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Syntax {
+        String,
+        /// INTEGER and Integer32
+        #[default]
+        Integer,
+        /// Counter32, Gauge32, Unsigned32 and TimeTicks
+        Unsigned,
+        Counter64,
+        Oid,
+    }
+
+    /// one object walked on matching targets, a scalar or a column of a table
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct ObjectDef {
+        pub name: String,
+        pub oid: String,
+        #[serde(default)]
+        pub syntax: Syntax,
+    }
+
+    /// columns shown together as one table, lined up by instance index
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct TableDef {
+        pub name: String,
+        pub columns: Vec<ObjectDef>,
+    }
+
+    /// a plot of every instance of `objects`, opened from the vendor tab
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct PlotDef {
+        pub name: String,
+        pub objects: Vec<String>,
+    }
+
+    /// what is polled and shown for the targets whose sysObjectID starts with `sys_object_id`,
+    /// read from one JSON file of `VendorConfig::dir`
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct VendorProfile {
+        pub name: String,
+        pub sys_object_id: String,
+        #[serde(default)]
+        pub objects: Vec<ObjectDef>,
+        #[serde(default)]
+        pub tables: Vec<TableDef>,
+        #[serde(default)]
+        pub plots: Vec<PlotDef>,
+        /// added to `AlertConfig::rules` marked with the profile, so they only fire on targets it
+        /// was applied to
+        #[serde(default)]
+        pub alerts: Vec<AlertRule>,
+    }
+
+    impl VendorProfile {
+        /// every object and column of the profile, checked to have a usable OID
+        fn definitions(&self) -> impl Iterator<Item = &ObjectDef> {
+            self.objects.iter().chain(self.tables.iter().flat_map(|table| table.columns.iter()))
+        }
+
+        fn check(&self) -> Result<(), String> {
+            if parse_oid(&self.sys_object_id).is_none() {
+                return Err(format!("sys_object_id '{}' is not an OID", self.sys_object_id));
+            }
+            match self.definitions().find(|def| parse_oid(&def.oid).is_none()) {
+                Some(def) => Err(format!("the OID '{}' of {} is not an OID of arcs below 65536", def.oid, def.name)),
+                None => Ok(()),
+            }
+        }
+
+        /// the empty values walked for a target the profile applies to
+        pub fn vendor(&self) -> Vendor {
+            Vendor {
+                profile: self.name.clone(),
+                objects: self.objects.iter().filter_map(value).collect(),
+                tables: self
+                    .tables
+                    .iter()
+                    .map(|table| VendorTable { name: table.name.clone(), columns: table.columns.iter().filter_map(value).collect() })
+                    .collect(),
+            }
+        }
+    }
+
+    fn parse_oid(oid: &str) -> Option<Vec<u16>> {
+        let arcs = oid.trim_start_matches('.').split('.').map(|a| a.parse::<u16>().ok()).collect::<Option<Vec<u16>>>()?;
+        (arcs.len() >= 2).then_some(arcs)
+    }
+
+    fn value(def: &ObjectDef) -> Option<MibValue> {
+        let (name, oid) = (def.name.clone(), parse_oid(&def.oid)?);
+        Some(match def.syntax {
+            Syntax::String => MibValue::string(mvstring { name, oid, mutable: false, value: vec![], index: vec![] }),
+            Syntax::Integer => MibValue::inti32(mvinti32 { name, oid, mutable: false, value: vec![], index: vec![] }),
+            Syntax::Unsigned => MibValue::intu32(mvintu32 { name, oid, mutable: false, value: vec![], index: vec![] }),
+            Syntax::Counter64 => MibValue::intu64(mvintu64 { name, oid, mutable: false, value: vec![], index: vec![] }),
+            Syntax::Oid => MibValue::oid(mvoid { name, oid, mutable: false, value: vec![], index: vec![] }),
+        })
+    }
+
+    /// every profile in the `.json` files of `dir`, ordered by file name; files that do not
+    /// parse or name an invalid OID are skipped with a message
+    pub fn load(dir: &str) -> Vec<VendorProfile> {
+        let mut paths = match fs::read_dir(dir) {
+            Ok(entries) => entries.filter_map(|a| a.ok()).map(|a| a.path()).filter(|a| a.extension().is_some_and(|a| a == "json")).collect::<Vec<_>>(),
+            Err(_) => return vec![],
+        };
+        paths.sort();
+        paths.iter().filter_map(|path| match read(path) {
+            Ok(profile) => Some(profile),
+            Err(err) => {
+                println!("skipping vendor profile {}: {}", path.display(), err);
+                None
+            }
+        }).collect()
+    }
+
+    fn read(path: &Path) -> Result<VendorProfile, String> {
+        let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        let profile = serde_json::from_str::<VendorProfile>(&contents).map_err(|err| err.to_string())?;
+        profile.check()?;
+        Ok(profile)
+    }
+
+    /// the profile with the longest `sys_object_id` that `sys_object_id` equals or lies under
+    pub fn matching<'a>(profiles: &'a [VendorProfile], sys_object_id: &str) -> Option<&'a VendorProfile> {
+        let sys_object_id = sys_object_id.trim_start_matches('.');
+        profiles
+            .iter()
+            .filter(|profile| {
+                let prefix = profile.sys_object_id.trim_start_matches('.');
+                sys_object_id == prefix || sys_object_id.starts_with(&[prefix, "."].concat())
+            })
+            .max_by_key(|profile| profile.sys_object_id.trim_start_matches('.').len())
+    }
+
+    /// the sysObjectID of the agent `client` talks to, with every arc intact unlike the
+    /// polled `sysObjectID` value
+    pub async fn sys_object_id(client: &Snmp2cClient) -> Option<String> {
+        let value = client.get(ObjectIdentifier::from_str(SYS_OBJECT_ID).ok()?).await.ok()?;
+        value.as_oid().map(|a| a.to_string())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn profile(name: &str, sys_object_id: &str) -> VendorProfile {
+            VendorProfile { name: name.to_owned(), sys_object_id: sys_object_id.to_owned(), objects: vec![], tables: vec![], plots: vec![], alerts: vec![] }
+        }
+
+        #[test]
+        fn test_matching() {
+            let profiles = vec![profile("cisco", "1.3.6.1.4.1.9"), profile("catalyst", "1.3.6.1.4.1.9.1.1208"), profile("juniper", "1.3.6.1.4.1.2636")];
+            assert_eq!(matching(&profiles, "1.3.6.1.4.1.9.1.1208").unwrap().name, "catalyst");
+            assert_eq!(matching(&profiles, "1.3.6.1.4.1.9.1.1745").unwrap().name, "cisco");
+            assert_eq!(matching(&profiles, ".1.3.6.1.4.1.2636.1.1.1.2.29").unwrap().name, "juniper");
+            // 1.3.6.1.4.1.90 is not under Cisco's 9
+            assert!(matching(&profiles, "1.3.6.1.4.1.90.1").is_none());
+        }
+
+        #[test]
+        fn test_profile() {
+            let json = r#"{
+                "name": "cisco",
+                "sys_object_id": "1.3.6.1.4.1.9",
+                "objects": [{"name": "avgBusy5", "oid": "1.3.6.1.4.1.9.2.1.58"}],
+                "tables": [{"name": "cpmCPUTotalTable", "columns": [
+                    {"name": "cpmCPUTotal5minRev", "oid": "1.3.6.1.4.1.9.9.109.1.1.1.1.8", "syntax": "unsigned"},
+                    {"name": "cpmCPUMemoryUsed", "oid": "1.3.6.1.4.1.9.9.109.1.1.1.1.12", "syntax": "unsigned"}
+                ]}],
+                "plots": [{"name": "cisco cpu", "objects": ["cpmCPUTotal5minRev"]}],
+                "alerts": [{"name": "cisco cpu high", "expr": "cpmCPUTotal5minRev > 90 for 10m", "severity": "critical"}]
+            }"#;
+            let profile = serde_json::from_str::<VendorProfile>(json).unwrap();
+            assert!(profile.check().is_ok());
+            let vendor = profile.vendor();
+            assert_eq!(vendor.profile, "cisco");
+            assert_eq!(vendor.objects[0].get_oid(), vec![1, 3, 6, 1, 4, 1, 9, 2, 1, 58]);
+            assert!(matches!(vendor.table("cpmCPUTotalTable").unwrap()[0], MibValue::intu32(_)));
+            assert_eq!(profile.alerts[0].expr, "cpmCPUTotal5minRev > 90 for 10m");
+
+            let mut broken = profile.clone();
+            broken.tables[0].columns[1].oid = "1.3.6.1.4.1.9.99999".to_owned();
+            assert!(broken.check().is_err());
+        }
+    }
+}